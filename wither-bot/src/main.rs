@@ -4,13 +4,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     log::info!("Hello World!");
 
-    let instance = wither_client::Client::new("localhost", 25565, "bot").await?;
+    let events = wither_client::event::EventBus::new();
+    let mut receiver = events.subscribe();
+
+    let _instance = wither_client::Client::with_events("localhost", 25565, "bot", events).await?;
 
     // client.send_packet(&protocol::server::status::StatusRequest::new()).await.unwrap();
     // client.send_packet(&protocol::server::status::PingRequest::new(0)).await.unwrap();
     // client.send_packet(&protocol::server::status::PingRequest::new(512)).await.unwrap();
 
-    instance.raw_client.get_notify("close").notified().await;
+    while let Some(event) = receiver.recv().await {
+        if let wither_client::event::Event::Disconnect(_) = event {
+            break;
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,543 @@
+use std::collections::{HashMap, HashSet};
+
+use wither_network::{
+    codec::{
+        identifier::Identifier,
+        item_component::{ItemComponent, Tool, ToolRule},
+        slot::Slot,
+        var_int::VarInt,
+    },
+    protocol::{client::config::Tag, server::play::PlayerActionStatus},
+    types::HolderSet,
+};
+use wither_util::math::vector3::Vector3;
+use wither_world::{
+    block::{BlockFace, BlockState},
+    coordinates::{BlockCoordinates, Height},
+    item::item_registry::{self, ToolBlocks},
+};
+
+use crate::world::ClientWorld;
+
+// Ids in the mob effect registry.
+pub const HASTE: i32 = 2;
+pub const MINING_FATIGUE: i32 = 3;
+pub const CONDUIT_POWER: i32 = 28;
+/// Type id of the `minecraft:tool` item component.
+const TOOL_COMPONENT: i32 = 26;
+/// How far from the eyes the player can break blocks.
+pub const BLOCK_REACH: f64 = 4.5;
+/// Ticks to wait after breaking a block before digging the next one.
+const DESTROY_DELAY: u32 = 5;
+
+/// Block tags sent by the server, keyed by name like `minecraft:mineable/pickaxe`.
+#[derive(Clone, Debug, Default)]
+pub struct BlockTags {
+    tags: HashMap<String, HashSet<u16>>,
+}
+
+impl BlockTags {
+    pub fn new(tags: &[Tag]) -> Self {
+        let tags = tags
+            .iter()
+            .map(|tag| {
+                let blocks = tag.entries.iter().map(|entry| entry.0 as u16).collect();
+                (tag.name.to_string(), blocks)
+            })
+            .collect();
+        Self { tags }
+    }
+
+    pub fn contains(&self, tag: &str, state: BlockState) -> bool {
+        self.tags
+            .get(tag)
+            .is_some_and(|blocks| blocks.contains(&state.block().id))
+    }
+}
+
+/// Everything about the player that changes how fast it breaks blocks.
+#[derive(Clone, Debug, Default)]
+pub struct Miner {
+    /// The tool component of the held item, `None` for items that are no tool.
+    pub tool: Option<Tool>,
+    pub efficiency: i32,
+    /// Amplifier of haste or conduit power, whichever is higher.
+    pub haste: Option<i32>,
+    pub mining_fatigue: Option<i32>,
+    /// Whether the helmet has aqua affinity.
+    pub aqua_affinity: bool,
+    /// Whether the eyes are in water.
+    pub underwater: bool,
+    pub on_ground: bool,
+}
+
+impl Miner {
+    /// How fast the block breaks, before dividing by its hardness.
+    pub fn destroy_speed(&self, state: BlockState, tags: &BlockTags) -> f32 {
+        let mut speed = self.tool.as_ref().map_or(1.0, |tool| {
+            tool.rules
+                .iter()
+                .filter(|rule| rule_matches(rule, state, tags))
+                .find_map(|rule| rule.speed)
+                .unwrap_or(tool.default_mining_speed)
+        });
+        if speed > 1.0 && self.efficiency > 0 {
+            speed += (self.efficiency * self.efficiency + 1) as f32;
+        }
+        if let Some(haste) = self.haste {
+            speed *= 1.0 + (haste + 1) as f32 * 0.2;
+        }
+        if let Some(mining_fatigue) = self.mining_fatigue {
+            speed *= match mining_fatigue {
+                0 => 0.3,
+                1 => 0.09,
+                2 => 0.0027,
+                _ => 8.1e-4,
+            };
+        }
+        if self.underwater && !self.aqua_affinity {
+            speed *= 0.2;
+        }
+        if !self.on_ground {
+            speed /= 5.0;
+        }
+        speed
+    }
+
+    /// Whether the block drops anything when broken with the held item.
+    pub fn has_correct_tool(&self, state: BlockState, tags: &BlockTags) -> bool {
+        !state.block().requires_correct_tool
+            || self.tool.as_ref().is_some_and(|tool| {
+                tool.rules
+                    .iter()
+                    .filter(|rule| rule_matches(rule, state, tags))
+                    .find_map(|rule| rule.correct_for_drops)
+                    .unwrap_or(false)
+            })
+    }
+
+    /// Part of the block broken every tick, it breaks once this adds up to 1.
+    pub fn destroy_progress(&self, state: BlockState, tags: &BlockTags) -> f32 {
+        let hardness = state.block().hardness;
+        if hardness < 0.0 {
+            return 0.0;
+        }
+        let divider = if self.has_correct_tool(state, tags) {
+            30.0
+        } else {
+            100.0
+        };
+        self.destroy_speed(state, tags) / hardness / divider
+    }
+
+    /// Ticks of digging after starting to break the block, 0 if it breaks right away.
+    /// `None` for blocks that can't be broken.
+    pub fn break_ticks(&self, state: BlockState, tags: &BlockTags) -> Option<u32> {
+        let progress = self.destroy_progress(state, tags);
+        if progress <= 0.0 {
+            None
+        } else if progress >= 1.0 {
+            Some(0)
+        } else {
+            Some((1.0 / progress).ceil() as u32)
+        }
+    }
+}
+
+fn rule_matches(rule: &ToolRule, state: BlockState, tags: &BlockTags) -> bool {
+    match &rule.blocks {
+        HolderSet::Tag(tag) => tags.contains(&tag.to_string(), state),
+        HolderSet::Direct(blocks) => blocks
+            .iter()
+            .any(|block| block.0 == state.block().id as i32),
+    }
+}
+
+/// The tool component of an item stack, which replaces the one of its item.
+pub fn held_tool(slot: &Slot) -> Option<Tool> {
+    if slot.is_empty() {
+        return None;
+    }
+    if let Some(tool) = slot
+        .components_to_add
+        .iter()
+        .find_map(|component| match component {
+            ItemComponent::Tool(tool) => Some(tool.clone()),
+            _ => None,
+        })
+    {
+        return Some(tool);
+    }
+    if slot.components_to_remove.contains(&VarInt(TOOL_COMPONENT)) {
+        return None;
+    }
+    let name = item_registry::ITEMS_REGISTRY_NAME_BY_ID.get(&(slot.item_id.as_ref()?.0 as u16))?;
+    let tool = item_registry::get_item(name)?.components.tool.as_ref()?;
+    Some(Tool {
+        rules: tool
+            .rules
+            .iter()
+            .map(|rule| ToolRule {
+                blocks: holder_set(&rule.blocks),
+                speed: rule.speed,
+                correct_for_drops: rule.correct_for_drops,
+            })
+            .collect(),
+        default_mining_speed: tool.default_mining_speed,
+        damage_per_block: VarInt(1),
+    })
+}
+
+fn holder_set(blocks: &ToolBlocks) -> HolderSet {
+    let names = match blocks {
+        ToolBlocks::One(name) => {
+            if let Some(tag) = name.strip_prefix('#') {
+                let (namespace, path) = tag.split_once(':').unwrap_or(("minecraft", tag));
+                return HolderSet::Tag(Identifier {
+                    namespace: namespace.to_string(),
+                    path: path.to_string(),
+                });
+            }
+            std::slice::from_ref(name)
+        }
+        ToolBlocks::Many(names) => names.as_slice(),
+    };
+    HolderSet::Direct(
+        names
+            .iter()
+            .filter_map(|name| BlockState::new(name))
+            .map(|state| VarInt(state.block().id as i32))
+            .collect(),
+    )
+}
+
+/// Level of an enchantment on the item, `enchantment` being its id in the enchantment
+/// registry the server sent.
+pub fn enchantment_level(slot: &Slot, enchantment: i32) -> i32 {
+    slot.components_to_add
+        .iter()
+        .find_map(|component| match component {
+            ItemComponent::Enchantments(enchantments) => enchantments
+                .levels
+                .iter()
+                .find(|(id, _)| id.0 == enchantment)
+                .map(|(_, level)| level.0),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+/// A `player_action` to send for digging.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DigPacket {
+    pub status: PlayerActionStatus,
+    pub position: Vector3<i32>,
+    pub face: BlockFace,
+    /// Whether the block breaks with this packet. The break is predicted until the
+    /// server acknowledges the sequence number of the packet.
+    pub breaks: bool,
+}
+
+/// What digging needs the client to do after a tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DigUpdate {
+    Send(DigPacket),
+    /// Digging the block stopped before it broke.
+    Failed(Vector3<i32>),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Dig {
+    position: Vector3<i32>,
+    face: BlockFace,
+    state: BlockState,
+    progress: f32,
+    started: bool,
+}
+
+/// Breaks blocks tick by tick like the vanilla client, which is what the server expects.
+#[derive(Debug, Default)]
+pub struct Digger {
+    target: Option<Dig>,
+    delay: u32,
+    /// Aborts of blocks dropped between ticks.
+    queued: Vec<DigUpdate>,
+    /// Blocks predicted broken, with the sequence number confirming them and the state
+    /// they had.
+    pending: Vec<(i32, Vector3<i32>, BlockState)>,
+}
+
+impl Digger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The block being broken.
+    pub fn target(&self) -> Option<Vector3<i32>> {
+        self.target.map(|dig| dig.position)
+    }
+
+    /// Starts breaking the block on the next tick, instead of the one being broken.
+    /// Digging the same block again changes nothing.
+    pub fn dig(&mut self, position: Vector3<i32>, face: BlockFace) {
+        if self.target() == Some(position) {
+            return;
+        }
+        self.stop();
+        self.target = Some(Dig {
+            position,
+            face,
+            state: BlockState::AIR,
+            progress: 0.0,
+            started: false,
+        });
+    }
+
+    /// Stops breaking the block, the server is told on the next tick.
+    pub fn stop(&mut self) {
+        let update = self.abort();
+        self.queued.extend(update);
+    }
+
+    fn abort(&mut self) -> Vec<DigUpdate> {
+        let Some(dig) = self.target.take() else {
+            return Vec::new();
+        };
+        let mut updates = Vec::new();
+        if dig.started {
+            updates.push(DigUpdate::Send(DigPacket {
+                status: PlayerActionStatus::AbortDestroyBlock,
+                position: dig.position,
+                face: dig.face,
+                breaks: false,
+            }));
+        }
+        updates.push(DigUpdate::Failed(dig.position));
+        updates
+    }
+
+    /// Continues breaking the block. Digging stops when the block changes or gets out of
+    /// reach of `eye`.
+    pub fn tick(
+        &mut self,
+        world: &ClientWorld,
+        miner: &Miner,
+        tags: &BlockTags,
+        eye: Vector3<f64>,
+    ) -> Vec<DigUpdate> {
+        let mut updates = std::mem::take(&mut self.queued);
+        if self.delay > 0 {
+            self.delay -= 1;
+            return updates;
+        }
+        let Some(dig) = self.target.as_mut() else {
+            return updates;
+        };
+        let state = block_at(world, dig.position);
+        let changed = dig.started && state != dig.state;
+        if changed || state.is_air() || distance_to_block(eye, dig.position) > BLOCK_REACH {
+            updates.extend(self.abort());
+            return updates;
+        }
+
+        let progress = miner.destroy_progress(state, tags);
+        let status = if !dig.started {
+            dig.started = true;
+            dig.state = state;
+            if progress < 1.0 {
+                updates.push(DigUpdate::Send(DigPacket {
+                    status: PlayerActionStatus::StartDestroyBlock,
+                    position: dig.position,
+                    face: dig.face,
+                    breaks: false,
+                }));
+                return updates;
+            }
+            PlayerActionStatus::StartDestroyBlock
+        } else {
+            dig.progress += progress;
+            if dig.progress < 1.0 {
+                return updates;
+            }
+            self.delay = DESTROY_DELAY;
+            PlayerActionStatus::StopDestroyBlock
+        };
+        let dig = *dig;
+        self.target = None;
+        updates.push(DigUpdate::Send(DigPacket {
+            status,
+            position: dig.position,
+            face: dig.face,
+            breaks: true,
+        }));
+        updates
+    }
+
+    /// Turns the block into air until the server confirms `sequence`.
+    pub fn predict(&mut self, world: &mut ClientWorld, sequence: i32, position: Vector3<i32>) {
+        let state = block_at(world, position);
+        self.pending.push((sequence, position, state));
+        world.set_block(coordinates(position), BlockState::AIR.state_id);
+    }
+
+    /// Handles `block_changed_ack`, returns each predicted block with whether it really
+    /// broke. The server sends the real state of rejected blocks before the ack.
+    pub fn acknowledge(&mut self, world: &ClientWorld, sequence: i32) -> Vec<(Vector3<i32>, bool)> {
+        let mut confirmed = Vec::new();
+        self.pending.retain(|&(pending, position, state)| {
+            if pending > sequence {
+                return true;
+            }
+            confirmed.push((position, block_at(world, position) != state));
+            false
+        });
+        confirmed
+    }
+}
+
+fn coordinates(position: Vector3<i32>) -> BlockCoordinates {
+    BlockCoordinates {
+        x: position.x,
+        y: Height(position.y as i16),
+        z: position.z,
+    }
+}
+
+pub(crate) fn block_at(world: &ClientWorld, position: Vector3<i32>) -> BlockState {
+    if !world.height().contains(position.y) {
+        return BlockState::AIR;
+    }
+    world.block_at(coordinates(position))
+}
+
+/// Distance from `eye` to the closest point of the block.
+fn distance_to_block(eye: Vector3<f64>, block: Vector3<i32>) -> f64 {
+    let axis = |eye: f64, block: i32| eye - eye.clamp(block as f64, block as f64 + 1.0);
+    let (x, y, z) = (
+        axis(eye.x, block.x),
+        axis(eye.y, block.y),
+        axis(eye.z, block.z),
+    );
+    (x * x + y * y + z * z).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use wither_world::item::item_registry::get_item;
+
+    use crate::world::{set_test_block, test_world};
+
+    use super::*;
+
+    fn tags() -> BlockTags {
+        BlockTags::new(&[Tag {
+            name: Identifier::vanilla("mineable/pickaxe"),
+            entries: vec![VarInt(BlockState::new("stone").unwrap().block_id as i32)],
+        }])
+    }
+
+    fn item(name: &str) -> Slot {
+        Slot {
+            item_count: VarInt(1),
+            item_id: Some(VarInt(get_item(name).unwrap().id as i32)),
+            components_to_add: Vec::new(),
+            components_to_remove: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn break_times() {
+        let tags = tags();
+        let stone = BlockState::new("stone").unwrap();
+        let hand = Miner {
+            on_ground: true,
+            ..Miner::default()
+        };
+        assert!(!hand.has_correct_tool(stone, &tags));
+        assert_eq!(hand.break_ticks(stone, &tags), Some(150));
+        // Mined with a pickaxe, but dropped without one.
+        for name in ["shulker_box", "pointed_dripstone", "ice"] {
+            assert!(hand.has_correct_tool(BlockState::new(name).unwrap(), &tags));
+        }
+        assert!(!hand.has_correct_tool(BlockState::new("cobweb").unwrap(), &tags));
+        assert_eq!(
+            hand.break_ticks(BlockState::new("dirt").unwrap(), &tags),
+            Some(15)
+        );
+        assert_eq!(
+            hand.break_ticks(BlockState::new("short_grass").unwrap(), &tags),
+            Some(0)
+        );
+        assert_eq!(
+            hand.break_ticks(BlockState::new("bedrock").unwrap(), &tags),
+            None
+        );
+
+        let pickaxe = Miner {
+            tool: held_tool(&item("wooden_pickaxe")),
+            ..hand.clone()
+        };
+        assert!(pickaxe.has_correct_tool(stone, &tags));
+        assert_eq!(pickaxe.break_ticks(stone, &tags), Some(23));
+        assert!(held_tool(&item("stone")).is_none());
+
+        let falling = Miner {
+            on_ground: false,
+            ..hand
+        };
+        assert_eq!(falling.break_ticks(stone, &tags), Some(750));
+    }
+
+    #[test]
+    fn digs_until_broken() {
+        let mut world = test_world(false, &[(2, 64, 2, "dirt")]);
+        let position = Vector3::new(2, 64, 2);
+        let (tags, eye) = (tags(), Vector3::new(2.5, 65.62, 3.5));
+        let miner = Miner {
+            on_ground: true,
+            ..Miner::default()
+        };
+
+        let mut digger = Digger::new();
+        digger.dig(position, BlockFace::Top);
+        let updates = digger.tick(&world, &miner, &tags, eye);
+        assert!(matches!(
+            updates[..],
+            [DigUpdate::Send(DigPacket {
+                status: PlayerActionStatus::StartDestroyBlock,
+                breaks: false,
+                ..
+            })]
+        ));
+        for _ in 1..15 {
+            assert!(digger.tick(&world, &miner, &tags, eye).is_empty());
+        }
+        let updates = digger.tick(&world, &miner, &tags, eye);
+        assert!(matches!(
+            updates[..],
+            [DigUpdate::Send(DigPacket {
+                status: PlayerActionStatus::StopDestroyBlock,
+                breaks: true,
+                ..
+            })]
+        ));
+        assert_eq!(digger.target(), None);
+
+        digger.predict(&mut world, 2, position);
+        assert!(block_at(&world, position).is_air());
+        assert!(digger.acknowledge(&world, 1).is_empty());
+        assert_eq!(digger.acknowledge(&world, 2), vec![(position, true)]);
+
+        // Digging out of reach stops right away.
+        set_test_block(&mut world, 2, 64, 2, "dirt");
+        digger.dig(position, BlockFace::Top);
+        let far = Vector3::new(2.5, 70.0, 2.5);
+        for _ in 0..DESTROY_DELAY {
+            assert!(digger.tick(&world, &miner, &tags, far).is_empty());
+        }
+        assert_eq!(
+            digger.tick(&world, &miner, &tags, far),
+            vec![DigUpdate::Failed(position)]
+        );
+    }
+}
@@ -0,0 +1,751 @@
+use std::collections::HashMap;
+
+use wither_declare::entity::{Kind, Pose, Profession};
+use wither_network::{
+    codec::slot::Slot,
+    protocol::client::play::{self, PositionMoveRotation},
+    types::{EntityDataValue, VillagerData},
+    PositionFlag,
+};
+use wither_util::{
+    math::{
+        boundingbox::{BoundingBox, BoundingBoxSize},
+        vector3::Vector3,
+    },
+    text::TextComponent,
+};
+use wither_world::entity::entity_registry::ENTITIES;
+
+/// Position deltas are sent in 1/4096 of a block.
+const POSITION_SCALE: f64 = 4096.0;
+/// Velocities are sent in 1/8000 of a block per tick.
+const VELOCITY_SCALE: f64 = 8000.0;
+
+// Metadata indices shared by every entity, the rest depend on the entity class.
+const FLAGS_INDEX: u8 = 0;
+const CUSTOM_NAME_INDEX: u8 = 2;
+const POSE_INDEX: u8 = 6;
+const HEALTH_INDEX: u8 = 9;
+
+const ON_FIRE_FLAG: i8 = 0x01;
+const CROUCHING_FLAG: i8 = 0x02;
+const SPRINTING_FLAG: i8 = 0x08;
+const SWIMMING_FLAG: i8 = 0x10;
+const INVISIBLE_FLAG: i8 = 0x20;
+const GLOWING_FLAG: i8 = 0x40;
+
+/// An entity the server told the client about.
+#[derive(Clone, Debug)]
+pub struct Entity {
+    pub id: i32,
+    pub uuid: uuid::Uuid,
+    pub kind: Kind,
+    pub position: Vector3<f64>,
+    /// In blocks per tick.
+    pub velocity: Vector3<f64>,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub head_yaw: f32,
+    pub on_ground: bool,
+    /// The `data` field of `add_entity`, like the block state of a falling block or the
+    /// owner of a projectile.
+    pub data: i32,
+    /// The latest `set_entity_data` value of each index.
+    pub metadata: HashMap<u8, EntityDataValue>,
+    /// Keyed by equipment slot, 0: main hand, 1: off hand, 2-5: boots to helmet, 6: body.
+    pub equipment: HashMap<u8, Slot>,
+    /// Amplifier of each active effect by its id, 0 for level I.
+    pub effects: HashMap<i32, i32>,
+}
+
+impl Entity {
+    pub fn new(id: i32, uuid: uuid::Uuid, kind: Kind, position: Vector3<f64>) -> Self {
+        Self {
+            id,
+            uuid,
+            kind,
+            position,
+            velocity: Vector3::default(),
+            yaw: 0.0,
+            pitch: 0.0,
+            head_yaw: 0.0,
+            on_ground: false,
+            data: 0,
+            metadata: HashMap::new(),
+            equipment: HashMap::new(),
+            effects: HashMap::new(),
+        }
+    }
+
+    /// Width and height of the entity from the entity registry, scaled for babies and
+    /// slimes.
+    pub fn dimensions(&self) -> BoundingBoxSize {
+        let [width, height] = match ENTITIES.get(self.kind.name()) {
+            Some(entity) => entity.dimension,
+            // Missing from the registry.
+            None => match self.kind {
+                Kind::Creaking => [0.9, 2.7],
+                Kind::PaleOakBoat | Kind::PaleOakChestBoat => [1.375, 0.5625],
+                _ => [0.6, 1.8],
+            },
+        };
+        let scale = self.scale();
+        BoundingBoxSize {
+            width: width as f64 * scale,
+            height: height as f64 * scale,
+        }
+    }
+
+    /// Height of the eyes above the feet when standing. The entity registry does not
+    /// have it, so it comes from the entity types that set one, and is 85% of the
+    /// height for the others like in vanilla.
+    pub fn eye_height(&self) -> f64 {
+        let eye_height = match self.kind {
+            Kind::Allay => 0.36,
+            Kind::Armadillo => 0.26,
+            Kind::ArmorStand => 1.7775,
+            Kind::Arrow | Kind::SpectralArrow | Kind::Trident => 0.13,
+            Kind::Axolotl => 0.2751,
+            Kind::Bat => 0.45,
+            Kind::Bee | Kind::Dolphin => 0.3,
+            Kind::Breeze => 1.3452,
+            Kind::Camel => 2.275,
+            Kind::Cat => 0.35,
+            Kind::CaveSpider => 0.45,
+            Kind::Chicken => 0.644,
+            Kind::Cod => 0.195,
+            Kind::Cow | Kind::Mooshroom => 1.3,
+            Kind::Donkey => 1.425,
+            Kind::ElderGuardian => 0.99875,
+            Kind::Enderman => 2.55,
+            Kind::Endermite | Kind::Silverfish => 0.13,
+            Kind::Fox => 0.4,
+            Kind::Ghast => 2.6,
+            Kind::Giant => 10.44,
+            Kind::GlowSquid | Kind::Squid => 0.4,
+            Kind::Guardian => 0.425,
+            Kind::Horse | Kind::Mule | Kind::SkeletonHorse | Kind::ZombieHorse => 1.52,
+            Kind::Item => 0.2125,
+            Kind::Llama | Kind::TraderLlama => 1.7765,
+            Kind::MagmaCube | Kind::Slime => 0.325,
+            Kind::Parrot => 0.54,
+            Kind::Phantom => 0.175,
+            Kind::Piglin | Kind::PiglinBrute | Kind::ZombifiedPiglin => 1.79,
+            Kind::Player | Kind::Villager | Kind::WanderingTrader | Kind::Witch => 1.62,
+            Kind::Salmon | Kind::TropicalFish => 0.26,
+            Kind::Sheep => 1.235,
+            Kind::Shulker => 0.5,
+            Kind::Bogged
+            | Kind::Drowned
+            | Kind::Husk
+            | Kind::Skeleton
+            | Kind::Stray
+            | Kind::Zombie
+            | Kind::ZombieVillager => 1.74,
+            Kind::Sniffer => 1.05,
+            Kind::SnowGolem => 1.7,
+            Kind::Spider => 0.65,
+            Kind::Tadpole => 0.195,
+            Kind::Vex => 0.51875,
+            Kind::Warden => 2.4,
+            Kind::WitherSkeleton => 2.1,
+            Kind::Wolf => 0.68,
+            _ => return self.dimensions().height * 0.85,
+        };
+        eye_height * self.scale()
+    }
+
+    /// Size of slimes, and of babies compared to adults.
+    fn scale(&self) -> f64 {
+        match self.slime_size() {
+            Some(size) => size as f64,
+            // Most babies are half as big.
+            None if self.is_baby() => 0.5,
+            None => 1.0,
+        }
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        let position = self.position;
+        BoundingBox::new_from_pos(position.x, position.y, position.z, &self.dimensions())
+    }
+
+    fn flags(&self) -> i8 {
+        match self.metadata.get(&FLAGS_INDEX) {
+            Some(EntityDataValue::Byte(flags)) => *flags,
+            _ => 0,
+        }
+    }
+
+    pub fn is_on_fire(&self) -> bool {
+        self.flags() & ON_FIRE_FLAG != 0
+    }
+
+    pub fn is_crouching(&self) -> bool {
+        self.flags() & CROUCHING_FLAG != 0
+    }
+
+    pub fn is_sprinting(&self) -> bool {
+        self.flags() & SPRINTING_FLAG != 0
+    }
+
+    pub fn is_swimming(&self) -> bool {
+        self.flags() & SWIMMING_FLAG != 0
+    }
+
+    pub fn is_invisible(&self) -> bool {
+        self.flags() & INVISIBLE_FLAG != 0
+    }
+
+    pub fn is_glowing(&self) -> bool {
+        self.flags() & GLOWING_FLAG != 0
+    }
+
+    pub fn custom_name(&self) -> Option<&TextComponent> {
+        match self.metadata.get(&CUSTOM_NAME_INDEX) {
+            Some(EntityDataValue::OptionalComponent(name)) => name.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn pose(&self) -> Pose {
+        match self.metadata.get(&POSE_INDEX) {
+            Some(EntityDataValue::Pose(pose)) => Pose::from_id(pose.0).unwrap_or(Pose::Standing),
+            _ => Pose::Standing,
+        }
+    }
+
+    /// `None` for entities without health, or when the server did not send it yet.
+    pub fn health(&self) -> Option<f32> {
+        if !self.kind.is_living() {
+            return None;
+        }
+        match self.metadata.get(&HEALTH_INDEX) {
+            Some(EntityDataValue::Float(health)) => Some(*health),
+            _ => None,
+        }
+    }
+
+    pub fn is_baby(&self) -> bool {
+        let index = match self.kind {
+            Kind::Piglin => 17,
+            kind if is_ageable(kind) => 16,
+            _ => return false,
+        };
+        matches!(
+            self.metadata.get(&index),
+            Some(EntityDataValue::Boolean(true))
+        )
+    }
+
+    /// The item of a dropped item, item frame or item display.
+    pub fn item(&self) -> Option<&Slot> {
+        let index = match self.kind {
+            Kind::Item | Kind::ItemFrame | Kind::GlowItemFrame | Kind::OminousItemSpawner => 8,
+            Kind::ItemDisplay => 23,
+            _ => return None,
+        };
+        match self.metadata.get(&index) {
+            Some(EntityDataValue::Slot(slot)) => Some(slot),
+            _ => None,
+        }
+    }
+
+    pub fn villager_data(&self) -> Option<&VillagerData> {
+        let index = match self.kind {
+            Kind::Villager => 18,
+            Kind::ZombieVillager => 20,
+            _ => return None,
+        };
+        match self.metadata.get(&index) {
+            Some(EntityDataValue::VillagerData(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    pub fn profession(&self) -> Option<Profession> {
+        Profession::from_id(self.villager_data()?.profession.0)
+    }
+
+    /// The size of a slime or magma cube, 1 for the smallest.
+    pub fn slime_size(&self) -> Option<i32> {
+        if !matches!(self.kind, Kind::Slime | Kind::MagmaCube) {
+            return None;
+        }
+        match self.metadata.get(&16) {
+            Some(EntityDataValue::VarInt(size)) => Some(size.0),
+            _ => None,
+        }
+    }
+
+    pub fn distance_squared(&self, position: Vector3<f64>) -> f64 {
+        self.position.squared_distance_to_vec(position)
+    }
+
+    /// Applies a teleport, where fields in `relatives` are added to the current values.
+    pub fn apply_change(&mut self, change: &PositionMoveRotation, relatives: &[PositionFlag]) {
+        let relative = |flag| relatives.contains(&flag);
+        let pick = |flag, old: f64, new: f64| if relative(flag) { old + new } else { new };
+
+        let yaw = if relative(PositionFlag::YRot) {
+            self.yaw + change.yaw
+        } else {
+            change.yaw
+        };
+        let pitch = if relative(PositionFlag::XRot) {
+            self.pitch + change.pitch
+        } else {
+            change.pitch
+        };
+
+        let mut velocity = self.velocity;
+        if relative(PositionFlag::RotateDelta) {
+            // Turns the current velocity with the entity.
+            let (sin, cos) = ((self.pitch - pitch) as f64).to_radians().sin_cos();
+            velocity = Vector3::new(
+                velocity.x,
+                velocity.y * cos + velocity.z * sin,
+                velocity.z * cos - velocity.y * sin,
+            );
+            let (sin, cos) = ((self.yaw - yaw) as f64).to_radians().sin_cos();
+            velocity = Vector3::new(
+                velocity.x * cos + velocity.z * sin,
+                velocity.y,
+                velocity.z * cos - velocity.x * sin,
+            );
+        }
+
+        self.position = Vector3::new(
+            pick(PositionFlag::X, self.position.x, change.position.x),
+            pick(PositionFlag::Y, self.position.y, change.position.y),
+            pick(PositionFlag::Z, self.position.z, change.position.z),
+        );
+        self.velocity = Vector3::new(
+            pick(PositionFlag::DeltaX, velocity.x, change.delta_movement.x),
+            pick(PositionFlag::DeltaY, velocity.y, change.delta_movement.y),
+            pick(PositionFlag::DeltaZ, velocity.z, change.delta_movement.z),
+        );
+        self.yaw = yaw;
+        self.pitch = pitch.clamp(-90.0, 90.0);
+    }
+}
+
+/// The entities around the player, kept up to date with the entity packets.
+#[derive(Debug, Default)]
+pub struct EntityTracker {
+    entities: HashMap<i32, Entity>,
+    player_id: Option<i32>,
+}
+
+impl EntityTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, id: i32) -> Option<&Entity> {
+        self.entities.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: i32) -> Option<&mut Entity> {
+        self.entities.get_mut(&id)
+    }
+
+    pub fn by_uuid(&self, uuid: uuid::Uuid) -> Option<&Entity> {
+        self.entities.values().find(|entity| entity.uuid == uuid)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entity> {
+        self.entities.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// The entity of the local player, known once the server sent `login`.
+    pub fn player(&self) -> Option<&Entity> {
+        self.entities.get(&self.player_id?)
+    }
+
+    pub fn player_mut(&mut self) -> Option<&mut Entity> {
+        self.entities.get_mut(&self.player_id?)
+    }
+
+    /// Entities other than the player within `radius` of `center`.
+    pub fn within(&self, center: Vector3<f64>, radius: f64) -> impl Iterator<Item = &Entity> {
+        self.iter().filter(move |entity| {
+            Some(entity.id) != self.player_id && entity.distance_squared(center) <= radius * radius
+        })
+    }
+
+    /// The closest entity other than the player within `radius` of `center` that matches
+    /// `filter`.
+    pub fn nearest(
+        &self,
+        center: Vector3<f64>,
+        radius: f64,
+        filter: impl Fn(&Entity) -> bool,
+    ) -> Option<&Entity> {
+        self.within(center, radius)
+            .filter(|entity| filter(entity))
+            .min_by(|a, b| {
+                a.distance_squared(center)
+                    .total_cmp(&b.distance_squared(center))
+            })
+    }
+
+    pub fn nearest_hostile(&self, center: Vector3<f64>, radius: f64) -> Option<&Entity> {
+        self.nearest(center, radius, |entity| entity.kind.is_hostile())
+    }
+
+    pub fn nearest_of_kind(
+        &self,
+        center: Vector3<f64>,
+        radius: f64,
+        kind: Kind,
+    ) -> Option<&Entity> {
+        self.nearest(center, radius, |entity| entity.kind == kind)
+    }
+
+    /// Forgets every entity and tracks the player under its new id, sent on `login`.
+    pub fn reset(&mut self, player_id: i32, player_uuid: uuid::Uuid) {
+        let position = self
+            .player()
+            .map_or(Vector3::default(), |player| player.position);
+        self.entities.clear();
+        self.entities.insert(
+            player_id,
+            Entity::new(player_id, player_uuid, Kind::Player, position),
+        );
+        self.player_id = Some(player_id);
+    }
+
+    /// Forgets every entity but the player, the server sends them again after a respawn.
+    pub fn respawn(&mut self) {
+        let player_id = self.player_id;
+        self.entities.retain(|&id, _| Some(id) == player_id);
+        if let Some(player) = self.player_mut() {
+            player.effects.clear();
+        }
+    }
+
+    pub fn add_entity(&mut self, packet: &play::AddEntity) {
+        let Some(kind) = Kind::from_id(packet.entity_type.0) else {
+            log::warn!("unknown entity type {}", packet.entity_type.0);
+            return;
+        };
+        let id = packet.entity_id.0;
+        let mut entity = Entity::new(
+            id,
+            packet.entity_uuid.0,
+            kind,
+            Vector3::new(packet.x, packet.y, packet.z),
+        );
+        entity.velocity = velocity(packet.velocity_x, packet.velocity_y, packet.velocity_z);
+        entity.yaw = angle(packet.yaw);
+        entity.pitch = angle(packet.pitch);
+        entity.head_yaw = angle(packet.head_yaw);
+        entity.data = packet.data.0;
+        self.entities.insert(id, entity);
+    }
+
+    pub fn remove_entities(&mut self, packet: &play::RemoveEntities) {
+        for id in &packet.entity_ids {
+            self.entities.remove(&id.0);
+        }
+    }
+
+    pub fn move_entity_pos(&mut self, packet: &play::MoveEntityPos) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            move_by(entity, packet.delta_x, packet.delta_y, packet.delta_z);
+            entity.on_ground = packet.on_ground;
+        }
+    }
+
+    pub fn move_entity_pos_rot(&mut self, packet: &play::MoveEntityPosRot) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            move_by(entity, packet.delta_x, packet.delta_y, packet.delta_z);
+            entity.yaw = angle(packet.yaw);
+            entity.pitch = angle(packet.pitch);
+            entity.on_ground = packet.on_ground;
+        }
+    }
+
+    pub fn move_entity_rot(&mut self, packet: &play::MoveEntityRot) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.yaw = angle(packet.yaw);
+            entity.pitch = angle(packet.pitch);
+            entity.on_ground = packet.on_ground;
+        }
+    }
+
+    pub fn rotate_head(&mut self, packet: &play::RotateHead) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.head_yaw = angle(packet.head_yaw);
+        }
+    }
+
+    pub fn teleport_entity(&mut self, packet: &play::TeleportEntity) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.apply_change(&packet.change, &packet.relatives);
+            entity.on_ground = packet.on_ground;
+        }
+    }
+
+    pub fn entity_position_sync(&mut self, packet: &play::EntityPositionSync) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.apply_change(&packet.values, &[]);
+            entity.on_ground = packet.on_ground;
+        }
+    }
+
+    /// Moves the player, returns its new position and rotation to confirm to the server.
+    pub fn player_position(&mut self, packet: &play::PlayerPosition) -> Option<&Entity> {
+        let player = self.player_mut()?;
+        player.apply_change(&packet.change, &packet.relatives);
+        Some(player)
+    }
+
+    pub fn set_entity_motion(&mut self, packet: &play::SetEntityMotion) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.velocity = velocity(packet.velocity_x, packet.velocity_y, packet.velocity_z);
+        }
+    }
+
+    pub fn set_entity_data(&mut self, packet: &play::SetEntityData) {
+        let Some(entity) = self.entities.get_mut(&packet.entity_id.0) else {
+            return;
+        };
+        match packet.entries() {
+            Ok(entries) => {
+                for entry in entries {
+                    entity.metadata.insert(entry.index, entry.value);
+                }
+            }
+            Err(err) => log::warn!("Failed to decode entity data of {}: {err}", entity.id),
+        }
+    }
+
+    pub fn set_equipment(&mut self, packet: &play::SetEquipment) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            for (slot, item) in &packet.equipment {
+                entity.equipment.insert(*slot, item.clone());
+            }
+        }
+    }
+
+    pub fn update_mob_effect(&mut self, packet: &play::UpdateMobEffect) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.effects.insert(packet.effect.0, packet.amplifier.0);
+        }
+    }
+
+    pub fn remove_mob_effect(&mut self, packet: &play::RemoveMobEffect) {
+        if let Some(entity) = self.entities.get_mut(&packet.entity_id.0) {
+            entity.effects.remove(&packet.effect.0);
+        }
+    }
+}
+
+/// Mobs that have a baby flag, at index 16 for all but piglins.
+fn is_ageable(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::Armadillo
+            | Kind::Axolotl
+            | Kind::Bee
+            | Kind::Camel
+            | Kind::Cat
+            | Kind::Chicken
+            | Kind::Cow
+            | Kind::Dolphin
+            | Kind::Donkey
+            | Kind::Drowned
+            | Kind::Fox
+            | Kind::Frog
+            | Kind::GlowSquid
+            | Kind::Goat
+            | Kind::Hoglin
+            | Kind::Horse
+            | Kind::Husk
+            | Kind::Llama
+            | Kind::Mooshroom
+            | Kind::Mule
+            | Kind::Ocelot
+            | Kind::Panda
+            | Kind::Parrot
+            | Kind::Pig
+            | Kind::PolarBear
+            | Kind::Rabbit
+            | Kind::Sheep
+            | Kind::SkeletonHorse
+            | Kind::Sniffer
+            | Kind::Squid
+            | Kind::Strider
+            | Kind::TraderLlama
+            | Kind::Turtle
+            | Kind::Villager
+            | Kind::WanderingTrader
+            | Kind::Wolf
+            | Kind::Zoglin
+            | Kind::Zombie
+            | Kind::ZombieHorse
+            | Kind::ZombieVillager
+            | Kind::ZombifiedPiglin
+    )
+}
+
+fn move_by(entity: &mut Entity, x: i16, y: i16, z: i16) {
+    entity.position = entity.position.add(&Vector3::new(
+        x as f64 / POSITION_SCALE,
+        y as f64 / POSITION_SCALE,
+        z as f64 / POSITION_SCALE,
+    ));
+}
+
+fn velocity(x: i16, y: i16, z: i16) -> Vector3<f64> {
+    Vector3::new(
+        x as f64 / VELOCITY_SCALE,
+        y as f64 / VELOCITY_SCALE,
+        z as f64 / VELOCITY_SCALE,
+    )
+}
+
+/// Angles are sent in 1/256 of a full turn.
+fn angle(angle: u8) -> f32 {
+    angle as i8 as f32 * 360.0 / 256.0
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use wither_declare::entity::{Kind, Pose, Profession};
+    use wither_network::{
+        codec::var_int::VarInt,
+        protocol::client::play,
+        types::{EntityData, EntityDataValue, Uuid, VillagerData},
+    };
+    use wither_util::{math::vector3::Vector3, text::TextComponent};
+
+    use super::EntityTracker;
+
+    fn add(tracker: &mut EntityTracker, id: i32, kind: Kind, x: f64) {
+        tracker.add_entity(&play::AddEntity::new(
+            VarInt(id),
+            Uuid(uuid::Uuid::new_v4()),
+            VarInt(kind.id()),
+            x,
+            64.0,
+            0.0,
+            0,
+            64,
+            64,
+            VarInt(0),
+            0,
+            8000,
+            0,
+        ));
+    }
+
+    #[test]
+    fn tracks_entities() {
+        let mut tracker = EntityTracker::new();
+        tracker.reset(1, uuid::Uuid::new_v4());
+        add(&mut tracker, 2, Kind::Zombie, 10.0);
+        add(&mut tracker, 3, Kind::Cow, 2.0);
+        add(&mut tracker, 4, Kind::Creeper, 20.0);
+
+        let zombie = tracker.get(2).unwrap();
+        assert_eq!(zombie.yaw, 90.0);
+        assert_eq!(zombie.velocity, Vector3::new(0.0, 1.0, 0.0));
+        assert!((zombie.bounding_box().max_y - 65.95).abs() < 1e-6);
+
+        tracker.move_entity_pos(&play::MoveEntityPos::new(VarInt(2), -4096, 0, 0, true));
+        assert_eq!(tracker.get(2).unwrap().position.x, 9.0);
+
+        let center = Vector3::new(0.0, 64.0, 0.0);
+        assert_eq!(tracker.nearest_hostile(center, 32.0).unwrap().id, 2);
+        assert_eq!(tracker.nearest(center, 32.0, |_| true).unwrap().id, 3);
+        assert_eq!(tracker.within(center, 15.0).count(), 2);
+
+        tracker.remove_entities(&play::RemoveEntities::new(vec![VarInt(2)]));
+        assert_eq!(tracker.nearest_hostile(center, 32.0).unwrap().id, 4);
+        assert!(tracker.nearest_hostile(center, 8.0).is_none());
+
+        tracker.respawn();
+        assert_eq!(tracker.len(), 1);
+        assert_eq!(tracker.player().unwrap().kind, Kind::Player);
+    }
+
+    fn set_data(tracker: &mut EntityTracker, id: i32, entries: Vec<EntityData>) {
+        let mut bytes = BytesMut::new();
+        EntityData::write_list(&entries, &mut bytes);
+        tracker.set_entity_data(&play::SetEntityData::new(VarInt(id), bytes.freeze()));
+    }
+
+    #[test]
+    fn decodes_metadata() {
+        let mut tracker = EntityTracker::new();
+        add(&mut tracker, 1, Kind::Villager, 0.0);
+        add(&mut tracker, 2, Kind::Zombie, 0.0);
+        add(&mut tracker, 3, Kind::Slime, 0.0);
+
+        set_data(
+            &mut tracker,
+            1,
+            vec![
+                EntityData::new(0, EntityDataValue::Byte(0x01 | 0x02)),
+                EntityData::new(
+                    2,
+                    EntityDataValue::OptionalComponent(Some(TextComponent::text("Bob"))),
+                ),
+                EntityData::new(6, EntityDataValue::Pose(VarInt(Pose::Sleeping as i32))),
+                EntityData::new(9, EntityDataValue::Float(14.0)),
+                EntityData::new(16, EntityDataValue::Boolean(true)),
+                EntityData::new(
+                    18,
+                    EntityDataValue::VillagerData(VillagerData {
+                        kind: VarInt(0),
+                        profession: VarInt(9),
+                        level: VarInt(2),
+                    }),
+                ),
+            ],
+        );
+        let villager = tracker.get(1).unwrap();
+        assert!(villager.is_on_fire() && villager.is_crouching() && !villager.is_sprinting());
+        assert_eq!(villager.custom_name(), Some(&TextComponent::text("Bob")));
+        assert_eq!(villager.pose(), Pose::Sleeping);
+        assert_eq!(villager.health(), Some(14.0));
+        assert!(villager.is_baby());
+        assert_eq!(villager.profession(), Some(Profession::Librarian));
+
+        // Later updates only carry the changed indices.
+        set_data(
+            &mut tracker,
+            1,
+            vec![EntityData::new(16, EntityDataValue::Boolean(false))],
+        );
+        assert!(!tracker.get(1).unwrap().is_baby());
+        assert_eq!(tracker.get(1).unwrap().health(), Some(14.0));
+
+        set_data(
+            &mut tracker,
+            2,
+            vec![EntityData::new(16, EntityDataValue::Boolean(true))],
+        );
+        assert!((tracker.get(2).unwrap().dimensions().height - 0.975).abs() < 1e-6);
+
+        set_data(
+            &mut tracker,
+            3,
+            vec![EntityData::new(16, EntityDataValue::VarInt(VarInt(4)))],
+        );
+        assert_eq!(tracker.get(3).unwrap().slime_size(), Some(4));
+        assert!((tracker.get(3).unwrap().dimensions().width - 2.08).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,216 @@
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::broadcast::{
+    self,
+    error::{RecvError, TryRecvError},
+};
+
+use wither_network::RawPacket;
+use wither_util::{math::vector3::Vector3, text::TextComponent};
+
+/// Something that happened on a connection, in the order it happened.
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// The TCP connection to the server is open.
+    Connected,
+    /// The server accepted the login, the connection moved to configuration.
+    LoginFinished {
+        uuid: uuid::Uuid,
+        username: String,
+    },
+    /// Configuration is done, the connection moved to play.
+    ConfigFinished,
+    /// The server moved the client to another server, the connection is reopened there
+    /// and goes through login and configuration again.
+    Transfer {
+        host: String,
+        port: u16,
+    },
+    /// The server placed the player in the world for the first time.
+    Spawned,
+    Chat(ChatMessage),
+    /// The player reached the goal given to the pathfinder.
+    GoalReached,
+    /// The pathfinder gave up on its goal, no step gets closer to it.
+    PathNotFound,
+    /// The server confirmed that the player broke the block.
+    BlockBroken(Vector3<i32>),
+    /// Digging the block stopped before it broke, or the server undid the break.
+    DigFailed(Vector3<i32>),
+    /// The connection is closed, either by the server or locally. Sent once.
+    Disconnect(TextComponent),
+    /// Sent for every packet before the client handles it.
+    PacketReceived(RawPacket),
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+    /// `None` for system messages.
+    pub sender: Option<uuid::Uuid>,
+    pub content: TextComponent,
+    /// Shown above the hotbar instead of in the chat.
+    pub overlay: bool,
+}
+
+/// Events kept for subscribers that have not received them yet.
+pub const EVENT_CAPACITY: usize = 1024;
+
+type Handler = Arc<dyn Fn(&Event) + Send + Sync>;
+
+/// Fans every event out to all subscribers.
+///
+/// Subscribers share a queue of the last `EVENT_CAPACITY` events, so one that stops
+/// receiving holds at most that many. A subscriber that falls further behind skips the
+/// oldest events, which never makes another one miss events. Subscribe before
+/// connecting to see `Connected`.
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<Event>,
+    handlers: Arc<Mutex<Vec<Handler>>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self {
+            sender: broadcast::channel(EVENT_CAPACITY).0,
+            handlers: Arc::default(),
+        }
+    }
+
+    /// Returns a receiver for every event emitted from now on.
+    pub fn subscribe(&self) -> Subscription {
+        Subscription {
+            receiver: self.sender.subscribe(),
+            missed: 0,
+        }
+    }
+
+    /// Calls `handler` for every event emitted from now on, on the connection task.
+    /// The handler should not block. Handlers registered by a handler are called from
+    /// the next event on.
+    pub fn on(&self, handler: impl Fn(&Event) + Send + Sync + 'static) {
+        self.handlers.lock().unwrap().push(Arc::new(handler));
+    }
+
+    pub(crate) fn emit(&self, event: Event) {
+        // Handlers may register other handlers, so they run without the lock.
+        let handlers = self.handlers.lock().unwrap().clone();
+        for handler in handlers {
+            handler(&event);
+        }
+        // Fails when nobody is subscribed, which is fine.
+        let _ = self.sender.send(event);
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The events of an `EventBus` since subscribing.
+pub struct Subscription {
+    receiver: broadcast::Receiver<Event>,
+    missed: u64,
+}
+
+impl Subscription {
+    /// Waits for the next event, `None` once the bus is gone.
+    pub async fn recv(&mut self) -> Option<Event> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(missed)) => self.missed += missed,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// The next event if one was emitted already.
+    pub fn try_recv(&mut self) -> Option<Event> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Lagged(missed)) => self.missed += missed,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Events skipped because more than `EVENT_CAPACITY` were waiting.
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_in_order() {
+        let bus = EventBus::new();
+        let mut receiver = bus.subscribe();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for name in ["first", "second"] {
+            let seen = seen.clone();
+            bus.on(move |event| {
+                if let Event::BlockBroken(position) = event {
+                    seen.lock().unwrap().push((name, position.x));
+                }
+            });
+        }
+
+        bus.emit(Event::BlockBroken(Vector3::new(1, 0, 0)));
+        bus.emit(Event::BlockBroken(Vector3::new(2, 0, 0)));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [("first", 1), ("second", 1), ("first", 2), ("second", 2)]
+        );
+        for x in [1, 2] {
+            assert!(matches!(
+                receiver.try_recv(),
+                Some(Event::BlockBroken(position)) if position.x == x
+            ));
+        }
+        assert!(receiver.try_recv().is_none());
+    }
+
+    #[test]
+    fn slow_subscribers_skip_the_oldest_events() {
+        let bus = EventBus::new();
+        let mut slow = bus.subscribe();
+        for x in 0..EVENT_CAPACITY as i32 + 10 {
+            bus.emit(Event::BlockBroken(Vector3::new(x, 0, 0)));
+        }
+        bus.emit(Event::Spawned);
+
+        assert!(matches!(
+            slow.try_recv(),
+            Some(Event::BlockBroken(position)) if position.x == 11
+        ));
+        assert_eq!(slow.missed(), 11);
+        let rest: Vec<_> = std::iter::from_fn(|| slow.try_recv()).collect();
+        assert_eq!(rest.len(), EVENT_CAPACITY - 1);
+        assert!(matches!(rest.last(), Some(Event::Spawned)));
+    }
+
+    #[test]
+    fn handlers_can_register_handlers() {
+        let bus = EventBus::new();
+        let calls = Arc::new(Mutex::new(0));
+        let inner = (bus.clone(), calls.clone());
+        bus.on(move |event| {
+            if let Event::Connected = event {
+                let calls = inner.1.clone();
+                inner.0.on(move |_| *calls.lock().unwrap() += 1);
+            }
+        });
+
+        bus.emit(Event::Connected);
+        assert_eq!(*calls.lock().unwrap(), 0);
+        bus.emit(Event::Spawned);
+        assert_eq!(*calls.lock().unwrap(), 1);
+    }
+}
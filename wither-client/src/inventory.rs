@@ -0,0 +1,89 @@
+use wither_network::{codec::slot::Slot, protocol::client::play};
+
+/// Slots of the player inventory menu: crafting, armor, main inventory, hotbar and
+/// off hand.
+pub const SLOT_COUNT: usize = 46;
+pub const HELMET_SLOT: usize = 5;
+pub const HOTBAR_START: usize = 36;
+pub const OFFHAND_SLOT: usize = 45;
+
+/// The inventory of the player, numbered like the slots of its menu.
+#[derive(Clone, Debug)]
+pub struct PlayerInventory {
+    slots: Vec<Slot>,
+    /// Hotbar slot from 0 to 8.
+    selected: usize,
+}
+
+impl Default for PlayerInventory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerInventory {
+    pub fn new() -> Self {
+        Self {
+            slots: vec![Slot::empty(); SLOT_COUNT],
+            selected: 0,
+        }
+    }
+
+    pub fn slot(&self, slot: usize) -> Option<&Slot> {
+        self.slots.get(slot)
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// The item in the selected hotbar slot.
+    pub fn held_item(&self) -> &Slot {
+        &self.slots[HOTBAR_START + self.selected]
+    }
+
+    pub fn helmet(&self) -> &Slot {
+        &self.slots[HELMET_SLOT]
+    }
+
+    /// Only the player inventory, container 0, is tracked.
+    pub fn set_content(&mut self, packet: &play::ContainerSetContent) {
+        if packet.container_id.0 != 0 {
+            return;
+        }
+        for (slot, item) in self.slots.iter_mut().zip(&packet.items) {
+            *slot = item.clone();
+        }
+    }
+
+    pub fn set_slot(&mut self, packet: &play::ContainerSetSlot) {
+        if packet.container_id.0 != 0 {
+            return;
+        }
+        if let Some(slot) = usize::try_from(packet.slot)
+            .ok()
+            .and_then(|slot| self.slots.get_mut(slot))
+        {
+            *slot = packet.item.clone();
+        }
+    }
+
+    /// `set_player_inventory` numbers slots like the inventory itself: the hotbar, the
+    /// main inventory, armor from the boots up and the off hand.
+    pub fn set_player_inventory(&mut self, packet: &play::SetPlayerInventory) {
+        let slot = match packet.slot.0 {
+            slot @ 0..=8 => HOTBAR_START + slot as usize,
+            slot @ 9..=35 => slot as usize,
+            slot @ 36..=39 => 8 - (slot as usize - 36),
+            40 => OFFHAND_SLOT,
+            _ => return,
+        };
+        self.slots[slot] = packet.contents.clone();
+    }
+
+    pub fn select(&mut self, slot: usize) {
+        if slot < 9 {
+            self.selected = slot;
+        }
+    }
+}
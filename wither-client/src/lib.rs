@@ -1,9 +1,34 @@
+pub mod digging;
+pub mod entities;
+pub mod event;
+pub mod inventory;
+pub mod options;
+pub mod pathfinder;
+pub mod physics;
+pub mod ping;
+pub mod query;
+pub mod raycast;
+pub mod world;
+
+pub use digging::{Digger, Miner};
+pub use entities::{Entity, EntityTracker};
+pub use inventory::PlayerInventory;
+pub use pathfinder::{Goal, PathOptions, Pathfinder};
+pub use physics::{MovementInput, PlayerPhysics};
+pub use ping::{legacy_ping, ping};
+pub use query::{query_basic, query_full};
+pub use raycast::{BlockHit, EntityHit, RaycastHit};
+
 use std::{
-    collections::VecDeque,
     error::Error,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{
+        atomic::{AtomicBool, AtomicI32},
+        Arc,
+    },
+    time::Duration,
 };
 
+use bytes::Bytes;
 use rand::Rng;
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
@@ -14,16 +39,30 @@ use rsa::{BigUint, Pkcs1v15Encrypt, RsaPublicKey};
 
 use dashmap::DashMap;
 
-use wither_declare::*;
+use wither_nbt::tag::NbtTag;
 use wither_network::{
-    packet_decoder::PacketDecoder, packet_encoder::PacketEncoder, protocol, ClientIntent,
-    CompressionLevel, CompressionThreshold, ConnectionProtocol, Packet, PacketId, RawPacket,
+    codec::identifier::Identifier, packet_decoder::PacketDecoder, packet_encoder::PacketEncoder,
+    protocol, ClientIntent, CompressionLevel, CompressionThreshold, ConnectionProtocol, Packet,
+    PacketId, RawPacket,
+};
+use wither_util::{
+    math::{position::WorldPosition, vector2::Vector2, vector3::Vector3},
+    text::TextComponent,
 };
+use wither_world::dimension::{get_dimension_height, DimensionHeight};
 
-pub struct Entity {
-    pub id: i32,
-    pub kind: entity::Kind,
-}
+use digging::{BlockTags, DigUpdate};
+use event::{ChatMessage, Event, EventBus};
+use options::ClientOptions;
+use pathfinder::{PathAction, PathUpdate};
+use physics::MovePacket;
+use world::ClientWorld;
+
+/// Chunk rate asked for after every chunk batch, vanilla adapts it to how fast it
+/// handles chunks but a bot keeps up with anything the server sends.
+const DESIRED_CHUNKS_PER_TICK: f32 = 64.0;
+
+const TICK_DURATION: Duration = Duration::from_millis(50);
 
 pub struct RawClient {
     id: Arc<std::sync::Mutex<Option<uuid::Uuid>>>,
@@ -34,19 +73,52 @@ pub struct RawClient {
     decoder: Arc<Mutex<PacketDecoder>>,
 
     intent: Arc<OnceCell<ClientIntent>>,
+    /// Sent in the handshake.
+    protocol_version: i32,
+    virtual_host: String,
+    virtual_port: u16,
     pub protocol: Arc<RwLock<ConnectionProtocol>>,
 
-    notify: Arc<DashMap<String, tokio::sync::Notify>>,
-
-    packet_queue: Arc<Mutex<VecDeque<RawPacket>>>,
-
-    pub living_entity: Option<Entity>,
+    pub events: EventBus,
+
+    /// Registry contents received during configuration, keyed by registry id.
+    pub registries: Arc<DashMap<Identifier, Vec<protocol::client::config::RegistryEntry>>>,
+    /// Tags received through `update_tags`, keyed by registry id.
+    pub tags: Arc<DashMap<Identifier, Vec<protocol::client::config::Tag>>>,
+    /// Cookies stored by servers, kept across transfers and sent back when requested.
+    pub cookies: Arc<DashMap<Identifier, Bytes>>,
+    /// Chunks around the player, filled during play.
+    pub world: Arc<std::sync::RwLock<ClientWorld>>,
+    /// Entities around the player, including the player itself.
+    pub entities: Arc<std::sync::RwLock<EntityTracker>>,
+    /// Movement of the player, advanced every tick.
+    pub physics: Arc<std::sync::Mutex<PlayerPhysics>>,
+    /// Steers the player towards a goal, ticked before the physics.
+    pub pathfinder: Arc<std::sync::Mutex<Pathfinder>>,
+    /// Breaks blocks, ticked before the physics.
+    pub digger: Arc<std::sync::Mutex<Digger>>,
+    /// Items of the player, kept up to date by the server.
+    pub inventory: Arc<std::sync::Mutex<PlayerInventory>>,
+    /// The `minecraft:block` part of `tags`, for looking up tools.
+    block_tags: std::sync::RwLock<BlockTags>,
+    /// Last sequence number sent with a block interaction.
+    sequence: AtomicI32,
+    username: std::sync::Mutex<Option<String>>,
 
     pub closed: AtomicBool,
+    spawned: AtomicBool,
 }
 
 impl RawClient {
-    pub async fn new(host: &str, port: u16) -> Result<Self, Box<dyn Error>> {
+    pub async fn new(
+        host: &str,
+        port: u16,
+        options: ClientOptions,
+    ) -> Result<Self, Box<dyn Error>> {
+        let virtual_host = options.virtual_host.unwrap_or_else(|| host.to_string());
+        let virtual_port = options.virtual_port.unwrap_or(port);
+        let events = options.events;
+
         let host = match tokio::net::lookup_host(format!("{host}:{port}"))
             .await?
             .next()
@@ -61,15 +133,7 @@ impl RawClient {
 
         let (connection_reader, connection_writer) = connection.into_split();
 
-        let notify = DashMap::<String, tokio::sync::Notify>::new();
-
-        notify.insert("close".into(), tokio::sync::Notify::new());
-        notify.insert("packet".into(), tokio::sync::Notify::new());
-        notify.insert("login".into(), tokio::sync::Notify::new());
-        // notify.insert("on_connect".into(), tokio::sync::Notify::new());
-
-        // notify.insert("on_connect".into(), tokio::sync::Notify::new());
-        // notify.get("on_connect".into()).unwrap().notify_one();
+        events.emit(Event::Connected);
 
         Ok(Self {
             id: Arc::new(std::sync::Mutex::new(None)),
@@ -78,12 +142,26 @@ impl RawClient {
             encoder: Arc::new(Mutex::new(PacketEncoder::default())),
             decoder: Arc::new(Mutex::new(PacketDecoder::default())),
             intent: Arc::new(OnceCell::new()),
+            protocol_version: options.protocol_version,
+            virtual_host,
+            virtual_port,
             protocol: Arc::new(RwLock::new(ConnectionProtocol::HandShake)),
-            packet_queue: Arc::new(Mutex::new(VecDeque::new())),
-
-            notify: Arc::new(notify),
-            living_entity: None,
+            registries: Arc::new(DashMap::new()),
+            tags: Arc::new(DashMap::new()),
+            cookies: Arc::new(DashMap::new()),
+            world: Arc::new(std::sync::RwLock::new(ClientWorld::new())),
+            entities: Arc::new(std::sync::RwLock::new(EntityTracker::new())),
+            physics: Arc::new(std::sync::Mutex::new(PlayerPhysics::new())),
+            pathfinder: Arc::new(std::sync::Mutex::new(Pathfinder::new())),
+            digger: Arc::new(std::sync::Mutex::new(Digger::new())),
+            inventory: Arc::new(std::sync::Mutex::new(PlayerInventory::new())),
+            block_tags: std::sync::RwLock::new(BlockTags::default()),
+            sequence: AtomicI32::new(0),
+            username: std::sync::Mutex::new(None),
+
+            events,
             closed: AtomicBool::new(false),
+            spawned: AtomicBool::new(false),
         })
     }
 
@@ -95,9 +173,9 @@ impl RawClient {
         self.intent
             .get_or_init(|| async {
                 self.send_packet(&protocol::server::handshake::HandShake::new(
-                    768,
-                    "localhost".into(),
-                    25565,
+                    self.protocol_version,
+                    self.virtual_host.clone(),
+                    self.virtual_port,
                     intent,
                 ))
                 .await
@@ -105,10 +183,7 @@ impl RawClient {
 
                 *self.protocol.write().await = match intent {
                     ClientIntent::Status => ConnectionProtocol::Status,
-                    ClientIntent::Login => ConnectionProtocol::Login,
-                    ClientIntent::Transfer => {
-                        unimplemented!()
-                    }
+                    ClientIntent::Login | ClientIntent::Transfer => ConnectionProtocol::Login,
                 };
 
                 intent
@@ -121,27 +196,91 @@ impl RawClient {
     pub async fn attempt_login(&self, name: &str) -> Result<(), Box<dyn Error>> {
         match self.intent.get() {
             Some(intent) => match intent {
-                ClientIntent::Login => {
+                ClientIntent::Login | ClientIntent::Transfer => {
+                    // Subscribe before sending so the answer can't be missed.
+                    let mut events = self.events.subscribe();
+
+                    *self.username.lock().unwrap() = Some(name.to_string());
                     self.send_packet(&protocol::server::login::Hello::new(
                         name.into(),
                         wither_network::types::Uuid(uuid::Uuid::new_v4()),
                     ))
                     .await?;
 
-                    let login = self.get_notify("login");
-                    let close = self.get_notify("close");
-
-                    tokio::select! {
-                        _ = login.notified() => Ok(()),
-                        _ = close.notified() => Err("client has been disconnected".into()),
+                    loop {
+                        match events.recv().await {
+                            Some(Event::LoginFinished { .. }) => break Ok(()),
+                            Some(Event::Disconnect(_)) | None => {
+                                break Err(Box::new(ClientError::Disconnect))
+                            }
+                            Some(_) => {}
+                        }
                     }
                 }
-                _ => Err("client intent must be ClientIntent::Login".into()),
+                _ => Err("client intent must be ClientIntent::Login or Transfer".into()),
             },
             None => Err("client intent is not initialized".into()),
         }
     }
 
+    /// Follows a transfer from the server: drops the current connection, connects to
+    /// `host` with intent Transfer and logs in again with the same profile.
+    ///
+    /// Stored cookies stay, so the new server can read them back. The connection is
+    /// closed if the new server can't be reached.
+    async fn transfer(&self, host: String, port: u16) -> Result<(), Box<dyn Error>> {
+        let result = self.reconnect(host, port).await;
+        if let Err(err) = &result {
+            log::warn!("Failed to transfer: {err}");
+            self.disconnect(TextComponent::text(format!("Transfer failed: {err}")));
+        }
+        result
+    }
+
+    async fn reconnect(&self, host: String, port: u16) -> Result<(), Box<dyn Error>> {
+        // Compression, encryption and the protocol state belong to the old connection,
+        // the new server negotiates them again.
+        *self.protocol.write().await = ConnectionProtocol::HandShake;
+        *self.encoder.lock().await = PacketEncoder::default();
+        *self.decoder.lock().await = PacketDecoder::default();
+        self.spawned
+            .store(false, std::sync::atomic::Ordering::Relaxed);
+
+        let address = tokio::net::lookup_host(format!("{host}:{port}"))
+            .await?
+            .next()
+            .ok_or(ClientError::InvalidHost)?;
+        let connection = tokio::net::TcpStream::connect(address).await?;
+        let (connection_reader, connection_writer) = connection.into_split();
+
+        *self.reader.lock().await = connection_reader;
+        *self.writer.lock().await = connection_writer;
+
+        self.events.emit(Event::Transfer {
+            host: host.clone(),
+            port,
+        });
+
+        self.send_packet(&protocol::server::handshake::HandShake::new(
+            self.protocol_version,
+            host,
+            port,
+            ClientIntent::Transfer,
+        ))
+        .await?;
+        *self.protocol.write().await = ConnectionProtocol::Login;
+
+        let username = self.username.lock().unwrap().clone().unwrap_or_default();
+        let id = self.id.lock().unwrap().unwrap_or_else(uuid::Uuid::new_v4);
+        self.send_packet(&protocol::server::login::Hello::new(
+            username,
+            wither_network::types::Uuid(id),
+        ))
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn send_packet<P: Packet>(&self, packet: &P) -> Result<(), Box<dyn Error>> {
         let mut encoder = self.encoder.lock().await;
         encoder.append_packet(packet)?;
@@ -167,39 +306,22 @@ impl RawClient {
         Ok(())
     }
 
-    pub async fn peek_packet(&self) -> Option<RawPacket> {
-        loop {
-            self.get_notify("packet").notified().await;
-
-            if let Some(packet) = self.packet_queue.lock().await.pop_front() {
-                return Some(packet);
-            } else {
-                break None;
-            }
-        }
-    }
-
     async fn process_packet(&self, packet: &mut RawPacket) -> Result<(), Box<dyn Error>> {
-        self.packet_queue.lock().await.push_back(packet.clone());
-        self.get_notify("packet").notify_one();
+        self.events.emit(Event::PacketReceived(packet.clone()));
 
         let protocol = *self.protocol.read().await;
 
         match protocol {
-            ConnectionProtocol::HandShake => match packet.id.0 {
-                _ => {
-                    unimplemented!()
-                }
-            },
+            // The server never sends anything before the client picks a protocol.
+            ConnectionProtocol::HandShake => {
+                log::warn!("received packet {} during handshake", packet.id.0);
+            }
             ConnectionProtocol::Status => match packet.id.0 {
-                protocol::client::status::StatusResponse::PACKET_ID => {
-                    todo!()
-                }
-                protocol::client::status::PongResponse::PACKET_ID => {
-                    todo!()
-                }
+                // Read by `ping` through `Event::PacketReceived`.
+                protocol::client::status::StatusResponse::PACKET_ID
+                | protocol::client::status::PongResponse::PACKET_ID => {}
                 _ => {
-                    unimplemented!()
+                    log::warn!("received unknown status packet {}", packet.id.0);
                 }
             },
             ConnectionProtocol::Login => match packet.id.0 {
@@ -211,8 +333,6 @@ impl RawClient {
                     let public_key =
                         RsaPublicKey::new(BigUint::from_bytes_be(&n), BigUint::from_bytes_be(&e))?;
 
-                    println!("public_key : {:?}", public_key);
-
                     let mut rng = rand::rngs::OsRng;
 
                     let symmetric_key = rng.gen::<[u8; 16]>();
@@ -261,45 +381,757 @@ impl RawClient {
 
                     *self.protocol.write().await = ConnectionProtocol::Config;
 
-                    self.get_notify("login").notify_waiters();
+                    self.events.emit(Event::LoginFinished {
+                        uuid: packet.id.0,
+                        username: packet.name,
+                    });
 
-                    // self.send_packet(protocol::client::)
+                    self.send_packet(&protocol::server::config::CustomPayload::brand("vanilla"))
+                        .await?;
+                    self.send_packet(&protocol::server::config::ClientInformation::default())
+                        .await?;
+                }
+                protocol::client::login::CookieRequest::PACKET_ID => {
+                    let packet = protocol::client::login::CookieRequest::read(&mut packet.bytebuf)?;
+
+                    let payload = self.cookies.get(&packet.key).map(|cookie| cookie.clone());
+                    self.send_packet(&protocol::server::login::CookieResponse::new(
+                        packet.key, payload,
+                    ))
+                    .await?;
                 }
                 protocol::client::login::LoginDisconnect::PACKET_ID => {
-                    let packet = protocol::client::login::LoginDisconnect::read(&mut packet.bytebuf)?;
+                    let packet =
+                        protocol::client::login::LoginDisconnect::read(&mut packet.bytebuf)?;
 
                     log::warn!("received disconnect packet! reason: {}", packet.reason);
+                    // The reason is JSON text during login.
+                    let reason = serde_json::from_str(&packet.reason)
+                        .unwrap_or_else(|_| TextComponent::text(packet.reason));
+                    self.disconnect(reason);
+                }
+                protocol::client::login::CustomQuery::PACKET_ID => {
+                    let packet = protocol::client::login::CustomQuery::read(&mut packet.bytebuf)?;
+
+                    // No login plugin channels are understood, like vanilla.
+                    self.send_packet(&protocol::server::login::CustomQueryAnswer::new(
+                        packet.message_id,
+                        None,
+                    ))
+                    .await?;
                 }
                 _ => {
-                    unimplemented!()
+                    log::warn!("received unknown login packet {}", packet.id.0);
                 }
             },
-            _ => {}
+            ConnectionProtocol::Config => match packet.id.0 {
+                protocol::client::config::CookieRequest::PACKET_ID => {
+                    let packet =
+                        protocol::client::config::CookieRequest::read(&mut packet.bytebuf)?;
+
+                    let payload = self.cookies.get(&packet.key).map(|cookie| cookie.clone());
+                    self.send_packet(&protocol::server::config::CookieResponse::new(
+                        packet.key, payload,
+                    ))
+                    .await?;
+                }
+                protocol::client::config::StoreCookie::PACKET_ID => {
+                    let packet = protocol::client::config::StoreCookie::read(&mut packet.bytebuf)?;
+
+                    self.cookies.insert(packet.key, packet.payload);
+                }
+                protocol::client::config::Transfer::PACKET_ID => {
+                    let packet = protocol::client::config::Transfer::read(&mut packet.bytebuf)?;
+
+                    self.transfer(packet.host, packet.port.0 as u16).await?;
+                }
+                protocol::client::config::CustomPayload::PACKET_ID => {
+                    let packet =
+                        protocol::client::config::CustomPayload::read(&mut packet.bytebuf)?;
+
+                    log::debug!("received custom payload on channel {}", packet.channel);
+                }
+                protocol::client::config::Disconnect::PACKET_ID => {
+                    let packet = protocol::client::config::Disconnect::read(&mut packet.bytebuf)?;
+
+                    log::warn!(
+                        "received disconnect packet! reason: {}",
+                        packet.reason.clone().to_pretty_console()
+                    );
+                    self.disconnect(packet.reason);
+                }
+                protocol::client::config::FinishConfiguration::PACKET_ID => {
+                    self.send_packet(&protocol::server::config::FinishConfiguration::new())
+                        .await?;
+
+                    *self.protocol.write().await = ConnectionProtocol::Play;
+
+                    self.events.emit(Event::ConfigFinished);
+                }
+                protocol::client::config::KeepAlive::PACKET_ID => {
+                    let packet = protocol::client::config::KeepAlive::read(&mut packet.bytebuf)?;
+
+                    self.send_packet(&protocol::server::config::KeepAlive::new(
+                        packet.keep_alive_id,
+                    ))
+                    .await?;
+                }
+                protocol::client::config::Ping::PACKET_ID => {
+                    let packet = protocol::client::config::Ping::read(&mut packet.bytebuf)?;
+
+                    self.send_packet(&protocol::server::config::Pong::new(packet.id))
+                        .await?;
+                }
+                protocol::client::config::RegistryData::PACKET_ID => {
+                    let packet = protocol::client::config::RegistryData::read(&mut packet.bytebuf)?;
+
+                    self.registries.insert(packet.registry_id, packet.entries);
+                }
+                protocol::client::config::ResourcePackPush::PACKET_ID => {
+                    let packet =
+                        protocol::client::config::ResourcePackPush::read(&mut packet.bytebuf)?;
+
+                    // We never apply resource packs, but servers that require one kick
+                    // clients that decline, so report it as loaded.
+                    for status in [
+                        protocol::server::config::ResourcePackStatus::Accepted,
+                        protocol::server::config::ResourcePackStatus::Downloaded,
+                        protocol::server::config::ResourcePackStatus::SuccessfullyLoaded,
+                    ] {
+                        self.send_packet(&protocol::server::config::ResourcePack::new(
+                            packet.id, status,
+                        ))
+                        .await?;
+                    }
+                }
+                protocol::client::config::SelectKnownPacks::PACKET_ID => {
+                    let packet =
+                        protocol::client::config::SelectKnownPacks::read(&mut packet.bytebuf)?;
+
+                    // Like vanilla, only claim the packs that ship with the game.
+                    let known_packs = packet
+                        .known_packs
+                        .into_iter()
+                        .filter(|pack| pack.namespace == "minecraft")
+                        .collect();
+
+                    self.send_packet(&protocol::server::config::SelectKnownPacks::new(
+                        known_packs,
+                    ))
+                    .await?;
+                }
+                protocol::client::config::UpdateTags::PACKET_ID => {
+                    let packet = protocol::client::config::UpdateTags::read(&mut packet.bytebuf)?;
+
+                    self.update_tags(packet.registries);
+                }
+                protocol::client::config::ResetChat::PACKET_ID
+                | protocol::client::config::ResourcePackPop::PACKET_ID
+                | protocol::client::config::UpdateEnabledFeatures::PACKET_ID
+                | protocol::client::config::CustomReportDetails::PACKET_ID
+                | protocol::client::config::ServerLinks::PACKET_ID => {}
+                _ => {
+                    log::warn!("received unknown config packet {}", packet.id.0);
+                }
+            },
+            ConnectionProtocol::Play => match packet.id.0 {
+                protocol::client::play::Disconnect::PACKET_ID => {
+                    let packet = protocol::client::play::Disconnect::read(&mut packet.bytebuf)?;
+
+                    log::warn!(
+                        "received disconnect packet! reason: {}",
+                        packet.reason.clone().to_pretty_console()
+                    );
+                    self.disconnect(packet.reason);
+                }
+                protocol::client::play::CookieRequest::PACKET_ID => {
+                    let packet = protocol::client::play::CookieRequest::read(&mut packet.bytebuf)?;
+
+                    let payload = self.cookies.get(&packet.key).map(|cookie| cookie.clone());
+                    self.send_packet(&protocol::server::play::CookieResponse::new(
+                        packet.key, payload,
+                    ))
+                    .await?;
+                }
+                protocol::client::play::StoreCookie::PACKET_ID => {
+                    let packet = protocol::client::play::StoreCookie::read(&mut packet.bytebuf)?;
+
+                    self.cookies.insert(packet.key, packet.payload);
+                }
+                protocol::client::play::Transfer::PACKET_ID => {
+                    let packet = protocol::client::play::Transfer::read(&mut packet.bytebuf)?;
+
+                    self.transfer(packet.host, packet.port.0 as u16).await?;
+                }
+                protocol::client::play::KeepAlive::PACKET_ID => {
+                    let packet = protocol::client::play::KeepAlive::read(&mut packet.bytebuf)?;
+
+                    self.send_packet(&protocol::server::play::KeepAlive::new(
+                        packet.keep_alive_id,
+                    ))
+                    .await?;
+                }
+                protocol::client::play::Ping::PACKET_ID => {
+                    let packet = protocol::client::play::Ping::read(&mut packet.bytebuf)?;
+
+                    self.send_packet(&protocol::server::play::Pong::new(packet.id))
+                        .await?;
+                }
+                protocol::client::play::PlayerPosition::PACKET_ID => {
+                    let packet = protocol::client::play::PlayerPosition::read(&mut packet.bytebuf)?;
+
+                    self.send_packet(&protocol::server::play::AcceptTeleportation::new(
+                        packet.teleport_id.0,
+                    ))
+                    .await?;
+                    let player = self
+                        .entities
+                        .write()
+                        .unwrap()
+                        .player_position(&packet)
+                        .map(|player| (player.position, player.velocity, player.yaw, player.pitch));
+                    let (position, velocity, yaw, pitch) = match player {
+                        Some(player) => player,
+                        // Only relative changes can't be confirmed without knowing where
+                        // the player is.
+                        None if packet.relatives.is_empty() => {
+                            let change = packet.change;
+                            (
+                                change.position,
+                                change.delta_movement,
+                                change.yaw,
+                                change.pitch,
+                            )
+                        }
+                        None => return Ok(()),
+                    };
+                    self.physics
+                        .lock()
+                        .unwrap()
+                        .teleport(position, velocity, yaw, pitch);
+                    self.send_packet(&protocol::server::play::MovePlayerPosRot::new(
+                        position.x, position.y, position.z, yaw, pitch, false, false,
+                    ))
+                    .await?;
+
+                    if !self
+                        .spawned
+                        .swap(true, std::sync::atomic::Ordering::Relaxed)
+                    {
+                        self.events.emit(Event::Spawned);
+                    }
+                }
+                protocol::client::play::Login::PACKET_ID => {
+                    let packet = protocol::client::play::Login::read(&mut packet.bytebuf)?;
+
+                    let uuid = self.id.lock().unwrap().unwrap_or_default();
+                    self.entities
+                        .write()
+                        .unwrap()
+                        .reset(packet.entity_id, uuid);
+                    self.physics.lock().unwrap().respawn();
+                    *self.digger.lock().unwrap() = Digger::new();
+                    let height = self.dimension_height(packet.spawn_info.dimension_type.0);
+                    let mut world = self.world.write().unwrap();
+                    world.change_dimension(packet.spawn_info.dimension_name, height);
+                    world.set_radius(packet.view_distance.0);
+                }
+                protocol::client::play::Respawn::PACKET_ID => {
+                    let packet = protocol::client::play::Respawn::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().respawn();
+                    self.physics.lock().unwrap().respawn();
+                    *self.digger.lock().unwrap() = Digger::new();
+                    let height = self.dimension_height(packet.spawn_info.dimension_type.0);
+                    self.world
+                        .write()
+                        .unwrap()
+                        .change_dimension(packet.spawn_info.dimension_name, height);
+                }
+                protocol::client::play::LevelChunkWithLight::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::LevelChunkWithLight::read(&mut packet.bytebuf)?;
+
+                    self.world.write().unwrap().load_chunk(&packet)?;
+                }
+                protocol::client::play::LightUpdate::PACKET_ID => {
+                    let packet = protocol::client::play::LightUpdate::read(&mut packet.bytebuf)?;
+
+                    self.world.write().unwrap().light_update(&packet);
+                }
+                protocol::client::play::ForgetLevelChunk::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::ForgetLevelChunk::read(&mut packet.bytebuf)?;
+
+                    self.world.write().unwrap().forget_chunk(packet.pos);
+                }
+                protocol::client::play::BlockUpdate::PACKET_ID => {
+                    let packet = protocol::client::play::BlockUpdate::read(&mut packet.bytebuf)?;
+
+                    self.world.write().unwrap().block_update(&packet);
+                    self.pathfinder
+                        .lock()
+                        .unwrap()
+                        .block_changed(packet.location.0);
+                }
+                protocol::client::play::SectionBlocksUpdate::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::SectionBlocksUpdate::read(&mut packet.bytebuf)?;
+
+                    self.world.write().unwrap().section_blocks_update(&packet);
+                    let mut pathfinder = self.pathfinder.lock().unwrap();
+                    for (position, _) in &packet.changes {
+                        pathfinder.block_changed(Vector3::new(
+                            packet.section.x * 16 + position.x as i32,
+                            packet.section.y * 16 + position.y as i32,
+                            packet.section.z * 16 + position.z as i32,
+                        ));
+                    }
+                }
+                protocol::client::play::SetChunkCacheCenter::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::SetChunkCacheCenter::read(&mut packet.bytebuf)?;
+
+                    self.world
+                        .write()
+                        .unwrap()
+                        .set_center(Vector2::new(packet.x.0, packet.z.0));
+                }
+                protocol::client::play::SetChunkCacheRadius::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::SetChunkCacheRadius::read(&mut packet.bytebuf)?;
+
+                    self.world.write().unwrap().set_radius(packet.radius.0);
+                }
+                protocol::client::play::AddEntity::PACKET_ID => {
+                    let packet = protocol::client::play::AddEntity::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().add_entity(&packet);
+                }
+                protocol::client::play::RemoveEntities::PACKET_ID => {
+                    let packet = protocol::client::play::RemoveEntities::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().remove_entities(&packet);
+                }
+                protocol::client::play::MoveEntityPos::PACKET_ID => {
+                    let packet = protocol::client::play::MoveEntityPos::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().move_entity_pos(&packet);
+                }
+                protocol::client::play::MoveEntityPosRot::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::MoveEntityPosRot::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().move_entity_pos_rot(&packet);
+                }
+                protocol::client::play::MoveEntityRot::PACKET_ID => {
+                    let packet = protocol::client::play::MoveEntityRot::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().move_entity_rot(&packet);
+                }
+                protocol::client::play::RotateHead::PACKET_ID => {
+                    let packet = protocol::client::play::RotateHead::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().rotate_head(&packet);
+                }
+                protocol::client::play::TeleportEntity::PACKET_ID => {
+                    let packet = protocol::client::play::TeleportEntity::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().teleport_entity(&packet);
+                }
+                protocol::client::play::EntityPositionSync::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::EntityPositionSync::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().entity_position_sync(&packet);
+                }
+                protocol::client::play::SetEntityMotion::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::SetEntityMotion::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().set_entity_motion(&packet);
+                }
+                protocol::client::play::SetEntityData::PACKET_ID => {
+                    let packet = protocol::client::play::SetEntityData::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().set_entity_data(&packet);
+                }
+                protocol::client::play::SetEquipment::PACKET_ID => {
+                    let packet = protocol::client::play::SetEquipment::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().set_equipment(&packet);
+                }
+                protocol::client::play::UpdateMobEffect::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::UpdateMobEffect::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().update_mob_effect(&packet);
+                }
+                protocol::client::play::RemoveMobEffect::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::RemoveMobEffect::read(&mut packet.bytebuf)?;
+
+                    self.entities.write().unwrap().remove_mob_effect(&packet);
+                }
+                protocol::client::play::ContainerSetContent::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::ContainerSetContent::read(&mut packet.bytebuf)?;
+
+                    self.inventory.lock().unwrap().set_content(&packet);
+                }
+                protocol::client::play::ContainerSetSlot::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::ContainerSetSlot::read(&mut packet.bytebuf)?;
+
+                    self.inventory.lock().unwrap().set_slot(&packet);
+                }
+                protocol::client::play::SetPlayerInventory::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::SetPlayerInventory::read(&mut packet.bytebuf)?;
+
+                    self.inventory.lock().unwrap().set_player_inventory(&packet);
+                }
+                protocol::client::play::SetHeldSlot::PACKET_ID => {
+                    let packet = protocol::client::play::SetHeldSlot::read(&mut packet.bytebuf)?;
+
+                    self.inventory
+                        .lock()
+                        .unwrap()
+                        .select(packet.slot.0 as usize);
+                }
+                protocol::client::play::BlockChangedAck::PACKET_ID => {
+                    let packet =
+                        protocol::client::play::BlockChangedAck::read(&mut packet.bytebuf)?;
+
+                    let confirmed = {
+                        let world = self.world.read().unwrap();
+                        self.digger
+                            .lock()
+                            .unwrap()
+                            .acknowledge(&world, packet.sequence.0)
+                    };
+                    for (position, broken) in confirmed {
+                        self.events.emit(if broken {
+                            Event::BlockBroken(position)
+                        } else {
+                            Event::DigFailed(position)
+                        });
+                    }
+                }
+                protocol::client::play::UpdateTags::PACKET_ID => {
+                    let packet = protocol::client::play::UpdateTags::read(&mut packet.bytebuf)?;
+
+                    self.update_tags(packet.registries);
+                }
+                protocol::client::play::ChunkBatchFinished::PACKET_ID => {
+                    // The server waits for this before sending the next batch.
+                    self.send_packet(&protocol::server::play::ChunkBatchReceived::new(
+                        DESIRED_CHUNKS_PER_TICK,
+                    ))
+                    .await?;
+                }
+                protocol::client::play::StartConfiguration::PACKET_ID => {
+                    self.send_packet(&protocol::server::play::ConfigurationAcknowledged::new())
+                        .await?;
+
+                    *self.protocol.write().await = ConnectionProtocol::Config;
+                }
+                protocol::client::play::SystemChat::PACKET_ID => {
+                    let packet = protocol::client::play::SystemChat::read(&mut packet.bytebuf)?;
+
+                    self.events.emit(Event::Chat(ChatMessage {
+                        sender: None,
+                        content: packet.content,
+                        overlay: packet.overlay,
+                    }));
+                }
+                protocol::client::play::PlayerChat::PACKET_ID => {
+                    let packet = protocol::client::play::PlayerChat::read(&mut packet.bytebuf)?;
+
+                    self.events.emit(Event::Chat(ChatMessage {
+                        sender: Some(packet.sender),
+                        content: packet
+                            .unsigned_content
+                            .unwrap_or_else(|| TextComponent::text(packet.message)),
+                        overlay: false,
+                    }));
+                }
+                protocol::client::play::DisguisedChat::PACKET_ID => {
+                    let packet = protocol::client::play::DisguisedChat::read(&mut packet.bytebuf)?;
+
+                    self.events.emit(Event::Chat(ChatMessage {
+                        sender: None,
+                        content: packet.message,
+                        overlay: false,
+                    }));
+                }
+                _ => {}
+            },
         }
 
         Ok(())
+    }
+
+    /// Runs one client tick, moving the player and sending what the vanilla client sends
+    /// at the end of a tick.
+    pub async fn tick(&self) -> Result<(), Box<dyn Error>> {
+        if *self.protocol.read().await != ConnectionProtocol::Play {
+            return Ok(());
+        }
+
+        // The world and entities stay locked while the pathfinder searches, which is at
+        // most `PathOptions::nodes_per_tick` blocks, longer searches go on next tick.
+        let path_update = {
+            let physics = self.physics.lock().unwrap().clone();
+            let world = self.world.read().unwrap();
+            let entities = self.entities.read().unwrap();
+            self.pathfinder
+                .lock()
+                .unwrap()
+                .tick(&world, &entities, &physics)
+        };
+        let (packets, player_id, dig_updates, swing) = {
+            let mut world = self.world.write().unwrap();
+            let mut physics = self.physics.lock().unwrap();
+            let mut digger = self.digger.lock().unwrap();
+            match path_update {
+                Some(PathUpdate::Steer(steering)) => {
+                    physics.input = steering.input;
+                    physics.yaw = steering.yaw;
+                    physics.pitch = steering.pitch;
+                    if let Some(PathAction::Dig(position, face)) = steering.action {
+                        digger.dig(position, face);
+                    }
+                }
+                Some(PathUpdate::Reached | PathUpdate::NoPath) => {
+                    physics.input = MovementInput::new();
+                }
+                None => {
+                    if let Some(target) = digger.target() {
+                        physics.look_at(Vector3::new(
+                            target.x as f64 + 0.5,
+                            target.y as f64 + 0.5,
+                            target.z as f64 + 0.5,
+                        ));
+                    }
+                }
+            }
+
+            // Like vanilla, digging happens before moving.
+            let miner = self.miner(&world, &physics);
+            let mut dig_updates = Vec::new();
+            let mut swing = digger.target().is_some();
+            for update in digger.tick(
+                &world,
+                &miner,
+                &self.block_tags.read().unwrap(),
+                physics.eye_position(),
+            ) {
+                let mut sequence = 0;
+                if let DigUpdate::Send(packet) = update {
+                    if packet.status
+                        != protocol::server::play::PlayerActionStatus::AbortDestroyBlock
+                    {
+                        sequence = self.next_sequence();
+                        swing = true;
+                    }
+                    if packet.breaks {
+                        digger.predict(&mut world, sequence, packet.position);
+                    }
+                }
+                dig_updates.push((update, sequence));
+            }
+            drop(digger);
+
+            let packets = physics.tick(&world);
+            let mut entities = self.entities.write().unwrap();
+            if packets.is_some() {
+                if let Some(player) = entities.player_mut() {
+                    player.position = physics.position;
+                    player.velocity = physics.velocity;
+                    player.yaw = physics.yaw;
+                    player.head_yaw = physics.yaw;
+                    player.pitch = physics.pitch;
+                    player.on_ground = physics.on_ground;
+                }
+            }
+            (
+                packets,
+                entities.player().map(|player| player.id),
+                dig_updates,
+                swing,
+            )
+        };
 
-        // println!("clientbound::Hello : {:?}", packet);
+        match path_update {
+            Some(PathUpdate::Steer(steering)) => {
+                if let Some(PathAction::UseOn(position, face)) = steering.action {
+                    self.use_item_on(position, face).await?;
+                }
+            }
+            Some(PathUpdate::Reached) => self.events.emit(Event::GoalReached),
+            Some(PathUpdate::NoPath) => self.events.emit(Event::PathNotFound),
+            None => (),
+        }
+
+        for (update, sequence) in dig_updates {
+            match update {
+                DigUpdate::Send(packet) => {
+                    self.send_packet(&protocol::server::play::PlayerAction::new(
+                        packet.status,
+                        WorldPosition(packet.position),
+                        packet.face,
+                        sequence,
+                    ))
+                    .await?;
+                }
+                DigUpdate::Failed(position) => self.events.emit(Event::DigFailed(position)),
+            }
+        }
+        if swing {
+            self.send_packet(&protocol::server::play::Swing::new(
+                wither_network::Hand::Main,
+            ))
+            .await?;
+        }
 
-        // let packet = match client.peek_packet().await {
-        //     Some(mut packet) => packet,
-        //     _ => {
-        //         break;
-        //     }
-        // };
+        if let Some(packets) = packets {
+            if let Some(input) = packets.input {
+                self.send_packet(&input).await?;
+            }
+            if let (Some(sprinting), Some(player_id)) = (packets.sprinting, player_id) {
+                let action = if sprinting {
+                    protocol::server::play::PlayerCommandAction::StartSprinting
+                } else {
+                    protocol::server::play::PlayerCommandAction::StopSprinting
+                };
+                self.send_packet(&protocol::server::play::PlayerCommand::new(
+                    player_id, action, 0,
+                ))
+                .await?;
+            }
+            match packets.movement {
+                Some(MovePacket::PosRot(packet)) => self.send_packet(&packet).await?,
+                Some(MovePacket::Pos(packet)) => self.send_packet(&packet).await?,
+                Some(MovePacket::Rot(packet)) => self.send_packet(&packet).await?,
+                Some(MovePacket::StatusOnly(packet)) => self.send_packet(&packet).await?,
+                None => (),
+            }
+        }
+        self.send_packet(&protocol::server::play::ClientTickEnd::new())
+            .await
     }
 
-    pub fn get_notify(
+    fn next_sequence(&self) -> i32 {
+        self.sequence
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            + 1
+    }
+
+    /// Right clicks the block with the main hand.
+    async fn use_item_on(
         &self,
-        name: &str,
-    ) -> dashmap::mapref::one::Ref<'_, String, tokio::sync::Notify> {
-        self.notify.get(name).unwrap()
+        position: Vector3<i32>,
+        face: wither_world::block::BlockFace,
+    ) -> Result<(), Box<dyn Error>> {
+        self.send_packet(&protocol::server::play::UseItemOn::new(
+            wither_network::Hand::Main,
+            WorldPosition(position),
+            face,
+            Vector3::new(0.5, 0.5, 0.5),
+            false,
+            self.next_sequence(),
+        ))
+        .await?;
+        self.send_packet(&protocol::server::play::Swing::new(
+            wither_network::Hand::Main,
+        ))
+        .await
+    }
+
+    /// Heights of an entry of the `minecraft:dimension_type` registry. Entries the server
+    /// left out are vanilla ones.
+    fn dimension_height(&self, dimension_type: i32) -> DimensionHeight {
+        let Some(entries) = self.registries.get(&Identifier::vanilla("dimension_type")) else {
+            return DimensionHeight::default();
+        };
+        let Some(entry) = usize::try_from(dimension_type)
+            .ok()
+            .and_then(|id| entries.get(id))
+        else {
+            return DimensionHeight::default();
+        };
+        match &entry.data {
+            Some(NbtTag::Compound(data)) => data
+                .get_int("min_y")
+                .zip(data.get_int("height"))
+                .map(|(min_y, height)| DimensionHeight { min_y, height }),
+            _ => get_dimension_height(&entry.entry_id.to_string()),
+        }
+        .unwrap_or_default()
+    }
+
+    fn update_tags(&self, registries: Vec<protocol::client::config::RegistryTags>) {
+        for registry in registries {
+            if registry.registry == Identifier::vanilla("block") {
+                *self.block_tags.write().unwrap() = BlockTags::new(&registry.tags);
+            }
+            self.tags.insert(registry.registry, registry.tags);
+        }
+    }
+
+    /// What changes how fast the player breaks blocks right now.
+    fn miner(&self, world: &ClientWorld, physics: &PlayerPhysics) -> Miner {
+        let enchantment = |name: &str| {
+            self.registries
+                .get(&Identifier::vanilla("enchantment"))
+                .and_then(|entries| {
+                    entries
+                        .iter()
+                        .position(|entry| entry.entry_id == Identifier::vanilla(name))
+                })
+                .map_or(-1, |id| id as i32)
+        };
+        let effects = self
+            .entities
+            .read()
+            .unwrap()
+            .player()
+            .map(|player| player.effects.clone())
+            .unwrap_or_default();
+        let inventory = self.inventory.lock().unwrap();
+        let eye = physics.eye_position();
+        let haste = [digging::HASTE, digging::CONDUIT_POWER]
+            .iter()
+            .filter_map(|effect| effects.get(effect).copied())
+            .max();
+
+        Miner {
+            tool: digging::held_tool(inventory.held_item()),
+            efficiency: digging::enchantment_level(
+                inventory.held_item(),
+                enchantment("efficiency"),
+            ),
+            haste,
+            mining_fatigue: effects.get(&digging::MINING_FATIGUE).copied(),
+            aqua_affinity: digging::enchantment_level(
+                inventory.helmet(),
+                enchantment("aqua_affinity"),
+            ) > 0,
+            underwater: physics::is_eye_in_water(world, eye),
+            on_ground: physics.on_ground,
+        }
     }
 
     pub fn close(&self) {
-        self.closed
-            .store(true, std::sync::atomic::Ordering::Relaxed);
-        self.get_notify("close").notify_waiters();
+        self.disconnect(TextComponent::text("Connection closed"));
+    }
+
+    fn disconnect(&self, reason: TextComponent) {
+        if self.closed.swap(true, std::sync::atomic::Ordering::Relaxed) {
+            return;
+        }
+        self.events.emit(Event::Disconnect(reason));
 
         log::info!(
             "Closed connection for {}",
@@ -384,7 +1216,27 @@ pub struct Client {
 
 impl Client {
     pub async fn new(host: &str, port: u16, username: &str) -> Result<Self, Box<dyn Error>> {
-        let raw_client = Arc::new(RawClient::new(host, port).await?);
+        Self::with_options(host, port, username, ClientOptions::new()).await
+    }
+
+    /// Connects with an event bus the caller has already subscribed to, so no event of
+    /// the connection is missed.
+    pub async fn with_events(
+        host: &str,
+        port: u16,
+        username: &str,
+        events: EventBus,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::with_options(host, port, username, ClientOptions::new().events(events)).await
+    }
+
+    pub async fn with_options(
+        host: &str,
+        port: u16,
+        username: &str,
+        options: ClientOptions,
+    ) -> Result<Self, Box<dyn Error>> {
+        let raw_client = Arc::new(RawClient::new(host, port, options).await?);
 
         tokio::spawn({
             let client = raw_client.clone();
@@ -401,9 +1253,256 @@ impl Client {
             }
         });
 
+        tokio::spawn({
+            let client = raw_client.clone();
+            async move {
+                let mut interval = tokio::time::interval(TICK_DURATION);
+                while !client.closed.load(std::sync::atomic::Ordering::Relaxed) {
+                    interval.tick().await;
+                    if let Err(err) = client.tick().await {
+                        log::warn!("Failed to tick: {:?}", err);
+                    }
+                }
+            }
+        });
+
         raw_client.set_protocol(ClientIntent::Login).await?;
         raw_client.attempt_login(username).await?;
 
         Ok(Self { raw_client })
     }
+
+    pub fn events(&self) -> &EventBus {
+        &self.raw_client.events
+    }
+
+    /// The entity of the player, once the client is in the world.
+    pub fn living_entity(&self) -> Option<Entity> {
+        self.raw_client.entities.read().unwrap().player().cloned()
+    }
+
+    /// Holds the given movement keys until they are changed again.
+    pub fn set_input(&self, input: MovementInput) {
+        self.raw_client.physics.lock().unwrap().input = input;
+    }
+
+    pub fn look(&self, yaw: f32, pitch: f32) {
+        let mut physics = self.raw_client.physics.lock().unwrap();
+        physics.yaw = yaw;
+        physics.pitch = pitch.clamp(-90.0, 90.0);
+    }
+
+    /// What the crosshair of the player points at within `max_distance` blocks, an entity
+    /// if it is in front of the first block.
+    pub fn raycast(&self, max_distance: f64) -> Option<RaycastHit> {
+        let (eye, direction) = {
+            let physics = self.raw_client.physics.lock().unwrap();
+            (physics.eye_position(), physics.view_direction())
+        };
+        let block = self
+            .raw_client
+            .world
+            .read()
+            .unwrap()
+            .raycast(eye, direction, max_distance);
+        let distance = block.map_or(max_distance, |block| {
+            block.point.squared_distance_to_vec(eye).sqrt()
+        });
+        let entity =
+            self.raw_client
+                .entities
+                .read()
+                .unwrap()
+                .raycast(eye, direction, distance, |_| true);
+        entity
+            .map(RaycastHit::Entity)
+            .or(block.map(RaycastHit::Block))
+    }
+
+    /// Whether the eyes of the entity can be seen from those of the player.
+    pub fn can_see(&self, id: i32) -> bool {
+        let target = {
+            let entities = self.raw_client.entities.read().unwrap();
+            let Some(entity) = entities.get(id) else {
+                return false;
+            };
+            entity
+                .position
+                .add(&Vector3::new(0.0, entity.eye_height(), 0.0))
+        };
+        let eye = self.raw_client.physics.lock().unwrap().eye_position();
+        self.raw_client
+            .world
+            .read()
+            .unwrap()
+            .has_line_of_sight(eye, target)
+    }
+
+    /// Starts breaking the block, taking as long as the vanilla client would. Emits
+    /// `BlockBroken` once the server confirms it, or `DigFailed`.
+    pub fn dig(&self, position: Vector3<i32>) {
+        let eye = self.raw_client.physics.lock().unwrap().eye_position();
+        let center = Vector3::new(
+            position.x as f64 + 0.5,
+            position.y as f64 + 0.5,
+            position.z as f64 + 0.5,
+        );
+        let face = self
+            .raw_client
+            .world
+            .read()
+            .unwrap()
+            .raycast(eye, center.sub(&eye), digging::BLOCK_REACH + 1.0)
+            .filter(|hit| hit.position == position)
+            .map_or_else(|| pathfinder::facing_face(eye, position), |hit| hit.face);
+        self.raw_client.digger.lock().unwrap().dig(position, face);
+    }
+
+    pub fn stop_digging(&self) {
+        self.raw_client.digger.lock().unwrap().stop();
+    }
+
+    /// Ticks it would take the player to break the block right now, 0 if it breaks
+    /// instantly and `None` if it can't be broken.
+    pub fn break_ticks(&self, position: Vector3<i32>) -> Option<u32> {
+        let raw_client = &self.raw_client;
+        let world = raw_client.world.read().unwrap();
+        let miner = raw_client.miner(&world, &raw_client.physics.lock().unwrap());
+        let state = digging::block_at(&world, position);
+        miner.break_ticks(state, &raw_client.block_tags.read().unwrap())
+    }
+
+    /// Walks to `goal` with the pathfinder, replacing any previous goal. Emits
+    /// `GoalReached` or `PathNotFound` when done.
+    pub fn goto(&self, goal: Goal) {
+        self.raw_client.pathfinder.lock().unwrap().set_goal(goal);
+    }
+
+    /// Forgets the goal of the pathfinder and stops moving.
+    pub fn stop_pathing(&self) {
+        self.raw_client.pathfinder.lock().unwrap().stop();
+        self.set_input(MovementInput::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+    use wither_network::codec::var_int::VarInt;
+
+    use super::*;
+
+    async fn connect(listener: &TcpListener) -> RawClient {
+        let port = listener.local_addr().unwrap().port();
+        RawClient::new("127.0.0.1", port, ClientOptions::new())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn transfer_starts_a_clean_connection() {
+        let first = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let second = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = connect(&first).await;
+        *client.username.lock().unwrap() = Some("Steve".to_string());
+        *client.protocol.write().await = ConnectionProtocol::Play;
+        let mut encoder = client.encoder.lock().await;
+        encoder.set_encryption(Some(&[7; 16]));
+        encoder
+            .set_compression(Some((CompressionThreshold(0), CompressionLevel(6))))
+            .unwrap();
+        drop(encoder);
+        client.decoder.lock().await.set_compression(true);
+
+        let port = second.local_addr().unwrap().port();
+        client
+            .transfer("127.0.0.1".to_string(), port)
+            .await
+            .unwrap();
+        assert_eq!(*client.protocol.read().await, ConnectionProtocol::Login);
+
+        // A fresh decoder only reads the packets if they are neither compressed nor
+        // encrypted.
+        let (mut stream, _) = second.accept().await.unwrap();
+        let mut decoder = PacketDecoder::default();
+        let mut packets = Vec::new();
+        while packets.len() < 2 {
+            let mut buf = [0; 1024];
+            let read = stream.read(&mut buf).await.unwrap();
+            assert_ne!(read, 0);
+            decoder.queue_slice(&buf[..read]);
+            while let Some(packet) = decoder.decode().unwrap() {
+                packets.push(packet);
+            }
+        }
+        let handshake =
+            protocol::server::handshake::HandShake::read(&mut packets[0].bytebuf).unwrap();
+        assert_eq!(handshake.intention, ClientIntent::Transfer);
+        assert_eq!(handshake.port, port);
+        let hello = protocol::server::login::Hello::read(&mut packets[1].bytebuf).unwrap();
+        assert_eq!(hello.name, "Steve");
+    }
+
+    #[tokio::test]
+    async fn answers_login_plugin_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = connect(&listener).await;
+        let (mut stream, _) = listener.accept().await.unwrap();
+        *client.protocol.write().await = ConnectionProtocol::Login;
+
+        let mut bytebuf = bytes::BytesMut::new();
+        protocol::client::login::CustomQuery::new(
+            VarInt(5),
+            Identifier {
+                namespace: "velocity".to_string(),
+                path: "player_info".to_string(),
+            },
+            bytes::Bytes::from_static(&[1]),
+        )
+        .write(&mut bytebuf);
+        client
+            .process_packet(&mut RawPacket {
+                id: VarInt(protocol::client::login::CustomQuery::PACKET_ID),
+                bytebuf: bytebuf.freeze(),
+            })
+            .await
+            .unwrap();
+
+        let mut decoder = PacketDecoder::default();
+        let mut buf = [0; 64];
+        let packet = loop {
+            if let Some(packet) = decoder.decode().unwrap() {
+                break packet;
+            }
+            let read = stream.read(&mut buf).await.unwrap();
+            assert_ne!(read, 0);
+            decoder.queue_slice(&buf[..read]);
+        };
+        assert_eq!(
+            packet.id.0,
+            protocol::server::login::CustomQueryAnswer::PACKET_ID
+        );
+        let answer =
+            protocol::server::login::CustomQueryAnswer::read(&mut packet.bytebuf.clone()).unwrap();
+        assert_eq!(answer.message_id.0, 5);
+        assert!(answer.data.is_none());
+    }
+
+    #[tokio::test]
+    async fn failed_transfer_closes_the_connection() {
+        let first = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = connect(&first).await;
+        let mut events = client.events.subscribe();
+        let port = {
+            let unused = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            unused.local_addr().unwrap().port()
+        };
+
+        assert!(client
+            .transfer("127.0.0.1".to_string(), port)
+            .await
+            .is_err());
+        assert!(client.closed.load(std::sync::atomic::Ordering::Relaxed));
+        assert!(matches!(events.try_recv(), Some(Event::Disconnect(_))));
+    }
 }
@@ -0,0 +1,55 @@
+use wither_network::CURRENT_MC_PROTOCOL;
+
+use crate::event::EventBus;
+
+/// How a client connects, everything not set falls back to the vanilla behaviour.
+#[derive(Clone)]
+pub struct ClientOptions {
+    pub protocol_version: i32,
+    /// Host name sent in the handshake, defaults to the host that was dialled.
+    ///
+    /// Proxies like BungeeCord and Velocity route by this, and it should be the name
+    /// before SRV resolution.
+    pub virtual_host: Option<String>,
+    /// Port sent in the handshake, defaults to the port that was dialled.
+    pub virtual_port: Option<u16>,
+    pub events: EventBus,
+}
+
+impl ClientOptions {
+    pub fn new() -> Self {
+        Self {
+            protocol_version: CURRENT_MC_PROTOCOL.get() as i32,
+            virtual_host: None,
+            virtual_port: None,
+            events: EventBus::new(),
+        }
+    }
+
+    pub fn protocol_version(mut self, protocol_version: i32) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    pub fn virtual_host(mut self, host: impl Into<String>) -> Self {
+        self.virtual_host = Some(host.into());
+        self
+    }
+
+    pub fn virtual_port(mut self, port: u16) -> Self {
+        self.virtual_port = Some(port);
+        self
+    }
+
+    /// Uses a bus the caller has already subscribed to, so no event is missed.
+    pub fn events(mut self, events: EventBus) -> Self {
+        self.events = events;
+        self
+    }
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
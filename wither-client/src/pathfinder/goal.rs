@@ -0,0 +1,61 @@
+use wither_util::math::vector3::Vector3;
+
+/// Where the pathfinder should take the player, positions are of the feet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Goal {
+    /// Standing in exactly this block.
+    Block(Vector3<i32>),
+    /// Standing within `radius` blocks of a position.
+    Near { position: Vector3<i32>, radius: f64 },
+    /// Standing in this column, at any height.
+    XZ { x: i32, z: i32 },
+    /// Standing at this height, anywhere.
+    Y(i32),
+    /// Staying within `radius` blocks of an entity, the path is replanned as it moves.
+    Entity { id: i32, radius: f64 },
+}
+
+impl Goal {
+    /// `Entity` goals are planned as `Near` goals around where the entity is.
+    pub(crate) fn is_reached(&self, position: Vector3<i32>) -> bool {
+        match *self {
+            Goal::Block(target) => position == target,
+            Goal::Near {
+                position: target,
+                radius,
+            } => distance_squared(position, target) <= radius * radius,
+            Goal::XZ { x, z } => position.x == x && position.z == z,
+            Goal::Y(y) => position.y == y,
+            Goal::Entity { .. } => false,
+        }
+    }
+
+    /// Blocks any path from `position` to the goal moves at least, horizontally and
+    /// vertically.
+    pub(crate) fn heuristic(&self, position: Vector3<i32>) -> f64 {
+        match *self {
+            Goal::Block(target) => block_distance(position, target),
+            Goal::Near {
+                position: target,
+                radius,
+            } => (distance_squared(position, target).sqrt() - radius).max(0.0),
+            Goal::XZ { x, z } => horizontal_distance(position, x, z),
+            Goal::Y(y) => (position.y - y).abs() as f64,
+            Goal::Entity { .. } => 0.0,
+        }
+    }
+}
+
+fn distance_squared(a: Vector3<i32>, b: Vector3<i32>) -> f64 {
+    let (x, y, z) = ((a.x - b.x) as f64, (a.y - b.y) as f64, (a.z - b.z) as f64);
+    x * x + y * y + z * z
+}
+
+fn horizontal_distance(position: Vector3<i32>, x: i32, z: i32) -> f64 {
+    let (x, z) = ((position.x - x) as f64, (position.z - z) as f64);
+    (x * x + z * z).sqrt()
+}
+
+fn block_distance(a: Vector3<i32>, b: Vector3<i32>) -> f64 {
+    horizontal_distance(a, b.x, b.z) + (a.y - b.y).abs() as f64
+}
@@ -0,0 +1,873 @@
+mod goal;
+mod moves;
+
+pub use goal::Goal;
+pub(crate) use moves::facing_face;
+pub use moves::{Movement, Step};
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    task::Poll,
+};
+
+use wither_util::math::vector3::Vector3;
+use wither_world::block::BlockFace;
+
+use crate::{
+    entities::EntityTracker,
+    physics::{look_rotation, MovementInput, PlayerPhysics},
+    world::ClientWorld,
+};
+
+/// Blocks a followed entity has to move before the path is planned again.
+const FOLLOW_REPLAN_DISTANCE: f64 = 2.0;
+/// Ticks without getting closer to the next step before the path is planned again.
+const STUCK_TICKS: u32 = 60;
+/// Ticks before a door or block placement is tried again.
+const USE_COOLDOWN: u32 = 10;
+
+/// Costs of each kind of movement, in blocks walked.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Costs {
+    pub walk: f64,
+    /// Added to walking for jumping up a block.
+    pub jump: f64,
+    /// Per block fallen.
+    pub fall: f64,
+    pub swim: f64,
+    pub climb: f64,
+    /// Added for opening a door or fence gate.
+    pub door: f64,
+    /// Added for every block broken.
+    pub break_block: f64,
+    /// Added for every tick spent breaking a block.
+    pub break_tick: f64,
+    /// Added for every block placed.
+    pub place: f64,
+}
+
+impl Costs {
+    /// The least any move costs per block it moves, which keeps the heuristic from
+    /// overestimating.
+    fn min_per_block(&self) -> f64 {
+        [self.walk, self.jump, self.fall, self.swim, self.climb]
+            .into_iter()
+            .fold(f64::INFINITY, f64::min)
+            .max(0.0)
+    }
+}
+
+impl Default for Costs {
+    fn default() -> Self {
+        Self {
+            walk: 1.0,
+            jump: 1.0,
+            fall: 0.5,
+            swim: 2.0,
+            climb: 1.5,
+            door: 1.0,
+            break_block: 2.0,
+            break_tick: 0.1,
+            place: 4.0,
+        }
+    }
+}
+
+/// What the pathfinder may do to reach a goal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathOptions {
+    pub costs: Costs,
+    /// Break blocks in the way with whatever is held.
+    pub allow_break: bool,
+    /// Place the held block to bridge gaps and pillar up.
+    pub allow_place: bool,
+    /// Highest fall taken, falls into water are always taken.
+    pub max_drop: u32,
+    pub sprint: bool,
+    /// Blocks looked at before settling for a path that only gets closer.
+    pub max_nodes: usize,
+    /// Blocks looked at per tick, the search goes on the next tick after that.
+    pub nodes_per_tick: usize,
+}
+
+impl PathOptions {
+    pub fn new() -> Self {
+        Self {
+            costs: Costs::default(),
+            allow_break: false,
+            allow_place: false,
+            max_drop: 3,
+            sprint: true,
+            max_nodes: 20_000,
+            nodes_per_tick: 5_000,
+        }
+    }
+
+    pub fn costs(mut self, costs: Costs) -> Self {
+        self.costs = costs;
+        self
+    }
+
+    pub fn allow_break(mut self, allow_break: bool) -> Self {
+        self.allow_break = allow_break;
+        self
+    }
+
+    pub fn allow_place(mut self, allow_place: bool) -> Self {
+        self.allow_place = allow_place;
+        self
+    }
+
+    pub fn max_drop(mut self, max_drop: u32) -> Self {
+        self.max_drop = max_drop;
+        self
+    }
+
+    pub fn sprint(mut self, sprint: bool) -> Self {
+        self.sprint = sprint;
+        self
+    }
+
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    pub fn nodes_per_tick(mut self, nodes_per_tick: usize) -> Self {
+        self.nodes_per_tick = nodes_per_tick;
+        self
+    }
+}
+
+impl Default for PathOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Steps from where the search started, which is the first step.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path {
+    pub steps: Vec<Step>,
+    pub cost: f64,
+    /// `false` if the search gave up and the path only gets closer to the goal.
+    pub complete: bool,
+}
+
+/// Cost to reach each block so far, and the step that got there from which block.
+type Visited = HashMap<Vector3<i32>, (f64, Option<(Vector3<i32>, Step)>)>;
+
+#[derive(Debug, PartialEq)]
+struct Open {
+    estimate: f64,
+    /// Cost to reach the block when it was pushed.
+    cost: f64,
+    position: Vector3<i32>,
+}
+
+impl Eq for Open {}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, the heap pops the lowest estimate first.
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// An A* search that can be spread over several ticks.
+#[derive(Debug)]
+struct Search {
+    start: Vector3<i32>,
+    /// Cost of the path up to `start`, when planning the rest of a path.
+    start_cost: f64,
+    goal: Goal,
+    /// Turns heuristics into costs, see `Costs::min_per_block`.
+    scale: f64,
+    open: BinaryHeap<Open>,
+    visited: Visited,
+    /// Heuristic of the block closest to the goal, and that block.
+    closest: (f64, Vector3<i32>),
+    expanded: usize,
+}
+
+impl Search {
+    fn new(start: Vector3<i32>, start_cost: f64, goal: Goal, options: &PathOptions) -> Self {
+        let scale = options.costs.min_per_block();
+        let mut search = Self {
+            start,
+            start_cost,
+            goal,
+            scale,
+            open: BinaryHeap::new(),
+            visited: Visited::new(),
+            closest: (goal.heuristic(start), start),
+            expanded: 0,
+        };
+        search.visited.insert(start, (start_cost, None));
+        search.open.push(Open {
+            estimate: start_cost + goal.heuristic(start) * scale,
+            cost: start_cost,
+            position: start,
+        });
+        search
+    }
+
+    /// Looks at up to `nodes` more blocks. Ready once the goal is reached, `None` if the
+    /// search gave up without getting closer to it than `start`.
+    fn advance(
+        &mut self,
+        world: &ClientWorld,
+        options: &PathOptions,
+        nodes: usize,
+    ) -> Poll<Option<Path>> {
+        for _ in 0..nodes {
+            let Some(Open { position, cost, .. }) = self.open.pop() else {
+                return Poll::Ready(self.closest_path());
+            };
+            // Left behind when a cheaper way to the block was found.
+            if cost > self.visited[&position].0 {
+                continue;
+            }
+            if self.goal.is_reached(position) {
+                return Poll::Ready(Some(build_path(&self.visited, self.start, position, true)));
+            }
+            self.expanded += 1;
+            if self.expanded > options.max_nodes {
+                return Poll::Ready(self.closest_path());
+            }
+            for (step, step_cost) in moves::neighbours(world, position, options) {
+                let next_cost = cost + step_cost;
+                let next = step.position;
+                if self
+                    .visited
+                    .get(&next)
+                    .is_some_and(|(known, _)| *known <= next_cost)
+                {
+                    continue;
+                }
+                let heuristic = self.goal.heuristic(next);
+                if heuristic < self.closest.0 {
+                    self.closest = (heuristic, next);
+                }
+                self.visited
+                    .insert(next, (next_cost, Some((position, step))));
+                self.open.push(Open {
+                    estimate: next_cost + heuristic * self.scale,
+                    cost: next_cost,
+                    position: next,
+                });
+            }
+        }
+        Poll::Pending
+    }
+
+    fn closest_path(&self) -> Option<Path> {
+        let closest = self.closest.1;
+        (closest != self.start).then(|| build_path(&self.visited, self.start, closest, false))
+    }
+
+    /// Whether a change of `block` can change the cost of a block already looked at.
+    fn touches(&self, block: Vector3<i32>) -> bool {
+        // Moves look at the blocks from below the feet to above the head, next to them.
+        (-1..=1).any(|x| {
+            (-2..=1).any(|y| {
+                (-1..=1).any(|z| {
+                    self.visited
+                        .contains_key(&block.add(&Vector3::new(x, y, z)))
+                })
+            })
+        })
+    }
+
+    fn restart(&mut self, options: &PathOptions) {
+        *self = Self::new(self.start, self.start_cost, self.goal, options);
+    }
+}
+
+/// Finds the cheapest path from `start` to `goal` with A*. When the goal can't be
+/// reached within `options.max_nodes` blocks, the path to the block closest to it is
+/// returned instead, `None` if no block is closer than `start`.
+pub fn find_path(
+    world: &ClientWorld,
+    start: Vector3<i32>,
+    goal: &Goal,
+    options: &PathOptions,
+) -> Option<Path> {
+    let mut search = Search::new(start, 0.0, *goal, options);
+    loop {
+        if let Poll::Ready(path) = search.advance(world, options, usize::MAX) {
+            return path;
+        }
+    }
+}
+
+fn build_path(visited: &Visited, start: Vector3<i32>, end: Vector3<i32>, complete: bool) -> Path {
+    let mut steps = Vec::new();
+    let mut position = end;
+    while let Some((from, step)) = &visited[&position].1 {
+        let mut step = step.clone();
+        step.cost = visited[&position].0;
+        steps.push(step);
+        position = *from;
+    }
+    let mut first = Step::new(start, Movement::Start);
+    first.cost = visited[&start].0;
+    steps.push(first);
+    steps.reverse();
+    Path {
+        steps,
+        cost: visited[&end].0,
+        complete,
+    }
+}
+
+/// Something the player has to do to a block before moving on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathAction {
+    /// Breaks the block, sent every tick until it is gone.
+    Dig(Vector3<i32>, BlockFace),
+    /// Right clicks the block, to open a door or to place the held block against it.
+    UseOn(Vector3<i32>, BlockFace),
+}
+
+/// How to move the player this tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Steering {
+    pub input: MovementInput,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub action: Option<PathAction>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathUpdate {
+    Steer(Steering),
+    /// The goal is reached and forgotten.
+    Reached,
+    /// No step gets closer to the goal, it is forgotten.
+    NoPath,
+}
+
+/// Plans paths to a goal and steers the player along them.
+#[derive(Debug, Default)]
+pub struct Pathfinder {
+    pub options: PathOptions,
+    goal: Option<Goal>,
+    path: Option<Path>,
+    /// Index of the step walked to.
+    next: usize,
+    /// Steps from this one on go through blocks that changed since planning.
+    changed_from: Option<usize>,
+    /// The search still planning, and whether it plans the rest of `path` or all of it.
+    search: Option<(Search, bool)>,
+    /// Where a followed entity was when its path was planned.
+    follow_position: Option<Vector3<f64>>,
+    closest_distance: f64,
+    stuck_ticks: u32,
+    use_cooldown: u32,
+}
+
+impl Pathfinder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn goal(&self) -> Option<&Goal> {
+        self.goal.as_ref()
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_ref()
+    }
+
+    pub fn set_goal(&mut self, goal: Goal) {
+        self.stop();
+        self.goal = Some(goal);
+    }
+
+    pub fn stop(&mut self) {
+        *self = Self {
+            options: self.options.clone(),
+            ..Self::new()
+        };
+    }
+
+    /// Marks the rest of the path for replanning if it goes through the changed block.
+    pub fn block_changed(&mut self, position: Vector3<i32>) {
+        if let Some((search, _)) = &mut self.search {
+            if search.touches(position) {
+                search.restart(&self.options);
+            }
+        }
+        let Some(path) = &self.path else {
+            return;
+        };
+        let changed = path
+            .steps
+            .windows(2)
+            .enumerate()
+            .skip(self.next.saturating_sub(1))
+            .find(|(_, steps)| steps[1].touches(steps[0].position, position));
+        if let Some((index, _)) = changed {
+            let index = index + 1;
+            self.changed_from = Some(self.changed_from.map_or(index, |from| from.min(index)));
+        }
+    }
+
+    /// Decides how the player moves this tick, `None` without a goal. While a path is
+    /// searched for, the player waits or walks the part of the path that is still fine.
+    pub fn tick(
+        &mut self,
+        world: &ClientWorld,
+        entities: &EntityTracker,
+        physics: &PlayerPhysics,
+    ) -> Option<PathUpdate> {
+        let goal = self.goal?;
+        let position = current_node(world, physics.position);
+
+        let planned_goal = match goal {
+            Goal::Entity { id, radius } => {
+                let Some(entity) = entities.get(id) else {
+                    self.stop();
+                    return Some(PathUpdate::NoPath);
+                };
+                let moved = self.follow_position.is_none_or(|followed| {
+                    followed.squared_distance_to_vec(entity.position)
+                        > FOLLOW_REPLAN_DISTANCE * FOLLOW_REPLAN_DISTANCE
+                });
+                if moved {
+                    self.path = None;
+                    self.search = None;
+                    self.follow_position = Some(entity.position);
+                }
+                let target = entity.position;
+                Goal::Near {
+                    position: Vector3::new(
+                        target.x.floor() as i32,
+                        target.y.floor() as i32,
+                        target.z.floor() as i32,
+                    ),
+                    radius,
+                }
+            }
+            goal => goal,
+        };
+
+        if planned_goal.is_reached(position) {
+            if let Goal::Entity { .. } = goal {
+                self.path = None;
+                return Some(PathUpdate::Steer(self.idle(physics)));
+            }
+            self.stop();
+            return Some(PathUpdate::Reached);
+        }
+
+        match self.replan(world, position, &planned_goal) {
+            Poll::Ready(false) => {
+                self.stop();
+                Some(PathUpdate::NoPath)
+            }
+            Poll::Pending if self.path.is_none() => Some(PathUpdate::Steer(self.idle(physics))),
+            _ => Some(PathUpdate::Steer(self.steer(world, physics, position))),
+        }
+    }
+
+    /// Plans a new path when there is none, the route changed or the player left it.
+    /// Pending while the search goes on, ready with `false` if no path is found.
+    fn replan(&mut self, world: &ClientWorld, position: Vector3<i32>, goal: &Goal) -> Poll<bool> {
+        let finished_partial = self
+            .path
+            .as_ref()
+            .is_some_and(|path| !path.complete && self.next >= path.steps.len());
+        let off_path = self.path.as_ref().is_some_and(|path| {
+            let previous = &path.steps[self.next.saturating_sub(1)].position;
+            let distance = previous.squared_distance_to_vec(position);
+            distance > 4
+        });
+        if finished_partial || off_path || self.stuck_ticks > STUCK_TICKS {
+            self.path = None;
+        }
+        if self.path.is_none() && self.search.as_ref().is_some_and(|(_, rest)| *rest) {
+            self.search = None;
+        }
+
+        if let Some(changed_from) = self.changed_from.take() {
+            match self.path.as_mut() {
+                Some(path) if changed_from > self.next => {
+                    // Keep the steps that are still fine and plan from the last of them.
+                    path.steps.truncate(changed_from);
+                    let last = &path.steps[changed_from - 1];
+                    let search = Search::new(last.position, last.cost, *goal, &self.options);
+                    self.search = Some((search, true));
+                }
+                _ => self.path = None,
+            }
+        }
+        if self.path.is_none() && self.search.is_none() {
+            self.next = 1;
+            self.stuck_ticks = 0;
+            self.closest_distance = f64::MAX;
+            self.search = Some((Search::new(position, 0.0, *goal, &self.options), false));
+        }
+
+        let Some((search, rest)) = self.search.as_mut() else {
+            return Poll::Ready(true);
+        };
+        let Poll::Ready(found) = search.advance(world, &self.options, self.options.nodes_per_tick)
+        else {
+            return Poll::Pending;
+        };
+        let rest = *rest;
+        self.search = None;
+        match (self.path.as_mut(), found) {
+            (Some(path), Some(found)) if rest => {
+                // The costs of the rest already include the steps kept.
+                path.steps.extend(found.steps.into_iter().skip(1));
+                path.complete = found.complete;
+                path.cost = found.cost;
+                Poll::Ready(true)
+            }
+            (_, None) if rest => {
+                self.path = None;
+                self.replan(world, position, goal)
+            }
+            (_, found) => {
+                self.path = found;
+                Poll::Ready(self.path.is_some())
+            }
+        }
+    }
+
+    fn steer(
+        &mut self,
+        world: &ClientWorld,
+        physics: &PlayerPhysics,
+        position: Vector3<i32>,
+    ) -> Steering {
+        let path = self.path.as_ref().unwrap();
+        // Skip steps already reached, the player may pass through several in one tick.
+        while let Some(step) = path.steps.get(self.next) {
+            let center = block_center(step.position);
+            let horizontal =
+                (physics.position.x - center.x).powi(2) + (physics.position.z - center.z).powi(2);
+            if position != step.position || horizontal > 0.35 * 0.35 {
+                break;
+            }
+            self.next += 1;
+            self.closest_distance = f64::MAX;
+            self.stuck_ticks = 0;
+        }
+        let Some(step) = path.steps.get(self.next).cloned() else {
+            return self.idle(physics);
+        };
+
+        let eye = physics.eye_position();
+        if let Some(steering) = self.act(world, physics, eye, &step) {
+            return steering;
+        }
+
+        let target = block_center(step.position);
+        let (dx, dz) = (target.x - physics.position.x, target.z - physics.position.z);
+        let horizontal = (dx * dx + dz * dz).sqrt();
+        let distance = horizontal + (target.y - physics.position.y).abs();
+        if distance < self.closest_distance - 0.01 {
+            self.closest_distance = distance;
+            self.stuck_ticks = 0;
+        } else {
+            self.stuck_ticks += 1;
+        }
+
+        let rising = step.position.y > position.y;
+        let mut input = MovementInput::new();
+        input.forward = horizontal > 0.1;
+        input.jump = rising
+            && match step.movement {
+                Movement::Jump => horizontal < 1.5 && physics.position.y < target.y - 0.4,
+                Movement::Swim | Movement::Climb | Movement::Pillar => true,
+                _ => false,
+            };
+        // In water the player sinks unless swimming up.
+        input.jump |=
+            physics.in_water && step.movement != Movement::Drop && step.position.y >= position.y;
+        input.sprint = self.options.sprint
+            && matches!(step.movement, Movement::Walk | Movement::Diagonal)
+            && !physics.in_water;
+        input.sneak = step.place.is_some() && step.movement == Movement::Walk;
+
+        let yaw = if horizontal > 0.1 {
+            (-dx.atan2(dz)).to_degrees() as f32
+        } else {
+            physics.yaw
+        };
+        Steering {
+            input,
+            yaw,
+            pitch: 0.0,
+            action: None,
+        }
+    }
+
+    /// Breaks, opens and places what the step needs before moving into it.
+    fn act(
+        &mut self,
+        world: &ClientWorld,
+        physics: &PlayerPhysics,
+        eye: Vector3<f64>,
+        step: &Step,
+    ) -> Option<Steering> {
+        self.use_cooldown = self.use_cooldown.saturating_sub(1);
+
+        if let Some(&block) = step
+            .breaks
+            .iter()
+            .find(|&&block| !moves::block(world, block).collision_shape().is_empty())
+        {
+            let action = PathAction::Dig(block, moves::facing_face(eye, block));
+            return Some(self.look_at(physics, eye, block, Some(action)));
+        }
+
+        if let Some(&door) = step
+            .opens
+            .iter()
+            .find(|&&door| moves::block(world, door).property("open") == Some("false"))
+        {
+            let action = (self.use_cooldown == 0).then(|| {
+                self.use_cooldown = USE_COOLDOWN;
+                PathAction::UseOn(door, moves::facing_face(eye, door))
+            });
+            return Some(self.look_at(physics, eye, door, action));
+        }
+
+        let place = step.place?;
+        if !moves::block(world, place).is_replaceable() {
+            return None;
+        }
+        let against = place.sub(&Vector3::new(0, 1, 0));
+        let (against, face) = if step.movement == Movement::Pillar {
+            // Placed on the block below once the jump is high enough.
+            if physics.position.y < place.y as f64 + 1.0 {
+                let mut steering = self.look_at(physics, eye, against, None);
+                steering.input.jump = true;
+                return Some(steering);
+            }
+            (against, BlockFace::Top)
+        } else {
+            // Bridging places against the side of the block the player stands on.
+            let from = current_node(world, physics.position).sub(&Vector3::new(0, 1, 0));
+            let offset = place.sub(&Vector3::new(0, 1, 0)).sub(&from);
+            let face = match (offset.x, offset.z) {
+                (1, _) => BlockFace::East,
+                (-1, _) => BlockFace::West,
+                (_, 1) => BlockFace::South,
+                _ => BlockFace::North,
+            };
+            (from, face)
+        };
+        let action = (self.use_cooldown == 0).then(|| {
+            self.use_cooldown = USE_COOLDOWN;
+            PathAction::UseOn(against, face)
+        });
+        let mut steering = self.look_at(physics, eye, against, action);
+        steering.input.sneak = true;
+        steering.input.jump = step.movement == Movement::Pillar;
+        Some(steering)
+    }
+
+    fn look_at(
+        &self,
+        physics: &PlayerPhysics,
+        eye: Vector3<f64>,
+        block: Vector3<i32>,
+        action: Option<PathAction>,
+    ) -> Steering {
+        let target = block_center(block).add(&Vector3::new(0.0, 0.5, 0.0));
+        let (yaw, pitch) = look_rotation(eye, target, physics.yaw);
+        Steering {
+            input: MovementInput::new(),
+            yaw,
+            pitch,
+            action,
+        }
+    }
+
+    fn idle(&self, physics: &PlayerPhysics) -> Steering {
+        Steering {
+            input: MovementInput::new(),
+            yaw: physics.yaw,
+            pitch: physics.pitch,
+            action: None,
+        }
+    }
+}
+
+/// Bottom center of a block.
+fn block_center(block: Vector3<i32>) -> Vector3<f64> {
+    Vector3::new(block.x as f64 + 0.5, block.y as f64, block.z as f64 + 0.5)
+}
+
+/// The block the player counts as standing in, which is above a slab it stands on and
+/// the lower one while jumping.
+pub(crate) fn current_node(world: &ClientWorld, position: Vector3<f64>) -> Vector3<i32> {
+    let (x, z) = (position.x.floor() as i32, position.z.floor() as i32);
+    let rounded = Vector3::new(x, (position.y + 0.5).floor() as i32, z);
+    [rounded, Vector3::new(x, position.y.floor() as i32, z)]
+        .into_iter()
+        .find(|&node| moves::is_node(world, node))
+        .unwrap_or(rounded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::world::{set_test_block, test_world};
+
+    use super::*;
+
+    fn player(x: f64, y: f64, z: f64) -> PlayerPhysics {
+        let mut physics = PlayerPhysics::new();
+        physics.teleport(Vector3::new(x, y, z), Vector3::new(0.0, 0.0, 0.0), 0.0, 0.0);
+        physics
+    }
+
+    #[test]
+    fn walks_around_walls() {
+        let mut blocks = Vec::new();
+        for z in 0..8 {
+            blocks.push((5, 65, z, "stone"));
+            blocks.push((5, 66, z, "stone"));
+        }
+        let world = test_world(true, &blocks);
+        let goal = Goal::Block(Vector3::new(8, 65, 2));
+        let path = find_path(&world, Vector3::new(2, 65, 2), &goal, &PathOptions::new()).unwrap();
+
+        assert!(path.complete);
+        assert_eq!(path.steps.last().unwrap().position, Vector3::new(8, 65, 2));
+        assert!(path
+            .steps
+            .iter()
+            .all(|step| step.position.x != 5 || step.position.z >= 8));
+    }
+
+    #[test]
+    fn jumps_up_and_breaks_through() {
+        let world = test_world(true, &[(4, 65, 2, "stone")]);
+        let start = Vector3::new(2, 65, 2);
+
+        let goal = Goal::Block(Vector3::new(4, 66, 2));
+        let path = find_path(&world, start, &goal, &PathOptions::new()).unwrap();
+        assert!(path
+            .steps
+            .iter()
+            .any(|step| step.movement == Movement::Jump));
+
+        // Walled in, only breaking gets through.
+        let mut blocks = Vec::new();
+        for z in 0..16 {
+            blocks.push((4, 65, z, "stone"));
+            blocks.push((4, 66, z, "stone"));
+        }
+        let world = test_world(true, &blocks);
+        let goal = Goal::Block(Vector3::new(6, 65, 2));
+        assert!(
+            find_path(&world, start, &goal, &PathOptions::new()).is_none_or(|path| !path.complete)
+        );
+        let options = PathOptions::new().allow_break(true);
+        let path = find_path(&world, start, &goal, &options).unwrap();
+        assert!(path.complete);
+        assert!(path.steps.iter().any(|step| !step.breaks.is_empty()));
+    }
+
+    #[test]
+    fn replans_around_new_blocks() {
+        let mut world = test_world(true, &[]);
+        let entities = EntityTracker::new();
+        let physics = player(2.5, 65.0, 2.5);
+        let mut pathfinder = Pathfinder::new();
+        pathfinder.set_goal(Goal::Block(Vector3::new(10, 65, 2)));
+
+        assert!(matches!(
+            pathfinder.tick(&world, &entities, &physics),
+            Some(PathUpdate::Steer(_))
+        ));
+        let blocked = pathfinder.path().unwrap().steps[4].position;
+        set_test_block(&mut world, blocked.x, blocked.y, blocked.z, "stone");
+        set_test_block(&mut world, blocked.x, blocked.y + 1, blocked.z, "stone");
+        pathfinder.block_changed(blocked);
+        pathfinder.tick(&world, &entities, &physics);
+
+        let path = pathfinder.path().unwrap();
+        assert!(path.complete);
+        assert!(path.steps.iter().all(|step| step.position != blocked));
+        // The cost includes the steps kept from the first path.
+        let goal = Goal::Block(Vector3::new(10, 65, 2));
+        let fresh = find_path(&world, Vector3::new(2, 65, 2), &goal, &PathOptions::new());
+        assert!(path.cost >= fresh.unwrap().cost);
+        assert_eq!(path.cost, path.steps.last().unwrap().cost);
+    }
+
+    #[test]
+    fn searches_over_several_ticks() {
+        let wall = [
+            (5, 65, 1, "stone"),
+            (5, 65, 2, "stone"),
+            (5, 65, 3, "stone"),
+        ];
+        let world = test_world(true, &wall);
+        let entities = EntityTracker::new();
+        let physics = player(2.5, 65.0, 2.5);
+        let goal = Goal::Block(Vector3::new(10, 65, 2));
+        let mut pathfinder = Pathfinder::new();
+        pathfinder.options = PathOptions::new().nodes_per_tick(2);
+        pathfinder.set_goal(goal);
+
+        // The player waits until the search is done.
+        let mut ticks = 1;
+        while let Some(PathUpdate::Steer(steering)) = pathfinder.tick(&world, &entities, &physics) {
+            if pathfinder.path().is_some() {
+                break;
+            }
+            assert!(!steering.input.forward);
+            ticks += 1;
+        }
+        assert!(ticks > 1);
+        assert_eq!(
+            pathfinder.path(),
+            find_path(&world, Vector3::new(2, 65, 2), &goal, &PathOptions::new()).as_ref()
+        );
+    }
+
+    #[test]
+    fn steers_the_player_to_the_goal() {
+        let world = test_world(true, &[(6, 65, 6, "stone"), (6, 65, 7, "stone")]);
+        let entities = EntityTracker::new();
+        let mut physics = player(2.5, 65.0, 2.5);
+        let mut pathfinder = Pathfinder::new();
+        pathfinder.set_goal(Goal::Block(Vector3::new(10, 65, 10)));
+
+        for _ in 0..200 {
+            match pathfinder.tick(&world, &entities, &physics) {
+                Some(PathUpdate::Steer(steering)) => {
+                    physics.input = steering.input;
+                    physics.yaw = steering.yaw;
+                    physics.pitch = steering.pitch;
+                }
+                Some(PathUpdate::Reached) => {
+                    assert_eq!(
+                        current_node(&world, physics.position),
+                        Vector3::new(10, 65, 10)
+                    );
+                    return;
+                }
+                update => panic!("unexpected {update:?}"),
+            }
+            physics.tick(&world);
+        }
+        panic!("goal not reached, stopped at {:?}", physics.position);
+    }
+}
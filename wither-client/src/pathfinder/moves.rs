@@ -0,0 +1,389 @@
+use std::f64::consts::SQRT_2;
+
+use wither_util::math::vector3::Vector3;
+use wither_world::{
+    block::{BlockFace, BlockState},
+    coordinates::{BlockCoordinates, Height},
+};
+
+use crate::{
+    physics::{is_climbable, is_lava, is_water},
+    world::ClientWorld,
+};
+
+use super::PathOptions;
+
+const HORIZONTAL: [Vector3<i32>; 4] = [
+    Vector3::new(0, 0, -1),
+    Vector3::new(0, 0, 1),
+    Vector3::new(-1, 0, 0),
+    Vector3::new(1, 0, 0),
+];
+const UP: Vector3<i32> = Vector3::new(0, 1, 0);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    /// Where the path starts.
+    Start,
+    Walk,
+    Diagonal,
+    /// Up one block onto the next one.
+    Jump,
+    /// Down any number of blocks, also used after digging down.
+    Drop,
+    Swim,
+    /// Up or down a ladder, vine or similar.
+    Climb,
+    /// Straight up, placing a block below while jumping.
+    Pillar,
+}
+
+/// One node of a path, the block the feet of the player end up in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    pub position: Vector3<i32>,
+    pub movement: Movement,
+    /// Blocks in the way, broken before moving.
+    pub breaks: Vec<Vector3<i32>>,
+    /// Where a block is placed to stand on, before moving.
+    pub place: Option<Vector3<i32>>,
+    /// Doors and fence gates opened before moving.
+    pub opens: Vec<Vector3<i32>>,
+    /// Cost of the path up to and including this step.
+    pub cost: f64,
+}
+
+impl Step {
+    pub(crate) fn new(position: Vector3<i32>, movement: Movement) -> Self {
+        Self {
+            position,
+            movement,
+            breaks: Vec::new(),
+            place: None,
+            opens: Vec::new(),
+            cost: 0.0,
+        }
+    }
+
+    /// Blocks whose change can make this step impossible or cheaper, coming from `from`.
+    pub(crate) fn touches(&self, from: Vector3<i32>, block: Vector3<i32>) -> bool {
+        let (min, max) = (
+            Vector3::new(
+                from.x.min(self.position.x),
+                from.y.min(self.position.y),
+                from.z.min(self.position.z),
+            ),
+            Vector3::new(
+                from.x.max(self.position.x),
+                from.y.max(self.position.y),
+                from.z.max(self.position.z),
+            ),
+        );
+        // From the block below the feet to the one above the head, jumping included.
+        (min.x..=max.x).contains(&block.x)
+            && (min.y - 1..=max.y + 2).contains(&block.y)
+            && (min.z..=max.z).contains(&block.z)
+    }
+}
+
+/// What a block means for a player moving through it.
+enum Cell {
+    Passable,
+    /// A closed door or fence gate that can be opened.
+    Door(Vector3<i32>),
+    /// In the way, but can be broken in this many ticks.
+    Breakable(u32),
+    Blocked,
+}
+
+pub(crate) fn block(world: &ClientWorld, position: Vector3<i32>) -> BlockState {
+    if !world.height().contains(position.y) {
+        return BlockState::AIR;
+    }
+    world.block_at(BlockCoordinates {
+        x: position.x,
+        y: Height(position.y as i16),
+        z: position.z,
+    })
+}
+
+/// Estimated ticks to break a block with an empty hand for path costs, assuming it
+/// drops without a tool. `None` for blocks that can't be broken.
+pub(crate) fn break_ticks(state: BlockState) -> Option<u32> {
+    let hardness = state.block().hardness;
+    if hardness < 0.0 || is_water(state) || is_lava(state) {
+        return None;
+    }
+    Some((hardness * 30.0).ceil() as u32)
+}
+
+/// Blocks that hurt or trap a player, never walked through.
+fn is_dangerous(state: BlockState) -> bool {
+    is_lava(state)
+        || matches!(
+            state.name(),
+            "fire"
+                | "soul_fire"
+                | "cobweb"
+                | "sweet_berry_bush"
+                | "powder_snow"
+                | "wither_rose"
+                | "cactus"
+                | "magma_block"
+                | "campfire"
+                | "soul_campfire"
+        )
+}
+
+fn is_door(state: BlockState) -> bool {
+    let name = state.name();
+    (name.ends_with("_door") && name != "iron_door") || name.ends_with("_fence_gate")
+}
+
+fn cell(world: &ClientWorld, position: Vector3<i32>, options: &PathOptions) -> Cell {
+    let state = block(world, position);
+    if is_dangerous(state) {
+        return Cell::Blocked;
+    }
+    if is_door(state) {
+        if state.property("open") == Some("true") {
+            return Cell::Passable;
+        }
+        // Both halves of a door open together, from the lower one.
+        let lower = if state.property("half") == Some("upper") {
+            position.sub(&UP)
+        } else {
+            position
+        };
+        return Cell::Door(lower);
+    }
+    if state.collision_shape().is_empty() || is_climbable(state) {
+        return Cell::Passable;
+    }
+    match break_ticks(state) {
+        Some(ticks) if options.allow_break && !next_to_fluid(world, position) => {
+            Cell::Breakable(ticks)
+        }
+        _ => Cell::Blocked,
+    }
+}
+
+/// Breaking next to fluid would let it flow into the path.
+fn next_to_fluid(world: &ClientWorld, position: Vector3<i32>) -> bool {
+    HORIZONTAL.iter().chain([&UP]).any(|offset| {
+        let state = block(world, position.add(offset));
+        is_water(state) || is_lava(state)
+    })
+}
+
+fn passable(world: &ClientWorld, position: Vector3<i32>) -> bool {
+    let state = block(world, position);
+    !is_dangerous(state) && (state.collision_shape().is_empty() || is_climbable(state))
+}
+
+/// Whether the top of the block can be stood on.
+fn is_support(world: &ClientWorld, position: Vector3<i32>) -> bool {
+    let state = block(world, position);
+    if is_dangerous(state) {
+        return false;
+    }
+    state
+        .collision_shape()
+        .bounds()
+        .is_some_and(|bounds| bounds.max_y <= 1.0)
+}
+
+/// Whether the player can stay with its feet in this block.
+pub(crate) fn is_node(world: &ClientWorld, position: Vector3<i32>) -> bool {
+    let state = block(world, position);
+    is_support(world, position.sub(&UP)) || is_water(state) || is_climbable(state)
+}
+
+/// Cost of clearing a block for the player to move through, with what has to be done.
+fn clear(
+    world: &ClientWorld,
+    position: Vector3<i32>,
+    options: &PathOptions,
+    step: &mut Step,
+) -> Option<f64> {
+    match cell(world, position, options) {
+        Cell::Passable => Some(0.0),
+        Cell::Door(door) => {
+            if !step.opens.contains(&door) {
+                step.opens.push(door);
+            }
+            Some(options.costs.door)
+        }
+        Cell::Breakable(ticks) => {
+            step.breaks.push(position);
+            Some(options.costs.break_block + ticks as f64 * options.costs.break_tick)
+        }
+        Cell::Blocked => None,
+    }
+}
+
+/// Cost of clearing the feet and head blocks at `position`.
+fn clear_body(
+    world: &ClientWorld,
+    position: Vector3<i32>,
+    options: &PathOptions,
+    step: &mut Step,
+) -> Option<f64> {
+    Some(clear(world, position, options, step)? + clear(world, position.add(&UP), options, step)?)
+}
+
+/// Every step that can be taken from `from`, with its cost.
+pub(crate) fn neighbours(
+    world: &ClientWorld,
+    from: Vector3<i32>,
+    options: &PathOptions,
+) -> Vec<(Step, f64)> {
+    let mut steps = Vec::new();
+    let costs = &options.costs;
+    let from_state = block(world, from);
+    let in_water = is_water(from_state);
+    let can_jump = is_support(world, from.sub(&UP)) || in_water || is_climbable(from_state);
+
+    for offset in HORIZONTAL {
+        let to = from.add(&offset);
+
+        let mut step = Step::new(to, Movement::Walk);
+        if let Some(clear_cost) = clear_body(world, to, options, &mut step) {
+            let below = to.sub(&UP);
+            if is_node(world, to) && (in_water || is_water(block(world, to))) {
+                step.movement = Movement::Swim;
+                steps.push((step, costs.swim + clear_cost));
+            } else if is_node(world, to) {
+                steps.push((step, costs.walk + clear_cost));
+            } else if options.allow_place
+                && block(world, below).is_replaceable()
+                && is_support(world, from.sub(&UP))
+            {
+                // Bridging over a gap, against the side of the block stood on.
+                let mut bridge = step.clone();
+                bridge.place = Some(below);
+                steps.push((bridge, costs.walk + costs.place + clear_cost));
+                drop_from(world, step, clear_cost, options, &mut steps);
+            } else {
+                drop_from(world, step, clear_cost, options, &mut steps);
+            }
+        }
+
+        if can_jump {
+            let to = to.add(&UP);
+            let mut step = Step::new(to, Movement::Jump);
+            let cost = clear(world, from.add(&UP).add(&UP), options, &mut step)
+                .and_then(|above| Some(above + clear_body(world, to, options, &mut step)?));
+            if let Some(clear_cost) = cost {
+                if is_node(world, to) {
+                    steps.push((step, costs.walk + costs.jump + clear_cost));
+                }
+            }
+        }
+    }
+
+    for (x, z) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+        let to = from.add(&Vector3::new(x, 0, z));
+        let corners = [
+            from.add(&Vector3::new(x, 0, 0)),
+            from.add(&Vector3::new(0, 0, z)),
+            to,
+        ];
+        let open = corners
+            .iter()
+            .all(|&corner| passable(world, corner) && passable(world, corner.add(&UP)));
+        if open && is_support(world, to.sub(&UP)) && !in_water {
+            steps.push((Step::new(to, Movement::Diagonal), costs.walk * SQRT_2));
+        }
+    }
+
+    let up = from.add(&UP);
+    let up_state = block(world, up);
+    if passable(world, up) && passable(world, up.add(&UP)) {
+        if is_climbable(from_state) && is_climbable(up_state) {
+            steps.push((Step::new(up, Movement::Climb), costs.climb));
+        } else if in_water && is_water(up_state) {
+            steps.push((Step::new(up, Movement::Swim), costs.swim));
+        } else if options.allow_place
+            && is_support(world, from.sub(&UP))
+            && from_state.is_replaceable()
+            && !in_water
+        {
+            let mut step = Step::new(up, Movement::Pillar);
+            step.place = Some(from);
+            steps.push((step, costs.jump + costs.place));
+        }
+    }
+
+    let down = from.sub(&UP);
+    let down_state = block(world, down);
+    if is_climbable(down_state) {
+        steps.push((Step::new(down, Movement::Climb), costs.climb));
+    } else if is_water(down_state) {
+        steps.push((Step::new(down, Movement::Swim), costs.swim));
+    } else if let Cell::Breakable(ticks) = cell(world, down, options) {
+        // Digging straight down, only onto something to stand on.
+        if is_support(world, down.sub(&UP)) {
+            let mut step = Step::new(down, Movement::Drop);
+            step.breaks.push(down);
+            let cost = costs.break_block + ticks as f64 * costs.break_tick + costs.fall;
+            steps.push((step, cost));
+        }
+    }
+
+    steps
+}
+
+/// Walks off an edge and falls until something is below, if that is not too far down.
+fn drop_from(
+    world: &ClientWorld,
+    mut step: Step,
+    clear_cost: f64,
+    options: &PathOptions,
+    steps: &mut Vec<(Step, f64)>,
+) {
+    let mut position = step.position;
+    for depth in 1.. {
+        position = position.sub(&UP);
+        if position.y < world.height().min_y || !passable(world, position) {
+            return;
+        }
+        if is_node(world, position) {
+            // Any fall into water is safe.
+            if depth <= options.max_drop || is_water(block(world, position)) {
+                step.position = position;
+                step.movement = Movement::Drop;
+                let cost = options.costs.walk + options.costs.fall * depth as f64 + clear_cost;
+                steps.push((step, cost));
+            }
+            return;
+        }
+    }
+}
+
+/// The face of `block` that is seen from `eye`.
+pub(crate) fn facing_face(eye: Vector3<f64>, block: Vector3<i32>) -> BlockFace {
+    let center = Vector3::new(
+        block.x as f64 + 0.5,
+        block.y as f64 + 0.5,
+        block.z as f64 + 0.5,
+    );
+    let delta = eye.sub(&center);
+    if delta.y.abs() >= delta.x.abs() && delta.y.abs() >= delta.z.abs() {
+        if delta.y > 0.0 {
+            BlockFace::Top
+        } else {
+            BlockFace::Bottom
+        }
+    } else if delta.x.abs() >= delta.z.abs() {
+        if delta.x > 0.0 {
+            BlockFace::East
+        } else {
+            BlockFace::West
+        }
+    } else if delta.z > 0.0 {
+        BlockFace::South
+    } else {
+        BlockFace::North
+    }
+}
@@ -0,0 +1,1073 @@
+use wither_network::protocol::server::play::{
+    MovePlayerPos, MovePlayerPosRot, MovePlayerRot, MovePlayerStatusOnly, PlayerInput,
+};
+use wither_util::math::{
+    boundingbox::{Axis, BoundingBox, BoundingBoxSize},
+    vector2::Vector2,
+    vector3::Vector3,
+    voxel_shape::VoxelShape,
+};
+use wither_world::{
+    block::BlockState,
+    coordinates::{BlockCoordinates, Height},
+};
+
+use crate::world::ClientWorld;
+
+pub const GRAVITY: f64 = 0.08;
+pub const STEP_HEIGHT: f64 = 0.6;
+const JUMP_POWER: f64 = 0.42;
+/// Delay in ticks before jumping again while the jump key is held.
+const JUMP_DELAY: u32 = 10;
+const MOVEMENT_SPEED: f64 = 0.1;
+const SPRINT_SPEED_MODIFIER: f64 = 0.3;
+const SNEAKING_SPEED: f64 = 0.3;
+const PLAYER_WIDTH: f64 = 0.6;
+const STANDING_HEIGHT: f64 = 1.8;
+const CROUCHING_HEIGHT: f64 = 1.5;
+const STANDING_EYE_HEIGHT: f64 = 1.62;
+const CROUCHING_EYE_HEIGHT: f64 = 1.27;
+/// Below this depth a player standing on the bottom jumps instead of swimming up.
+const FLUID_JUMP_THRESHOLD: f64 = 0.4;
+/// The block that affects movement is the one this far below the feet.
+const BELOW_OFFSET: f64 = 0.500001;
+const COLLISION_EPSILON: f64 = 1.0e-7;
+/// Smallest position change the vanilla client sends a packet for.
+const MOVE_THRESHOLD: f64 = 2.0e-4;
+/// The position is sent at least this often in ticks, even when standing still.
+const POSITION_REMINDER: u32 = 20;
+
+/// The movement keys held by the player.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MovementInput {
+    pub forward: bool,
+    pub backward: bool,
+    pub left: bool,
+    pub right: bool,
+    pub jump: bool,
+    pub sneak: bool,
+    pub sprint: bool,
+}
+
+impl MovementInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The flags of the `player_input` packet.
+    pub fn flags(&self) -> u8 {
+        [
+            (self.forward, PlayerInput::FORWARD),
+            (self.backward, PlayerInput::BACKWARD),
+            (self.left, PlayerInput::LEFT),
+            (self.right, PlayerInput::RIGHT),
+            (self.jump, PlayerInput::JUMP),
+            (self.sneak, PlayerInput::SHIFT),
+            (self.sprint, PlayerInput::SPRINT),
+        ]
+        .into_iter()
+        .filter(|(held, _)| *held)
+        .fold(0, |flags, (_, flag)| flags | flag)
+    }
+}
+
+/// One of the four move packets, whichever carries what changed.
+#[derive(Debug)]
+pub enum MovePacket {
+    PosRot(MovePlayerPosRot),
+    Pos(MovePlayerPos),
+    Rot(MovePlayerRot),
+    StatusOnly(MovePlayerStatusOnly),
+}
+
+/// What the vanilla client would send after a tick, in the order it sends it.
+#[derive(Debug, Default)]
+pub struct TickPackets {
+    pub input: Option<PlayerInput>,
+    /// The new sprinting state, sent as a `player_command`.
+    pub sprinting: Option<bool>,
+    pub movement: Option<MovePacket>,
+}
+
+/// What the server last heard about the player.
+#[derive(Clone, Copy, Debug, Default)]
+struct LastSent {
+    position: Vector3<f64>,
+    yaw: f32,
+    pitch: f32,
+    on_ground: bool,
+    horizontal_collision: bool,
+    input: u8,
+    sprinting: bool,
+    /// Ticks since the position was last sent.
+    position_reminder: u32,
+}
+
+/// Movement of the local player, simulated tick by tick like the vanilla client does.
+/// Currents of flowing water and status effects are not simulated.
+#[derive(Clone, Debug)]
+pub struct PlayerPhysics {
+    pub position: Vector3<f64>,
+    /// In blocks per tick.
+    pub velocity: Vector3<f64>,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub on_ground: bool,
+    pub horizontal_collision: bool,
+    pub vertical_collision: bool,
+    pub input: MovementInput,
+    pub sprinting: bool,
+    pub crouching: bool,
+    pub in_water: bool,
+    pub in_lava: bool,
+    water_height: f64,
+    lava_height: f64,
+    fall_distance: f64,
+    jump_delay: u32,
+    /// Set by blocks like cobwebs and applied to the next move.
+    stuck_multiplier: Vector3<f64>,
+    /// Whether the server has placed the player yet.
+    spawned: bool,
+    last_sent: LastSent,
+}
+
+impl Default for PlayerPhysics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerPhysics {
+    pub fn new() -> Self {
+        Self {
+            position: Vector3::new(0.0, 0.0, 0.0),
+            velocity: Vector3::new(0.0, 0.0, 0.0),
+            yaw: 0.0,
+            pitch: 0.0,
+            on_ground: false,
+            horizontal_collision: false,
+            vertical_collision: false,
+            input: MovementInput::new(),
+            sprinting: false,
+            crouching: false,
+            in_water: false,
+            in_lava: false,
+            water_height: 0.0,
+            lava_height: 0.0,
+            fall_distance: 0.0,
+            jump_delay: 0,
+            stuck_multiplier: Vector3::new(0.0, 0.0, 0.0),
+            spawned: false,
+            last_sent: LastSent::default(),
+        }
+    }
+
+    /// Forgets everything but the held keys, until the server places the player again.
+    pub fn respawn(&mut self) {
+        *self = Self {
+            input: self.input,
+            ..Self::new()
+        };
+    }
+
+    /// Places the player where the server said, the confirmation is sent by the caller.
+    pub fn teleport(
+        &mut self,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+        yaw: f32,
+        pitch: f32,
+    ) {
+        self.position = position;
+        self.velocity = velocity;
+        self.yaw = yaw;
+        self.pitch = pitch;
+        self.spawned = true;
+        self.last_sent.position = position;
+        self.last_sent.yaw = yaw;
+        self.last_sent.pitch = pitch;
+        self.last_sent.position_reminder = 0;
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        self.bounding_box_at(self.position, self.crouching)
+    }
+
+    pub fn eye_position(&self) -> Vector3<f64> {
+        let height = if self.crouching {
+            CROUCHING_EYE_HEIGHT
+        } else {
+            STANDING_EYE_HEIGHT
+        };
+        self.position.add(&Vector3::new(0.0, height, 0.0))
+    }
+
+    /// Unit vector the player looks along.
+    pub fn view_direction(&self) -> Vector3<f64> {
+        let (yaw, pitch) = (
+            -(self.yaw as f64).to_radians(),
+            (self.pitch as f64).to_radians(),
+        );
+        Vector3::new(yaw.sin() * pitch.cos(), -pitch.sin(), yaw.cos() * pitch.cos())
+    }
+
+    /// Turns the player to look at `target`.
+    pub fn look_at(&mut self, target: Vector3<f64>) {
+        (self.yaw, self.pitch) = look_rotation(self.eye_position(), target, self.yaw);
+    }
+
+    fn bounding_box_at(&self, position: Vector3<f64>, crouching: bool) -> BoundingBox {
+        let height = if crouching {
+            CROUCHING_HEIGHT
+        } else {
+            STANDING_HEIGHT
+        };
+        BoundingBox::new_from_pos(
+            position.x,
+            position.y,
+            position.z,
+            &BoundingBoxSize {
+                width: PLAYER_WIDTH,
+                height,
+            },
+        )
+    }
+
+    /// Runs one tick of movement, nothing happens until the player is placed and while
+    /// the chunk it is in is not loaded.
+    pub fn tick(&mut self, world: &ClientWorld) -> Option<TickPackets> {
+        let chunk = Vector2::new(
+            (self.position.x.floor() as i32) >> 4,
+            (self.position.z.floor() as i32) >> 4,
+        );
+        if !self.spawned || !world.is_loaded(chunk) {
+            return None;
+        }
+
+        self.update_fluids(world);
+        self.ai_step(world);
+        self.update_pose(world);
+        Some(self.packets())
+    }
+
+    fn update_fluids(&mut self, world: &ClientWorld) {
+        let area = self.bounding_box().inflate(-0.001, -0.001, -0.001);
+        let water = fluid_height_in(world, &area, false);
+        let lava = fluid_height_in(world, &area, true);
+        self.in_water = water.is_some();
+        self.water_height = water.unwrap_or(0.0);
+        self.in_lava = lava.is_some_and(|height| height > 0.0);
+        self.lava_height = lava.unwrap_or(0.0);
+    }
+
+    fn ai_step(&mut self, world: &ClientWorld) {
+        self.jump_delay = self.jump_delay.saturating_sub(1);
+        self.update_sprinting();
+
+        let mut forward = impulse(self.input.forward, self.input.backward);
+        let mut strafe = impulse(self.input.left, self.input.right);
+        if self.crouching {
+            forward *= SNEAKING_SPEED as f32;
+            strafe *= SNEAKING_SPEED as f32;
+        }
+
+        for component in [
+            &mut self.velocity.x,
+            &mut self.velocity.y,
+            &mut self.velocity.z,
+        ] {
+            if component.abs() < 0.003 {
+                *component = 0.0;
+            }
+        }
+
+        if self.input.jump {
+            self.jump(world);
+        } else {
+            self.jump_delay = 0;
+        }
+
+        let input = Vector3::new((strafe * 0.98) as f64, 0.0, (forward * 0.98) as f64);
+        if self.in_water || self.in_lava {
+            self.travel_in_fluid(world, input);
+        } else {
+            self.travel_in_air(world, input);
+        }
+    }
+
+    fn update_sprinting(&mut self) {
+        let moving_forward = self.input.forward && !self.input.backward;
+        if self.sprinting {
+            if !moving_forward || self.horizontal_collision || self.crouching {
+                self.sprinting = false;
+            }
+        } else if self.input.sprint && moving_forward && !self.crouching {
+            self.sprinting = true;
+        }
+    }
+
+    fn jump(&mut self, world: &ClientWorld) {
+        let height = if self.in_lava {
+            self.lava_height
+        } else {
+            self.water_height
+        };
+        let swimming = self.in_water && height > 0.0;
+        let deep = height > FLUID_JUMP_THRESHOLD;
+        if (swimming || self.in_lava) && (!self.on_ground || deep) {
+            self.velocity.y += 0.04;
+        } else if (self.on_ground || swimming && !deep) && self.jump_delay == 0 {
+            self.jump_from_ground(world);
+            self.jump_delay = JUMP_DELAY;
+        }
+    }
+
+    fn jump_from_ground(&mut self, world: &ClientWorld) {
+        let power = JUMP_POWER * self.block_factor(world, jump_factor);
+        if power <= 1.0e-5 {
+            return;
+        }
+        self.velocity.y = self.velocity.y.max(power);
+        if self.sprinting {
+            let yaw = self.yaw.to_radians();
+            self.velocity.x -= (yaw.sin() * 0.2) as f64;
+            self.velocity.z += (yaw.cos() * 0.2) as f64;
+        }
+    }
+
+    fn travel_in_air(&mut self, world: &ClientWorld, input: Vector3<f64>) {
+        let friction = if self.on_ground {
+            friction(world.block_at(self.below_position()))
+        } else {
+            1.0
+        };
+        let speed = if self.on_ground {
+            self.speed() * (0.21600002 / (friction * friction * friction)) as f64
+        } else if self.sprinting {
+            0.025999999
+        } else {
+            0.02
+        };
+        self.move_relative(speed, input);
+        self.velocity = self.handle_on_climbable(world, self.velocity);
+        self.move_by(world, self.velocity);
+
+        let mut velocity = self.velocity;
+        if (self.horizontal_collision || self.input.jump) && self.on_climbable(world) {
+            velocity.y = 0.2;
+        }
+        velocity.y -= GRAVITY;
+        let drag = (friction * 0.91) as f64;
+        self.velocity = velocity.multiply(drag, 0.98, drag);
+    }
+
+    fn travel_in_fluid(&mut self, world: &ClientWorld, input: Vector3<f64>) {
+        let falling = self.velocity.y <= 0.0;
+        let start_y = self.position.y;
+        if self.in_water {
+            let slowdown = if self.sprinting { 0.9 } else { 0.8 };
+            self.move_relative(0.02, input);
+            self.move_by(world, self.velocity);
+            let mut velocity = self.velocity;
+            if self.horizontal_collision && self.on_climbable(world) {
+                velocity.y = 0.2;
+            }
+            velocity = velocity.multiply(slowdown, 0.8, slowdown);
+            self.velocity = self.fluid_falling_adjusted(falling, velocity);
+        } else {
+            self.move_relative(0.02, input);
+            self.move_by(world, self.velocity);
+            if self.lava_height <= FLUID_JUMP_THRESHOLD {
+                let velocity = self.velocity.multiply(0.5, 0.8, 0.5);
+                self.velocity = self.fluid_falling_adjusted(falling, velocity);
+            } else {
+                self.velocity = self.velocity * 0.5;
+            }
+            self.velocity.y -= GRAVITY / 4.0;
+        }
+
+        let velocity = self.velocity;
+        let above = self.bounding_box().offset(Vector3::new(
+            velocity.x,
+            velocity.y + STEP_HEIGHT - self.position.y + start_y,
+            velocity.z,
+        ));
+        if self.horizontal_collision
+            && collisions(world, &above).is_empty()
+            && !contains_fluid(world, &above)
+        {
+            self.velocity.y = 0.3;
+        }
+    }
+
+    fn fluid_falling_adjusted(&self, falling: bool, mut velocity: Vector3<f64>) -> Vector3<f64> {
+        if !self.sprinting {
+            velocity.y = if falling
+                && (velocity.y - 0.005).abs() >= 0.003
+                && (velocity.y - GRAVITY / 16.0).abs() < 0.003
+            {
+                -0.003
+            } else {
+                velocity.y - GRAVITY / 16.0
+            };
+        }
+        velocity
+    }
+
+    fn speed(&self) -> f64 {
+        if self.sprinting {
+            MOVEMENT_SPEED * (1.0 + SPRINT_SPEED_MODIFIER)
+        } else {
+            MOVEMENT_SPEED
+        }
+    }
+
+    /// Accelerates towards `input`, which is relative to where the player is facing.
+    fn move_relative(&mut self, speed: f64, input: Vector3<f64>) {
+        let length = input.length_squared();
+        if length < 1.0e-7 {
+            return;
+        }
+        let input = if length > 1.0 {
+            input.normalize()
+        } else {
+            input
+        } * speed;
+        let yaw = self.yaw.to_radians();
+        let (sin, cos) = (yaw.sin() as f64, yaw.cos() as f64);
+        self.velocity += Vector3::new(
+            input.x * cos - input.z * sin,
+            input.y,
+            input.z * cos + input.x * sin,
+        );
+    }
+
+    fn handle_on_climbable(&mut self, world: &ClientWorld, velocity: Vector3<f64>) -> Vector3<f64> {
+        if !self.on_climbable(world) {
+            return velocity;
+        }
+        self.fall_distance = 0.0;
+        let mut y = velocity.y.max(-0.15);
+        if y < 0.0
+            && self.input.sneak
+            && world.block_at(self.block_position()).name() != "scaffolding"
+        {
+            y = 0.0;
+        }
+        Vector3::new(
+            velocity.x.clamp(-0.15, 0.15),
+            y,
+            velocity.z.clamp(-0.15, 0.15),
+        )
+    }
+
+    fn on_climbable(&self, world: &ClientWorld) -> bool {
+        is_climbable(world.block_at(self.block_position()))
+    }
+
+    fn move_by(&mut self, world: &ClientWorld, movement: Vector3<f64>) {
+        let mut movement = movement;
+        if self.stuck_multiplier.length_squared() > 1.0e-7 {
+            let stuck = self.stuck_multiplier;
+            movement = movement.multiply(stuck.x, stuck.y, stuck.z);
+            self.stuck_multiplier = Vector3::new(0.0, 0.0, 0.0);
+            self.velocity = Vector3::new(0.0, 0.0, 0.0);
+        }
+        let movement = self.back_off_from_edge(world, movement);
+        let moved = self.collide(world, movement);
+        if moved.length_squared() > 1.0e-7
+            || movement.length_squared() - moved.length_squared() < 1.0e-7
+        {
+            self.position += moved;
+        }
+
+        let x_collision = (movement.x - moved.x).abs() >= 1.0e-5;
+        let z_collision = (movement.z - moved.z).abs() >= 1.0e-5;
+        self.horizontal_collision = x_collision || z_collision;
+        self.vertical_collision = movement.y != moved.y;
+        self.on_ground = self.vertical_collision && movement.y < 0.0;
+        if self.on_ground {
+            self.fall_distance = 0.0;
+        } else if moved.y < 0.0 {
+            self.fall_distance -= moved.y;
+        }
+
+        let on = world.block_at(block_position(
+            self.position.sub(&Vector3::new(0.0, 0.2, 0.0)),
+        ));
+        if x_collision {
+            self.velocity.x = 0.0;
+        }
+        if z_collision {
+            self.velocity.z = 0.0;
+        }
+        if self.vertical_collision {
+            let bounce = match on.name() {
+                "slime_block" => Some(1.0),
+                name if name.ends_with("_bed") => Some(0.66),
+                _ => None,
+            };
+            match bounce {
+                Some(bounce) if !self.input.sneak => {
+                    if self.velocity.y < 0.0 {
+                        self.velocity.y *= -bounce;
+                    }
+                }
+                _ => self.velocity.y = 0.0,
+            }
+        }
+        if self.on_ground && on.name() == "slime_block" && !self.input.sneak {
+            let bounce = self.velocity.y.abs();
+            if bounce < 0.1 {
+                let factor = 0.4 + bounce * 0.2;
+                self.velocity = self.velocity.multiply(factor, 1.0, factor);
+            }
+        }
+
+        self.check_inside_blocks(world);
+        let factor = self.block_factor(world, speed_factor);
+        self.velocity = self.velocity.multiply(factor, 1.0, factor);
+    }
+
+    /// Keeps a sneaking player from walking off the edge of what it stands on.
+    fn back_off_from_edge(&self, world: &ClientWorld, movement: Vector3<f64>) -> Vector3<f64> {
+        if movement.y > 0.0 || !self.input.sneak || !self.is_above_ground(world) {
+            return movement;
+        }
+        let step = |value: f64| {
+            if value.abs() <= 0.05 {
+                0.0
+            } else {
+                value - value.signum() * 0.05
+            }
+        };
+        let (mut x, mut z) = (movement.x, movement.z);
+        while x != 0.0 && self.can_fall_at_least(world, x, 0.0, STEP_HEIGHT) {
+            x = step(x);
+        }
+        while z != 0.0 && self.can_fall_at_least(world, 0.0, z, STEP_HEIGHT) {
+            z = step(z);
+        }
+        while x != 0.0 && z != 0.0 && self.can_fall_at_least(world, x, z, STEP_HEIGHT) {
+            x = step(x);
+            z = step(z);
+        }
+        Vector3::new(x, movement.y, z)
+    }
+
+    fn is_above_ground(&self, world: &ClientWorld) -> bool {
+        self.on_ground
+            || self.fall_distance < STEP_HEIGHT
+                && !self.can_fall_at_least(world, 0.0, 0.0, STEP_HEIGHT - self.fall_distance)
+    }
+
+    fn can_fall_at_least(&self, world: &ClientWorld, x: f64, z: f64, distance: f64) -> bool {
+        let current = self.bounding_box();
+        let area = BoundingBox::new(
+            Vector3::new(
+                current.min_x + COLLISION_EPSILON + x,
+                current.min_y - distance - COLLISION_EPSILON,
+                current.min_z + COLLISION_EPSILON + z,
+            ),
+            Vector3::new(
+                current.max_x - COLLISION_EPSILON + x,
+                current.min_y,
+                current.max_z - COLLISION_EPSILON + z,
+            ),
+        );
+        collisions(world, &area).is_empty()
+    }
+
+    /// Shortens `movement` so the player does not enter blocks, stepping up onto blocks
+    /// up to `STEP_HEIGHT` high when walking into them.
+    fn collide(&self, world: &ClientWorld, movement: Vector3<f64>) -> Vector3<f64> {
+        let current = self.bounding_box();
+        if movement.length_squared() == 0.0 {
+            return movement;
+        }
+        let colliders = collisions(world, &current.expand_towards(movement));
+        let moved = collide_with_shapes(movement, &current, &colliders);
+
+        let x_collision = movement.x != moved.x;
+        let z_collision = movement.z != moved.z;
+        let lands = movement.y != moved.y && movement.y < 0.0;
+        if !(lands || self.on_ground) || !(x_collision || z_collision) {
+            return moved;
+        }
+
+        let base = if lands {
+            current.offset(Vector3::new(0.0, moved.y, 0.0))
+        } else {
+            current
+        };
+        let mut area = base.expand_towards(Vector3::new(movement.x, STEP_HEIGHT, movement.z));
+        if !lands {
+            area = area.expand_towards(Vector3::new(0.0, -1.0e-5, 0.0));
+        }
+        let colliders = collisions(world, &area);
+        for height in step_heights(&base, &colliders, moved.y as f32) {
+            let stepped = collide_with_shapes(
+                Vector3::new(movement.x, height as f64, movement.z),
+                &base,
+                &colliders,
+            );
+            if horizontal_length_squared(stepped) > horizontal_length_squared(moved) {
+                return stepped + Vector3::new(0.0, base.min_y - current.min_y, 0.0);
+            }
+        }
+        moved
+    }
+
+    fn check_inside_blocks(&mut self, world: &ClientWorld) {
+        let area = self.bounding_box().inflate(-1.0e-5, -1.0e-5, -1.0e-5);
+        let feet = world.block_at(self.block_position());
+        for position in blocks_in(&area) {
+            let stuck = match world.block_at(position).name() {
+                "cobweb" => Vector3::new(0.25, 0.05, 0.25),
+                "sweet_berry_bush" => Vector3::new(0.8, 0.75, 0.8),
+                "powder_snow" if feet.name() == "powder_snow" => Vector3::new(0.9, 1.5, 0.9),
+                _ => continue,
+            };
+            self.fall_distance = 0.0;
+            self.stuck_multiplier = stuck;
+        }
+    }
+
+    /// The factor of the block the player is in, or of the block below if that one has
+    /// none.
+    fn block_factor(&self, world: &ClientWorld, factor: fn(BlockState) -> f64) -> f64 {
+        let inside = world.block_at(self.block_position());
+        let value = factor(inside);
+        if value != 1.0 || matches!(inside.name(), "water" | "bubble_column") {
+            return value;
+        }
+        factor(world.block_at(self.below_position()))
+    }
+
+    /// Crouches while sneaking or when there is no room to stand.
+    fn update_pose(&mut self, world: &ClientWorld) {
+        let fits = |crouching| {
+            let area = self.bounding_box_at(self.position, crouching).inflate(
+                -COLLISION_EPSILON,
+                -COLLISION_EPSILON,
+                -COLLISION_EPSILON,
+            );
+            collisions(world, &area).is_empty()
+        };
+        self.crouching = self.input.sneak || !fits(false) && fits(true);
+    }
+
+    fn packets(&mut self) -> TickPackets {
+        let mut packets = TickPackets::default();
+        let input = self.input.flags();
+        if input != self.last_sent.input {
+            packets.input = Some(PlayerInput::new(input));
+            self.last_sent.input = input;
+        }
+        if self.sprinting != self.last_sent.sprinting {
+            packets.sprinting = Some(self.sprinting);
+            self.last_sent.sprinting = self.sprinting;
+        }
+
+        let last = &mut self.last_sent;
+        last.position_reminder += 1;
+        let moved = self.position.squared_distance_to_vec(last.position)
+            > MOVE_THRESHOLD * MOVE_THRESHOLD
+            || last.position_reminder >= POSITION_REMINDER;
+        let rotated = self.yaw != last.yaw || self.pitch != last.pitch;
+        let Vector3 { x, y, z } = self.position;
+        let (on_ground, collision) = (self.on_ground, self.horizontal_collision);
+        packets.movement = if moved && rotated {
+            Some(MovePacket::PosRot(MovePlayerPosRot::new(
+                x, y, z, self.yaw, self.pitch, on_ground, collision,
+            )))
+        } else if moved {
+            Some(MovePacket::Pos(MovePlayerPos::new(
+                x, y, z, on_ground, collision,
+            )))
+        } else if rotated {
+            Some(MovePacket::Rot(MovePlayerRot::new(
+                self.yaw, self.pitch, on_ground, collision,
+            )))
+        } else if on_ground != last.on_ground || collision != last.horizontal_collision {
+            Some(MovePacket::StatusOnly(MovePlayerStatusOnly::new(
+                on_ground, collision,
+            )))
+        } else {
+            None
+        };
+
+        if moved {
+            last.position = self.position;
+            last.position_reminder = 0;
+        }
+        if rotated {
+            last.yaw = self.yaw;
+            last.pitch = self.pitch;
+        }
+        last.on_ground = on_ground;
+        last.horizontal_collision = collision;
+        packets
+    }
+
+    fn block_position(&self) -> BlockCoordinates {
+        block_position(self.position)
+    }
+
+    fn below_position(&self) -> BlockCoordinates {
+        block_position(self.position.sub(&Vector3::new(0.0, BELOW_OFFSET, 0.0)))
+    }
+}
+
+fn impulse(positive: bool, negative: bool) -> f32 {
+    match (positive, negative) {
+        (true, false) => 1.0,
+        (false, true) => -1.0,
+        _ => 0.0,
+    }
+}
+
+fn horizontal_length_squared(vector: Vector3<f64>) -> f64 {
+    vector.x * vector.x + vector.z * vector.z
+}
+
+fn block_position(position: Vector3<f64>) -> BlockCoordinates {
+    BlockCoordinates {
+        x: position.x.floor() as i32,
+        y: Height(position.y.floor().clamp(i16::MIN as f64, i16::MAX as f64) as i16),
+        z: position.z.floor() as i32,
+    }
+}
+
+/// The blocks `area` touches, blocks outside the height of the world are air.
+fn blocks_in(area: &BoundingBox) -> impl Iterator<Item = BlockCoordinates> {
+    let min_y = (area.min_y.floor() as i32).max(i16::MIN as i32);
+    let max_y = (area.max_y.floor() as i32).min(i16::MAX as i32);
+    let (min_x, max_x) = (area.min_x.floor() as i32, area.max_x.floor() as i32);
+    let (min_z, max_z) = (area.min_z.floor() as i32, area.max_z.floor() as i32);
+    (min_x..=max_x).flat_map(move |x| {
+        (min_y..=max_y).flat_map(move |y| {
+            (min_z..=max_z).map(move |z| BlockCoordinates {
+                x,
+                y: Height(y as i16),
+                z,
+            })
+        })
+    })
+}
+
+/// The collision shapes of blocks that overlap `area`, in world coordinates.
+fn collisions(world: &ClientWorld, area: &BoundingBox) -> Vec<VoxelShape> {
+    // Some shapes like fences reach into the next block.
+    let search = area.inflate(
+        1.0 + COLLISION_EPSILON,
+        1.0 + COLLISION_EPSILON,
+        1.0 + COLLISION_EPSILON,
+    );
+    blocks_in(&search)
+        .filter_map(|position| {
+            let shape = world.block_at(position).collision_shape();
+            let offset = Vector3::new(position.x as f64, position.y.0 as f64, position.z as f64);
+            (!shape.is_empty()).then(|| shape.offset(offset))
+        })
+        .filter(|shape| shape.intersects(area))
+        .collect()
+}
+
+/// Moves `current` by `movement` one axis at a time, Y first and then the larger of the
+/// horizontal ones.
+fn collide_with_shapes(
+    movement: Vector3<f64>,
+    current: &BoundingBox,
+    colliders: &[VoxelShape],
+) -> Vector3<f64> {
+    if colliders.is_empty() {
+        return movement;
+    }
+    let order = if movement.x.abs() < movement.z.abs() {
+        [Axis::Y, Axis::Z, Axis::X]
+    } else {
+        [Axis::Y, Axis::X, Axis::Z]
+    };
+    let mut moved = Vector3::new(0.0, 0.0, 0.0);
+    for axis in order {
+        let offset = axis.get(movement);
+        if offset == 0.0 {
+            continue;
+        }
+        let moving = current.offset(moved);
+        let offset = colliders.iter().fold(offset, |offset, collider| {
+            collider.collide(axis, &moving, offset)
+        });
+        match axis {
+            Axis::X => moved.x = offset,
+            Axis::Y => moved.y = offset,
+            Axis::Z => moved.z = offset,
+        }
+    }
+    moved
+}
+
+/// Heights above `base` worth trying to step up to, lowest first.
+fn step_heights(base: &BoundingBox, colliders: &[VoxelShape], skip: f32) -> Vec<f32> {
+    let mut heights: Vec<f32> = colliders
+        .iter()
+        .flat_map(|collider| collider.coords(Axis::Y))
+        .map(|y| (y - base.min_y) as f32)
+        .filter(|&height| height >= 0.0 && height != skip && height <= STEP_HEIGHT as f32)
+        .collect();
+    heights.sort_by(f32::total_cmp);
+    heights.dedup();
+    heights
+}
+
+fn friction(state: BlockState) -> f32 {
+    match state.name() {
+        "ice" | "packed_ice" | "frosted_ice" => 0.98,
+        "blue_ice" => 0.989,
+        "slime_block" => 0.8,
+        _ => 0.6,
+    }
+}
+
+fn speed_factor(state: BlockState) -> f64 {
+    match state.name() {
+        "soul_sand" | "honey_block" => 0.4,
+        _ => 1.0,
+    }
+}
+
+fn jump_factor(state: BlockState) -> f64 {
+    match state.name() {
+        "honey_block" => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Yaw and pitch looking from `from` to `to`, keeping `yaw` when looking straight up or
+/// down.
+pub(crate) fn look_rotation(from: Vector3<f64>, to: Vector3<f64>, yaw: f32) -> (f32, f32) {
+    let delta = to.sub(&from);
+    let horizontal = (delta.x * delta.x + delta.z * delta.z).sqrt();
+    let yaw = if horizontal > 1.0e-4 {
+        (-delta.x.atan2(delta.z)).to_degrees() as f32
+    } else {
+        yaw
+    };
+    (yaw, (-delta.y.atan2(horizontal)).to_degrees() as f32)
+}
+
+/// Blocks in the `climbable` tag, which players move up and down in.
+pub(crate) fn is_climbable(state: BlockState) -> bool {
+    matches!(
+        state.name(),
+        "ladder"
+            | "vine"
+            | "scaffolding"
+            | "weeping_vines"
+            | "weeping_vines_plant"
+            | "twisting_vines"
+            | "twisting_vines_plant"
+            | "cave_vines"
+            | "cave_vines_plant"
+    )
+}
+
+pub(crate) fn is_water(state: BlockState) -> bool {
+    fluid_amount(state, false).is_some()
+}
+
+pub(crate) fn is_lava(state: BlockState) -> bool {
+    fluid_amount(state, true).is_some()
+}
+
+/// How full of water, or lava, the block is, from 0 to 1.
+fn fluid_amount(state: BlockState, lava: bool) -> Option<f64> {
+    let level = || {
+        let level: u8 = state.property("level")?.parse().ok()?;
+        // Sources and falling fluid are full, flowing fluid loses a ninth per level.
+        Some(
+            if level == 0 || level >= 8 {
+                8.0
+            } else {
+                8.0 - level as f64
+            } / 9.0,
+        )
+    };
+    match state.name() {
+        "lava" if lava => level(),
+        _ if lava => None,
+        "water" => level(),
+        "bubble_column" | "kelp" | "kelp_plant" | "seagrass" | "tall_seagrass" => Some(8.0 / 9.0),
+        _ if state.property("waterlogged") == Some("true") => Some(8.0 / 9.0),
+        _ => None,
+    }
+}
+
+fn fluid_height(world: &ClientWorld, position: BlockCoordinates, lava: bool) -> Option<f64> {
+    let amount = fluid_amount(world.block_at(position), lava)?;
+    let above = BlockCoordinates {
+        y: Height(position.y.0 + 1),
+        ..position
+    };
+    // Fluid with more of it above fills the whole block.
+    Some(if fluid_amount(world.block_at(above), lava).is_some() {
+        1.0
+    } else {
+        amount
+    })
+}
+
+/// Whether the eyes are below the surface of water. Like vanilla, the surface is checked
+/// a ninth of a block below the eyes, so they don't dip in and out of small waves.
+pub(crate) fn is_eye_in_water(world: &ClientWorld, eye: Vector3<f64>) -> bool {
+    let y = eye.y - 1.0 / 9.0;
+    if !world.height().contains(y.floor() as i32) {
+        return false;
+    }
+    let position = BlockCoordinates {
+        x: eye.x.floor() as i32,
+        y: Height(y.floor() as i16),
+        z: eye.z.floor() as i32,
+    };
+    fluid_height(world, position, false).is_some_and(|height| y < y.floor() + height)
+}
+
+/// How deep `area` is in water, or lava, `None` if it does not touch any.
+fn fluid_height_in(world: &ClientWorld, area: &BoundingBox, lava: bool) -> Option<f64> {
+    let mut depth = None;
+    for position in blocks_in(area) {
+        let Some(height) = fluid_height(world, position, lava) else {
+            continue;
+        };
+        let top = position.y.0 as f64 + height;
+        if top >= area.min_y {
+            depth = Some(depth.unwrap_or(0.0f64).max(top - area.min_y));
+        }
+    }
+    depth
+}
+
+fn contains_fluid(world: &ClientWorld, area: &BoundingBox) -> bool {
+    blocks_in(area).any(|position| {
+        let state = world.block_at(position);
+        is_water(state) || is_lava(state)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::world::{set_test_block, test_world};
+
+    use super::*;
+
+    fn player(x: f64, y: f64, z: f64) -> PlayerPhysics {
+        let mut physics = PlayerPhysics::new();
+        physics.teleport(Vector3::new(x, y, z), Vector3::new(0.0, 0.0, 0.0), 0.0, 0.0);
+        physics
+    }
+
+    #[test]
+    fn eyes_in_water_below_the_surface() {
+        let water = BlockState::new("water").unwrap();
+        let flowing = water
+            .block()
+            .states
+            .iter()
+            .filter_map(|state| BlockState::from_state_id(state.id))
+            .find(|state| state.property("level") == Some("4"))
+            .unwrap();
+        let pool = [
+            (1, 65, 1, "water"),
+            (2, 65, 2, "water"),
+            (2, 66, 2, "water"),
+        ];
+        let mut world = test_world(true, &pool);
+        world.set_block(
+            BlockCoordinates {
+                x: 3,
+                y: Height(65),
+                z: 3,
+            },
+            flowing.state_id,
+        );
+
+        // A source is 8/9 high, or full with more water above.
+        assert!(is_eye_in_water(&world, Vector3::new(1.5, 65.9, 1.5)));
+        assert!(!is_eye_in_water(&world, Vector3::new(1.5, 66.05, 1.5)));
+        assert!(is_eye_in_water(&world, Vector3::new(2.5, 66.05, 2.5)));
+        // Flowing water of level 4 is 4/9 high.
+        assert!(is_eye_in_water(&world, Vector3::new(3.5, 65.5, 3.5)));
+        assert!(!is_eye_in_water(&world, Vector3::new(3.5, 65.6, 3.5)));
+    }
+
+    #[test]
+    fn falls_onto_ground() {
+        let world = test_world(true, &[]);
+        let mut physics = PlayerPhysics::new();
+        assert!(physics.tick(&world).is_none());
+
+        physics.teleport(
+            Vector3::new(0.5, 70.0, 0.5),
+            Vector3::new(0.0, 0.0, 0.0),
+            0.0,
+            0.0,
+        );
+        physics.tick(&world).unwrap();
+        assert!((physics.velocity.y + GRAVITY * 0.98).abs() < 1e-9);
+        for _ in 0..40 {
+            physics.tick(&world);
+        }
+        assert!(physics.on_ground);
+        assert_eq!(physics.position.y, 65.0);
+    }
+
+    #[test]
+    fn walks_and_steps_up() {
+        // Walking towards +x, a slab can be stepped onto but a full block is in the way.
+        let mut world = test_world(true, &[(3, 65, 5, "stone")]);
+        for x in 3..16 {
+            set_test_block(&mut world, x, 65, 1, "stone_slab");
+        }
+        let mut physics = player(1.5, 65.0, 1.5);
+        physics.yaw = -90.0;
+        physics.input.forward = true;
+
+        let packets = physics.tick(&world).unwrap();
+        assert_eq!(packets.input.unwrap().flags, PlayerInput::FORWARD);
+        assert!(matches!(packets.movement, Some(MovePacket::PosRot(_))));
+        for _ in 0..20 {
+            physics.tick(&world);
+        }
+        assert!(physics.position.x > 3.5);
+        assert_eq!(physics.position.y, 65.5);
+
+        let mut physics = player(1.5, 65.0, 5.5);
+        physics.yaw = -90.0;
+        physics.input.forward = true;
+        for _ in 0..20 {
+            physics.tick(&world);
+        }
+        assert!(physics.horizontal_collision);
+        assert!((physics.position.x - 2.7).abs() < 1e-6);
+        assert_eq!(physics.position.y, 65.0);
+    }
+
+    #[test]
+    fn sneaking_stops_at_edges() {
+        let mut world = test_world(true, &[]);
+        for z in 0..16 {
+            set_test_block(&mut world, 8, 64, z, "air");
+        }
+        let mut physics = player(6.5, 65.0, 1.5);
+        physics.yaw = -90.0;
+        physics.input.forward = true;
+        physics.input.sneak = true;
+        for _ in 0..60 {
+            physics.tick(&world);
+        }
+        assert!(physics.crouching);
+        assert_eq!(physics.position.y, 65.0);
+        assert!(physics.position.x < 8.3 && physics.position.x > 7.5);
+    }
+}
@@ -0,0 +1,383 @@
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+
+use base64::Engine;
+use bytes::Bytes;
+use serde::{Deserialize, Deserializer};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    time::timeout,
+};
+
+use wither_network::{
+    packet_decoder::{PacketDecoder, LEGACY_PING},
+    protocol, ClientIntent, Packet, PacketId, RawPacket,
+};
+use wither_util::text::TextComponent;
+
+use crate::{
+    event::{Event, Subscription},
+    options::ClientOptions,
+    ClientError, RawClient,
+};
+
+/// How long to wait for the connection and for every response of the server.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What a server shows in the multiplayer server list.
+#[derive(Debug, Clone)]
+pub struct ServerStatus {
+    pub version: Version,
+    /// `None` if the server hides its player count.
+    pub players: Option<Players>,
+    pub motd: TextComponent,
+    /// PNG image, 64x64 pixels.
+    pub favicon: Option<Bytes>,
+    pub enforce_secure_chat: bool,
+    /// Round trip time of the ping request.
+    pub latency: Duration,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Version {
+    pub name: String,
+    pub protocol: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Players {
+    pub max: u32,
+    pub online: u32,
+    /// Some of the online players, servers may leave this out or fill it with fake entries.
+    #[serde(default)]
+    pub sample: Vec<PlayerSample>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlayerSample {
+    pub name: String,
+    /// `None` if it is not a valid UUID, which fake entries often aren't.
+    #[serde(default, deserialize_with = "lenient_uuid")]
+    pub id: Option<uuid::Uuid>,
+}
+
+fn lenient_uuid<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<uuid::Uuid>, D::Error> {
+    let id = serde_json::Value::deserialize(deserializer)?;
+    Ok(id.as_str().and_then(|id| uuid::Uuid::parse_str(id).ok()))
+}
+
+/// The JSON document of the status response.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusJson {
+    #[serde(default)]
+    version: Version,
+    players: Option<Players>,
+    description: Option<serde_json::Value>,
+    favicon: Option<String>,
+    #[serde(default)]
+    enforces_secure_chat: bool,
+}
+
+/// Runs a server list ping: handshake, status request and a ping to measure latency.
+pub async fn ping(
+    host: &str,
+    port: u16,
+    options: ClientOptions,
+) -> Result<ServerStatus, Box<dyn Error>> {
+    let client = timeout(TIMEOUT, RawClient::new(host, port, options)).await??;
+    let mut events = client.events.subscribe();
+
+    client.set_protocol(ClientIntent::Status).await?;
+    client
+        .send_packet(&protocol::server::status::StatusRequest::new())
+        .await?;
+    let response = loop {
+        let mut packet = timeout(TIMEOUT, next_packet(&client, &mut events)).await??;
+        if packet.id.0 == protocol::client::status::StatusResponse::PACKET_ID {
+            break protocol::client::status::StatusResponse::read(&mut packet.bytebuf)?;
+        }
+    };
+
+    let payload = rand::random::<i64>();
+    let sent = Instant::now();
+    client
+        .send_packet(&protocol::server::status::PingRequest::new(payload))
+        .await?;
+    let latency = loop {
+        let mut packet = timeout(TIMEOUT, next_packet(&client, &mut events)).await??;
+        if packet.id.0 == protocol::client::status::PongResponse::PACKET_ID {
+            let pong = protocol::client::status::PongResponse::read(&mut packet.bytebuf)?;
+            if pong.payload == payload {
+                break sent.elapsed();
+            }
+        }
+    };
+    client.close();
+
+    parse_status(&response.json_response, latency)
+}
+
+/// Parses the JSON document of a status response.
+fn parse_status(json: &str, latency: Duration) -> Result<ServerStatus, Box<dyn Error>> {
+    let status: StatusJson = serde_json::from_str(json)?;
+    let motd = status
+        .description
+        .map_or_else(|| TextComponent::text(""), TextComponent::from_value);
+    let favicon = status
+        .favicon
+        .as_deref()
+        .and_then(|favicon| favicon.strip_prefix("data:image/png;base64,"))
+        .map(|data| base64::engine::general_purpose::STANDARD.decode(data))
+        .transpose()?
+        .map(Bytes::from);
+
+    Ok(ServerStatus {
+        version: status.version,
+        players: status.players,
+        motd,
+        favicon,
+        enforce_secure_chat: status.enforces_secure_chat,
+        latency,
+    })
+}
+
+/// Protocol version sent in a legacy ping, the one of 1.6.4.
+const LEGACY_PROTOCOL_VERSION: u8 = 78;
+
+/// Runs the server list ping of servers older than 1.7.
+///
+/// Legacy servers only report the version, MOTD and player counts, so `players` has no
+/// sample and there is no favicon.
+pub async fn legacy_ping(
+    host: &str,
+    port: u16,
+    options: ClientOptions,
+) -> Result<ServerStatus, Box<dyn Error>> {
+    let virtual_host = options.virtual_host.unwrap_or_else(|| host.to_string());
+    let virtual_port = options.virtual_port.unwrap_or(port);
+
+    let mut connection = timeout(TIMEOUT, tokio::net::TcpStream::connect((host, port))).await??;
+
+    // 1.6 style request, older servers only look at the first two bytes
+    let mut data = vec![LEGACY_PROTOCOL_VERSION];
+    put_legacy_string(&mut data, &virtual_host);
+    data.extend_from_slice(&(virtual_port as i32).to_be_bytes());
+
+    let mut request = vec![LEGACY_PING, 0x01, 0xFA];
+    put_legacy_string(&mut request, "MC|PingHost");
+    request.extend_from_slice(&(data.len() as u16).to_be_bytes());
+    request.extend_from_slice(&data);
+
+    let sent = Instant::now();
+    connection.write_all(&request).await?;
+
+    let mut decoder = PacketDecoder::default();
+    let mut buf = [0; 1024];
+    let message = loop {
+        let len = timeout(TIMEOUT, connection.read(&mut buf)).await??;
+        if len == 0 {
+            return Err(Box::new(ClientError::Disconnect));
+        }
+        decoder.queue_slice(&buf[..len]);
+        if let Some(message) = decoder.decode_legacy_kick()? {
+            break message;
+        }
+    };
+    let latency = sent.elapsed();
+
+    parse_legacy_status(&message, latency)
+}
+
+fn put_legacy_string(buf: &mut Vec<u8>, string: &str) {
+    let units: Vec<u16> = string.encode_utf16().collect();
+    buf.extend_from_slice(&(units.len() as u16).to_be_bytes());
+    for unit in units {
+        buf.extend_from_slice(&unit.to_be_bytes());
+    }
+}
+
+/// Parses `§1\0protocol\0version\0motd\0online\0max`, or `motd§online§max` from
+/// servers older than 1.4.
+fn parse_legacy_status(message: &str, latency: Duration) -> Result<ServerStatus, Box<dyn Error>> {
+    let (version, motd, online, max) = match message.strip_prefix("\u{a7}1\0") {
+        Some(fields) => {
+            let fields: Vec<&str> = fields.split('\0').collect();
+            let [protocol, name, motd, online, max] = fields[..] else {
+                return Err(format!("invalid legacy status {message:?}").into());
+            };
+            let version = Version {
+                name: name.to_string(),
+                protocol: protocol.parse()?,
+            };
+            (version, motd, online, max)
+        }
+        None => {
+            let mut fields = message.rsplitn(3, '\u{a7}');
+            let (Some(max), Some(online), Some(motd)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("invalid legacy status {message:?}").into());
+            };
+            (Version::default(), motd, online, max)
+        }
+    };
+
+    Ok(ServerStatus {
+        version,
+        players: Some(Players {
+            max: max.parse()?,
+            online: online.parse()?,
+            sample: Vec::new(),
+        }),
+        motd: TextComponent::text(motd.to_string()),
+        favicon: None,
+        enforce_secure_chat: false,
+        latency,
+    })
+}
+
+async fn next_packet(
+    client: &RawClient,
+    events: &mut Subscription,
+) -> Result<RawPacket, Box<dyn Error>> {
+    loop {
+        while let Some(event) = events.try_recv() {
+            if let Event::PacketReceived(packet) = event {
+                return Ok(packet);
+            }
+        }
+        if !client.poll().await {
+            return Err(Box::new(ClientError::Disconnect));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status() {
+        let status = parse_status(
+            r#"{
+                "version": {"name": "1.21.4", "protocol": 769},
+                "players": {"max": 20, "online": 1, "sample": [
+                    {"name": "Notch", "id": "069a79f4-44e9-4726-a5be-fca90e38aaf5"}
+                ]},
+                "description": {"text": "A ", "extra": [{"text": "server", "bold": true}]},
+                "favicon": "data:image/png;base64,iVBORw0K",
+                "enforcesSecureChat": true
+            }"#,
+            Duration::from_millis(12),
+        )
+        .unwrap();
+        assert_eq!(status.version.protocol, 769);
+        let players = status.players.unwrap();
+        assert_eq!((players.online, players.max), (1, 20));
+        assert_eq!(players.sample[0].name, "Notch");
+        assert_eq!(
+            players.sample[0].id,
+            Some(uuid::uuid!("069a79f4-44e9-4726-a5be-fca90e38aaf5"))
+        );
+        assert_eq!(
+            status.motd,
+            TextComponent::text("A ").add_child(TextComponent::text("server").bold())
+        );
+        assert_eq!(&status.favicon.unwrap()[1..4], b"PNG");
+        assert!(status.enforce_secure_chat);
+        assert_eq!(status.latency, Duration::from_millis(12));
+    }
+
+    #[test]
+    fn keeps_samples_with_bogus_ids() {
+        let status = parse_status(
+            r#"{"players": {"max": 1, "online": 0, "sample": [
+                {"name": "\u00a7cMaintenance", "id": "00000000-0000-0000-0000"},
+                {"name": "Steve", "id": 7},
+                {"name": "Alex"}
+            ]}}"#,
+            Duration::ZERO,
+        )
+        .unwrap();
+        let sample = status.players.unwrap().sample;
+        assert_eq!(sample.len(), 3);
+        assert_eq!(sample[0].name, "\u{a7}cMaintenance");
+        assert!(sample.iter().all(|player| player.id.is_none()));
+    }
+
+    #[test]
+    fn parses_lenient_descriptions() {
+        let motd = |description: &str| {
+            let json = format!(r#"{{"description": {description}}}"#);
+            parse_status(&json, Duration::ZERO).unwrap().motd
+        };
+        assert_eq!(motd(r#""plain""#), TextComponent::text("plain"));
+        assert_eq!(
+            motd(r#""\u00a7aLegacy""#),
+            TextComponent::text("\u{a7}aLegacy")
+        );
+        assert_eq!(
+            motd(r#"["a", {"text": "b"}]"#),
+            TextComponent::text("a").add_child(TextComponent::text("b"))
+        );
+        // Hover events showing items can't be represented, the text is kept.
+        assert_eq!(
+            motd(r#"{"text": "item", "hoverEvent": {"action": "show_item", "contents": 1}}"#),
+            TextComponent::text("item")
+        );
+
+        let status = parse_status("{}", Duration::ZERO).unwrap();
+        assert!(status.players.is_none());
+        assert_eq!(status.motd, TextComponent::text(""));
+        assert!(parse_status("not json", Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn parses_legacy_status() {
+        let status = parse_legacy_status(
+            "\u{a7}1\u{0}74\u{0}1.6.4\u{0}A \u{a7}aserver\u{0}3\u{0}20",
+            Duration::from_millis(5),
+        )
+        .unwrap();
+        assert_eq!(status.version.protocol, 74);
+        assert_eq!(status.version.name, "1.6.4");
+        assert_eq!(status.motd, TextComponent::text("A \u{a7}aserver"));
+        let players = status.players.unwrap();
+        assert_eq!((players.online, players.max), (3, 20));
+        assert!(players.sample.is_empty());
+        assert_eq!(status.latency, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn parses_pre_1_4_legacy_status() {
+        // The motd may contain formatting codes, only the last two fields are counts.
+        let status = parse_legacy_status("A \u{a7}aserver\u{a7}3\u{a7}20", Duration::ZERO).unwrap();
+        assert_eq!(status.version.protocol, 0);
+        assert_eq!(status.motd, TextComponent::text("A \u{a7}aserver"));
+        let players = status.players.unwrap();
+        assert_eq!((players.online, players.max), (3, 20));
+    }
+
+    #[test]
+    fn rejects_malformed_legacy_status() {
+        for message in [
+            "",
+            "no separators",
+            "motd\u{a7}20",
+            "motd\u{a7}three\u{a7}20",
+            "\u{a7}1\u{0}74\u{0}1.6.4\u{0}motd\u{0}3",
+            "\u{a7}1\u{0}new\u{0}1.6.4\u{0}motd\u{0}3\u{0}20",
+            "\u{a7}1\u{0}74\u{0}1.6.4\u{0}motd\u{0}3\u{0}20\u{0}extra",
+        ] {
+            assert!(
+                parse_legacy_status(message, Duration::ZERO).is_err(),
+                "{message:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    io,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+use tokio::net::UdpSocket;
+
+use wither_network::query::{
+    CBasicStatus, CFullStatus, CHandshake, QueryError, SHandshake, SStatusRequest,
+};
+
+/// How long to wait for each reply, query runs over UDP so a lost packet is never resent.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Largest reply we accept, a full stat with many players is a few KiB.
+const MAX_REPLY_SIZE: usize = 65507;
+
+/// Queries the basic stats (MOTD, map and player counts) of a server with `enable-query`.
+pub async fn query_basic(host: &str, port: u16) -> Result<CBasicStatus, QueryError> {
+    let (socket, session_id, challenge_token) = handshake(host, port).await?;
+
+    let reply = request(
+        &socket,
+        SStatusRequest {
+            session_id,
+            challenge_token,
+            is_full_request: false,
+        }
+        .encode()
+        .await,
+    )
+    .await?;
+    let status = CBasicStatus::decode(reply).await?;
+    check_session(session_id, status.session_id)?;
+
+    Ok(status)
+}
+
+/// Queries the full stats of a server with `enable-query`, including the complete
+/// player list and plugins.
+pub async fn query_full(host: &str, port: u16) -> Result<CFullStatus, QueryError> {
+    let (socket, session_id, challenge_token) = handshake(host, port).await?;
+
+    let reply = request(
+        &socket,
+        SStatusRequest {
+            session_id,
+            challenge_token,
+            is_full_request: true,
+        }
+        .encode()
+        .await,
+    )
+    .await?;
+    let status = CFullStatus::decode(reply).await?;
+    check_session(session_id, status.session_id)?;
+
+    Ok(status)
+}
+
+/// Gets a challenge token, returns the socket, session id and token to send it with.
+async fn handshake(host: &str, port: u16) -> Result<(UdpSocket, i32, i32), QueryError> {
+    let address = tokio::net::lookup_host((host, port))
+        .await?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("cannot resolve {host}")))?;
+    // The socket has to be of the same family as the server, an IPv4 one can't reach IPv6.
+    let local: SocketAddr = match address {
+        SocketAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+        SocketAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+    };
+    let socket = UdpSocket::bind(local).await?;
+    socket.connect(address).await?;
+
+    // Vanilla ignores the upper 4 bits of each byte
+    let session_id = rand::random::<i32>() & 0x0F0F0F0F;
+    let reply = request(&socket, SHandshake { session_id }.encode().await).await?;
+    let handshake = CHandshake::decode(reply).await?;
+    check_session(session_id, handshake.session_id)?;
+
+    Ok((socket, session_id, handshake.challenge_token))
+}
+
+async fn request(socket: &UdpSocket, packet: Vec<u8>) -> Result<Vec<u8>, QueryError> {
+    socket.send(&packet).await?;
+
+    let mut buf = vec![0; MAX_REPLY_SIZE];
+    let len = tokio::time::timeout(TIMEOUT, socket.recv(&mut buf))
+        .await
+        .map_err(|_| QueryError::Timeout)??;
+    buf.truncate(len);
+
+    Ok(buf)
+}
+
+fn check_session(expected: i32, actual: i32) -> Result<(), QueryError> {
+    if expected != actual {
+        return Err(QueryError::SessionMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use wither_network::query::{RawQueryPacket, SStatusRequest};
+
+    use super::*;
+
+    /// Answers one handshake and one basic status request, like a server with query on.
+    async fn serve(socket: UdpSocket) {
+        let mut buf = vec![0; MAX_REPLY_SIZE];
+        let (len, client) = socket.recv_from(&mut buf).await.unwrap();
+        let mut packet = RawQueryPacket::decode(buf[..len].to_vec()).await.unwrap();
+        let handshake = SHandshake::decode(&mut packet).await.unwrap();
+        let reply = CHandshake {
+            session_id: handshake.session_id,
+            challenge_token: 9513307,
+        };
+        socket.send_to(&reply.encode().await, client).await.unwrap();
+
+        let (len, client) = socket.recv_from(&mut buf).await.unwrap();
+        let mut packet = RawQueryPacket::decode(buf[..len].to_vec()).await.unwrap();
+        let request = SStatusRequest::decode(&mut packet).await.unwrap();
+        assert_eq!(request.challenge_token, 9513307);
+        let reply = CBasicStatus {
+            session_id: request.session_id,
+            motd: CString::new("A Minecraft Server").unwrap(),
+            map: CString::new("world").unwrap(),
+            num_players: 2,
+            max_players: 20,
+            host_port: 25565,
+            host_ip: CString::new("127.0.0.1").unwrap(),
+        };
+        socket.send_to(&reply.encode().await, client).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn queries_over_ipv4_and_ipv6() {
+        for host in ["127.0.0.1", "::1"] {
+            let Ok(server) = UdpSocket::bind((host, 0)).await else {
+                // No IPv6 on this machine.
+                continue;
+            };
+            let port = server.local_addr().unwrap().port();
+            let server = tokio::spawn(serve(server));
+
+            let status = query_basic(host, port).await.unwrap();
+            assert_eq!(status.motd.to_str().unwrap(), "A Minecraft Server");
+            assert_eq!((status.num_players, status.max_players), (2, 20));
+            server.await.unwrap();
+        }
+    }
+}
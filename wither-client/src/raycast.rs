@@ -0,0 +1,333 @@
+use wither_util::math::{vector3::Vector3, voxel_shape::VoxelShape};
+use wither_world::{
+    block::{BlockFace, BlockState},
+    coordinates::{BlockCoordinates, Height},
+};
+
+use crate::{
+    entities::{Entity, EntityTracker},
+    world::ClientWorld,
+};
+
+/// Where a ray hits a block.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlockHit {
+    pub position: Vector3<i32>,
+    pub state: BlockState,
+    /// The face the ray enters through, what `use_item_on` targets.
+    pub face: BlockFace,
+    pub point: Vector3<f64>,
+}
+
+/// Where a ray hits an entity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EntityHit {
+    pub id: i32,
+    pub point: Vector3<f64>,
+}
+
+/// Whatever the crosshair points at first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RaycastHit {
+    Block(BlockHit),
+    Entity(EntityHit),
+}
+
+impl ClientWorld {
+    /// The first block with an outline along `direction` from `from`, within `max_distance`
+    /// blocks. Unloaded chunks are empty.
+    pub fn raycast(
+        &self,
+        from: Vector3<f64>,
+        direction: Vector3<f64>,
+        max_distance: f64,
+    ) -> Option<BlockHit> {
+        let to = ray_end(from, direction, max_distance)?;
+        self.clip(from, to, BlockState::outline_shape)
+    }
+
+    /// Whether no collision shape is in the way from `from` to `to`, like mobs check
+    /// before seeing a player.
+    pub fn has_line_of_sight(&self, from: Vector3<f64>, to: Vector3<f64>) -> bool {
+        self.clip(from, to, BlockState::collision_shape).is_none()
+    }
+
+    /// Walks the blocks along the line from `from` to `to` in order, returning the first
+    /// hit on their `shape`.
+    fn clip(
+        &self,
+        from: Vector3<f64>,
+        to: Vector3<f64>,
+        shape: impl Fn(&BlockState) -> VoxelShape,
+    ) -> Option<BlockHit> {
+        let delta = to.sub(&from);
+        // The walk below never ends without finite steps.
+        if !delta.length_squared().is_finite() {
+            return None;
+        }
+        let mut block = floor(from);
+        let end = floor(to);
+        let step = Vector3::new(
+            delta.x.signum() as i32,
+            delta.y.signum() as i32,
+            delta.z.signum() as i32,
+        );
+        // Part of the line covered by crossing one block on each axis, and how far along
+        // the line the next block border on each axis is.
+        let crossing = |delta: f64| {
+            if delta == 0.0 {
+                f64::INFINITY
+            } else {
+                1.0 / delta.abs()
+            }
+        };
+        let border = |start: f64, delta: f64| {
+            let fraction = start - start.floor();
+            if delta == 0.0 {
+                f64::INFINITY
+            } else if delta > 0.0 {
+                (1.0 - fraction) * crossing(delta)
+            } else {
+                fraction * crossing(delta)
+            }
+        };
+        let t_delta = Vector3::new(crossing(delta.x), crossing(delta.y), crossing(delta.z));
+        let mut t_max = Vector3::new(
+            border(from.x, delta.x),
+            border(from.y, delta.y),
+            border(from.z, delta.z),
+        );
+
+        loop {
+            if let Some(hit) = self.clip_block(block, from, to, &shape) {
+                return Some(hit);
+            }
+            if block == end {
+                return None;
+            }
+            if t_max.x <= t_max.y && t_max.x <= t_max.z {
+                if t_max.x > 1.0 {
+                    return None;
+                }
+                block.x += step.x;
+                t_max.x += t_delta.x;
+            } else if t_max.y <= t_max.z {
+                if t_max.y > 1.0 {
+                    return None;
+                }
+                block.y += step.y;
+                t_max.y += t_delta.y;
+            } else {
+                if t_max.z > 1.0 {
+                    return None;
+                }
+                block.z += step.z;
+                t_max.z += t_delta.z;
+            }
+        }
+    }
+
+    fn clip_block(
+        &self,
+        block: Vector3<i32>,
+        from: Vector3<f64>,
+        to: Vector3<f64>,
+        shape: impl Fn(&BlockState) -> VoxelShape,
+    ) -> Option<BlockHit> {
+        if !self.height().contains(block.y) {
+            return None;
+        }
+        let state = self.block_at(BlockCoordinates {
+            x: block.x,
+            y: Height(block.y as i16),
+            z: block.z,
+        });
+        let corner = Vector3::new(block.x as f64, block.y as f64, block.z as f64);
+        let hit = shape(&state).raycast(from.sub(&corner), to.sub(&corner))?;
+        Some(BlockHit {
+            position: block,
+            state,
+            face: BlockFace::from_axis(hit.axis, hit.positive),
+            point: hit.position.add(&corner),
+        })
+    }
+}
+
+impl EntityTracker {
+    /// The closest entity other than the player whose bounding box is along `direction`
+    /// from `from`, within `max_distance` blocks, that matches `filter`. Blocks are not
+    /// checked, see `ClientWorld::raycast`.
+    pub fn raycast(
+        &self,
+        from: Vector3<f64>,
+        direction: Vector3<f64>,
+        max_distance: f64,
+        filter: impl Fn(&Entity) -> bool,
+    ) -> Option<EntityHit> {
+        let to = ray_end(from, direction, max_distance)?;
+        let player = self.player().map(|player| player.id);
+        self.iter()
+            .filter(|entity| Some(entity.id) != player && filter(entity))
+            .filter_map(|entity| {
+                let hit = VoxelShape::from(entity.bounding_box()).raycast(from, to)?;
+                Some(EntityHit {
+                    id: entity.id,
+                    point: hit.position,
+                })
+            })
+            .min_by(|a, b| {
+                a.point
+                    .squared_distance_to_vec(from)
+                    .total_cmp(&b.point.squared_distance_to_vec(from))
+            })
+    }
+}
+
+/// Where a ray of `max_distance` blocks ends, `None` if `direction` has no length.
+fn ray_end(from: Vector3<f64>, direction: Vector3<f64>, max_distance: f64) -> Option<Vector3<f64>> {
+    let length = direction.length_squared();
+    if length == 0.0 || !length.is_finite() {
+        return None;
+    }
+    Some(from.add(&(direction.normalize() * max_distance)))
+}
+
+fn floor(position: Vector3<f64>) -> Vector3<i32> {
+    Vector3::new(
+        position.x.floor() as i32,
+        position.y.floor() as i32,
+        position.z.floor() as i32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use wither_declare::entity::Kind;
+    use wither_network::{codec::var_int::VarInt, protocol::client::play, types::Uuid};
+
+    use crate::world::test_world;
+
+    use super::*;
+
+    #[test]
+    fn hits_block_faces() {
+        let world = test_world(false, &[(5, 64, 2, "stone"), (2, 64, 2, "stone_slab")]);
+        let eye = Vector3::new(2.5, 65.62, 2.5);
+
+        let hit = world
+            .raycast(eye, Vector3::new(1.0, -0.3, 0.0), 6.0)
+            .unwrap();
+        assert_eq!(hit.position, Vector3::new(5, 64, 2));
+        assert_eq!(hit.face, BlockFace::West);
+        assert!((hit.point.x - 5.0).abs() < 1.0e-9);
+
+        // The slab only fills the bottom half of its block.
+        let hit = world
+            .raycast(eye, Vector3::new(0.0, -1.0, 0.0), 6.0)
+            .unwrap();
+        assert_eq!(hit.position, Vector3::new(2, 64, 2));
+        assert_eq!(hit.face, BlockFace::Top);
+        assert!((hit.point.y - 64.5).abs() < 1.0e-9);
+
+        assert!(world
+            .raycast(eye, Vector3::new(-1.0, 0.0, 0.0), 6.0)
+            .is_none());
+        assert!(world
+            .raycast(eye, Vector3::new(1.0, -0.3, 0.0), 2.0)
+            .is_none());
+    }
+
+    #[test]
+    fn sees_through_torches_but_not_glass() {
+        let world = test_world(false, &[(4, 64, 2, "torch")]);
+        let from = Vector3::new(2.5, 64.5, 2.5);
+        let to = Vector3::new(6.5, 64.5, 2.5);
+
+        let hit = world.raycast(from, to.sub(&from), 6.0).unwrap();
+        assert_eq!(hit.position, Vector3::new(4, 64, 2));
+        assert_eq!(hit.face, BlockFace::West);
+        assert_eq!(hit.point.x, 4.375);
+        assert!(world.has_line_of_sight(from, to));
+        let world = test_world(false, &[(4, 64, 2, "glass")]);
+        assert!(!world.has_line_of_sight(from, to));
+    }
+
+    #[test]
+    fn hits_entities() {
+        let mut tracker = EntityTracker::new();
+        tracker.reset(1, uuid::Uuid::new_v4());
+        for (id, kind, x) in [(2, Kind::Zombie, 9.0), (3, Kind::Cow, 2.0)] {
+            tracker.add_entity(&play::AddEntity::new(
+                VarInt(id),
+                Uuid(uuid::Uuid::new_v4()),
+                VarInt(kind.id()),
+                x,
+                64.0,
+                0.0,
+                0,
+                0,
+                0,
+                VarInt(0),
+                0,
+                0,
+                0,
+            ));
+        }
+
+        let (eye, along) = (Vector3::new(0.0, 65.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(tracker.raycast(eye, along, 32.0, |_| true).unwrap().id, 3);
+        let hit = tracker
+            .raycast(eye, along, 32.0, |entity| entity.kind.is_hostile())
+            .unwrap();
+        assert_eq!(hit.id, 2);
+        assert!((hit.point.x - 8.7).abs() < 1e-6);
+        assert!(tracker.raycast(eye, along, 1.0, |_| true).is_none());
+
+        let zombie = tracker.get(2).unwrap();
+        assert!((zombie.eye_height() - 1.74).abs() < 1e-6);
+        assert!((tracker.get(1).unwrap().eye_height() - 1.62).abs() < 1e-6);
+    }
+
+    #[test]
+    fn axis_aligned_rays_on_block_borders() {
+        let world = test_world(false, &[(5, 64, 2, "stone"), (4, 64, 2, "glass")]);
+
+        let from = Vector3::new(2.5, 64.5, 2.0);
+        let hit = world
+            .raycast(from, Vector3::new(1.0, 0.0, 0.0), 6.0)
+            .unwrap();
+        assert_eq!(hit.position, Vector3::new(4, 64, 2));
+        assert!(world
+            .raycast(from, Vector3::new(-1.0, 0.0, 0.0), 6.0)
+            .is_none());
+
+        let from = Vector3::new(2.5, 64.0, 2.5);
+        assert!(!world.has_line_of_sight(from, Vector3::new(6.5, 64.0, 2.5)));
+        let from = Vector3::new(4.5, 67.0, 2.0);
+        assert_eq!(
+            world
+                .raycast(from, Vector3::new(0.0, -1.0, 0.0), 6.0)
+                .map(|hit| hit.face),
+            Some(BlockFace::Top)
+        );
+    }
+
+    #[test]
+    fn ignores_rays_without_direction() {
+        let world = test_world(false, &[(2, 65, 2, "stone")]);
+        let eye = Vector3::new(2.5, 65.5, 2.5);
+        let mut tracker = EntityTracker::new();
+        tracker.reset(1, uuid::Uuid::new_v4());
+
+        for direction in [
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(f64::NAN, 0.0, 1.0),
+            Vector3::new(f64::INFINITY, 0.0, 0.0),
+        ] {
+            assert!(world.raycast(eye, direction, 6.0).is_none());
+            assert!(tracker.raycast(eye, direction, 6.0, |_| true).is_none());
+        }
+        // Only has to return.
+        world.has_line_of_sight(eye, Vector3::new(f64::NAN, 65.5, 2.5));
+    }
+}
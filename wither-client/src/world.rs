@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use wither_network::{
+    codec::{bit_set::BitSet, identifier::Identifier},
+    protocol::client::play,
+};
+use wither_util::math::vector2::Vector2;
+use wither_world::{
+    block::BlockState,
+    chunk::{ChunkData, ChunkParsingError, LightArray, SECTION_SIZE},
+    coordinates::{BlockCoordinates, ChunkRelativeBlockCoordinates, Height},
+    dimension::DimensionHeight,
+};
+
+/// Vanilla keeps this many chunks beyond the view distance before dropping them.
+const CHUNK_CACHE_MARGIN: i32 = 3;
+
+/// The chunks the server sent, kept up to date with block changes.
+#[derive(Debug, Default)]
+pub struct ClientWorld {
+    dimension: Option<Identifier>,
+    height: DimensionHeight,
+    chunks: HashMap<Vector2<i32>, ChunkData>,
+    center: Vector2<i32>,
+    radius: i32,
+}
+
+impl ClientWorld {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn dimension(&self) -> Option<&Identifier> {
+        self.dimension.as_ref()
+    }
+
+    /// Heights of the current dimension, the overworld until the server says otherwise.
+    pub fn height(&self) -> DimensionHeight {
+        self.height
+    }
+
+    /// Drops every chunk when the player ends up in another dimension.
+    pub fn change_dimension(&mut self, dimension: Identifier, height: DimensionHeight) {
+        if self.dimension.as_ref() != Some(&dimension) || self.height != height {
+            self.chunks.clear();
+            self.dimension = Some(dimension);
+            self.height = height;
+        }
+    }
+
+    pub fn chunk(&self, position: Vector2<i32>) -> Option<&ChunkData> {
+        self.chunks.get(&position)
+    }
+
+    pub fn chunks(&self) -> impl Iterator<Item = &ChunkData> {
+        self.chunks.values()
+    }
+
+    pub fn is_loaded(&self, position: Vector2<i32>) -> bool {
+        self.chunks.contains_key(&position)
+    }
+
+    /// The block at `position`, air if its chunk is not loaded.
+    pub fn block_at(&self, position: BlockCoordinates) -> BlockState {
+        let (chunk, relative) = split_position(position);
+        self.chunks
+            .get(&chunk)
+            .and_then(|chunk| BlockState::from_state_id(chunk.get_block_state(relative)))
+            .unwrap_or(BlockState::AIR)
+    }
+
+    /// Sky and block light at `position`, both 0 if its chunk is not loaded.
+    pub fn light_at(&self, position: BlockCoordinates) -> (u8, u8) {
+        let (chunk, relative) = split_position(position);
+        self.chunks
+            .get(&chunk)
+            .map_or((0, 0), |chunk| chunk.get_light(relative))
+    }
+
+    /// Changes a block locally, does nothing if its chunk is not loaded.
+    pub fn set_block(&mut self, position: BlockCoordinates, state_id: u16) {
+        let (chunk, relative) = split_position(position);
+        if let Some(chunk) = self.chunks.get_mut(&chunk) {
+            chunk.set_block_state(relative, state_id);
+        }
+    }
+
+    /// Stores a chunk as if the server had sent it.
+    pub fn insert_chunk(&mut self, chunk: ChunkData) {
+        self.chunks.insert(chunk.position, chunk);
+    }
+
+    pub fn load_chunk(
+        &mut self,
+        packet: &play::LevelChunkWithLight,
+    ) -> Result<(), ChunkParsingError> {
+        let position = Vector2::new(packet.chunk_x, packet.chunk_z);
+        let mut chunk = ChunkData::decode(position, self.height.min_y, &mut &packet.data[..])?;
+        apply_light(&mut chunk, &packet.light_data);
+        self.chunks.insert(position, chunk);
+        Ok(())
+    }
+
+    /// Applies a light update, ignored if the chunk is not loaded.
+    pub fn light_update(&mut self, packet: &play::LightUpdate) {
+        let position = Vector2::new(packet.chunk_x.0, packet.chunk_z.0);
+        if let Some(chunk) = self.chunks.get_mut(&position) {
+            apply_light(chunk, &packet.light_data);
+        }
+    }
+
+    pub fn forget_chunk(&mut self, position: Vector2<i32>) {
+        self.chunks.remove(&position);
+    }
+
+    pub fn block_update(&mut self, packet: &play::BlockUpdate) {
+        let position = packet.location.0;
+        if !self.height.contains(position.y) {
+            return;
+        }
+        self.set_block(
+            BlockCoordinates {
+                x: position.x,
+                y: Height(position.y as i16),
+                z: position.z,
+            },
+            packet.block_state_id.0 as u16,
+        );
+    }
+
+    pub fn section_blocks_update(&mut self, packet: &play::SectionBlocksUpdate) {
+        let height = self.height;
+        let Some(chunk) = self
+            .chunks
+            .get_mut(&Vector2::new(packet.section.x, packet.section.z))
+        else {
+            return;
+        };
+        let section_y = packet.section.y * SECTION_SIZE as i32;
+        for (position, state) in &packet.changes {
+            let y = section_y + position.y as i32;
+            if !height.contains(y) {
+                continue;
+            }
+            let relative = ChunkRelativeBlockCoordinates {
+                x: position.x.into(),
+                y: Height(y as i16),
+                z: position.z.into(),
+            };
+            chunk.set_block_state(relative, state.0 as u16);
+        }
+    }
+
+    /// Moves the center of the loaded area, chunks too far from it are dropped.
+    pub fn set_center(&mut self, center: Vector2<i32>) {
+        self.center = center;
+        self.drop_out_of_range();
+    }
+
+    pub fn set_radius(&mut self, radius: i32) {
+        self.radius = radius;
+        self.drop_out_of_range();
+    }
+
+    fn drop_out_of_range(&mut self) {
+        let range = self.radius.max(2) + CHUNK_CACHE_MARGIN;
+        let center = self.center;
+        self.chunks.retain(|position, _| {
+            (position.x - center.x).abs() <= range && (position.z - center.z).abs() <= range
+        });
+    }
+}
+
+/// Stores the light arrays of `light` in the sections of `chunk`. Sections missing from
+/// both masks keep their light.
+fn apply_light(chunk: &mut ChunkData, light: &play::LightData) {
+    let mut sky_updates = light.sky_updates.iter();
+    let mut block_updates = light.block_updates.iter();
+    // Bit 0 is the section below the world, which is never stored.
+    for bit in 0..chunk.sections.len() + 2 {
+        let sky = update_light(
+            &light.sky_y_mask,
+            &light.empty_sky_y_mask,
+            &mut sky_updates,
+            bit,
+        );
+        let block = update_light(
+            &light.block_y_mask,
+            &light.empty_block_y_mask,
+            &mut block_updates,
+            bit,
+        );
+        let Some(section) = bit.checked_sub(1).and_then(|i| chunk.sections.get_mut(i)) else {
+            continue;
+        };
+        if let Some(sky) = sky {
+            section.sky_light = Some(sky);
+        }
+        if let Some(block) = block {
+            section.block_light = Some(block);
+        }
+    }
+}
+
+fn update_light<'a>(
+    mask: &BitSet,
+    empty_mask: &BitSet,
+    updates: &mut impl Iterator<Item = &'a Bytes>,
+    bit: usize,
+) -> Option<LightArray> {
+    if mask.get(bit) {
+        updates
+            .next()
+            .and_then(|bytes| LightArray::from_bytes(bytes))
+    } else if empty_mask.get(bit) {
+        Some(LightArray::new())
+    } else {
+        None
+    }
+}
+
+fn split_position(position: BlockCoordinates) -> (Vector2<i32>, ChunkRelativeBlockCoordinates) {
+    let chunk = Vector2::new(position.x.div_euclid(16), position.z.div_euclid(16));
+    let relative = ChunkRelativeBlockCoordinates {
+        x: (position.x.rem_euclid(16) as u8).into(),
+        y: position.y,
+        z: (position.z.rem_euclid(16) as u8).into(),
+    };
+    (chunk, relative)
+}
+
+/// A world with one chunk at 0, 0 holding `blocks`, for tests. With `floor`, y 64 of the
+/// chunk is stone before the blocks are set.
+#[cfg(test)]
+pub(crate) fn test_world(floor: bool, blocks: &[(i32, i32, i32, &str)]) -> ClientWorld {
+    let mut world = ClientWorld::new();
+    world.insert_chunk(ChunkData::empty(Vector2::new(0, 0), -64, 24));
+    if floor {
+        for x in 0..16 {
+            for z in 0..16 {
+                set_test_block(&mut world, x, 64, z, "stone");
+            }
+        }
+    }
+    for &(x, y, z, name) in blocks {
+        set_test_block(&mut world, x, y, z, name);
+    }
+    world
+}
+
+/// Sets a block of a `test_world` to the default state of `name`.
+#[cfg(test)]
+pub(crate) fn set_test_block(world: &mut ClientWorld, x: i32, y: i32, z: i32, name: &str) {
+    let position = BlockCoordinates {
+        x,
+        y: Height(y as i16),
+        z,
+    };
+    world.set_block(position, BlockState::new(name).unwrap().state_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use wither_network::codec::var_int::VarInt;
+    use wither_util::math::{position::WorldPosition, vector3::Vector3};
+
+    use super::*;
+
+    #[test]
+    fn updates_use_the_dimension_height() {
+        let nether = DimensionHeight {
+            min_y: 0,
+            height: 256,
+        };
+        let mut world = ClientWorld::new();
+        world.change_dimension(Identifier::vanilla("the_nether"), nether);
+        world.insert_chunk(ChunkData::empty(Vector2::new(0, 0), 0, 16));
+        let stone = BlockState::new("stone").unwrap();
+        let at = |x, y, z| BlockCoordinates { x, y: Height(y), z };
+
+        world.block_update(&play::BlockUpdate::new(
+            WorldPosition(Vector3::new(1, 5, 1)),
+            VarInt(stone.state_id as i32),
+        ));
+        assert_eq!(world.block_at(at(1, 5, 1)), stone);
+        assert_eq!(
+            world.chunk(Vector2::new(0, 0)).unwrap().sections[0].block_count,
+            1
+        );
+
+        world.section_blocks_update(&play::SectionBlocksUpdate::new(
+            Vector3::new(0, 15, 0),
+            vec![(Vector3::new(2, 15, 2), VarInt(stone.state_id as i32))],
+        ));
+        assert_eq!(world.block_at(at(2, 255, 2)), stone);
+        assert!(world.block_at(at(2, -1, 2)).is_air());
+
+        let empty = || BitSet(VarInt(0), Vec::new());
+        world.light_update(&play::LightUpdate::new(
+            VarInt(0),
+            VarInt(0),
+            play::LightData {
+                // Bit 1 is the lowest section of the world.
+                sky_y_mask: BitSet(VarInt(1), vec![0b10]),
+                block_y_mask: empty(),
+                empty_sky_y_mask: empty(),
+                empty_block_y_mask: empty(),
+                sky_updates: vec![Bytes::copy_from_slice(LightArray::filled(15).as_bytes())],
+                block_updates: Vec::new(),
+            },
+        ));
+        assert_eq!(world.light_at(at(3, 4, 3)), (15, 0));
+        assert_eq!(world.light_at(at(3, 16, 3)), (0, 0));
+
+        // Back in the overworld the chunks are gone.
+        world.change_dimension(Identifier::vanilla("overworld"), DimensionHeight::OVERWORLD);
+        assert!(!world.is_loaded(Vector2::new(0, 0)));
+    }
+}
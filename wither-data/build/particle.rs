@@ -10,6 +10,7 @@ pub(crate) fn build() -> TokenStream {
     let particle: Vec<String> = serde_json::from_str(include_str!("../../assets/particles.json"))
         .expect("Failed to parse particles.json");
     let mut variants = TokenStream::new();
+    let mut from_id = TokenStream::new();
 
     for (id, particle) in particle.iter().enumerate() {
         let id = id as u8;
@@ -18,6 +19,9 @@ pub(crate) fn build() -> TokenStream {
         variants.extend([quote! {
             #name = #id,
         }]);
+        from_id.extend([quote! {
+            #id => Some(Self::#name),
+        }]);
     }
     quote! {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -25,5 +29,14 @@ pub(crate) fn build() -> TokenStream {
         pub enum Particle {
             #variants
         }
+
+        impl Particle {
+            pub const fn from_id(id: u8) -> Option<Self> {
+                match id {
+                    #from_id
+                    _ => None,
+                }
+            }
+        }
     }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 
 
 
-#[derive(Clone)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(i32)]
 pub enum Kind {
     AcaciaBoat = 0,
@@ -155,7 +155,447 @@ pub enum Kind {
     FishingBobber = 148,
 }
 
-#[derive(Clone, Copy)]
+impl Kind {
+    pub const ALL: [Kind; 149] = [
+        Kind::AcaciaBoat,
+        Kind::AcaciaChestBoat,
+        Kind::Allay,
+        Kind::AreaEffectCloud,
+        Kind::Armadillo,
+        Kind::ArmorStand,
+        Kind::Arrow,
+        Kind::Axolotl,
+        Kind::BambooChestRaft,
+        Kind::BambooRaft,
+        Kind::Bat,
+        Kind::Bee,
+        Kind::BirchBoat,
+        Kind::BirchChestBoat,
+        Kind::Blaze,
+        Kind::BlockDisplay,
+        Kind::Bogged,
+        Kind::Breeze,
+        Kind::BreezeWindCharge,
+        Kind::Camel,
+        Kind::Cat,
+        Kind::CaveSpider,
+        Kind::CherryBoat,
+        Kind::CherryChestBoat,
+        Kind::ChestMinecart,
+        Kind::Chicken,
+        Kind::Cod,
+        Kind::CommandBlockMinecart,
+        Kind::Cow,
+        Kind::Creaking,
+        Kind::Creeper,
+        Kind::DarkOakBoat,
+        Kind::DarkOakChestBoat,
+        Kind::Dolphin,
+        Kind::Donkey,
+        Kind::DragonFireball,
+        Kind::Drowned,
+        Kind::Egg,
+        Kind::ElderGuardian,
+        Kind::Enderman,
+        Kind::Endermite,
+        Kind::EnderDragon,
+        Kind::EnderPearl,
+        Kind::EndCrystal,
+        Kind::Evoker,
+        Kind::EvokerFangs,
+        Kind::ExperienceBottle,
+        Kind::ExperienceOrb,
+        Kind::EyeOfEnder,
+        Kind::FallingBlock,
+        Kind::Fireball,
+        Kind::FireworkRocket,
+        Kind::Fox,
+        Kind::Frog,
+        Kind::FurnaceMinecart,
+        Kind::Ghast,
+        Kind::Giant,
+        Kind::GlowItemFrame,
+        Kind::GlowSquid,
+        Kind::Goat,
+        Kind::Guardian,
+        Kind::Hoglin,
+        Kind::HopperMinecart,
+        Kind::Horse,
+        Kind::Husk,
+        Kind::Illusioner,
+        Kind::Interaction,
+        Kind::IronGolem,
+        Kind::Item,
+        Kind::ItemDisplay,
+        Kind::ItemFrame,
+        Kind::JungleBoat,
+        Kind::JungleChestBoat,
+        Kind::LeashKnot,
+        Kind::LightningBolt,
+        Kind::Llama,
+        Kind::LlamaSpit,
+        Kind::MagmaCube,
+        Kind::MangroveBoat,
+        Kind::MangroveChestBoat,
+        Kind::Marker,
+        Kind::Minecart,
+        Kind::Mooshroom,
+        Kind::Mule,
+        Kind::OakBoat,
+        Kind::OakChestBoat,
+        Kind::Ocelot,
+        Kind::OminousItemSpawner,
+        Kind::Painting,
+        Kind::PaleOakBoat,
+        Kind::PaleOakChestBoat,
+        Kind::Panda,
+        Kind::Parrot,
+        Kind::Phantom,
+        Kind::Pig,
+        Kind::Piglin,
+        Kind::PiglinBrute,
+        Kind::Pillager,
+        Kind::PolarBear,
+        Kind::Potion,
+        Kind::Pufferfish,
+        Kind::Rabbit,
+        Kind::Ravager,
+        Kind::Salmon,
+        Kind::Sheep,
+        Kind::Shulker,
+        Kind::ShulkerBullet,
+        Kind::Silverfish,
+        Kind::Skeleton,
+        Kind::SkeletonHorse,
+        Kind::Slime,
+        Kind::SmallFireball,
+        Kind::Sniffer,
+        Kind::Snowball,
+        Kind::SnowGolem,
+        Kind::SpawnerMinecart,
+        Kind::SpectralArrow,
+        Kind::Spider,
+        Kind::SpruceBoat,
+        Kind::SpruceChestBoat,
+        Kind::Squid,
+        Kind::Stray,
+        Kind::Strider,
+        Kind::Tadpole,
+        Kind::TextDisplay,
+        Kind::Tnt,
+        Kind::TntMinecart,
+        Kind::TraderLlama,
+        Kind::Trident,
+        Kind::TropicalFish,
+        Kind::Turtle,
+        Kind::Vex,
+        Kind::Villager,
+        Kind::Vindicator,
+        Kind::WanderingTrader,
+        Kind::Warden,
+        Kind::WindCharge,
+        Kind::Witch,
+        Kind::Wither,
+        Kind::WitherSkeleton,
+        Kind::WitherSkull,
+        Kind::Wolf,
+        Kind::Zoglin,
+        Kind::Zombie,
+        Kind::ZombieHorse,
+        Kind::ZombieVillager,
+        Kind::ZombifiedPiglin,
+        Kind::Player,
+        Kind::FishingBobber,
+    ];
+
+    /// The kind with the given protocol id.
+    pub fn from_id(id: i32) -> Option<Kind> {
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| Self::ALL.get(id))
+            .copied()
+    }
+
+    pub fn id(self) -> i32 {
+        self as i32
+    }
+
+    /// The registry name without the `minecraft:` namespace.
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::AcaciaBoat => "acacia_boat",
+            Kind::AcaciaChestBoat => "acacia_chest_boat",
+            Kind::Allay => "allay",
+            Kind::AreaEffectCloud => "area_effect_cloud",
+            Kind::Armadillo => "armadillo",
+            Kind::ArmorStand => "armor_stand",
+            Kind::Arrow => "arrow",
+            Kind::Axolotl => "axolotl",
+            Kind::BambooChestRaft => "bamboo_chest_raft",
+            Kind::BambooRaft => "bamboo_raft",
+            Kind::Bat => "bat",
+            Kind::Bee => "bee",
+            Kind::BirchBoat => "birch_boat",
+            Kind::BirchChestBoat => "birch_chest_boat",
+            Kind::Blaze => "blaze",
+            Kind::BlockDisplay => "block_display",
+            Kind::Bogged => "bogged",
+            Kind::Breeze => "breeze",
+            Kind::BreezeWindCharge => "breeze_wind_charge",
+            Kind::Camel => "camel",
+            Kind::Cat => "cat",
+            Kind::CaveSpider => "cave_spider",
+            Kind::CherryBoat => "cherry_boat",
+            Kind::CherryChestBoat => "cherry_chest_boat",
+            Kind::ChestMinecart => "chest_minecart",
+            Kind::Chicken => "chicken",
+            Kind::Cod => "cod",
+            Kind::CommandBlockMinecart => "command_block_minecart",
+            Kind::Cow => "cow",
+            Kind::Creaking => "creaking",
+            Kind::Creeper => "creeper",
+            Kind::DarkOakBoat => "dark_oak_boat",
+            Kind::DarkOakChestBoat => "dark_oak_chest_boat",
+            Kind::Dolphin => "dolphin",
+            Kind::Donkey => "donkey",
+            Kind::DragonFireball => "dragon_fireball",
+            Kind::Drowned => "drowned",
+            Kind::Egg => "egg",
+            Kind::ElderGuardian => "elder_guardian",
+            Kind::Enderman => "enderman",
+            Kind::Endermite => "endermite",
+            Kind::EnderDragon => "ender_dragon",
+            Kind::EnderPearl => "ender_pearl",
+            Kind::EndCrystal => "end_crystal",
+            Kind::Evoker => "evoker",
+            Kind::EvokerFangs => "evoker_fangs",
+            Kind::ExperienceBottle => "experience_bottle",
+            Kind::ExperienceOrb => "experience_orb",
+            Kind::EyeOfEnder => "eye_of_ender",
+            Kind::FallingBlock => "falling_block",
+            Kind::Fireball => "fireball",
+            Kind::FireworkRocket => "firework_rocket",
+            Kind::Fox => "fox",
+            Kind::Frog => "frog",
+            Kind::FurnaceMinecart => "furnace_minecart",
+            Kind::Ghast => "ghast",
+            Kind::Giant => "giant",
+            Kind::GlowItemFrame => "glow_item_frame",
+            Kind::GlowSquid => "glow_squid",
+            Kind::Goat => "goat",
+            Kind::Guardian => "guardian",
+            Kind::Hoglin => "hoglin",
+            Kind::HopperMinecart => "hopper_minecart",
+            Kind::Horse => "horse",
+            Kind::Husk => "husk",
+            Kind::Illusioner => "illusioner",
+            Kind::Interaction => "interaction",
+            Kind::IronGolem => "iron_golem",
+            Kind::Item => "item",
+            Kind::ItemDisplay => "item_display",
+            Kind::ItemFrame => "item_frame",
+            Kind::JungleBoat => "jungle_boat",
+            Kind::JungleChestBoat => "jungle_chest_boat",
+            Kind::LeashKnot => "leash_knot",
+            Kind::LightningBolt => "lightning_bolt",
+            Kind::Llama => "llama",
+            Kind::LlamaSpit => "llama_spit",
+            Kind::MagmaCube => "magma_cube",
+            Kind::MangroveBoat => "mangrove_boat",
+            Kind::MangroveChestBoat => "mangrove_chest_boat",
+            Kind::Marker => "marker",
+            Kind::Minecart => "minecart",
+            Kind::Mooshroom => "mooshroom",
+            Kind::Mule => "mule",
+            Kind::OakBoat => "oak_boat",
+            Kind::OakChestBoat => "oak_chest_boat",
+            Kind::Ocelot => "ocelot",
+            Kind::OminousItemSpawner => "ominous_item_spawner",
+            Kind::Painting => "painting",
+            Kind::PaleOakBoat => "pale_oak_boat",
+            Kind::PaleOakChestBoat => "pale_oak_chest_boat",
+            Kind::Panda => "panda",
+            Kind::Parrot => "parrot",
+            Kind::Phantom => "phantom",
+            Kind::Pig => "pig",
+            Kind::Piglin => "piglin",
+            Kind::PiglinBrute => "piglin_brute",
+            Kind::Pillager => "pillager",
+            Kind::PolarBear => "polar_bear",
+            Kind::Potion => "potion",
+            Kind::Pufferfish => "pufferfish",
+            Kind::Rabbit => "rabbit",
+            Kind::Ravager => "ravager",
+            Kind::Salmon => "salmon",
+            Kind::Sheep => "sheep",
+            Kind::Shulker => "shulker",
+            Kind::ShulkerBullet => "shulker_bullet",
+            Kind::Silverfish => "silverfish",
+            Kind::Skeleton => "skeleton",
+            Kind::SkeletonHorse => "skeleton_horse",
+            Kind::Slime => "slime",
+            Kind::SmallFireball => "small_fireball",
+            Kind::Sniffer => "sniffer",
+            Kind::Snowball => "snowball",
+            Kind::SnowGolem => "snow_golem",
+            Kind::SpawnerMinecart => "spawner_minecart",
+            Kind::SpectralArrow => "spectral_arrow",
+            Kind::Spider => "spider",
+            Kind::SpruceBoat => "spruce_boat",
+            Kind::SpruceChestBoat => "spruce_chest_boat",
+            Kind::Squid => "squid",
+            Kind::Stray => "stray",
+            Kind::Strider => "strider",
+            Kind::Tadpole => "tadpole",
+            Kind::TextDisplay => "text_display",
+            Kind::Tnt => "tnt",
+            Kind::TntMinecart => "tnt_minecart",
+            Kind::TraderLlama => "trader_llama",
+            Kind::Trident => "trident",
+            Kind::TropicalFish => "tropical_fish",
+            Kind::Turtle => "turtle",
+            Kind::Vex => "vex",
+            Kind::Villager => "villager",
+            Kind::Vindicator => "vindicator",
+            Kind::WanderingTrader => "wandering_trader",
+            Kind::Warden => "warden",
+            Kind::WindCharge => "wind_charge",
+            Kind::Witch => "witch",
+            Kind::Wither => "wither",
+            Kind::WitherSkeleton => "wither_skeleton",
+            Kind::WitherSkull => "wither_skull",
+            Kind::Wolf => "wolf",
+            Kind::Zoglin => "zoglin",
+            Kind::Zombie => "zombie",
+            Kind::ZombieHorse => "zombie_horse",
+            Kind::ZombieVillager => "zombie_villager",
+            Kind::ZombifiedPiglin => "zombified_piglin",
+            Kind::Player => "player",
+            Kind::FishingBobber => "fishing_bobber",
+        }
+    }
+
+    /// Mobs vanilla counts as enemies, which includes neutral ones like endermen and
+    /// zombified piglins.
+    pub fn is_hostile(self) -> bool {
+        matches!(
+            self,
+            Kind::Blaze
+                | Kind::Bogged
+                | Kind::Breeze
+                | Kind::CaveSpider
+                | Kind::Creaking
+                | Kind::Creeper
+                | Kind::Drowned
+                | Kind::ElderGuardian
+                | Kind::EnderDragon
+                | Kind::Enderman
+                | Kind::Endermite
+                | Kind::Evoker
+                | Kind::Ghast
+                | Kind::Giant
+                | Kind::Guardian
+                | Kind::Hoglin
+                | Kind::Husk
+                | Kind::Illusioner
+                | Kind::MagmaCube
+                | Kind::Phantom
+                | Kind::Piglin
+                | Kind::PiglinBrute
+                | Kind::Pillager
+                | Kind::Ravager
+                | Kind::Shulker
+                | Kind::Silverfish
+                | Kind::Skeleton
+                | Kind::Slime
+                | Kind::Spider
+                | Kind::Stray
+                | Kind::Vex
+                | Kind::Vindicator
+                | Kind::Warden
+                | Kind::Witch
+                | Kind::Wither
+                | Kind::WitherSkeleton
+                | Kind::Zoglin
+                | Kind::Zombie
+                | Kind::ZombieVillager
+                | Kind::ZombifiedPiglin
+        )
+    }
+
+    /// Entities with health, which includes players and armor stands.
+    pub fn is_living(self) -> bool {
+        !matches!(
+            self,
+            Kind::AcaciaBoat
+                | Kind::AcaciaChestBoat
+                | Kind::AreaEffectCloud
+                | Kind::Arrow
+                | Kind::BambooChestRaft
+                | Kind::BambooRaft
+                | Kind::BirchBoat
+                | Kind::BirchChestBoat
+                | Kind::BlockDisplay
+                | Kind::BreezeWindCharge
+                | Kind::CherryBoat
+                | Kind::CherryChestBoat
+                | Kind::ChestMinecart
+                | Kind::CommandBlockMinecart
+                | Kind::DarkOakBoat
+                | Kind::DarkOakChestBoat
+                | Kind::DragonFireball
+                | Kind::Egg
+                | Kind::EnderPearl
+                | Kind::EndCrystal
+                | Kind::EvokerFangs
+                | Kind::ExperienceBottle
+                | Kind::ExperienceOrb
+                | Kind::EyeOfEnder
+                | Kind::FallingBlock
+                | Kind::Fireball
+                | Kind::FireworkRocket
+                | Kind::FurnaceMinecart
+                | Kind::GlowItemFrame
+                | Kind::HopperMinecart
+                | Kind::Interaction
+                | Kind::Item
+                | Kind::ItemDisplay
+                | Kind::ItemFrame
+                | Kind::JungleBoat
+                | Kind::JungleChestBoat
+                | Kind::LeashKnot
+                | Kind::LightningBolt
+                | Kind::LlamaSpit
+                | Kind::MangroveBoat
+                | Kind::MangroveChestBoat
+                | Kind::Marker
+                | Kind::Minecart
+                | Kind::OakBoat
+                | Kind::OakChestBoat
+                | Kind::OminousItemSpawner
+                | Kind::Painting
+                | Kind::PaleOakBoat
+                | Kind::PaleOakChestBoat
+                | Kind::Potion
+                | Kind::ShulkerBullet
+                | Kind::SmallFireball
+                | Kind::Snowball
+                | Kind::SpawnerMinecart
+                | Kind::SpectralArrow
+                | Kind::SpruceBoat
+                | Kind::SpruceChestBoat
+                | Kind::TextDisplay
+                | Kind::Tnt
+                | Kind::TntMinecart
+                | Kind::Trident
+                | Kind::WindCharge
+                | Kind::WitherSkull
+                | Kind::FishingBobber
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i32)]
 pub enum Pose {
     Standing = 0,
@@ -177,3 +617,81 @@ pub enum Pose {
     Shooting,
     Inhaling,
 }
+
+impl Pose {
+    pub const ALL: [Pose; 18] = [
+        Pose::Standing,
+        Pose::FallFlying,
+        Pose::Sleeping,
+        Pose::Swimming,
+        Pose::SpinAttack,
+        Pose::Crouching,
+        Pose::LongJumping,
+        Pose::Dying,
+        Pose::Croaking,
+        Pose::UsingTongue,
+        Pose::Sitting,
+        Pose::Roaring,
+        Pose::Sniffing,
+        Pose::Emerging,
+        Pose::Digging,
+        Pose::Sliding,
+        Pose::Shooting,
+        Pose::Inhaling,
+    ];
+
+    pub fn from_id(id: i32) -> Option<Pose> {
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| Self::ALL.get(id))
+            .copied()
+    }
+}
+
+/// Villager professions in the order of their registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Profession {
+    None = 0,
+    Armorer,
+    Butcher,
+    Cartographer,
+    Cleric,
+    Farmer,
+    Fisherman,
+    Fletcher,
+    Leatherworker,
+    Librarian,
+    Mason,
+    Nitwit,
+    Shepherd,
+    Toolsmith,
+    Weaponsmith,
+}
+
+impl Profession {
+    pub const ALL: [Profession; 15] = [
+        Profession::None,
+        Profession::Armorer,
+        Profession::Butcher,
+        Profession::Cartographer,
+        Profession::Cleric,
+        Profession::Farmer,
+        Profession::Fisherman,
+        Profession::Fletcher,
+        Profession::Leatherworker,
+        Profession::Librarian,
+        Profession::Mason,
+        Profession::Nitwit,
+        Profession::Shepherd,
+        Profession::Toolsmith,
+        Profession::Weaponsmith,
+    ];
+
+    pub fn from_id(id: i32) -> Option<Profession> {
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| Self::ALL.get(id))
+            .copied()
+    }
+}
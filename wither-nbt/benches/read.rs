@@ -0,0 +1,141 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde::Deserialize;
+use wither_nbt::{
+    borrowed::{NbtCompoundRef, NbtTagRef},
+    compound::NbtCompound,
+    deserializer::from_bytes,
+    tag::NbtTag,
+    Nbt,
+};
+
+#[derive(Deserialize)]
+struct Chunk {
+    #[serde(rename = "DataVersion")]
+    _data_version: i32,
+    sections: Vec<Section>,
+}
+
+#[derive(Deserialize)]
+struct Section {
+    block_states: BlockStates,
+}
+
+#[derive(Deserialize)]
+struct BlockStates {
+    #[serde(rename = "palette")]
+    _palette: Vec<BlockState>,
+    #[serde(default)]
+    data: Vec<i64>,
+}
+
+#[derive(Deserialize)]
+struct BlockState {
+    #[serde(rename = "Name")]
+    _name: String,
+    #[serde(rename = "Properties")]
+    _properties: Properties,
+}
+
+#[derive(Deserialize)]
+struct Properties {
+    #[serde(rename = "facing")]
+    _facing: String,
+    #[serde(rename = "waterlogged")]
+    _waterlogged: String,
+}
+
+/// Roughly the shape of a chunk as stored in region files.
+fn chunk() -> Vec<u8> {
+    let sections = (-4..20)
+        .map(|y| {
+            let palette = (0..12)
+                .map(|i| {
+                    let mut properties = NbtCompound::new();
+                    properties.put("facing".to_string(), "north");
+                    properties.put("waterlogged".to_string(), "false");
+                    let mut state = NbtCompound::new();
+                    state.put(
+                        "Name".to_string(),
+                        NbtTag::String(format!("minecraft:block_{i}")),
+                    );
+                    state.put("Properties".to_string(), NbtTag::Compound(properties));
+                    NbtTag::Compound(state)
+                })
+                .collect();
+            let mut block_states = NbtCompound::new();
+            block_states.put("palette".to_string(), NbtTag::List(palette));
+            block_states.put(
+                "data".to_string(),
+                NbtTag::LongArray((0..256).map(|i| i * 0x0123_4567).collect()),
+            );
+            let mut section = NbtCompound::new();
+            section.put("Y".to_string(), NbtTag::Byte(y));
+            section.put("block_states".to_string(), NbtTag::Compound(block_states));
+            section.put("SkyLight".to_string(), &[0xFF; 2048][..]);
+            NbtTag::Compound(section)
+        })
+        .collect();
+
+    let mut chunk = NbtCompound::new();
+    chunk.put("DataVersion".to_string(), NbtTag::Int(4189));
+    chunk.put("sections".to_string(), NbtTag::List(sections));
+    Nbt::new(String::new(), chunk).write().to_vec()
+}
+
+fn read(c: &mut Criterion) {
+    let bytes = chunk();
+    let mut group = c.benchmark_group("chunk");
+
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            let nbt = Nbt::read(&mut black_box(&bytes[..])).unwrap();
+            nbt.get_list("sections")
+                .unwrap()
+                .iter()
+                .filter_map(|section| section.extract_compound()?.get_compound("block_states"))
+                .map(|states| states.get_long_array("data").map_or(0, Vec::len))
+                .sum::<usize>()
+        })
+    });
+
+    group.bench_function("serde", |b| {
+        b.iter(|| {
+            let chunk: Chunk = from_bytes(&mut black_box(&bytes[..])).unwrap();
+            chunk
+                .sections
+                .iter()
+                .map(|section| section.block_states.data.len())
+                .sum::<usize>()
+        })
+    });
+
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            let (_, chunk) = NbtCompoundRef::read(black_box(&bytes)).unwrap();
+            chunk
+                .get_list("sections")
+                .unwrap()
+                .iter()
+                .filter_map(|section| match section {
+                    NbtTagRef::Compound(section) => section.get_compound("block_states"),
+                    _ => None,
+                })
+                .map(|states| states.get_long_array("data").map_or(0, |data| data.len()))
+                .sum::<usize>()
+        })
+    });
+
+    group.bench_function("borrowed_data_version", |b| {
+        b.iter(|| {
+            let (_, chunk) = NbtCompoundRef::read(black_box(&bytes)).unwrap();
+            chunk.get_int("DataVersion")
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read);
+criterion_main!(benches);
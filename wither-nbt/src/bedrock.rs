@@ -0,0 +1,186 @@
+//! The little-endian NBT flavour used by Bedrock Edition files such as `level.dat`.
+//! Tags are the same as in Java Edition, but numbers are little-endian and strings are
+//! plain UTF-8.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::{borrowed::MAX_DEPTH, compound::NbtCompound, tag::NbtTag, *};
+
+impl Nbt {
+    /// Reads little-endian NBT. Bedrock `level.dat` files start with an 8 byte header of
+    /// the storage version and the length, which is skipped when present.
+    pub fn read_bedrock(bytes: &mut impl Buf) -> Result<Nbt, Error> {
+        if let [_, _, _, _, a, b, c, d, ..] = *bytes.chunk() {
+            if u32::from_le_bytes([a, b, c, d]) as usize == bytes.remaining() - 8 {
+                bytes.advance(8);
+            }
+        }
+
+        let tag_type_id = get_u8(bytes)?;
+        if tag_type_id != COMPOUND_ID {
+            return Err(Error::NoRootCompound(tag_type_id));
+        }
+        Ok(Nbt {
+            name: get_string(bytes)?,
+            root_tag: read_compound(bytes, 0)?,
+        })
+    }
+
+    /// Writes little-endian NBT, without the `level.dat` header.
+    pub fn write_bedrock(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(COMPOUND_ID);
+        put_string(&mut bytes, &self.name);
+        write_compound(&mut bytes, &self.root_tag);
+        bytes.freeze()
+    }
+}
+
+fn ensure(bytes: &impl Buf, len: usize) -> Result<(), Error> {
+    if bytes.remaining() < len {
+        return Err(Error::UnexpectedEnd);
+    }
+    Ok(())
+}
+
+fn get_u8(bytes: &mut impl Buf) -> Result<u8, Error> {
+    ensure(bytes, 1)?;
+    Ok(bytes.get_u8())
+}
+
+fn get_len(bytes: &mut impl Buf) -> Result<usize, Error> {
+    ensure(bytes, 4)?;
+    Ok(bytes.get_i32_le().max(0) as usize)
+}
+
+fn get_string(bytes: &mut impl Buf) -> Result<String, Error> {
+    ensure(bytes, 2)?;
+    let len = bytes.get_u16_le() as usize;
+    ensure(bytes, len)?;
+    String::from_utf8(bytes.copy_to_bytes(len).to_vec()).map_err(|_| Error::InvalidUtf8)
+}
+
+fn put_string(bytes: &mut BytesMut, string: &str) {
+    bytes.put_u16_le(string.len() as u16);
+    bytes.put_slice(string.as_bytes());
+}
+
+fn read_compound(bytes: &mut impl Buf, depth: usize) -> Result<NbtCompound, Error> {
+    if depth >= MAX_DEPTH {
+        return Err(Error::TooDeep(MAX_DEPTH));
+    }
+    let mut compound = NbtCompound::new();
+    loop {
+        let tag_id = get_u8(bytes)?;
+        if tag_id == END_ID {
+            return Ok(compound);
+        }
+        let name = get_string(bytes)?;
+        compound.put(name, read_tag(bytes, tag_id, depth + 1)?);
+    }
+}
+
+fn read_tag(bytes: &mut impl Buf, tag_id: u8, depth: usize) -> Result<NbtTag, Error> {
+    Ok(match tag_id {
+        END_ID => NbtTag::End,
+        BYTE_ID => NbtTag::Byte(get_u8(bytes)? as i8),
+        SHORT_ID => {
+            ensure(bytes, 2)?;
+            NbtTag::Short(bytes.get_i16_le())
+        }
+        INT_ID => {
+            ensure(bytes, 4)?;
+            NbtTag::Int(bytes.get_i32_le())
+        }
+        LONG_ID => {
+            ensure(bytes, 8)?;
+            NbtTag::Long(bytes.get_i64_le())
+        }
+        FLOAT_ID => {
+            ensure(bytes, 4)?;
+            NbtTag::Float(bytes.get_f32_le())
+        }
+        DOUBLE_ID => {
+            ensure(bytes, 8)?;
+            NbtTag::Double(bytes.get_f64_le())
+        }
+        BYTE_ARRAY_ID => {
+            let len = get_len(bytes)?;
+            ensure(bytes, len)?;
+            NbtTag::ByteArray(bytes.copy_to_bytes(len))
+        }
+        STRING_ID => NbtTag::String(get_string(bytes)?),
+        LIST_ID => {
+            let tag_type_id = get_u8(bytes)?;
+            let len = get_len(bytes)?;
+            if depth >= MAX_DEPTH {
+                return Err(Error::TooDeep(MAX_DEPTH));
+            }
+            // Elements are at least a byte long, except ends which nothing should list.
+            ensure(bytes, len)?;
+            let mut list = Vec::new();
+            for _ in 0..len {
+                list.push(read_tag(bytes, tag_type_id, depth + 1)?);
+            }
+            NbtTag::List(list)
+        }
+        COMPOUND_ID => NbtTag::Compound(read_compound(bytes, depth)?),
+        INT_ARRAY_ID => {
+            let len = get_len(bytes)?;
+            ensure(bytes, len * 4)?;
+            NbtTag::IntArray((0..len).map(|_| bytes.get_i32_le()).collect())
+        }
+        LONG_ARRAY_ID => {
+            let len = get_len(bytes)?;
+            ensure(bytes, len * 8)?;
+            NbtTag::LongArray((0..len).map(|_| bytes.get_i64_le()).collect())
+        }
+        _ => return Err(Error::UnknownTagId(tag_id)),
+    })
+}
+
+fn write_compound(bytes: &mut BytesMut, compound: &NbtCompound) {
+    for (name, tag) in &compound.child_tags {
+        bytes.put_u8(tag.get_type_id());
+        put_string(bytes, name);
+        write_tag(bytes, tag);
+    }
+    bytes.put_u8(END_ID);
+}
+
+fn write_tag(bytes: &mut BytesMut, tag: &NbtTag) {
+    match tag {
+        NbtTag::End => {}
+        NbtTag::Byte(byte) => bytes.put_i8(*byte),
+        NbtTag::Short(short) => bytes.put_i16_le(*short),
+        NbtTag::Int(int) => bytes.put_i32_le(*int),
+        NbtTag::Long(long) => bytes.put_i64_le(*long),
+        NbtTag::Float(float) => bytes.put_f32_le(*float),
+        NbtTag::Double(double) => bytes.put_f64_le(*double),
+        NbtTag::ByteArray(byte_array) => {
+            bytes.put_i32_le(byte_array.len() as i32);
+            bytes.put_slice(byte_array);
+        }
+        NbtTag::String(string) => put_string(bytes, string),
+        NbtTag::List(list) => {
+            bytes.put_u8(list.first().unwrap_or(&NbtTag::End).get_type_id());
+            bytes.put_i32_le(list.len() as i32);
+            for tag in list {
+                write_tag(bytes, tag);
+            }
+        }
+        NbtTag::Compound(compound) => write_compound(bytes, compound),
+        NbtTag::IntArray(int_array) => {
+            bytes.put_i32_le(int_array.len() as i32);
+            for int in int_array {
+                bytes.put_i32_le(*int);
+            }
+        }
+        NbtTag::LongArray(long_array) => {
+            bytes.put_i32_le(long_array.len() as i32);
+            for long in long_array {
+                bytes.put_i64_le(*long);
+            }
+        }
+    }
+}
@@ -0,0 +1,467 @@
+//! A zero-copy NBT reader that walks tags lazily over a byte slice. The structure is
+//! validated once when the root is read, after that subtrees are only looked at when
+//! asked for, and strings and arrays borrow from the input.
+
+use std::borrow::Cow;
+
+use bytes::Bytes;
+
+use crate::{compound::NbtCompound, tag::NbtTag, *};
+
+/// Vanilla refuses NBT nested deeper than this.
+pub const MAX_DEPTH: usize = 512;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NbtTagRef<'a> {
+    End,
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(&'a [u8]),
+    String(NbtStr<'a>),
+    List(NbtListRef<'a>),
+    Compound(NbtCompoundRef<'a>),
+    IntArray(IntArrayRef<'a>),
+    LongArray(LongArrayRef<'a>),
+}
+
+/// A string in Java's modified UTF-8, which is plain UTF-8 for everything but null and
+/// characters outside the BMP.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NbtStr<'a>(&'a [u8]);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NbtCompoundRef<'a> {
+    /// The tags up to and including the end tag.
+    data: &'a [u8],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NbtListRef<'a> {
+    tag_id: u8,
+    len: usize,
+    /// The elements, without the header.
+    data: &'a [u8],
+}
+
+/// Big-endian ints, read when accessed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntArrayRef<'a>(&'a [u8]);
+
+/// Big-endian longs, read when accessed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LongArrayRef<'a>(&'a [u8]);
+
+impl<'a> NbtCompoundRef<'a> {
+    /// Reads NBT with a named root compound, returning the name and the compound.
+    pub fn read(data: &'a [u8]) -> Result<(NbtStr<'a>, Self), Error> {
+        let (&tag_id, rest) = data.split_first().ok_or(Error::UnexpectedEnd)?;
+        if tag_id != COMPOUND_ID {
+            return Err(Error::NoRootCompound(tag_id));
+        }
+        let name_len = tag_len(rest, STRING_ID, 0)?;
+        let (name, rest) = rest.split_at(name_len);
+        Ok((NbtStr(&name[2..]), Self::read_content(rest)?))
+    }
+
+    /// Reads NBT without the name of the root compound, like network NBT.
+    pub fn read_unnamed(data: &'a [u8]) -> Result<Self, Error> {
+        let (&tag_id, rest) = data.split_first().ok_or(Error::UnexpectedEnd)?;
+        if tag_id != COMPOUND_ID {
+            return Err(Error::NoRootCompound(tag_id));
+        }
+        Self::read_content(rest)
+    }
+
+    fn read_content(data: &'a [u8]) -> Result<Self, Error> {
+        let len = tag_len(data, COMPOUND_ID, 0)?;
+        Ok(Self { data: &data[..len] })
+    }
+
+    pub fn iter(&self) -> CompoundIter<'a> {
+        CompoundIter { data: self.data }
+    }
+
+    pub fn get(&self, name: &str) -> Option<NbtTagRef<'a>> {
+        let name = cesu8::to_java_cesu8(name);
+        self.iter()
+            .find(|(key, _)| key.as_bytes() == &name[..])
+            .map(|(_, tag)| tag)
+    }
+
+    pub fn get_byte(&self, name: &str) -> Option<i8> {
+        match self.get(name)? {
+            NbtTagRef::Byte(byte) => Some(byte),
+            _ => None,
+        }
+    }
+
+    pub fn get_short(&self, name: &str) -> Option<i16> {
+        match self.get(name)? {
+            NbtTagRef::Short(short) => Some(short),
+            _ => None,
+        }
+    }
+
+    pub fn get_int(&self, name: &str) -> Option<i32> {
+        match self.get(name)? {
+            NbtTagRef::Int(int) => Some(int),
+            _ => None,
+        }
+    }
+
+    pub fn get_long(&self, name: &str) -> Option<i64> {
+        match self.get(name)? {
+            NbtTagRef::Long(long) => Some(long),
+            _ => None,
+        }
+    }
+
+    pub fn get_str(&self, name: &str) -> Option<NbtStr<'a>> {
+        match self.get(name)? {
+            NbtTagRef::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn get_list(&self, name: &str) -> Option<NbtListRef<'a>> {
+        match self.get(name)? {
+            NbtTagRef::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn get_compound(&self, name: &str) -> Option<NbtCompoundRef<'a>> {
+        match self.get(name)? {
+            NbtTagRef::Compound(compound) => Some(compound),
+            _ => None,
+        }
+    }
+
+    pub fn get_long_array(&self, name: &str) -> Option<LongArrayRef<'a>> {
+        match self.get(name)? {
+            NbtTagRef::LongArray(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    pub fn to_owned(&self) -> NbtCompound {
+        self.iter()
+            .map(|(name, tag)| (name.to_str().into_owned(), tag.to_owned()))
+            .collect()
+    }
+}
+
+pub struct CompoundIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for CompoundIter<'a> {
+    type Item = (NbtStr<'a>, NbtTagRef<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The data was validated when the root was read.
+        let (&tag_id, rest) = self.data.split_first()?;
+        if tag_id == END_ID {
+            return None;
+        }
+        let name_len = u16::from_be_bytes([rest[0], rest[1]]) as usize;
+        let name = NbtStr(&rest[2..2 + name_len]);
+        let rest = &rest[2 + name_len..];
+        let len = tag_len(rest, tag_id, 0).ok()?;
+        self.data = &rest[len..];
+        Some((name, NbtTagRef::new(tag_id, &rest[..len])))
+    }
+}
+
+impl<'a> NbtListRef<'a> {
+    /// The id of the element type.
+    pub fn tag_id(&self) -> u8 {
+        self.tag_id
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> ListIter<'a> {
+        ListIter {
+            tag_id: self.tag_id,
+            remaining: self.len,
+            data: self.data,
+        }
+    }
+}
+
+pub struct ListIter<'a> {
+    tag_id: u8,
+    remaining: usize,
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for ListIter<'a> {
+    type Item = NbtTagRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let len = tag_len(self.data, self.tag_id, 0).ok()?;
+        let (tag, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(NbtTagRef::new(self.tag_id, tag))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> NbtStr<'a> {
+    /// The raw modified UTF-8 bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Borrows the string when it is valid UTF-8, which almost all are.
+    pub fn to_str(&self) -> Cow<'a, str> {
+        cesu8::from_java_cesu8(self.0)
+            .unwrap_or_else(|_| Cow::Owned(String::from_utf8_lossy(self.0).into_owned()))
+    }
+}
+
+impl PartialEq<str> for NbtStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.to_str() == other
+    }
+}
+
+macro_rules! impl_array_ref {
+    ($name:ident, $type:ty) => {
+        impl<'a> $name<'a> {
+            const SIZE: usize = size_of::<$type>();
+
+            /// The raw big-endian bytes.
+            pub fn as_bytes(&self) -> &'a [u8] {
+                self.0
+            }
+
+            pub fn len(&self) -> usize {
+                self.0.len() / Self::SIZE
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            pub fn get(&self, index: usize) -> Option<$type> {
+                let bytes = self.0.get(index * Self::SIZE..(index + 1) * Self::SIZE)?;
+                Some(<$type>::from_be_bytes(bytes.try_into().unwrap()))
+            }
+
+            pub fn iter(&self) -> impl ExactSizeIterator<Item = $type> + 'a {
+                self.0
+                    .chunks_exact(Self::SIZE)
+                    .map(|bytes| <$type>::from_be_bytes(bytes.try_into().unwrap()))
+            }
+        }
+    };
+}
+
+impl_array_ref!(IntArrayRef, i32);
+impl_array_ref!(LongArrayRef, i64);
+
+impl<'a> NbtTagRef<'a> {
+    /// `data` is exactly the payload of a validated tag.
+    fn new(tag_id: u8, data: &'a [u8]) -> Self {
+        let array = |data: &'a [u8]| &data[4..];
+        match tag_id {
+            BYTE_ID => NbtTagRef::Byte(data[0] as i8),
+            SHORT_ID => NbtTagRef::Short(i16::from_be_bytes(data.try_into().unwrap())),
+            INT_ID => NbtTagRef::Int(i32::from_be_bytes(data.try_into().unwrap())),
+            LONG_ID => NbtTagRef::Long(i64::from_be_bytes(data.try_into().unwrap())),
+            FLOAT_ID => NbtTagRef::Float(f32::from_be_bytes(data.try_into().unwrap())),
+            DOUBLE_ID => NbtTagRef::Double(f64::from_be_bytes(data.try_into().unwrap())),
+            BYTE_ARRAY_ID => NbtTagRef::ByteArray(array(data)),
+            STRING_ID => NbtTagRef::String(NbtStr(&data[2..])),
+            LIST_ID => NbtTagRef::List(NbtListRef {
+                tag_id: data[0],
+                len: i32::from_be_bytes(data[1..5].try_into().unwrap()).max(0) as usize,
+                data: &data[5..],
+            }),
+            COMPOUND_ID => NbtTagRef::Compound(NbtCompoundRef { data }),
+            INT_ARRAY_ID => NbtTagRef::IntArray(IntArrayRef(array(data))),
+            LONG_ARRAY_ID => NbtTagRef::LongArray(LongArrayRef(array(data))),
+            _ => NbtTagRef::End,
+        }
+    }
+
+    pub fn to_owned(&self) -> NbtTag {
+        match *self {
+            NbtTagRef::End => NbtTag::End,
+            NbtTagRef::Byte(byte) => NbtTag::Byte(byte),
+            NbtTagRef::Short(short) => NbtTag::Short(short),
+            NbtTagRef::Int(int) => NbtTag::Int(int),
+            NbtTagRef::Long(long) => NbtTag::Long(long),
+            NbtTagRef::Float(float) => NbtTag::Float(float),
+            NbtTagRef::Double(double) => NbtTag::Double(double),
+            NbtTagRef::ByteArray(bytes) => NbtTag::ByteArray(Bytes::copy_from_slice(bytes)),
+            NbtTagRef::String(string) => NbtTag::String(string.to_str().into_owned()),
+            NbtTagRef::List(list) => NbtTag::List(list.iter().map(|tag| tag.to_owned()).collect()),
+            NbtTagRef::Compound(compound) => NbtTag::Compound(compound.to_owned()),
+            NbtTagRef::IntArray(array) => NbtTag::IntArray(array.iter().collect()),
+            NbtTagRef::LongArray(array) => NbtTag::LongArray(array.iter().collect()),
+        }
+    }
+}
+
+/// Length of the payload of a tag at the start of `data`, checking that it is all there.
+fn tag_len(data: &[u8], tag_id: u8, depth: usize) -> Result<usize, Error> {
+    let fixed = |size: usize| {
+        if data.len() < size {
+            return Err(Error::UnexpectedEnd);
+        }
+        Ok(size)
+    };
+    let prefixed = |element_size: usize| {
+        let len = fixed(4)?;
+        let count = i32::from_be_bytes(data[..4].try_into().unwrap()).max(0) as usize;
+        fixed(len + count * element_size)
+    };
+
+    match tag_id {
+        END_ID => Ok(0),
+        BYTE_ID => fixed(1),
+        SHORT_ID => fixed(2),
+        INT_ID | FLOAT_ID => fixed(4),
+        LONG_ID | DOUBLE_ID => fixed(8),
+        BYTE_ARRAY_ID => prefixed(1),
+        INT_ARRAY_ID => prefixed(4),
+        LONG_ARRAY_ID => prefixed(8),
+        STRING_ID => {
+            fixed(2)?;
+            fixed(2 + u16::from_be_bytes([data[0], data[1]]) as usize)
+        }
+        LIST_ID => {
+            fixed(5)?;
+            let element_id = data[0];
+            let count = i32::from_be_bytes(data[1..5].try_into().unwrap()).max(0) as usize;
+            match element_id {
+                BYTE_ID => fixed(5 + count),
+                SHORT_ID => fixed(5 + count * 2),
+                INT_ID | FLOAT_ID => fixed(5 + count * 4),
+                LONG_ID | DOUBLE_ID => fixed(5 + count * 8),
+                _ => {
+                    if depth >= MAX_DEPTH {
+                        return Err(Error::TooDeep(MAX_DEPTH));
+                    }
+                    // Elements are at least a byte long, except ends which nothing
+                    // should list.
+                    fixed(5 + count)?;
+                    let mut len = 5;
+                    for _ in 0..count {
+                        len += tag_len(&data[len..], element_id, depth + 1)?;
+                    }
+                    Ok(len)
+                }
+            }
+        }
+        COMPOUND_ID => {
+            if depth >= MAX_DEPTH {
+                return Err(Error::TooDeep(MAX_DEPTH));
+            }
+            let mut len = 0;
+            loop {
+                let tag_id = *data.get(len).ok_or(Error::UnexpectedEnd)?;
+                len += 1;
+                if tag_id == END_ID {
+                    return Ok(len);
+                }
+                len += tag_len(&data[len..], STRING_ID, depth)?;
+                len += tag_len(&data[len..], tag_id, depth + 1)?;
+            }
+        }
+        _ => Err(Error::UnknownTagId(tag_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compound::NbtCompound, tag::NbtTag, Error, Nbt};
+
+    use super::{NbtCompoundRef, NbtTagRef};
+
+    #[test]
+    fn walks_lazily() {
+        let compound: NbtCompound = r#"{
+            DataVersion: 4189, Status: "minecraft:full",
+            Heightmaps: {WORLD_SURFACE: [L; 1, -2, 3]},
+            sections: [{Y: -4b, biomes: {palette: ["minecraft:plains"]}}, {Y: -3b}],
+            "unié": [B; 1, 2], empty: []
+        }"#
+        .parse()
+        .unwrap();
+        let bytes = Nbt::new("chunk".to_string(), compound.clone()).write();
+
+        let (name, root) = NbtCompoundRef::read(&bytes).unwrap();
+        assert_eq!(name.to_str(), "chunk");
+        assert_eq!(root.get_int("DataVersion"), Some(4189));
+        assert!(root.get_str("Status").unwrap() == *"minecraft:full");
+        let heightmap = root
+            .get_compound("Heightmaps")
+            .unwrap()
+            .get_long_array("WORLD_SURFACE")
+            .unwrap();
+        assert_eq!(heightmap.iter().collect::<Vec<_>>(), vec![1, -2, 3]);
+        assert_eq!(heightmap.get(1), Some(-2));
+        let sections = root.get_list("sections").unwrap();
+        assert_eq!(sections.len(), 2);
+        let ys: Vec<_> = sections
+            .iter()
+            .filter_map(|section| match section {
+                NbtTagRef::Compound(section) => section.get_byte("Y"),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ys, vec![-4, -3]);
+        assert!(matches!(
+            root.get("uni\u{e9}"),
+            Some(NbtTagRef::ByteArray([1, 2]))
+        ));
+        assert_eq!(root.to_owned(), compound);
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        let mut compound = NbtCompound::new();
+        compound.put("long".to_string(), NbtTag::Long(1));
+        let bytes = Nbt::new(String::new(), compound).write();
+        for len in 0..bytes.len() {
+            assert!(NbtCompoundRef::read(&bytes[..len]).is_err());
+        }
+
+        let mut deep = vec![10, 0, 0];
+        for _ in 0..1000 {
+            deep.extend_from_slice(&[10, 0, 0]);
+        }
+        assert!(matches!(
+            NbtCompoundRef::read(&deep),
+            Err(Error::TooDeep(_))
+        ));
+
+        // A list of `i32::MAX` end tags.
+        let ends = [10, 0, 0, 9, 0, 1, b'a', 0, 0x7f, 0xff, 0xff, 0xff, 0];
+        assert!(matches!(
+            NbtCompoundRef::read(&ends),
+            Err(Error::UnexpectedEnd)
+        ));
+    }
+}
@@ -79,6 +79,13 @@ impl NbtCompound {
         None
     }
 
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut NbtTag> {
+        self.child_tags
+            .iter_mut()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+
     pub fn get_short(&self, name: &str) -> Option<i16> {
         self.get(name).and_then(|tag| tag.extract_short())
     }
@@ -57,6 +57,20 @@ where
     T::deserialize(&mut deserializer)
 }
 
+/// Deserializes a value from an already parsed tag.
+pub fn from_tag<T>(tag: &NbtTag) -> Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut data = tag.serialize_data();
+    let mut deserializer = Deserializer {
+        input: &mut data,
+        tag_to_deserialize: Some(tag.get_type_id()),
+        is_named: false,
+    };
+    T::deserialize(&mut deserializer)
+}
+
 impl<'de, T: Buf> de::Deserializer<'de> for &mut Deserializer<'de, T> {
     type Error = Error;
 
@@ -126,7 +140,7 @@ impl<'de, T: Buf> de::Deserializer<'de> for &mut Deserializer<'de, T> {
 
             if self.is_named {
                 // Consume struct name
-                NbtTag::deserialize(self.input)?;
+                get_nbt_string(self.input).map_err(|_| Error::Cesu8DecodingError)?;
             }
         }
 
@@ -0,0 +1,219 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use flate2::{
+    read::{GzDecoder, ZlibDecoder},
+    write::{GzEncoder, ZlibEncoder},
+};
+
+use crate::{Error, Nbt};
+
+/// Compression of an NBT file. Java Edition stores `.dat` files with gzip.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    #[default]
+    Gzip,
+    Zlib,
+}
+
+impl Compression {
+    /// Guesses the compression from the first bytes of a file.
+    pub fn detect(bytes: &[u8]) -> Self {
+        match bytes {
+            [0x1F, 0x8B, ..] => Self::Gzip,
+            // Uncompressed NBT starts with the compound id 10, which is not the deflate method 8.
+            [cmf, flg, ..]
+                if cmf & 0x0F == 8 && u16::from_be_bytes([*cmf, *flg]).is_multiple_of(31) =>
+            {
+                Self::Zlib
+            }
+            _ => Self::None,
+        }
+    }
+
+    pub fn decompress(self, bytes: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut output = Vec::new();
+        match self {
+            Self::None => output.extend_from_slice(bytes),
+            Self::Gzip => {
+                GzDecoder::new(bytes).read_to_end(&mut output)?;
+            }
+            Self::Zlib => {
+                ZlibDecoder::new(bytes).read_to_end(&mut output)?;
+            }
+        }
+        Ok(output)
+    }
+
+    pub fn compress(self, bytes: &[u8], mut writer: impl Write) -> Result<(), Error> {
+        let level = flate2::Compression::default();
+        match self {
+            Self::None => writer.write_all(bytes)?,
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(writer, level);
+                encoder.write_all(bytes)?;
+                encoder.finish()?;
+            }
+            Self::Zlib => {
+                let mut encoder = ZlibEncoder::new(writer, level);
+                encoder.write_all(bytes)?;
+                encoder.finish()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Which edition's encoding an NBT file uses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NbtFlavor {
+    /// Big-endian with Java's modified UTF-8 strings.
+    #[default]
+    Java,
+    /// Little-endian with UTF-8 strings.
+    Bedrock,
+}
+
+impl Nbt {
+    /// Reads a Java Edition NBT file, detecting its compression.
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Nbt, Error> {
+        Self::read_file_with(path, NbtFlavor::Java)
+    }
+
+    pub fn read_file_with(path: impl AsRef<Path>, flavor: NbtFlavor) -> Result<Nbt, Error> {
+        Self::read_from_reader_with(BufReader::new(File::open(path)?), flavor)
+    }
+
+    /// Reads Java Edition NBT from a reader, detecting its compression.
+    pub fn read_from_reader(reader: impl Read) -> Result<Nbt, Error> {
+        Self::read_from_reader_with(reader, NbtFlavor::Java)
+    }
+
+    pub fn read_from_reader_with(mut reader: impl Read, flavor: NbtFlavor) -> Result<Nbt, Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let bytes = Compression::detect(&bytes).decompress(&bytes)?;
+        if bytes.is_empty() {
+            return Err(Error::UnexpectedEnd);
+        }
+        match flavor {
+            NbtFlavor::Java => Self::read(&mut &bytes[..]),
+            NbtFlavor::Bedrock => Self::read_bedrock(&mut &bytes[..]),
+        }
+    }
+
+    /// Writes a Java Edition NBT file.
+    pub fn write_file(
+        &self,
+        path: impl AsRef<Path>,
+        compression: Compression,
+    ) -> Result<(), Error> {
+        self.write_file_with(path, compression, NbtFlavor::Java)
+    }
+
+    pub fn write_file_with(
+        &self,
+        path: impl AsRef<Path>,
+        compression: Compression,
+        flavor: NbtFlavor,
+    ) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_compressed_with(&mut writer, compression, flavor)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes Java Edition NBT to a writer.
+    pub fn write_compressed(
+        &self,
+        writer: impl Write,
+        compression: Compression,
+    ) -> Result<(), Error> {
+        self.write_compressed_with(writer, compression, NbtFlavor::Java)
+    }
+
+    pub fn write_compressed_with(
+        &self,
+        writer: impl Write,
+        compression: Compression,
+        flavor: NbtFlavor,
+    ) -> Result<(), Error> {
+        let bytes = match flavor {
+            NbtFlavor::Java => self.write(),
+            NbtFlavor::Bedrock => self.write_bedrock(),
+        };
+        compression.compress(&bytes, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{compound::NbtCompound, tag::NbtTag, Error, Nbt};
+
+    use super::{Compression, NbtFlavor};
+
+    fn sample() -> Nbt {
+        let mut list = NbtCompound::new();
+        list.put("name".to_string(), "wither");
+        let mut root = NbtCompound::new();
+        root.put("int".to_string(), NbtTag::Int(-123456));
+        root.put("long".to_string(), NbtTag::Long(1 << 40));
+        root.put("double".to_string(), NbtTag::Double(0.25));
+        root.put("bytes".to_string(), &[1, 2, 3][..]);
+        root.put(
+            "list".to_string(),
+            NbtTag::List(vec![NbtTag::Compound(list)]),
+        );
+        root.put("longs".to_string(), NbtTag::LongArray(vec![-1, 2]));
+        Nbt::new("root".to_string(), root)
+    }
+
+    #[test]
+    fn detects_compression() {
+        let nbt = sample();
+        for flavor in [NbtFlavor::Java, NbtFlavor::Bedrock] {
+            for compression in [Compression::None, Compression::Gzip, Compression::Zlib] {
+                let mut bytes = Vec::new();
+                nbt.write_compressed_with(&mut bytes, compression, flavor)
+                    .unwrap();
+                assert_eq!(Compression::detect(&bytes), compression);
+                assert_eq!(Nbt::read_from_reader_with(&bytes[..], flavor).unwrap(), nbt);
+            }
+        }
+    }
+
+    #[test]
+    fn bedrock_level_dat_header() {
+        let nbt = sample();
+        let body = nbt.write_bedrock();
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&10i32.to_le_bytes());
+        bytes.extend_from_slice(&(body.len() as i32).to_le_bytes());
+        bytes.extend_from_slice(&body);
+        assert_eq!(Nbt::read_bedrock(&mut &bytes[..]).unwrap(), nbt);
+        assert_eq!(&body[..3], &[10, 4, 0]);
+    }
+
+    #[test]
+    fn bedrock_rejects_malformed() {
+        let mut deep = vec![10, 0, 0];
+        for _ in 0..1000 {
+            deep.extend_from_slice(&[10, 0, 0]);
+        }
+        assert!(matches!(
+            Nbt::read_bedrock(&mut &deep[..]),
+            Err(Error::TooDeep(_))
+        ));
+
+        // A list of `i32::MAX` end tags.
+        let ends = [10, 0, 0, 9, 1, 0, b'a', 0, 0xff, 0xff, 0xff, 0x7f, 0];
+        assert!(matches!(
+            Nbt::read_bedrock(&mut &ends[..]),
+            Err(Error::UnexpectedEnd)
+        ));
+    }
+}
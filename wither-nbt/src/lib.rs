@@ -12,11 +12,19 @@ use serde::{Deserialize, Deserializer};
 use tag::NbtTag;
 use thiserror::Error;
 
+mod bedrock;
+pub mod borrowed;
 pub mod compound;
 pub mod deserializer;
+mod file;
+mod path;
 pub mod serializer;
+mod snbt;
 pub mod tag;
 
+pub use file::*;
+pub use path::NbtPath;
+
 // This NBT crate is inspired from CrabNBT
 
 pub const END_ID: u8 = 0;
@@ -45,6 +53,22 @@ pub enum Error {
     SerdeError(String),
     #[error("NBT doesn't support this type {0}")]
     UnsupportedType(String),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("The NBT data ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("NBT is nested deeper than {0} levels")]
+    TooDeep(usize),
+    #[error("Invalid UTF-8 string")]
+    InvalidUtf8,
+    #[error("Invalid SNBT at {0}: {1}")]
+    InvalidSnbt(usize, &'static str),
+    #[error("Invalid NBT path at {0}: {1}")]
+    InvalidNbtPath(usize, &'static str),
+    #[error("Found no elements matching {0}")]
+    NoPathMatch(String),
+    #[error("Found more than one element matching {0}")]
+    AmbiguousPath(String),
 }
 
 impl ser::Error for Error {
@@ -208,7 +232,10 @@ mod test {
     use crate::BytesArray;
     use crate::IntArray;
     use crate::LongArray;
-    use crate::{deserializer::from_bytes_unnamed, serializer::to_bytes_unnamed};
+    use crate::{
+        deserializer::{from_bytes, from_bytes_unnamed},
+        serializer::{to_bytes, to_bytes_unnamed},
+    };
 
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
     struct Test {
@@ -236,6 +263,22 @@ mod test {
         assert_eq!(test, recreated_struct);
     }
 
+    #[test]
+    fn test_simple_ser_de_named() {
+        let test = Test {
+            byte: -2,
+            short: 7,
+            int: 1 << 20,
+            long: -34,
+            float: 0.5,
+            string: "Hello named".to_string(),
+        };
+        let mut bytes = to_bytes(&test, "root".to_string()).unwrap();
+        let recreated_struct: Test = from_bytes(&mut bytes).unwrap();
+
+        assert_eq!(test, recreated_struct);
+    }
+
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
     struct TestArray {
         #[serde(with = "BytesArray")]
@@ -0,0 +1,672 @@
+//! NBT paths as used by the `/data` command, like
+//! `Inventory[{Slot:0b}].components."minecraft:custom_name"`.
+//!
+//! Indexing into byte, int and long arrays is not supported, only lists.
+
+use std::{
+    fmt::{self, Display, Formatter, Write},
+    mem,
+    str::FromStr,
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    compound::NbtCompound,
+    deserializer::from_tag,
+    snbt::{read_compound_prefix, read_quoted_prefix, write_quoted},
+    tag::NbtTag,
+    Error,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NbtPath {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    /// `{filter}` at the start of the path.
+    RootFilter(NbtCompound),
+    /// `key`
+    Key(String),
+    /// `key{filter}`
+    KeyFilter(String, NbtCompound),
+    /// `[index]`, negative indices count from the end.
+    Index(i32),
+    /// `[]`
+    All,
+    /// `[{filter}]`
+    ListFilter(NbtCompound),
+}
+
+impl NbtPath {
+    fn no_match(&self) -> Error {
+        Error::NoPathMatch(self.to_string())
+    }
+
+    fn get<'a>(&self, root: &'a NbtTag) -> Result<Vec<&'a NbtTag>, Error> {
+        let mut tags = vec![root];
+        for node in &self.nodes {
+            tags = tags.into_iter().flat_map(|tag| node.get(tag)).collect();
+        }
+        if tags.is_empty() {
+            return Err(self.no_match());
+        }
+        Ok(tags)
+    }
+
+    fn get_as<T: DeserializeOwned>(&self, root: &NbtTag) -> Result<T, Error> {
+        match self.get(root)?[..] {
+            [tag] => from_tag(tag),
+            _ => Err(Error::AmbiguousPath(self.to_string())),
+        }
+    }
+
+    /// The tags that the last node of the path is applied to.
+    fn parents<'a>(
+        &self,
+        root: &'a mut NbtTag,
+        create: bool,
+    ) -> Result<(Vec<&'a mut NbtTag>, &Node), Error> {
+        let Some((last, nodes)) = self.nodes.split_last() else {
+            return Err(self.no_match());
+        };
+        Ok((get_mut(nodes, vec![root], Some(last), create), last))
+    }
+
+    fn set(&self, root: &mut NbtTag, value: NbtTag) -> Result<usize, Error> {
+        let (parents, last) = self.parents(root, true)?;
+        let count = parents
+            .into_iter()
+            .map(|parent| last.set(parent, &value))
+            .sum();
+        match count {
+            0 => Err(self.no_match()),
+            count => Ok(count),
+        }
+    }
+
+    fn remove(&self, root: &mut NbtTag) -> Result<usize, Error> {
+        let (parents, last) = self.parents(root, false)?;
+        let count = parents.into_iter().map(|parent| last.remove(parent)).sum();
+        match count {
+            0 => Err(self.no_match()),
+            count => Ok(count),
+        }
+    }
+
+    fn merge(&self, root: &mut NbtTag, source: &NbtCompound) -> Result<usize, Error> {
+        let mut count = 0;
+        for tag in get_mut(&self.nodes, vec![root], None, true) {
+            if let NbtTag::Compound(target) = tag {
+                merge(target, source);
+                count += 1;
+            }
+        }
+        match count {
+            0 => Err(self.no_match()),
+            count => Ok(count),
+        }
+    }
+}
+
+/// Follows `nodes`, creating missing compounds and lists along the way if `create` is
+/// set. `last` is the node that will be applied to the result, `None` if missing results
+/// should be compounds.
+fn get_mut<'a>(
+    nodes: &[Node],
+    mut tags: Vec<&'a mut NbtTag>,
+    last: Option<&Node>,
+    create: bool,
+) -> Vec<&'a mut NbtTag> {
+    for (index, node) in nodes.iter().enumerate() {
+        let next = nodes.get(index + 1).or(last);
+        let empty =
+            create.then(|| next.map_or(NbtTag::Compound(NbtCompound::new()), Node::empty_parent));
+        tags = tags
+            .into_iter()
+            .flat_map(|tag| node.get_mut(tag, empty.as_ref()))
+            .collect();
+    }
+    tags
+}
+
+impl Node {
+    fn get<'a>(&self, tag: &'a NbtTag) -> Vec<&'a NbtTag> {
+        match (self, tag) {
+            (Node::RootFilter(filter), tag) => {
+                matches(filter, tag).then_some(tag).into_iter().collect()
+            }
+            (Node::Key(_) | Node::KeyFilter(..), NbtTag::Compound(compound)) => {
+                self.get_child(compound).into_iter().collect()
+            }
+            (Node::Index(index), NbtTag::List(list)) => resolve_index(*index, list.len())
+                .map(|index| &list[index])
+                .into_iter()
+                .collect(),
+            (Node::All, NbtTag::List(list)) => list.iter().collect(),
+            (Node::ListFilter(filter), NbtTag::List(list)) => {
+                list.iter().filter(|tag| matches(filter, tag)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_child<'a>(&self, compound: &'a NbtCompound) -> Option<&'a NbtTag> {
+        match self {
+            Node::Key(key) => compound.get(key),
+            Node::KeyFilter(key, filter) => compound.get(key).filter(|tag| matches(filter, tag)),
+            _ => None,
+        }
+    }
+
+    /// What a missing tag is created as when this node is applied to it.
+    fn empty_parent(&self) -> NbtTag {
+        match self {
+            Node::RootFilter(_) | Node::Key(_) | Node::KeyFilter(..) => {
+                NbtTag::Compound(NbtCompound::new())
+            }
+            Node::Index(_) | Node::All | Node::ListFilter(_) => NbtTag::List(Vec::new()),
+        }
+    }
+
+    /// Like `get`, but creates missing tags as `empty` if given.
+    fn get_mut<'a>(&self, tag: &'a mut NbtTag, empty: Option<&NbtTag>) -> Vec<&'a mut NbtTag> {
+        match (self, tag) {
+            (Node::RootFilter(filter), tag) => {
+                if matches(filter, tag) {
+                    vec![tag]
+                } else {
+                    Vec::new()
+                }
+            }
+            (Node::Key(key), NbtTag::Compound(compound)) => {
+                if let Some(empty) = empty.filter(|_| compound.get(key).is_none()) {
+                    compound.child_tags.push((key.clone(), empty.clone()));
+                }
+                compound.get_mut(key).into_iter().collect()
+            }
+            (Node::KeyFilter(key, filter), NbtTag::Compound(compound)) => {
+                if empty.is_some() && compound.get(key).is_none() {
+                    compound
+                        .child_tags
+                        .push((key.clone(), NbtTag::Compound(filter.clone())));
+                }
+                compound
+                    .get_mut(key)
+                    .filter(|tag| matches(filter, tag))
+                    .into_iter()
+                    .collect()
+            }
+            (Node::Index(index), NbtTag::List(list)) => match resolve_index(*index, list.len()) {
+                Some(index) => vec![&mut list[index]],
+                None => Vec::new(),
+            },
+            (Node::All, NbtTag::List(list)) => {
+                if let Some(empty) = empty.filter(|_| list.is_empty()) {
+                    list.push(empty.clone());
+                }
+                list.iter_mut().collect()
+            }
+            (Node::ListFilter(filter), NbtTag::List(list)) => {
+                let compounds = list
+                    .first()
+                    .is_none_or(|first| matches!(first, NbtTag::Compound(_)));
+                if empty.is_some() && compounds && !list.iter().any(|tag| matches(filter, tag)) {
+                    list.push(NbtTag::Compound(filter.clone()));
+                }
+                list.iter_mut().filter(|tag| matches(filter, tag)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn set(&self, parent: &mut NbtTag, value: &NbtTag) -> usize {
+        match (self, parent) {
+            (Node::Key(key), NbtTag::Compound(compound)) => {
+                match compound.get_mut(key) {
+                    Some(tag) => *tag = value.clone(),
+                    None => compound.child_tags.push((key.clone(), value.clone())),
+                }
+                1
+            }
+            (Node::KeyFilter(key, filter), NbtTag::Compound(compound)) => {
+                match compound.get_mut(key).filter(|tag| matches(filter, tag)) {
+                    Some(tag) => {
+                        *tag = value.clone();
+                        1
+                    }
+                    None => 0,
+                }
+            }
+            (Node::Index(index), NbtTag::List(list)) => {
+                match resolve_index(*index, list.len()).filter(|_| accepts(list, value)) {
+                    Some(index) => {
+                        list[index] = value.clone();
+                        1
+                    }
+                    None => 0,
+                }
+            }
+            (Node::All, NbtTag::List(list)) => {
+                if list.is_empty() {
+                    list.push(value.clone());
+                }
+                for tag in list.iter_mut() {
+                    *tag = value.clone();
+                }
+                list.len()
+            }
+            (Node::ListFilter(filter), NbtTag::List(list)) if accepts(list, value) => {
+                let mut count = 0;
+                for tag in list.iter_mut().filter(|tag| matches(filter, tag)) {
+                    *tag = value.clone();
+                    count += 1;
+                }
+                count
+            }
+            _ => 0,
+        }
+    }
+
+    fn remove(&self, parent: &mut NbtTag) -> usize {
+        match (self, parent) {
+            (Node::Key(_) | Node::KeyFilter(..), NbtTag::Compound(compound)) => {
+                let len = compound.child_tags.len();
+                compound.child_tags.retain(|(key, tag)| match self {
+                    Node::KeyFilter(name, filter) => key != name || !matches(filter, tag),
+                    Node::Key(name) => key != name,
+                    _ => true,
+                });
+                len - compound.child_tags.len()
+            }
+            (Node::Index(index), NbtTag::List(list)) => match resolve_index(*index, list.len()) {
+                Some(index) => {
+                    list.remove(index);
+                    1
+                }
+                None => 0,
+            },
+            (Node::All, NbtTag::List(list)) => mem::take(list).len(),
+            (Node::ListFilter(filter), NbtTag::List(list)) => {
+                let len = list.len();
+                list.retain(|tag| !matches(filter, tag));
+                len - list.len()
+            }
+            _ => 0,
+        }
+    }
+}
+
+fn resolve_index(index: i32, len: usize) -> Option<usize> {
+    let index = if index < 0 {
+        len.checked_sub(index.unsigned_abs() as usize)?
+    } else {
+        index as usize
+    };
+    (index < len).then_some(index)
+}
+
+/// Lists only hold one type of tag.
+fn accepts(list: &[NbtTag], value: &NbtTag) -> bool {
+    list.first()
+        .is_none_or(|first| first.get_type_id() == value.get_type_id())
+}
+
+fn matches(filter: &NbtCompound, tag: &NbtTag) -> bool {
+    tag.extract_compound()
+        .is_some_and(|compound| compound_matches(filter, compound))
+}
+
+/// Every tag of the filter has to be in the compound. Nested compounds match the same
+/// way, and lists match when every filter element matches one of their elements.
+fn compound_matches(filter: &NbtCompound, compound: &NbtCompound) -> bool {
+    filter.child_tags.iter().all(|(key, expected)| {
+        compound
+            .get(key)
+            .is_some_and(|actual| tag_matches(expected, actual))
+    })
+}
+
+fn tag_matches(expected: &NbtTag, actual: &NbtTag) -> bool {
+    match (expected, actual) {
+        (NbtTag::Compound(expected), NbtTag::Compound(actual)) => {
+            compound_matches(expected, actual)
+        }
+        (NbtTag::List(expected), NbtTag::List(actual)) if !expected.is_empty() => expected
+            .iter()
+            .all(|expected| actual.iter().any(|actual| tag_matches(expected, actual))),
+        _ => expected == actual,
+    }
+}
+
+/// Nested compounds are merged, everything else is overwritten.
+fn merge(target: &mut NbtCompound, source: &NbtCompound) {
+    for (key, value) in &source.child_tags {
+        match (target.get_mut(key), value) {
+            (Some(NbtTag::Compound(target)), NbtTag::Compound(source)) => merge(target, source),
+            (Some(tag), value) => *tag = value.clone(),
+            (None, value) => target.child_tags.push((key.clone(), value.clone())),
+        }
+    }
+}
+
+impl NbtTag {
+    /// All tags matching `path`, failing if there are none.
+    pub fn get_path(&self, path: &NbtPath) -> Result<Vec<&NbtTag>, Error> {
+        path.get(self)
+    }
+
+    /// Deserializes the only tag matching `path`.
+    pub fn get_path_as<T: DeserializeOwned>(&self, path: &NbtPath) -> Result<T, Error> {
+        path.get_as(self)
+    }
+
+    /// Sets all tags matching `path` to `value`, creating missing parents, and returns how
+    /// many were set.
+    pub fn set_path(&mut self, path: &NbtPath, value: impl Into<NbtTag>) -> Result<usize, Error> {
+        path.set(self, value.into())
+    }
+
+    /// Removes all tags matching `path` and returns how many were removed.
+    pub fn remove_path(&mut self, path: &NbtPath) -> Result<usize, Error> {
+        path.remove(self)
+    }
+
+    /// Merges `source` into all compounds matching `path`, creating them if missing, and
+    /// returns how many were merged into.
+    pub fn merge_path(&mut self, path: &NbtPath, source: &NbtCompound) -> Result<usize, Error> {
+        path.merge(self, source)
+    }
+}
+
+impl NbtCompound {
+    /// All tags matching `path`, failing if there are none. Paths selecting the compound
+    /// itself, like `{}`, match nothing.
+    pub fn get_path(&self, path: &NbtPath) -> Result<Vec<&NbtTag>, Error> {
+        let mut nodes = &path.nodes[..];
+        if let [Node::RootFilter(filter), rest @ ..] = nodes {
+            if !compound_matches(filter, self) {
+                return Err(path.no_match());
+            }
+            nodes = rest;
+        }
+        let Some((first, nodes)) = nodes.split_first() else {
+            return Err(path.no_match());
+        };
+        let Some(child) = first.get_child(self) else {
+            return Err(path.no_match());
+        };
+        NbtPath {
+            nodes: nodes.to_vec(),
+        }
+        .get(child)
+        .map_err(|_| path.no_match())
+    }
+
+    /// Deserializes the only tag matching `path`.
+    pub fn get_path_as<T: DeserializeOwned>(&self, path: &NbtPath) -> Result<T, Error> {
+        match self.get_path(path)?[..] {
+            [tag] => from_tag(tag),
+            _ => Err(Error::AmbiguousPath(path.to_string())),
+        }
+    }
+
+    /// Sets all tags matching `path` to `value`, creating missing parents, and returns how
+    /// many were set.
+    pub fn set_path(&mut self, path: &NbtPath, value: impl Into<NbtTag>) -> Result<usize, Error> {
+        self.with_root(|root| path.set(root, value.into()))
+    }
+
+    /// Removes all tags matching `path` and returns how many were removed.
+    pub fn remove_path(&mut self, path: &NbtPath) -> Result<usize, Error> {
+        self.with_root(|root| path.remove(root))
+    }
+
+    /// Merges `source` into all compounds matching `path`, creating them if missing, and
+    /// returns how many were merged into.
+    pub fn merge_path(&mut self, path: &NbtPath, source: &NbtCompound) -> Result<usize, Error> {
+        self.with_root(|root| path.merge(root, source))
+    }
+
+    fn with_root<T>(&mut self, f: impl FnOnce(&mut NbtTag) -> T) -> T {
+        let mut root = NbtTag::Compound(mem::take(self));
+        let result = f(&mut root);
+        if let NbtTag::Compound(compound) = root {
+            *self = compound;
+        }
+        result
+    }
+}
+
+impl FromStr for NbtPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut nodes = Vec::new();
+        let mut position = 0;
+        let snbt_error = |offset: usize| {
+            move |error: Error| match error {
+                Error::InvalidSnbt(position, message) => {
+                    Error::InvalidNbtPath(offset + position, message)
+                }
+                error => error,
+            }
+        };
+
+        loop {
+            let rest = &path[position..];
+            let node = match rest.as_bytes().first() {
+                None => return Err(Error::InvalidNbtPath(position, "Expected a node")),
+                Some(b'{') if nodes.is_empty() => {
+                    let (filter, len) = read_compound_prefix(rest).map_err(snbt_error(position))?;
+                    position += len;
+                    Node::RootFilter(filter)
+                }
+                Some(b'[') => {
+                    position += 1;
+                    let rest = &path[position..];
+                    let node = match rest.as_bytes().first() {
+                        Some(b'{') => {
+                            let (filter, len) =
+                                read_compound_prefix(rest).map_err(snbt_error(position))?;
+                            position += len;
+                            Node::ListFilter(filter)
+                        }
+                        Some(b']') => Node::All,
+                        _ => {
+                            let len = rest.find(']').unwrap_or(rest.len());
+                            let index = rest[..len]
+                                .parse()
+                                .map_err(|_| Error::InvalidNbtPath(position, "Invalid index"))?;
+                            position += len;
+                            Node::Index(index)
+                        }
+                    };
+                    if path.as_bytes().get(position) != Some(&b']') {
+                        return Err(Error::InvalidNbtPath(position, "Expected ']'"));
+                    }
+                    position += 1;
+                    node
+                }
+                Some(b'"' | b'\'') => {
+                    let (key, len) = read_quoted_prefix(rest).map_err(snbt_error(position))?;
+                    position += len;
+                    key_node(path, &mut position, key)?
+                }
+                Some(_) => {
+                    let len = rest
+                        .find(|char| !is_unquoted_key_char(char))
+                        .unwrap_or(rest.len());
+                    if len == 0 {
+                        return Err(Error::InvalidNbtPath(position, "Expected a key"));
+                    }
+                    position += len;
+                    key_node(path, &mut position, rest[..len].to_string())?
+                }
+            };
+            nodes.push(node);
+
+            match path.as_bytes().get(position) {
+                None => break,
+                Some(b'.') => position += 1,
+                Some(b'[') => {}
+                Some(_) => return Err(Error::InvalidNbtPath(position, "Expected '.' or '['")),
+            }
+        }
+        Ok(NbtPath { nodes })
+    }
+}
+
+fn key_node(path: &str, position: &mut usize, key: String) -> Result<Node, Error> {
+    if path.as_bytes().get(*position) != Some(&b'{') {
+        return Ok(Node::Key(key));
+    }
+    let (filter, len) = read_compound_prefix(&path[*position..]).map_err(|error| match error {
+        Error::InvalidSnbt(offset, message) => Error::InvalidNbtPath(*position + offset, message),
+        error => error,
+    })?;
+    *position += len;
+    Ok(Node::KeyFilter(key, filter))
+}
+
+fn is_unquoted_key_char(char: char) -> bool {
+    !matches!(char, ' ' | '"' | '\'' | '[' | ']' | '.' | '{' | '}')
+}
+
+impl Display for NbtPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, node) in self.nodes.iter().enumerate() {
+            match node {
+                Node::RootFilter(filter) => write!(f, "{filter}")?,
+                Node::Key(key) | Node::KeyFilter(key, _) => {
+                    if index > 0 {
+                        f.write_char('.')?;
+                    }
+                    if !key.is_empty() && key.chars().all(is_unquoted_key_char) {
+                        f.write_str(key)?;
+                    } else {
+                        write_quoted(f, key)?;
+                    }
+                    if let Node::KeyFilter(_, filter) = node {
+                        write!(f, "{filter}")?;
+                    }
+                }
+                Node::Index(index) => write!(f, "[{index}]")?,
+                Node::All => f.write_str("[]")?,
+                Node::ListFilter(filter) => write!(f, "[{filter}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::{compound::NbtCompound, tag::NbtTag, Error};
+
+    use super::NbtPath;
+
+    fn player() -> NbtCompound {
+        r#"{Inventory: [
+            {Slot: 0b, id: "minecraft:diamond_sword", count: 1,
+                components: {"minecraft:custom_name": '"Blade"'}},
+            {Slot: 1b, id: "minecraft:stone", count: 64}
+        ], Pos: [0.5d, 64.0d, 0.5d]}"#
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_and_print() {
+        for path in [
+            r#"Inventory[{Slot:0b}].components."custom name""#,
+            "components.minecraft:custom_name",
+            "{foo:1b}.bar[-1][]",
+            "a{b:[I;1]}.c",
+        ] {
+            assert_eq!(path.parse::<NbtPath>().unwrap().to_string(), path);
+        }
+        for path in ["", "a.", "a..b", "a[", "a[x]", "a b"] {
+            assert!(path.parse::<NbtPath>().is_err(), "{path}");
+        }
+    }
+
+    #[test]
+    fn get_and_deserialize() {
+        let player = player();
+        let path: NbtPath = r#"Inventory[{Slot:0b}].components."minecraft:custom_name""#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            player.get_path(&path).unwrap(),
+            vec![&NbtTag::String("\"Blade\"".to_string())]
+        );
+        assert_eq!(
+            player
+                .get_path(&"Inventory[].count".parse().unwrap())
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            player
+                .get_path_as::<f64>(&"Pos[-2]".parse().unwrap())
+                .unwrap(),
+            64.0
+        );
+
+        #[derive(Deserialize)]
+        struct Item {
+            id: String,
+            count: i32,
+        }
+        let item: Item = player
+            .get_path_as(&"Inventory[{Slot:1b}]".parse().unwrap())
+            .unwrap();
+        assert_eq!((item.id.as_str(), item.count), ("minecraft:stone", 64));
+
+        assert!(matches!(
+            player.get_path(&"Inventory[{Slot:9b}]".parse().unwrap()),
+            Err(Error::NoPathMatch(_))
+        ));
+        assert!(matches!(
+            player.get_path_as::<i32>(&"Inventory[].count".parse().unwrap()),
+            Err(Error::AmbiguousPath(_))
+        ));
+    }
+
+    #[test]
+    fn set_remove_and_merge() {
+        let mut player = player();
+
+        let count = "Inventory[].count".parse().unwrap();
+        assert_eq!(player.set_path(&count, NbtTag::Int(2)).unwrap(), 2);
+        let path = "Inventory[{Slot:5b}].components.lore".parse().unwrap();
+        player.set_path(&path, "shiny").unwrap();
+        assert_eq!(player.get_list("Inventory").unwrap().len(), 3);
+        assert!(player
+            .set_path(&"Pos[0]".parse().unwrap(), NbtTag::Int(1))
+            .is_err());
+
+        let source: NbtCompound = "{components: {a: 1b}, count: 3}".parse().unwrap();
+        let slot = "Inventory[{Slot:0b}]".parse().unwrap();
+        assert_eq!(player.merge_path(&slot, &source).unwrap(), 1);
+        let item = player.get_path(&slot).unwrap()[0]
+            .extract_compound()
+            .unwrap();
+        assert_eq!(item.get_int("count"), Some(3));
+        let components = item.get_compound("components").unwrap();
+        assert_eq!(components.child_tags.len(), 2);
+
+        let remove = "Inventory[{count:2}]".parse().unwrap();
+        assert_eq!(player.remove_path(&remove).unwrap(), 1);
+        assert!(player.remove_path(&remove).is_err());
+        assert!(player.remove_path(&"Missing.key".parse().unwrap()).is_err());
+        assert!(player.get("Missing").is_none());
+        assert_eq!(player.get_list("Inventory").unwrap().len(), 2);
+    }
+}
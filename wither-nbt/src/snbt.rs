@@ -0,0 +1,603 @@
+//! Stringified NBT, the text form used by commands, chat components and data packs,
+//! like `{id:"minecraft:stone",count:1b,data:[I;1,2]}`.
+//!
+//! `Display` prints the canonical compact form, and `{:#}` prints it indented.
+
+use std::{
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+
+use bytes::Bytes;
+
+use crate::{borrowed::MAX_DEPTH, compound::NbtCompound, tag::NbtTag, Error};
+
+const INDENT: &str = "    ";
+
+impl FromStr for NbtTag {
+    type Err = Error;
+
+    fn from_str(snbt: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(snbt);
+        let tag = parser.read_value()?;
+        parser.finish()?;
+        Ok(tag)
+    }
+}
+
+impl FromStr for NbtCompound {
+    type Err = Error;
+
+    fn from_str(snbt: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(snbt);
+        parser.skip_whitespace();
+        let compound = parser.read_compound()?;
+        parser.finish()?;
+        Ok(compound)
+    }
+}
+
+/// Parses the compound at the start of `snbt`, returning it with the length it took up.
+pub(crate) fn read_compound_prefix(snbt: &str) -> Result<(NbtCompound, usize), Error> {
+    let mut parser = Parser::new(snbt);
+    let compound = parser.read_compound()?;
+    Ok((compound, parser.position))
+}
+
+/// Parses the quoted string at the start of `snbt`, returning it with the length it took
+/// up.
+pub(crate) fn read_quoted_prefix(snbt: &str) -> Result<(String, usize), Error> {
+    let mut parser = Parser::new(snbt);
+    let string = parser.read_quoted()?;
+    Ok((string, parser.position))
+}
+
+struct Parser<'a> {
+    snbt: &'a str,
+    position: usize,
+    /// Containers the parser is inside of.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(snbt: &'a str) -> Self {
+        Self {
+            snbt,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    fn error(&self, message: &'static str) -> Error {
+        Error::InvalidSnbt(self.position, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.snbt[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(char) = self.peek().filter(|char| char.is_whitespace()) {
+            self.position += char.len_utf8();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        self.skip_whitespace();
+        if self.peek() != Some(expected) {
+            return Err(self.error(match expected {
+                ':' => "Expected ':'",
+                ']' => "Expected ']'",
+                '}' => "Expected '}'",
+                _ => "Unexpected character",
+            }));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Consumes a `,` between elements, returns whether the container goes on.
+    fn next_element(&mut self, end: char) -> Result<bool, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') => {
+                self.position += 1;
+                self.skip_whitespace();
+                Ok(true)
+            }
+            Some(char) if char == end => Ok(false),
+            _ => Err(self.error("Expected ',' or the end of the container")),
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        self.skip_whitespace();
+        if self.position != self.snbt.len() {
+            return Err(self.error("Trailing data"));
+        }
+        Ok(())
+    }
+
+    fn read_value(&mut self) -> Result<NbtTag, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.nested(|parser| Ok(NbtTag::Compound(parser.read_compound()?))),
+            Some('[') => self.nested(Self::read_list_or_array),
+            Some('"' | '\'') => Ok(NbtTag::String(self.read_quoted()?)),
+            _ => {
+                let start = self.position;
+                let token = self.read_unquoted();
+                if token.is_empty() {
+                    self.position = start;
+                    return Err(self.error("Expected a value"));
+                }
+                Ok(parse_unquoted(token))
+            }
+        }
+    }
+
+    /// Reads a container inside the current one, failing past `MAX_DEPTH` like the
+    /// binary readers so deep input can't overflow the stack.
+    fn nested(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<NbtTag, Error>,
+    ) -> Result<NbtTag, Error> {
+        if self.depth >= MAX_DEPTH {
+            return Err(Error::TooDeep(MAX_DEPTH));
+        }
+        self.depth += 1;
+        let tag = read(self);
+        self.depth -= 1;
+        tag
+    }
+
+    fn read_compound(&mut self) -> Result<NbtCompound, Error> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let mut compound = NbtCompound::new();
+        while self.peek() != Some('}') {
+            let key = match self.peek() {
+                Some('"' | '\'') => self.read_quoted()?,
+                _ => self.read_unquoted().to_string(),
+            };
+            if key.is_empty() {
+                return Err(self.error("Expected a key"));
+            }
+            self.expect(':')?;
+            let value = self.read_value()?;
+            // Like vanilla, a repeated key overwrites the earlier value.
+            match compound
+                .child_tags
+                .iter_mut()
+                .find(|(name, _)| *name == key)
+            {
+                Some((_, tag)) => *tag = value,
+                None => compound.child_tags.push((key, value)),
+            }
+            if !self.next_element('}')? {
+                break;
+            }
+        }
+        self.expect('}')?;
+        Ok(compound)
+    }
+
+    fn read_list_or_array(&mut self) -> Result<NbtTag, Error> {
+        self.expect('[')?;
+        let rest = &self.snbt[self.position..];
+        let array_type = match rest.as_bytes() {
+            [kind @ (b'B' | b'I' | b'L'), b';', ..] => Some(*kind),
+            _ => None,
+        };
+        let Some(array_type) = array_type else {
+            return self.read_list();
+        };
+        self.position += 2;
+        self.skip_whitespace();
+
+        let mut values = Vec::new();
+        while self.peek() != Some(']') {
+            let value = match self.read_value()? {
+                NbtTag::Byte(value) => value as i64,
+                NbtTag::Short(value) => value as i64,
+                NbtTag::Int(value) => value as i64,
+                NbtTag::Long(value) => value,
+                _ => return Err(self.error("Arrays can only hold integers")),
+            };
+            values.push(value);
+            if !self.next_element(']')? {
+                break;
+            }
+        }
+        self.expect(']')?;
+
+        let out_of_range = || self.error("Array value out of range");
+        Ok(match array_type {
+            b'B' => NbtTag::ByteArray(Bytes::from(
+                values
+                    .into_iter()
+                    .map(|value| i8::try_from(value).map(|value| value as u8))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| out_of_range())?,
+            )),
+            b'I' => NbtTag::IntArray(
+                values
+                    .into_iter()
+                    .map(i32::try_from)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| out_of_range())?,
+            ),
+            _ => NbtTag::LongArray(values),
+        })
+    }
+
+    fn read_list(&mut self) -> Result<NbtTag, Error> {
+        self.skip_whitespace();
+        let mut list: Vec<NbtTag> = Vec::new();
+        while self.peek() != Some(']') {
+            let start = self.position;
+            let value = self.read_value()?;
+            if list
+                .first()
+                .is_some_and(|first| first.get_type_id() != value.get_type_id())
+            {
+                self.position = start;
+                return Err(self.error("Lists can only hold one type"));
+            }
+            list.push(value);
+            if !self.next_element(']')? {
+                break;
+            }
+        }
+        self.expect(']')?;
+        Ok(NbtTag::List(list))
+    }
+
+    fn read_quoted(&mut self) -> Result<String, Error> {
+        let Some(quote) = self.peek() else {
+            return Err(self.error("Expected a string"));
+        };
+        self.position += 1;
+        let mut string = String::new();
+        let mut chars = self.snbt[self.position..].char_indices();
+        while let Some((offset, char)) = chars.next() {
+            match char {
+                '\\' => {
+                    let Some((_, escaped)) = chars.next() else {
+                        break;
+                    };
+                    match escaped {
+                        '\\' | '"' | '\'' => string.push(escaped),
+                        'n' => string.push('\n'),
+                        't' => string.push('\t'),
+                        'r' => string.push('\r'),
+                        'u' => {
+                            let start = self.position + offset + 2;
+                            let char = self
+                                .snbt
+                                .get(start..start + 4)
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .and_then(char::from_u32);
+                            let Some(char) = char else {
+                                self.position = start;
+                                return Err(self.error("Invalid unicode escape"));
+                            };
+                            string.push(char);
+                            chars.nth(3);
+                        }
+                        _ => {
+                            self.position += offset;
+                            return Err(self.error("Invalid escape sequence"));
+                        }
+                    }
+                }
+                char if char == quote => {
+                    self.position += offset + 1;
+                    return Ok(string);
+                }
+                char => string.push(char),
+            }
+        }
+        self.position = self.snbt.len();
+        Err(self.error("Unterminated string"))
+    }
+
+    fn read_unquoted(&mut self) -> &str {
+        let start = self.position;
+        while self.peek().is_some_and(is_unquoted_char) {
+            self.position += 1;
+        }
+        &self.snbt[start..self.position]
+    }
+}
+
+fn is_unquoted_char(char: char) -> bool {
+    char.is_ascii_alphanumeric() || matches!(char, '_' | '-' | '.' | '+')
+}
+
+/// Unquoted tokens are numbers or booleans when they look like one, strings otherwise.
+fn parse_unquoted(token: &str) -> NbtTag {
+    if token.eq_ignore_ascii_case("true") {
+        return NbtTag::Byte(1);
+    }
+    if token.eq_ignore_ascii_case("false") {
+        return NbtTag::Byte(0);
+    }
+
+    let (number, suffix) = match token.char_indices().last() {
+        Some((index, suffix)) if suffix.is_ascii_alphabetic() => {
+            (&token[..index], Some(suffix.to_ascii_lowercase()))
+        }
+        _ => (token, None),
+    };
+    let tag = match suffix {
+        Some('b') if is_integer(number) => number.parse().ok().map(NbtTag::Byte),
+        Some('s') if is_integer(number) => number.parse().ok().map(NbtTag::Short),
+        Some('l') if is_integer(number) => number.parse().ok().map(NbtTag::Long),
+        Some('f') if is_decimal(number, false) || is_non_finite(number) => {
+            number.parse().ok().map(NbtTag::Float)
+        }
+        Some('d') if is_decimal(number, false) || is_non_finite(number) => {
+            number.parse().ok().map(NbtTag::Double)
+        }
+        None if is_integer(number) => number.parse().ok().map(NbtTag::Int),
+        None if is_decimal(number, true) => number.parse().ok().map(NbtTag::Double),
+        _ => None,
+    };
+    // Numbers out of range stay strings, like in vanilla.
+    tag.unwrap_or_else(|| NbtTag::String(token.to_string()))
+}
+
+/// `[-+]?(0|[1-9][0-9]*)`
+fn is_integer(number: &str) -> bool {
+    let digits = number.strip_prefix(['-', '+']).unwrap_or(number);
+    match digits.as_bytes() {
+        [b'0'] => true,
+        [b'1'..=b'9', rest @ ..] => rest.iter().all(u8::is_ascii_digit),
+        _ => false,
+    }
+}
+
+/// How Java prints floats that aren't numbers, written with a suffix so they read back.
+fn is_non_finite(number: &str) -> bool {
+    matches!(number, "NaN" | "Infinity" | "-Infinity")
+}
+
+/// `[-+]?([0-9]+[.]?|[0-9]*[.][0-9]+)(e[-+]?[0-9]+)?`, where unsuffixed numbers need
+/// the dot to not be an int.
+fn is_decimal(number: &str, needs_dot: bool) -> bool {
+    let number = number.strip_prefix(['-', '+']).unwrap_or(number);
+    let (mantissa, exponent) = match number.find(['e', 'E']) {
+        Some(index) => (&number[..index], Some(&number[index + 1..])),
+        None => (number, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    let digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    let mantissa_valid = match fraction {
+        Some(fraction) => {
+            digits(integer) && digits(fraction) && !(integer.is_empty() && fraction.is_empty())
+        }
+        None => !needs_dot && !integer.is_empty() && digits(integer),
+    };
+    let exponent_valid = exponent.is_none_or(|exponent| {
+        let exponent = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+        !exponent.is_empty() && digits(exponent)
+    });
+    mantissa_valid && exponent_valid
+}
+
+impl Display for NbtTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_tag(f, self, f.alternate().then_some(0))
+    }
+}
+
+impl Display for NbtCompound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_compound(f, self, f.alternate().then_some(0))
+    }
+}
+
+/// `depth` is `None` for the compact form.
+fn write_tag(f: &mut Formatter<'_>, tag: &NbtTag, depth: Option<usize>) -> fmt::Result {
+    match tag {
+        NbtTag::End => Ok(()),
+        NbtTag::Byte(byte) => write!(f, "{byte}b"),
+        NbtTag::Short(short) => write!(f, "{short}s"),
+        NbtTag::Int(int) => write!(f, "{int}"),
+        NbtTag::Long(long) => write!(f, "{long}L"),
+        NbtTag::Float(float) => write_float(f, *float as f64, 'f'),
+        NbtTag::Double(double) => write_float(f, *double, 'd'),
+        NbtTag::String(string) => write_quoted(f, string),
+        NbtTag::ByteArray(bytes) => {
+            write_array(f, "B", bytes.iter().map(|byte| format!("{}B", *byte as i8)))
+        }
+        NbtTag::IntArray(ints) => write_array(f, "I", ints.iter().map(i32::to_string)),
+        NbtTag::LongArray(longs) => {
+            write_array(f, "L", longs.iter().map(|long| format!("{long}L")))
+        }
+        NbtTag::List(list) => {
+            f.write_char('[')?;
+            for (index, tag) in list.iter().enumerate() {
+                write_separator(f, index, depth)?;
+                write_tag(f, tag, depth.map(|depth| depth + 1))?;
+            }
+            write_end(f, ']', list.is_empty(), depth)
+        }
+        NbtTag::Compound(compound) => write_compound(f, compound, depth),
+    }
+}
+
+fn write_float(f: &mut Formatter<'_>, value: f64, suffix: char) -> fmt::Result {
+    if value.is_nan() {
+        write!(f, "NaN{suffix}")
+    } else if value.is_infinite() {
+        let sign = if value < 0.0 { "-" } else { "" };
+        write!(f, "{sign}Infinity{suffix}")
+    } else if suffix == 'f' {
+        write!(f, "{:?}{suffix}", value as f32)
+    } else {
+        write!(f, "{value:?}{suffix}")
+    }
+}
+
+fn write_compound(
+    f: &mut Formatter<'_>,
+    compound: &NbtCompound,
+    depth: Option<usize>,
+) -> fmt::Result {
+    f.write_char('{')?;
+    for (index, (key, tag)) in compound.child_tags.iter().enumerate() {
+        write_separator(f, index, depth)?;
+        if !key.is_empty() && key.chars().all(is_unquoted_char) {
+            f.write_str(key)?;
+        } else {
+            write_quoted(f, key)?;
+        }
+        f.write_str(if depth.is_some() { ": " } else { ":" })?;
+        write_tag(f, tag, depth.map(|depth| depth + 1))?;
+    }
+    write_end(f, '}', compound.child_tags.is_empty(), depth)
+}
+
+fn write_separator(f: &mut Formatter<'_>, index: usize, depth: Option<usize>) -> fmt::Result {
+    if index > 0 {
+        f.write_char(',')?;
+    }
+    if let Some(depth) = depth {
+        f.write_char('\n')?;
+        f.write_str(&INDENT.repeat(depth + 1))?;
+    }
+    Ok(())
+}
+
+fn write_end(f: &mut Formatter<'_>, end: char, empty: bool, depth: Option<usize>) -> fmt::Result {
+    if let Some(depth) = depth.filter(|_| !empty) {
+        f.write_char('\n')?;
+        f.write_str(&INDENT.repeat(depth))?;
+    }
+    f.write_char(end)
+}
+
+fn write_array(
+    f: &mut Formatter<'_>,
+    kind: &str,
+    values: impl Iterator<Item = String>,
+) -> fmt::Result {
+    write!(f, "[{kind};")?;
+    for (index, value) in values.enumerate() {
+        if index > 0 {
+            f.write_char(',')?;
+        }
+        f.write_str(&value)?;
+    }
+    f.write_char(']')
+}
+
+/// Quotes with `"` unless the string holds `"` and no `'`, like vanilla.
+pub(crate) fn write_quoted(f: &mut Formatter<'_>, string: &str) -> fmt::Result {
+    let quote = if string.contains('"') && !string.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+    f.write_char(quote)?;
+    for char in string.chars() {
+        if char == quote || char == '\\' {
+            f.write_char('\\')?;
+        }
+        f.write_char(char)?;
+    }
+    f.write_char(quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{borrowed::MAX_DEPTH, compound::NbtCompound, tag::NbtTag, Error};
+
+    #[test]
+    fn parse_typed_values() {
+        let compound: NbtCompound = r#"{
+            byte: 1b, short: -2S, int: 3, long: 4l, float: 1.5f, double: .5,
+            flag: true, name: stone_bricks, "quoted key": 'it\'s "x"',
+            ints: [I; 1, -2], bytes: [B;1b,2], list: [{a: 1}, {}], empty: [],
+            big: 99999999999
+        }"#
+        .parse()
+        .unwrap();
+
+        assert_eq!(compound.get_byte("byte"), Some(1));
+        assert_eq!(compound.get_short("short"), Some(-2));
+        assert_eq!(compound.get_int("int"), Some(3));
+        assert_eq!(compound.get_long("long"), Some(4));
+        assert_eq!(compound.get_float("float"), Some(1.5));
+        assert_eq!(compound.get_double("double"), Some(0.5));
+        assert_eq!(compound.get_bool("flag"), Some(true));
+        assert_eq!(compound.get_string("name").unwrap(), "stone_bricks");
+        assert_eq!(compound.get_string("quoted key").unwrap(), "it's \"x\"");
+        assert_eq!(compound.get_int_array("ints"), Some(&vec![1, -2]));
+        assert_eq!(compound.get_list("list").unwrap().len(), 2);
+        assert_eq!(compound.get_string("big").unwrap(), "99999999999");
+
+        assert!("[1, 2b]".parse::<NbtTag>().is_err());
+        assert!("{a: 1".parse::<NbtTag>().is_err());
+        assert!("{a: 1} x".parse::<NbtTag>().is_err());
+    }
+
+    #[test]
+    fn print_round_trip() {
+        let snbt = concat!(
+            r#"{id:"minecraft:stone",count:1b,data:[I;1,2],list:[1.0d,-2.5d],"#,
+            r#""a b":'say "hi"',nested:{}}"#
+        );
+        let tag: NbtTag = snbt.parse().unwrap();
+        assert_eq!(tag.to_string(), snbt);
+
+        let pretty = format!("{tag:#}");
+        assert!(pretty.starts_with("{\n    id: \"minecraft:stone\",\n"));
+        assert!(pretty.contains("    nested: {}\n}"));
+        assert_eq!(pretty.parse::<NbtTag>().unwrap(), tag);
+    }
+
+    #[test]
+    fn non_finite_floats_round_trip() {
+        let tag: NbtTag = "[NaNf, Infinityf, -Infinityf]".parse().unwrap();
+        let NbtTag::List(floats) = &tag else {
+            panic!("{tag:?}");
+        };
+        assert!(matches!(floats[0], NbtTag::Float(float) if float.is_nan()));
+        assert_eq!(floats[1], NbtTag::Float(f32::INFINITY));
+        assert_eq!(floats[2], NbtTag::Float(f32::NEG_INFINITY));
+        assert_eq!(tag.to_string(), "[NaNf,Infinityf,-Infinityf]");
+
+        for double in [f64::INFINITY, f64::NEG_INFINITY] {
+            let tag = NbtTag::Double(double);
+            assert_eq!(tag.to_string().parse::<NbtTag>().unwrap(), tag);
+        }
+        let nan = NbtTag::Double(f64::NAN).to_string().parse().unwrap();
+        assert!(matches!(nan, NbtTag::Double(double) if double.is_nan()));
+        // Without a suffix they are words, not numbers.
+        assert_eq!(
+            "NaN".parse::<NbtTag>().unwrap(),
+            NbtTag::String("NaN".into())
+        );
+    }
+
+    #[test]
+    fn limits_depth() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(nested(MAX_DEPTH).parse::<NbtTag>().is_ok());
+        assert!(matches!(
+            nested(MAX_DEPTH + 1).parse::<NbtTag>(),
+            Err(Error::TooDeep(MAX_DEPTH))
+        ));
+        let compounds = "{a:".repeat(100_000) + &"}".repeat(100_000);
+        assert!(matches!(
+            compounds.parse::<NbtCompound>(),
+            Err(Error::TooDeep(_))
+        ));
+    }
+}
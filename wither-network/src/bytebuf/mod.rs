@@ -8,6 +8,11 @@ use crate::{
     FixedBitSet,
 };
 use bytes::{Buf, BufMut};
+use wither_nbt::tag::NbtTag;
+use wither_util::{
+    math::{position::WorldPosition, vector2::Vector2, vector3::Vector3},
+    text::TextComponent,
+};
 
 pub mod serializer;
 pub mod deserializer;
@@ -87,6 +92,27 @@ pub trait ByteBuf: Buf {
     fn try_get_uuid(&mut self) -> Result<Uuid, ReadingError>;
 
     fn try_get_fixed_bitset(&mut self, bits: usize) -> Result<FixedBitSet, ReadingError>;
+
+    /// Reads a VarInt length prefix followed by that many bytes.
+    fn try_get_byte_array(&mut self, max_size: usize) -> Result<bytes::Bytes, ReadingError>;
+
+    /// Consumes everything left in the buffer, used by packets that end in a raw payload.
+    fn try_get_remaining(&mut self, max_size: usize) -> Result<bytes::Bytes, ReadingError>;
+
+    /// Reads a nameless (network) NBT tag.
+    fn try_get_nbt(&mut self) -> Result<NbtTag, ReadingError>;
+
+    fn try_get_text_component(&mut self) -> Result<TextComponent, ReadingError>;
+
+    /// Reads a block position packed into a single long.
+    fn try_get_position(&mut self) -> Result<WorldPosition, ReadingError>;
+
+    fn try_get_vec3(&mut self) -> Result<Vector3<f64>, ReadingError>;
+
+    fn try_get_bit_set(&mut self) -> Result<BitSet, ReadingError>;
+
+    /// Reads a chunk position packed into a long, x in the low and z in the high half.
+    fn try_get_chunk_pos(&mut self) -> Result<Vector2<i32>, ReadingError>;
 }
 
 impl<T: Buf> ByteBuf for T {
@@ -278,6 +304,60 @@ impl<T: Buf> ByteBuf for T {
         self.try_copy_to_bytes(bits.div_ceil(8))
     }
 
+    fn try_get_byte_array(&mut self, max_size: usize) -> Result<bytes::Bytes, ReadingError> {
+        let length = self.try_get_var_int()?.0;
+        if length < 0 {
+            return Err(ReadingError::Message(
+                "Negative byte array length".to_string(),
+            ));
+        }
+        self.try_copy_to_bytes_len(length as usize, max_size)
+    }
+
+    fn try_get_remaining(&mut self, max_size: usize) -> Result<bytes::Bytes, ReadingError> {
+        self.try_copy_to_bytes_len(self.remaining(), max_size)
+    }
+
+    fn try_get_nbt(&mut self) -> Result<NbtTag, ReadingError> {
+        if !self.has_remaining() {
+            return Err(ReadingError::EOF("nbt".to_string()));
+        }
+        NbtTag::deserialize(self).map_err(|e| ReadingError::Message(e.to_string()))
+    }
+
+    fn try_get_text_component(&mut self) -> Result<TextComponent, ReadingError> {
+        Ok(TextComponent::from_nbt(&self.try_get_nbt()?))
+    }
+
+    fn try_get_position(&mut self) -> Result<WorldPosition, ReadingError> {
+        let packed = self.try_get_i64()?;
+        Ok(WorldPosition(Vector3 {
+            x: (packed >> 38) as i32,
+            y: (packed << 52 >> 52) as i32,
+            z: (packed << 26 >> 38) as i32,
+        }))
+    }
+
+    fn try_get_vec3(&mut self) -> Result<Vector3<f64>, ReadingError> {
+        Ok(Vector3 {
+            x: self.try_get_f64()?,
+            y: self.try_get_f64()?,
+            z: self.try_get_f64()?,
+        })
+    }
+
+    fn try_get_bit_set(&mut self) -> Result<BitSet, ReadingError> {
+        BitSet::decode(self).map_err(|error| match error {
+            DecodeError::Incomplete => ReadingError::Incomplete("bitset".to_string()),
+            DecodeError::TooLarge => ReadingError::TooLarge("bitset".to_string()),
+        })
+    }
+
+    fn try_get_chunk_pos(&mut self) -> Result<Vector2<i32>, ReadingError> {
+        let packed = self.try_get_i64()?;
+        Ok(Vector2::new(packed as i32, (packed >> 32) as i32))
+    }
+
     fn try_get_identifer(&mut self) -> Result<Identifier, ReadingError> {
         match Identifier::decode(self) {
             Ok(identifer) => Ok(identifer),
@@ -312,7 +392,23 @@ pub trait ByteBufMut {
 
     fn put_var_int(&mut self, value: &VarInt);
 
+    fn put_var_long(&mut self, value: &VarLong);
+
     fn put_varint_arr(&mut self, v: &[i32]);
+
+    /// Writes a VarInt length prefix followed by the bytes.
+    fn put_byte_array(&mut self, v: &[u8]);
+
+    /// Writes a nameless (network) NBT tag.
+    fn put_nbt(&mut self, tag: &NbtTag);
+
+    fn put_text_component(&mut self, text: &TextComponent);
+
+    fn put_position(&mut self, position: &WorldPosition);
+
+    fn put_vec3(&mut self, vec: &Vector3<f64>);
+
+    fn put_chunk_pos(&mut self, pos: &Vector2<i32>);
 }
 
 impl<T: BufMut> ByteBufMut for T {
@@ -354,6 +450,10 @@ impl<T: BufMut> ByteBufMut for T {
         var_int.encode(self);
     }
 
+    fn put_var_long(&mut self, var_long: &VarLong) {
+        var_long.encode(self);
+    }
+
     fn put_bit_set(&mut self, bit_set: &BitSet) {
         bit_set.encode(self);
     }
@@ -379,6 +479,38 @@ impl<T: BufMut> ByteBufMut for T {
     fn put_identifier(&mut self, val: &Identifier) {
         val.encode(self);
     }
+
+    fn put_byte_array(&mut self, v: &[u8]) {
+        self.put_var_int(&v.len().into());
+        self.put_slice(v);
+    }
+
+    fn put_nbt(&mut self, tag: &NbtTag) {
+        self.put_slice(&tag.serialize());
+    }
+
+    fn put_text_component(&mut self, text: &TextComponent) {
+        self.put_slice(&text.encode());
+    }
+
+    fn put_position(&mut self, position: &WorldPosition) {
+        let position = position.0;
+        self.put_i64(
+            ((position.x as i64 & 0x3FFFFFF) << 38)
+                | ((position.z as i64 & 0x3FFFFFF) << 12)
+                | (position.y as i64 & 0xFFF),
+        );
+    }
+
+    fn put_vec3(&mut self, vec: &Vector3<f64>) {
+        self.put_f64(vec.x);
+        self.put_f64(vec.y);
+        self.put_f64(vec.z);
+    }
+
+    fn put_chunk_pos(&mut self, pos: &Vector2<i32>) {
+        self.put_i64((pos.x as i64 & 0xFFFFFFFF) | ((pos.z as i64 & 0xFFFFFFFF) << 32));
+    }
 }
 
 #[cfg(test)]
@@ -386,8 +518,10 @@ mod test {
     use bytes::{Bytes, BytesMut};
     use serde::{Deserialize, Serialize};
 
+    use wither_util::math::{position::WorldPosition, vector3::Vector3};
+
     use crate::{
-        bytebuf::{deserializer, serializer},
+        bytebuf::{deserializer, serializer, ByteBuf, ByteBufMut},
         VarInt,
     };
 
@@ -424,4 +558,15 @@ mod test {
 
         assert_eq!(foo, deserialized);
     }
+
+    #[test]
+    fn test_position_reserialize() {
+        let position = WorldPosition(Vector3::new(-30_000_000, -64, 1_234_567));
+        let mut bytes = BytesMut::new();
+        bytes.put_position(&position);
+
+        let deserialized = Bytes::from(bytes).try_get_position().unwrap();
+
+        assert_eq!(position.0, deserialized.0);
+    }
 }
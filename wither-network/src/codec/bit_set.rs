@@ -8,8 +8,18 @@ use crate::bytebuf::ByteBufMut;
 
 use super::{var_int::VarInt, Codec, DecodeError};
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BitSet(pub VarInt, pub Vec<i64>);
 
+impl BitSet {
+    /// Whether bit `index` is set, bits past the end are unset.
+    pub fn get(&self, index: usize) -> bool {
+        self.1
+            .get(index / 64)
+            .is_some_and(|long| (long >> (index % 64)) & 1 == 1)
+    }
+}
+
 impl Codec<BitSet> for BitSet {
     /// The maximum size of the BitSet is `remaining / 8`.
     const MAX_SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(usize::MAX) };
@@ -31,7 +41,7 @@ impl Codec<BitSet> for BitSet {
             .try_get_var_int()
             .map_err(|_| DecodeError::Incomplete)?;
         // vanilla uses remaining / 8
-        if length.0 as usize >= read.remaining() / 8 {
+        if length.0 < 0 || length.0 as usize > read.remaining() / 8 {
             return Err(DecodeError::TooLarge);
         }
         let mut array: Vec<i64> = Vec::with_capacity(size_of::<i64>() * length.0 as usize);
@@ -0,0 +1,1104 @@
+use bytes::{Buf, BufMut};
+use wither_nbt::tag::NbtTag;
+use wither_util::{math::position::WorldPosition, text::TextComponent};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::{identifier::Identifier, slot::Slot},
+    types::{Holder, HolderSet, PropertyMap},
+    IDOrSoundEvent, VarInt,
+};
+
+const MAX_LORE_LINES: usize = 256;
+const MAX_CONTAINER_SLOTS: usize = 256;
+
+/// A data component attached to an item stack, keyed by its `minecraft:data_component_type`
+/// protocol id.
+///
+/// Registry backed values (enchantments, effects, attributes, ...) are kept as protocol ids.
+/// Components that the server only sends as NBT are kept as raw tags.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemComponent {
+    CustomData(NbtTag),
+    MaxStackSize(VarInt),
+    MaxDamage(VarInt),
+    Damage(VarInt),
+    Unbreakable { show_in_tooltip: bool },
+    CustomName(TextComponent),
+    ItemName(TextComponent),
+    ItemModel(Identifier),
+    Lore(Vec<TextComponent>),
+    /// 0: common, 1: uncommon, 2: rare, 3: epic
+    Rarity(VarInt),
+    Enchantments(Enchantments),
+    CanPlaceOn(AdventureModePredicate),
+    CanBreak(AdventureModePredicate),
+    AttributeModifiers(AttributeModifiers),
+    CustomModelData(CustomModelData),
+    HideAdditionalTooltip,
+    HideTooltip,
+    RepairCost(VarInt),
+    CreativeSlotLock,
+    EnchantmentGlintOverride(bool),
+    IntangibleProjectile(NbtTag),
+    Food(Food),
+    Consumable(Consumable),
+    UseRemainder(Box<Slot>),
+    UseCooldown(UseCooldown),
+    /// Tag of damage types the item is immune to.
+    DamageResistant(Identifier),
+    Tool(Tool),
+    Enchantable(VarInt),
+    Equippable(Equippable),
+    Repairable(HolderSet),
+    Glider,
+    TooltipStyle(Identifier),
+    DeathProtection(Vec<ConsumeEffect>),
+    StoredEnchantments(Enchantments),
+    DyedColor { rgb: i32, show_in_tooltip: bool },
+    MapColor(i32),
+    MapId(VarInt),
+    MapDecorations(NbtTag),
+    MapPostProcessing(VarInt),
+    ChargedProjectiles(Vec<Slot>),
+    BundleContents(Vec<Slot>),
+    PotionContents(PotionContents),
+    SuspiciousStewEffects(Vec<SuspiciousStewEffect>),
+    WritableBookContent(Vec<Filterable<String>>),
+    WrittenBookContent(WrittenBookContent),
+    Trim(Box<ArmorTrim>),
+    DebugStickState(NbtTag),
+    EntityData(NbtTag),
+    BucketEntityData(NbtTag),
+    BlockEntityData(NbtTag),
+    Instrument(Holder<Instrument>),
+    OminousBottleAmplifier(VarInt),
+    JukeboxPlayable(Box<JukeboxPlayable>),
+    Recipes(NbtTag),
+    LodestoneTracker(LodestoneTracker),
+    FireworkExplosion(FireworkExplosion),
+    Fireworks(Fireworks),
+    Profile(ResolvableProfile),
+    NoteBlockSound(Identifier),
+    BannerPatterns(Vec<BannerPatternLayer>),
+    /// Dye color id
+    BaseColor(VarInt),
+    /// Item ids of the four sherds, starting at the back.
+    PotDecorations(Vec<VarInt>),
+    Container(Vec<Slot>),
+    BlockState(Vec<(String, String)>),
+    Bees(Vec<Bee>),
+    Lock(NbtTag),
+    ContainerLoot(NbtTag),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enchantments {
+    /// Pairs of enchantment id and level.
+    pub levels: Vec<(VarInt, VarInt)>,
+    pub show_in_tooltip: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdventureModePredicate {
+    pub predicates: Vec<BlockPredicate>,
+    pub show_in_tooltip: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockPredicate {
+    pub blocks: Option<HolderSet>,
+    pub properties: Option<Vec<PropertyMatcher>>,
+    pub nbt: Option<NbtTag>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyMatcher {
+    pub name: String,
+    pub value: ValueMatcher,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueMatcher {
+    Exact(String),
+    Range {
+        min: Option<String>,
+        max: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifiers {
+    pub modifiers: Vec<AttributeModifierEntry>,
+    pub show_in_tooltip: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifierEntry {
+    pub attribute: VarInt,
+    pub modifier: AttributeModifier,
+    /// Equipment slot group id, e.g. 0: any, 1: main hand
+    pub slot: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    pub id: Identifier,
+    pub amount: f64,
+    /// 0: add value, 1: add multiplied base, 2: add multiplied total
+    pub operation: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomModelData {
+    pub floats: Vec<f32>,
+    pub flags: Vec<bool>,
+    pub strings: Vec<String>,
+    pub colors: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Food {
+    pub nutrition: VarInt,
+    pub saturation: f32,
+    pub can_always_eat: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Consumable {
+    pub consume_seconds: f32,
+    /// Item use animation id
+    pub animation: VarInt,
+    pub sound: IDOrSoundEvent,
+    pub has_consume_particles: bool,
+    pub on_consume_effects: Vec<ConsumeEffect>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsumeEffect {
+    ApplyEffects {
+        effects: Vec<MobEffectInstance>,
+        probability: f32,
+    },
+    RemoveEffects(HolderSet),
+    ClearAllEffects,
+    TeleportRandomly {
+        diameter: f32,
+    },
+    PlaySound(IDOrSoundEvent),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MobEffectInstance {
+    pub effect: VarInt,
+    pub details: MobEffectDetails,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MobEffectDetails {
+    pub amplifier: VarInt,
+    pub duration: VarInt,
+    pub ambient: bool,
+    pub show_particles: bool,
+    pub show_icon: bool,
+    /// The weaker effect that is restored once this one runs out.
+    pub hidden_effect: Option<Box<MobEffectDetails>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UseCooldown {
+    pub seconds: f32,
+    pub cooldown_group: Option<Identifier>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tool {
+    pub rules: Vec<ToolRule>,
+    pub default_mining_speed: f32,
+    pub damage_per_block: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolRule {
+    pub blocks: HolderSet,
+    pub speed: Option<f32>,
+    pub correct_for_drops: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equippable {
+    /// Equipment slot id
+    pub slot: VarInt,
+    pub equip_sound: IDOrSoundEvent,
+    pub asset_id: Option<Identifier>,
+    pub camera_overlay: Option<Identifier>,
+    pub allowed_entities: Option<HolderSet>,
+    pub dispensable: bool,
+    pub swappable: bool,
+    pub damage_on_hurt: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotionContents {
+    pub potion: Option<VarInt>,
+    pub custom_color: Option<i32>,
+    pub custom_effects: Vec<MobEffectInstance>,
+    pub custom_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuspiciousStewEffect {
+    pub effect: VarInt,
+    pub duration: VarInt,
+}
+
+/// A value together with the version shown to players with chat filtering enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filterable<T> {
+    pub raw: T,
+    pub filtered: Option<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrittenBookContent {
+    pub title: Filterable<String>, // 32
+    pub author: String,
+    pub generation: VarInt,
+    pub pages: Vec<Filterable<TextComponent>>,
+    pub resolved: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArmorTrim {
+    pub material: Holder<TrimMaterial>,
+    pub pattern: Holder<TrimPattern>,
+    pub show_in_tooltip: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrimMaterial {
+    pub asset_name: String,
+    pub ingredient: VarInt,
+    /// Pairs of equipment asset and the asset name used instead of `asset_name`.
+    pub override_armor_assets: Vec<(Identifier, String)>,
+    pub description: TextComponent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrimPattern {
+    pub asset_id: Identifier,
+    pub template_item: VarInt,
+    pub description: TextComponent,
+    pub decal: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instrument {
+    pub sound_event: IDOrSoundEvent,
+    pub use_duration: f32,
+    pub range: f32,
+    pub description: TextComponent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JukeboxPlayable {
+    pub song: JukeboxSongReference,
+    pub show_in_tooltip: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JukeboxSongReference {
+    Holder(Box<Holder<JukeboxSong>>),
+    Key(Identifier),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JukeboxSong {
+    pub sound_event: IDOrSoundEvent,
+    pub description: TextComponent,
+    pub length_in_seconds: f32,
+    pub comparator_output: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LodestoneTracker {
+    pub target: Option<(Identifier, WorldPosition)>,
+    pub tracked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FireworkExplosion {
+    /// 0: small ball, 1: large ball, 2: star, 3: creeper, 4: burst
+    pub shape: VarInt,
+    pub colors: Vec<i32>,
+    pub fade_colors: Vec<i32>,
+    pub has_trail: bool,
+    pub has_twinkle: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fireworks {
+    pub flight_duration: VarInt,
+    pub explosions: Vec<FireworkExplosion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvableProfile {
+    pub name: Option<String>, // 16
+    pub id: Option<uuid::Uuid>,
+    pub properties: PropertyMap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannerPatternLayer {
+    pub pattern: Holder<BannerPattern>,
+    /// Dye color id
+    pub color: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannerPattern {
+    pub asset_id: Identifier,
+    pub translation_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bee {
+    pub entity_data: NbtTag,
+    pub ticks_in_hive: VarInt,
+    pub min_ticks_in_hive: VarInt,
+}
+
+impl ItemComponent {
+    /// The `minecraft:data_component_type` protocol id of this component.
+    pub fn id(&self) -> i32 {
+        match self {
+            Self::CustomData(_) => 0,
+            Self::MaxStackSize(_) => 1,
+            Self::MaxDamage(_) => 2,
+            Self::Damage(_) => 3,
+            Self::Unbreakable { .. } => 4,
+            Self::CustomName(_) => 5,
+            Self::ItemName(_) => 6,
+            Self::ItemModel(_) => 7,
+            Self::Lore(_) => 8,
+            Self::Rarity(_) => 9,
+            Self::Enchantments(_) => 10,
+            Self::CanPlaceOn(_) => 11,
+            Self::CanBreak(_) => 12,
+            Self::AttributeModifiers(_) => 13,
+            Self::CustomModelData(_) => 14,
+            Self::HideAdditionalTooltip => 15,
+            Self::HideTooltip => 16,
+            Self::RepairCost(_) => 17,
+            Self::CreativeSlotLock => 18,
+            Self::EnchantmentGlintOverride(_) => 19,
+            Self::IntangibleProjectile(_) => 20,
+            Self::Food(_) => 21,
+            Self::Consumable(_) => 22,
+            Self::UseRemainder(_) => 23,
+            Self::UseCooldown(_) => 24,
+            Self::DamageResistant(_) => 25,
+            Self::Tool(_) => 26,
+            Self::Enchantable(_) => 27,
+            Self::Equippable(_) => 28,
+            Self::Repairable(_) => 29,
+            Self::Glider => 30,
+            Self::TooltipStyle(_) => 31,
+            Self::DeathProtection(_) => 32,
+            Self::StoredEnchantments(_) => 33,
+            Self::DyedColor { .. } => 34,
+            Self::MapColor(_) => 35,
+            Self::MapId(_) => 36,
+            Self::MapDecorations(_) => 37,
+            Self::MapPostProcessing(_) => 38,
+            Self::ChargedProjectiles(_) => 39,
+            Self::BundleContents(_) => 40,
+            Self::PotionContents(_) => 41,
+            Self::SuspiciousStewEffects(_) => 42,
+            Self::WritableBookContent(_) => 43,
+            Self::WrittenBookContent(_) => 44,
+            Self::Trim(_) => 45,
+            Self::DebugStickState(_) => 46,
+            Self::EntityData(_) => 47,
+            Self::BucketEntityData(_) => 48,
+            Self::BlockEntityData(_) => 49,
+            Self::Instrument(_) => 50,
+            Self::OminousBottleAmplifier(_) => 51,
+            Self::JukeboxPlayable(_) => 52,
+            Self::Recipes(_) => 53,
+            Self::LodestoneTracker(_) => 54,
+            Self::FireworkExplosion(_) => 55,
+            Self::Fireworks(_) => 56,
+            Self::Profile(_) => 57,
+            Self::NoteBlockSound(_) => 58,
+            Self::BannerPatterns(_) => 59,
+            Self::BaseColor(_) => 60,
+            Self::PotDecorations(_) => 61,
+            Self::Container(_) => 62,
+            Self::BlockState(_) => 63,
+            Self::Bees(_) => 64,
+            Self::Lock(_) => 65,
+            Self::ContainerLoot(_) => 66,
+        }
+    }
+
+    /// Reads the component type id followed by the component value.
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let id = bytebuf.try_get_var_int()?.0;
+        Ok(match id {
+            0 => Self::CustomData(bytebuf.try_get_nbt()?),
+            1 => Self::MaxStackSize(bytebuf.try_get_var_int()?),
+            2 => Self::MaxDamage(bytebuf.try_get_var_int()?),
+            3 => Self::Damage(bytebuf.try_get_var_int()?),
+            4 => Self::Unbreakable {
+                show_in_tooltip: bytebuf.try_get_bool()?,
+            },
+            5 => Self::CustomName(bytebuf.try_get_text_component()?),
+            6 => Self::ItemName(bytebuf.try_get_text_component()?),
+            7 => Self::ItemModel(bytebuf.try_get_identifer()?),
+            8 => Self::Lore(read_limited_list(bytebuf, MAX_LORE_LINES, |v| {
+                v.try_get_text_component()
+            })?),
+            9 => Self::Rarity(bytebuf.try_get_var_int()?),
+            10 => Self::Enchantments(Enchantments::read(bytebuf)?),
+            11 => Self::CanPlaceOn(AdventureModePredicate::read(bytebuf)?),
+            12 => Self::CanBreak(AdventureModePredicate::read(bytebuf)?),
+            13 => Self::AttributeModifiers(AttributeModifiers::read(bytebuf)?),
+            14 => Self::CustomModelData(CustomModelData {
+                floats: bytebuf.get_list(|v| v.try_get_f32())?,
+                flags: bytebuf.get_list(|v| v.try_get_bool())?,
+                strings: bytebuf.get_list(|v| v.try_get_string())?,
+                colors: bytebuf.get_list(|v| v.try_get_i32())?,
+            }),
+            15 => Self::HideAdditionalTooltip,
+            16 => Self::HideTooltip,
+            17 => Self::RepairCost(bytebuf.try_get_var_int()?),
+            18 => Self::CreativeSlotLock,
+            19 => Self::EnchantmentGlintOverride(bytebuf.try_get_bool()?),
+            20 => Self::IntangibleProjectile(bytebuf.try_get_nbt()?),
+            21 => Self::Food(Food {
+                nutrition: bytebuf.try_get_var_int()?,
+                saturation: bytebuf.try_get_f32()?,
+                can_always_eat: bytebuf.try_get_bool()?,
+            }),
+            22 => Self::Consumable(Consumable {
+                consume_seconds: bytebuf.try_get_f32()?,
+                animation: bytebuf.try_get_var_int()?,
+                sound: IDOrSoundEvent::read(bytebuf)?,
+                has_consume_particles: bytebuf.try_get_bool()?,
+                on_consume_effects: bytebuf.get_list(ConsumeEffect::read)?,
+            }),
+            23 => Self::UseRemainder(Box::new(Slot::read(bytebuf)?)),
+            24 => Self::UseCooldown(UseCooldown {
+                seconds: bytebuf.try_get_f32()?,
+                cooldown_group: bytebuf.try_get_option(|v| v.try_get_identifer())?,
+            }),
+            25 => Self::DamageResistant(bytebuf.try_get_identifer()?),
+            26 => Self::Tool(Tool {
+                rules: bytebuf.get_list(|v| {
+                    Ok(ToolRule {
+                        blocks: HolderSet::read(v)?,
+                        speed: v.try_get_option(|v| v.try_get_f32())?,
+                        correct_for_drops: v.try_get_option(|v| v.try_get_bool())?,
+                    })
+                })?,
+                default_mining_speed: bytebuf.try_get_f32()?,
+                damage_per_block: bytebuf.try_get_var_int()?,
+            }),
+            27 => Self::Enchantable(bytebuf.try_get_var_int()?),
+            28 => Self::Equippable(Equippable {
+                slot: bytebuf.try_get_var_int()?,
+                equip_sound: IDOrSoundEvent::read(bytebuf)?,
+                asset_id: bytebuf.try_get_option(|v| v.try_get_identifer())?,
+                camera_overlay: bytebuf.try_get_option(|v| v.try_get_identifer())?,
+                allowed_entities: bytebuf.try_get_option(HolderSet::read)?,
+                dispensable: bytebuf.try_get_bool()?,
+                swappable: bytebuf.try_get_bool()?,
+                damage_on_hurt: bytebuf.try_get_bool()?,
+            }),
+            29 => Self::Repairable(HolderSet::read(bytebuf)?),
+            30 => Self::Glider,
+            31 => Self::TooltipStyle(bytebuf.try_get_identifer()?),
+            32 => Self::DeathProtection(bytebuf.get_list(ConsumeEffect::read)?),
+            33 => Self::StoredEnchantments(Enchantments::read(bytebuf)?),
+            34 => Self::DyedColor {
+                rgb: bytebuf.try_get_i32()?,
+                show_in_tooltip: bytebuf.try_get_bool()?,
+            },
+            35 => Self::MapColor(bytebuf.try_get_i32()?),
+            36 => Self::MapId(bytebuf.try_get_var_int()?),
+            37 => Self::MapDecorations(bytebuf.try_get_nbt()?),
+            38 => Self::MapPostProcessing(bytebuf.try_get_var_int()?),
+            39 => Self::ChargedProjectiles(bytebuf.get_list(Slot::read)?),
+            40 => Self::BundleContents(bytebuf.get_list(Slot::read)?),
+            41 => Self::PotionContents(PotionContents {
+                potion: bytebuf.try_get_option(|v| v.try_get_var_int())?,
+                custom_color: bytebuf.try_get_option(|v| v.try_get_i32())?,
+                custom_effects: bytebuf.get_list(MobEffectInstance::read)?,
+                custom_name: bytebuf.try_get_option(|v| v.try_get_string())?,
+            }),
+            42 => Self::SuspiciousStewEffects(bytebuf.get_list(|v| {
+                Ok(SuspiciousStewEffect {
+                    effect: v.try_get_var_int()?,
+                    duration: v.try_get_var_int()?,
+                })
+            })?),
+            43 => Self::WritableBookContent(bytebuf.get_list(|v| {
+                Filterable::read(v, |v| v.try_get_string_len(1024))
+            })?),
+            44 => Self::WrittenBookContent(WrittenBookContent {
+                title: Filterable::read(bytebuf, |v| v.try_get_string_len(32))?,
+                author: bytebuf.try_get_string()?,
+                generation: bytebuf.try_get_var_int()?,
+                pages: bytebuf
+                    .get_list(|v| Filterable::read(v, |v| v.try_get_text_component()))?,
+                resolved: bytebuf.try_get_bool()?,
+            }),
+            45 => Self::Trim(Box::new(ArmorTrim {
+                material: Holder::read(bytebuf, |v| {
+                    Ok(TrimMaterial {
+                        asset_name: v.try_get_string()?,
+                        ingredient: v.try_get_var_int()?,
+                        override_armor_assets: v
+                            .get_list(|v| Ok((v.try_get_identifer()?, v.try_get_string()?)))?,
+                        description: v.try_get_text_component()?,
+                    })
+                })?,
+                pattern: Holder::read(bytebuf, |v| {
+                    Ok(TrimPattern {
+                        asset_id: v.try_get_identifer()?,
+                        template_item: v.try_get_var_int()?,
+                        description: v.try_get_text_component()?,
+                        decal: v.try_get_bool()?,
+                    })
+                })?,
+                show_in_tooltip: bytebuf.try_get_bool()?,
+            })),
+            46 => Self::DebugStickState(bytebuf.try_get_nbt()?),
+            47 => Self::EntityData(bytebuf.try_get_nbt()?),
+            48 => Self::BucketEntityData(bytebuf.try_get_nbt()?),
+            49 => Self::BlockEntityData(bytebuf.try_get_nbt()?),
+            50 => Self::Instrument(Holder::read(bytebuf, |v| {
+                Ok(Instrument {
+                    sound_event: IDOrSoundEvent::read(v)?,
+                    use_duration: v.try_get_f32()?,
+                    range: v.try_get_f32()?,
+                    description: v.try_get_text_component()?,
+                })
+            })?),
+            51 => Self::OminousBottleAmplifier(bytebuf.try_get_var_int()?),
+            52 => Self::JukeboxPlayable(Box::new(JukeboxPlayable {
+                song: if bytebuf.try_get_bool()? {
+                    JukeboxSongReference::Holder(Box::new(Holder::read(bytebuf, |v| {
+                        Ok(JukeboxSong {
+                            sound_event: IDOrSoundEvent::read(v)?,
+                            description: v.try_get_text_component()?,
+                            length_in_seconds: v.try_get_f32()?,
+                            comparator_output: v.try_get_var_int()?,
+                        })
+                    })?))
+                } else {
+                    JukeboxSongReference::Key(bytebuf.try_get_identifer()?)
+                },
+                show_in_tooltip: bytebuf.try_get_bool()?,
+            })),
+            53 => Self::Recipes(bytebuf.try_get_nbt()?),
+            54 => Self::LodestoneTracker(LodestoneTracker {
+                target: bytebuf
+                    .try_get_option(|v| Ok((v.try_get_identifer()?, v.try_get_position()?)))?,
+                tracked: bytebuf.try_get_bool()?,
+            }),
+            55 => Self::FireworkExplosion(FireworkExplosion::read(bytebuf)?),
+            56 => Self::Fireworks(Fireworks {
+                flight_duration: bytebuf.try_get_var_int()?,
+                explosions: read_limited_list(bytebuf, 256, FireworkExplosion::read)?,
+            }),
+            57 => Self::Profile(ResolvableProfile {
+                name: bytebuf.try_get_option(|v| v.try_get_string_len(16))?,
+                id: bytebuf.try_get_option(|v| v.try_get_uuid())?,
+                properties: PropertyMap::read(bytebuf)?,
+            }),
+            58 => Self::NoteBlockSound(bytebuf.try_get_identifer()?),
+            59 => Self::BannerPatterns(bytebuf.get_list(|v| {
+                Ok(BannerPatternLayer {
+                    pattern: Holder::read(v, |v| {
+                        Ok(BannerPattern {
+                            asset_id: v.try_get_identifer()?,
+                            translation_key: v.try_get_string()?,
+                        })
+                    })?,
+                    color: v.try_get_var_int()?,
+                })
+            })?),
+            60 => Self::BaseColor(bytebuf.try_get_var_int()?),
+            61 => Self::PotDecorations(read_limited_list(bytebuf, 4, |v| v.try_get_var_int())?),
+            62 => Self::Container(read_limited_list(
+                bytebuf,
+                MAX_CONTAINER_SLOTS,
+                Slot::read,
+            )?),
+            63 => Self::BlockState(
+                bytebuf.get_list(|v| Ok((v.try_get_string()?, v.try_get_string()?)))?,
+            ),
+            64 => Self::Bees(bytebuf.get_list(|v| {
+                Ok(Bee {
+                    entity_data: v.try_get_nbt()?,
+                    ticks_in_hive: v.try_get_var_int()?,
+                    min_ticks_in_hive: v.try_get_var_int()?,
+                })
+            })?),
+            65 => Self::Lock(bytebuf.try_get_nbt()?),
+            66 => Self::ContainerLoot(bytebuf.try_get_nbt()?),
+            _ => {
+                return Err(ReadingError::Message(format!(
+                    "Unknown item component type {id}"
+                )))
+            }
+        })
+    }
+
+    /// Writes the component type id followed by the component value.
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.id()));
+        match self {
+            Self::CustomData(nbt)
+            | Self::IntangibleProjectile(nbt)
+            | Self::MapDecorations(nbt)
+            | Self::DebugStickState(nbt)
+            | Self::EntityData(nbt)
+            | Self::BucketEntityData(nbt)
+            | Self::BlockEntityData(nbt)
+            | Self::Recipes(nbt)
+            | Self::Lock(nbt)
+            | Self::ContainerLoot(nbt) => bytebuf.put_nbt(nbt),
+            Self::MaxStackSize(value)
+            | Self::MaxDamage(value)
+            | Self::Damage(value)
+            | Self::Rarity(value)
+            | Self::RepairCost(value)
+            | Self::Enchantable(value)
+            | Self::MapId(value)
+            | Self::MapPostProcessing(value)
+            | Self::OminousBottleAmplifier(value)
+            | Self::BaseColor(value) => bytebuf.put_var_int(value),
+            Self::Unbreakable { show_in_tooltip } => bytebuf.put_bool(*show_in_tooltip),
+            Self::CustomName(text) | Self::ItemName(text) => bytebuf.put_text_component(text),
+            Self::ItemModel(identifier)
+            | Self::DamageResistant(identifier)
+            | Self::TooltipStyle(identifier)
+            | Self::NoteBlockSound(identifier) => bytebuf.put_identifier(identifier),
+            Self::Lore(lines) => bytebuf.put_list(lines, |p, line| p.put_text_component(line)),
+            Self::Enchantments(enchantments) | Self::StoredEnchantments(enchantments) => {
+                enchantments.write(bytebuf)
+            }
+            Self::CanPlaceOn(predicate) | Self::CanBreak(predicate) => predicate.write(bytebuf),
+            Self::AttributeModifiers(modifiers) => modifiers.write(bytebuf),
+            Self::CustomModelData(data) => {
+                bytebuf.put_list(&data.floats, |p, v| p.put_f32(*v));
+                bytebuf.put_list(&data.flags, |p, v| p.put_bool(*v));
+                bytebuf.put_list(&data.strings, |p, v| p.put_string(v));
+                bytebuf.put_list(&data.colors, |p, v| p.put_i32(*v));
+            }
+            Self::HideAdditionalTooltip | Self::HideTooltip | Self::CreativeSlotLock => {}
+            Self::Glider => {}
+            Self::EnchantmentGlintOverride(value) => bytebuf.put_bool(*value),
+            Self::Food(food) => {
+                bytebuf.put_var_int(&food.nutrition);
+                bytebuf.put_f32(food.saturation);
+                bytebuf.put_bool(food.can_always_eat);
+            }
+            Self::Consumable(consumable) => {
+                bytebuf.put_f32(consumable.consume_seconds);
+                bytebuf.put_var_int(&consumable.animation);
+                consumable.sound.write(bytebuf);
+                bytebuf.put_bool(consumable.has_consume_particles);
+                bytebuf.put_list(&consumable.on_consume_effects, |p, effect| effect.write(p));
+            }
+            Self::UseRemainder(slot) => slot.write(bytebuf),
+            Self::UseCooldown(cooldown) => {
+                bytebuf.put_f32(cooldown.seconds);
+                bytebuf.put_option(&cooldown.cooldown_group, |p, group| p.put_identifier(group));
+            }
+            Self::Tool(tool) => {
+                bytebuf.put_list(&tool.rules, |p, rule| {
+                    rule.blocks.write(p);
+                    p.put_option(&rule.speed, |p, speed| p.put_f32(*speed));
+                    p.put_option(&rule.correct_for_drops, |p, correct| p.put_bool(*correct));
+                });
+                bytebuf.put_f32(tool.default_mining_speed);
+                bytebuf.put_var_int(&tool.damage_per_block);
+            }
+            Self::Equippable(equippable) => {
+                bytebuf.put_var_int(&equippable.slot);
+                equippable.equip_sound.write(bytebuf);
+                bytebuf.put_option(&equippable.asset_id, |p, id| p.put_identifier(id));
+                bytebuf.put_option(&equippable.camera_overlay, |p, id| p.put_identifier(id));
+                bytebuf.put_option(&equippable.allowed_entities, |p, set| set.write(p));
+                bytebuf.put_bool(equippable.dispensable);
+                bytebuf.put_bool(equippable.swappable);
+                bytebuf.put_bool(equippable.damage_on_hurt);
+            }
+            Self::Repairable(items) => items.write(bytebuf),
+            Self::DeathProtection(effects) => {
+                bytebuf.put_list(effects, |p, effect| effect.write(p))
+            }
+            Self::DyedColor {
+                rgb,
+                show_in_tooltip,
+            } => {
+                bytebuf.put_i32(*rgb);
+                bytebuf.put_bool(*show_in_tooltip);
+            }
+            Self::MapColor(color) => bytebuf.put_i32(*color),
+            Self::ChargedProjectiles(slots)
+            | Self::BundleContents(slots)
+            | Self::Container(slots) => bytebuf.put_list(slots, |p, slot| slot.write(p)),
+            Self::PotionContents(contents) => {
+                bytebuf.put_option(&contents.potion, |p, potion| p.put_var_int(potion));
+                bytebuf.put_option(&contents.custom_color, |p, color| p.put_i32(*color));
+                bytebuf.put_list(&contents.custom_effects, |p, effect| effect.write(p));
+                bytebuf.put_option(&contents.custom_name, |p, name| p.put_string(name));
+            }
+            Self::SuspiciousStewEffects(effects) => bytebuf.put_list(effects, |p, effect| {
+                p.put_var_int(&effect.effect);
+                p.put_var_int(&effect.duration);
+            }),
+            Self::WritableBookContent(pages) => bytebuf.put_list(pages, |p, page| {
+                page.write(p, |p, v| p.put_string_len(v, 1024))
+            }),
+            Self::WrittenBookContent(content) => {
+                content
+                    .title
+                    .write(bytebuf, |p, v| p.put_string_len(v, 32));
+                bytebuf.put_string(&content.author);
+                bytebuf.put_var_int(&content.generation);
+                bytebuf.put_list(&content.pages, |p, page| {
+                    page.write(p, |p, v| p.put_text_component(v))
+                });
+                bytebuf.put_bool(content.resolved);
+            }
+            Self::Trim(trim) => {
+                trim.material.write(bytebuf, |p, material| {
+                    p.put_string(&material.asset_name);
+                    p.put_var_int(&material.ingredient);
+                    p.put_list(&material.override_armor_assets, |p, (asset, name)| {
+                        p.put_identifier(asset);
+                        p.put_string(name);
+                    });
+                    p.put_text_component(&material.description);
+                });
+                trim.pattern.write(bytebuf, |p, pattern| {
+                    p.put_identifier(&pattern.asset_id);
+                    p.put_var_int(&pattern.template_item);
+                    p.put_text_component(&pattern.description);
+                    p.put_bool(pattern.decal);
+                });
+                bytebuf.put_bool(trim.show_in_tooltip);
+            }
+            Self::Instrument(instrument) => instrument.write(bytebuf, |p, instrument| {
+                instrument.sound_event.write(p);
+                p.put_f32(instrument.use_duration);
+                p.put_f32(instrument.range);
+                p.put_text_component(&instrument.description);
+            }),
+            Self::JukeboxPlayable(playable) => {
+                match &playable.song {
+                    JukeboxSongReference::Holder(holder) => {
+                        bytebuf.put_bool(true);
+                        holder.write(bytebuf, |p, song| {
+                            song.sound_event.write(p);
+                            p.put_text_component(&song.description);
+                            p.put_f32(song.length_in_seconds);
+                            p.put_var_int(&song.comparator_output);
+                        });
+                    }
+                    JukeboxSongReference::Key(key) => {
+                        bytebuf.put_bool(false);
+                        bytebuf.put_identifier(key);
+                    }
+                }
+                bytebuf.put_bool(playable.show_in_tooltip);
+            }
+            Self::LodestoneTracker(tracker) => {
+                bytebuf.put_option(&tracker.target, |p, (dimension, position)| {
+                    p.put_identifier(dimension);
+                    p.put_position(position);
+                });
+                bytebuf.put_bool(tracker.tracked);
+            }
+            Self::FireworkExplosion(explosion) => explosion.write(bytebuf),
+            Self::Fireworks(fireworks) => {
+                bytebuf.put_var_int(&fireworks.flight_duration);
+                bytebuf.put_list(&fireworks.explosions, |p, explosion| explosion.write(p));
+            }
+            Self::Profile(profile) => {
+                bytebuf.put_option(&profile.name, |p, name| p.put_string_len(name, 16));
+                bytebuf.put_option(&profile.id, |p, id| p.put_uuid(id));
+                profile.properties.write(bytebuf);
+            }
+            Self::BannerPatterns(layers) => bytebuf.put_list(layers, |p, layer| {
+                layer.pattern.write(p, |p, pattern| {
+                    p.put_identifier(&pattern.asset_id);
+                    p.put_string(&pattern.translation_key);
+                });
+                p.put_var_int(&layer.color);
+            }),
+            Self::PotDecorations(items) => bytebuf.put_list(items, |p, item| p.put_var_int(item)),
+            Self::BlockState(properties) => bytebuf.put_list(properties, |p, (name, value)| {
+                p.put_string(name);
+                p.put_string(value);
+            }),
+            Self::Bees(bees) => bytebuf.put_list(bees, |p, bee| {
+                p.put_nbt(&bee.entity_data);
+                p.put_var_int(&bee.ticks_in_hive);
+                p.put_var_int(&bee.min_ticks_in_hive);
+            }),
+        }
+    }
+}
+
+impl Enchantments {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            levels: bytebuf.get_list(|v| Ok((v.try_get_var_int()?, v.try_get_var_int()?)))?,
+            show_in_tooltip: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.levels, |p, (enchantment, level)| {
+            p.put_var_int(enchantment);
+            p.put_var_int(level);
+        });
+        bytebuf.put_bool(self.show_in_tooltip);
+    }
+}
+
+impl AdventureModePredicate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            predicates: bytebuf.get_list(|v| {
+                Ok(BlockPredicate {
+                    blocks: v.try_get_option(HolderSet::read)?,
+                    properties: v.try_get_option(|v| v.get_list(PropertyMatcher::read))?,
+                    nbt: v.try_get_option(|v| v.try_get_nbt())?,
+                })
+            })?,
+            show_in_tooltip: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.predicates, |p, predicate| {
+            p.put_option(&predicate.blocks, |p, blocks| blocks.write(p));
+            p.put_option(&predicate.properties, |p, properties| {
+                p.put_list(properties, |p, property| property.write(p))
+            });
+            p.put_option(&predicate.nbt, |p, nbt| p.put_nbt(nbt));
+        });
+        bytebuf.put_bool(self.show_in_tooltip);
+    }
+}
+
+impl PropertyMatcher {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let name = bytebuf.try_get_string()?;
+        let value = if bytebuf.try_get_bool()? {
+            ValueMatcher::Exact(bytebuf.try_get_string()?)
+        } else {
+            ValueMatcher::Range {
+                min: bytebuf.try_get_option(|v| v.try_get_string())?,
+                max: bytebuf.try_get_option(|v| v.try_get_string())?,
+            }
+        };
+        Ok(Self { name, value })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.name);
+        match &self.value {
+            ValueMatcher::Exact(value) => {
+                bytebuf.put_bool(true);
+                bytebuf.put_string(value);
+            }
+            ValueMatcher::Range { min, max } => {
+                bytebuf.put_bool(false);
+                bytebuf.put_option(min, |p, min| p.put_string(min));
+                bytebuf.put_option(max, |p, max| p.put_string(max));
+            }
+        }
+    }
+}
+
+impl AttributeModifiers {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            modifiers: bytebuf.get_list(|v| {
+                Ok(AttributeModifierEntry {
+                    attribute: v.try_get_var_int()?,
+                    modifier: AttributeModifier::read(v)?,
+                    slot: v.try_get_var_int()?,
+                })
+            })?,
+            show_in_tooltip: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.modifiers, |p, entry| {
+            p.put_var_int(&entry.attribute);
+            entry.modifier.write(p);
+            p.put_var_int(&entry.slot);
+        });
+        bytebuf.put_bool(self.show_in_tooltip);
+    }
+}
+
+impl AttributeModifier {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_identifer()?,
+            amount: bytebuf.try_get_f64()?,
+            operation: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.id);
+        bytebuf.put_f64(self.amount);
+        bytebuf.put_var_int(&self.operation);
+    }
+}
+
+impl ConsumeEffect {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::ApplyEffects {
+                effects: bytebuf.get_list(MobEffectInstance::read)?,
+                probability: bytebuf.try_get_f32()?,
+            }),
+            1 => Ok(Self::RemoveEffects(HolderSet::read(bytebuf)?)),
+            2 => Ok(Self::ClearAllEffects),
+            3 => Ok(Self::TeleportRandomly {
+                diameter: bytebuf.try_get_f32()?,
+            }),
+            4 => Ok(Self::PlaySound(IDOrSoundEvent::read(bytebuf)?)),
+            id => Err(ReadingError::Message(format!(
+                "Unknown consume effect type {id}"
+            ))),
+        }
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::ApplyEffects {
+                effects,
+                probability,
+            } => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_list(effects, |p, effect| effect.write(p));
+                bytebuf.put_f32(*probability);
+            }
+            Self::RemoveEffects(effects) => {
+                bytebuf.put_var_int(&VarInt(1));
+                effects.write(bytebuf);
+            }
+            Self::ClearAllEffects => bytebuf.put_var_int(&VarInt(2)),
+            Self::TeleportRandomly { diameter } => {
+                bytebuf.put_var_int(&VarInt(3));
+                bytebuf.put_f32(*diameter);
+            }
+            Self::PlaySound(sound) => {
+                bytebuf.put_var_int(&VarInt(4));
+                sound.write(bytebuf);
+            }
+        }
+    }
+}
+
+impl MobEffectInstance {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            effect: bytebuf.try_get_var_int()?,
+            details: MobEffectDetails::read(bytebuf)?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.effect);
+        self.details.write(bytebuf);
+    }
+}
+
+impl MobEffectDetails {
+    fn read<B: Buf>(bytebuf: &mut B) -> Result<Self, ReadingError> {
+        Ok(Self {
+            amplifier: bytebuf.try_get_var_int()?,
+            duration: bytebuf.try_get_var_int()?,
+            ambient: bytebuf.try_get_bool()?,
+            show_particles: bytebuf.try_get_bool()?,
+            show_icon: bytebuf.try_get_bool()?,
+            hidden_effect: bytebuf.try_get_option(|v: &mut B| Ok(Box::new(Self::read(v)?)))?,
+        })
+    }
+
+    fn write<B: BufMut>(&self, bytebuf: &mut B) {
+        bytebuf.put_var_int(&self.amplifier);
+        bytebuf.put_var_int(&self.duration);
+        bytebuf.put_bool(self.ambient);
+        bytebuf.put_bool(self.show_particles);
+        bytebuf.put_bool(self.show_icon);
+        bytebuf.put_option(&self.hidden_effect, |p: &mut B, hidden| hidden.write(p));
+    }
+}
+
+impl<T> Filterable<T> {
+    fn read<B: Buf>(
+        bytebuf: &mut B,
+        read: impl Fn(&mut B) -> Result<T, ReadingError>,
+    ) -> Result<Self, ReadingError> {
+        Ok(Self {
+            raw: read(bytebuf)?,
+            filtered: bytebuf.try_get_option(&read)?,
+        })
+    }
+
+    fn write<B: BufMut>(&self, bytebuf: &mut B, write: impl Fn(&mut B, &T)) {
+        write(bytebuf, &self.raw);
+        bytebuf.put_option(&self.filtered, &write);
+    }
+}
+
+impl FireworkExplosion {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            shape: bytebuf.try_get_var_int()?,
+            colors: bytebuf.get_list(|v| v.try_get_i32())?,
+            fade_colors: bytebuf.get_list(|v| v.try_get_i32())?,
+            has_trail: bytebuf.try_get_bool()?,
+            has_twinkle: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.shape);
+        bytebuf.put_list(&self.colors, |p, color| p.put_i32(*color));
+        bytebuf.put_list(&self.fade_colors, |p, color| p.put_i32(*color));
+        bytebuf.put_bool(self.has_trail);
+        bytebuf.put_bool(self.has_twinkle);
+    }
+}
+
+/// Like [`ByteBuf::get_list`], but rejects lists longer than vanilla would send.
+fn read_limited_list<B: Buf, G>(
+    bytebuf: &mut B,
+    max_len: usize,
+    read: impl Fn(&mut B) -> Result<G, ReadingError>,
+) -> Result<Vec<G>, ReadingError> {
+    let len = bytebuf.try_get_var_int()?.0;
+    if len < 0 || len as usize > max_len {
+        return Err(ReadingError::TooLarge("list".to_string()));
+    }
+    (0..len).map(|_| read(bytebuf)).collect()
+}
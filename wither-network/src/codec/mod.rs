@@ -5,6 +5,7 @@ use thiserror::Error;
 
 pub mod bit_set;
 pub mod identifier;
+pub mod item_component;
 pub mod slot;
 pub mod var_int;
 pub mod var_long;
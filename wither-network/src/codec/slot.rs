@@ -1,131 +1,17 @@
+use crate::bytebuf::{ByteBuf, ByteBufMut, ReadingError};
+use crate::codec::item_component::ItemComponent;
 use crate::VarInt;
+use bytes::{Buf, BufMut};
 use wither_world::item::ItemStack;
-use serde::ser::SerializeSeq;
-use serde::{
-    de::{self, SeqAccess},
-    Deserialize, Serialize, Serializer,
-};
 
-#[derive(Debug, Clone)]
+/// An item stack as sent over the network. An item count of `0` is an empty slot.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Slot {
-    item_count: VarInt,
-    item_id: Option<VarInt>,
-    num_components_to_add: Option<VarInt>,
-    num_components_to_remove: Option<VarInt>,
-    components_to_add: Option<Vec<(VarInt, ())>>, // The second type depends on the varint
-    components_to_remove: Option<Vec<VarInt>>,
-}
-
-impl<'de> Deserialize<'de> for Slot {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        struct Visitor;
-        impl<'de> de::Visitor<'de> for Visitor {
-            type Value = Slot;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a valid VarInt encoded in a byte sequence")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: SeqAccess<'de>,
-            {
-                let item_count = seq
-                    .next_element::<VarInt>()?
-                    .ok_or(de::Error::custom("Failed to decode VarInt"))?;
-                if item_count.0 == 0 {
-                    return Ok(Slot {
-                        item_count: 0.into(),
-                        item_id: None,
-                        num_components_to_add: None,
-                        num_components_to_remove: None,
-                        components_to_add: None,
-                        components_to_remove: None,
-                    });
-                }
-                let item_id = seq
-                    .next_element::<VarInt>()?
-                    .ok_or(de::Error::custom("Failed to decode VarInt"))?;
-                let num_components_to_add = seq
-                    .next_element::<VarInt>()?
-                    .ok_or(de::Error::custom("Failed to decode VarInt"))?;
-                let num_components_to_remove = seq
-                    .next_element::<VarInt>()?
-                    .ok_or(de::Error::custom("Failed to decode VarInt"))?;
-                if num_components_to_add.0 != 0 || num_components_to_remove.0 != 0 {
-                    return Err(de::Error::custom(
-                        "Slot components are currently unsupported",
-                    ));
-                }
-
-                Ok(Slot {
-                    item_count,
-                    item_id: Some(item_id),
-                    num_components_to_add: Some(num_components_to_add),
-                    num_components_to_remove: Some(num_components_to_remove),
-                    components_to_add: None,
-                    components_to_remove: None,
-                })
-            }
-        }
-
-        deserializer.deserialize_seq(Visitor)
-    }
-}
-
-impl Serialize for Slot {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        if self.item_count == 0.into() {
-            let mut s = serializer.serialize_seq(Some(1))?;
-            s.serialize_element(&self.item_count)?;
-            s.end()
-        } else {
-            match (&self.num_components_to_add, &self.num_components_to_remove) {
-                (Some(to_add), Some(to_remove)) => {
-                    let mut s = serializer.serialize_seq(Some(6))?;
-                    s.serialize_element(&self.item_count)?;
-                    s.serialize_element(self.item_id.as_ref().unwrap())?;
-                    s.serialize_element(to_add)?;
-                    s.serialize_element(to_remove)?;
-                    s.serialize_element(self.components_to_add.as_ref().unwrap())?;
-                    s.serialize_element(self.components_to_remove.as_ref().unwrap())?;
-                    s.end()
-                }
-                (None, Some(to_remove)) => {
-                    let mut s = serializer.serialize_seq(Some(5))?;
-                    s.serialize_element(&self.item_count)?;
-                    s.serialize_element(self.item_id.as_ref().unwrap())?;
-                    s.serialize_element(&VarInt(0))?;
-                    s.serialize_element(to_remove)?;
-                    s.serialize_element(self.components_to_remove.as_ref().unwrap())?;
-                    s.end()
-                }
-                (Some(to_add), None) => {
-                    let mut s = serializer.serialize_seq(Some(5))?;
-                    s.serialize_element(&self.item_count)?;
-                    s.serialize_element(self.item_id.as_ref().unwrap())?;
-                    s.serialize_element(to_add)?;
-                    s.serialize_element(&VarInt(0))?;
-                    s.serialize_element(self.components_to_add.as_ref().unwrap())?;
-                    s.end()
-                }
-                (None, None) => {
-                    let mut s = serializer.serialize_seq(Some(4))?;
-                    s.serialize_element(&self.item_count)?;
-                    s.serialize_element(&self.item_id.as_ref().unwrap())?;
-                    s.serialize_element(&VarInt(0))?;
-                    s.serialize_element(&VarInt(0))?;
-                    s.end()
-                }
-            }
-        }
-    }
+    pub item_count: VarInt,
+    pub item_id: Option<VarInt>,
+    pub components_to_add: Vec<ItemComponent>,
+    /// Type ids of default components the stack does not have.
+    pub components_to_remove: Vec<VarInt>,
 }
 
 impl Slot {
@@ -141,10 +27,62 @@ impl Slot {
         Slot {
             item_count: VarInt(0),
             item_id: None,
-            num_components_to_add: None,
-            num_components_to_remove: None,
-            components_to_add: None,
-            components_to_remove: None,
+            components_to_add: Vec::new(),
+            components_to_remove: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.item_count.0 <= 0 || self.item_id.is_none()
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let item_count = bytebuf.try_get_var_int()?;
+        if item_count.0 <= 0 {
+            return Ok(Self::empty());
+        }
+
+        let item_id = bytebuf.try_get_var_int()?;
+        let num_components_to_add = bytebuf.try_get_var_int()?.0;
+        let num_components_to_remove = bytebuf.try_get_var_int()?.0;
+        if num_components_to_add < 0 || num_components_to_remove < 0 {
+            return Err(ReadingError::Message(
+                "Negative item component count".to_string(),
+            ));
+        }
+
+        let mut components_to_add = Vec::with_capacity(num_components_to_add as usize);
+        for _ in 0..num_components_to_add {
+            components_to_add.push(ItemComponent::read(bytebuf)?);
+        }
+        let mut components_to_remove = Vec::with_capacity(num_components_to_remove as usize);
+        for _ in 0..num_components_to_remove {
+            components_to_remove.push(bytebuf.try_get_var_int()?);
+        }
+
+        Ok(Slot {
+            item_count,
+            item_id: Some(item_id),
+            components_to_add,
+            components_to_remove,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        let Some(item_id) = self.item_id.as_ref().filter(|_| self.item_count.0 > 0) else {
+            bytebuf.put_var_int(&VarInt(0));
+            return;
+        };
+
+        bytebuf.put_var_int(&self.item_count);
+        bytebuf.put_var_int(item_id);
+        bytebuf.put_var_int(&self.components_to_add.len().into());
+        bytebuf.put_var_int(&self.components_to_remove.len().into());
+        for component in &self.components_to_add {
+            component.write(bytebuf);
+        }
+        for component in &self.components_to_remove {
+            bytebuf.put_var_int(component);
         }
     }
 }
@@ -155,10 +93,8 @@ impl From<&ItemStack> for Slot {
             item_count: item.item_count.into(),
             item_id: Some(VarInt(item.item_id as i32)),
             // TODO: add these
-            num_components_to_add: None,
-            num_components_to_remove: None,
-            components_to_add: None,
-            components_to_remove: None,
+            components_to_add: Vec::new(),
+            components_to_remove: Vec::new(),
         }
     }
 }
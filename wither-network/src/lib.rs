@@ -1,6 +1,6 @@
 use std::num::NonZeroU16;
 
-use bytebuf::{deserializer, serializer, ReadingError};
+use bytebuf::{deserializer, serializer, ByteBuf, ByteBufMut, ReadingError};
 use bytes::{Buf, BufMut, Bytes};
 use codec::{identifier::Identifier, var_int::{VarInt, VarIntType}};
 use wither_util::text::{style::Style, TextComponent};
@@ -72,6 +72,7 @@ impl TryFrom<VarInt> for ClientIntent {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SoundCategory {
     Master,
     Music,
@@ -85,19 +86,97 @@ pub enum SoundCategory {
     Voice,
 }
 
-#[derive(Serialize)]
+impl TryFrom<VarInt> for SoundCategory {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Master),
+            1 => Ok(Self::Music),
+            2 => Ok(Self::Records),
+            3 => Ok(Self::Weather),
+            4 => Ok(Self::Blocks),
+            5 => Ok(Self::Hostile),
+            6 => Ok(Self::Neutral),
+            7 => Ok(Self::Players),
+            8 => Ok(Self::Ambient),
+            9 => Ok(Self::Voice),
+            _ => Err(ReadingError::Message("Invalid sound category".to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+    Main,
+    Off,
+}
+
+impl TryFrom<VarInt> for Hand {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Main),
+            1 => Ok(Self::Off),
+            _ => Err(ReadingError::Message("Invalid hand".to_string())),
+        }
+    }
+}
+
+/// A sound referenced by its registry id, or sent inline when `sound_event` is set.
+#[derive(Debug, Clone, PartialEq)]
 pub struct IDOrSoundEvent {
     pub id: VarInt,
     pub sound_event: Option<SoundEvent>,
 }
 
-#[derive(Serialize)]
+impl IDOrSoundEvent {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self {
+                id: VarInt(0),
+                sound_event: Some(SoundEvent::read(bytebuf)?),
+            }),
+            id => Ok(Self {
+                id: VarInt(id - 1),
+                sound_event: None,
+            }),
+        }
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        match &self.sound_event {
+            Some(sound_event) => {
+                bytebuf.put_var_int(&VarInt(0));
+                sound_event.write(bytebuf);
+            }
+            None => bytebuf.put_var_int(&VarInt(self.id.0 + 1)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct SoundEvent {
     pub sound_name: Identifier,
     pub range: Option<f32>,
 }
 
-#[derive(Clone)]
+impl SoundEvent {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            sound_name: bytebuf.try_get_identifer()?,
+            range: bytebuf.try_get_option(|v| v.try_get_f32())?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.sound_name);
+        bytebuf.put_option(&self.range, |p, range| p.put_f32(*range));
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct RawPacket {
     pub id: VarInt,
     pub bytebuf: Bytes,
@@ -169,13 +248,38 @@ pub struct Sample {
     pub id: String,
 }
 
-pub struct KnownPack<'a> {
-    pub namespace: &'a str,
-    pub id: &'a str,
-    pub version: &'a str,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownPack {
+    pub namespace: String,
+    pub id: String,
+    pub version: String,
 }
 
-#[derive(Serialize)]
+impl KnownPack {
+    pub fn new(namespace: &str, id: &str, version: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            id: id.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            namespace: bytebuf.try_get_string()?,
+            id: bytebuf.try_get_string()?,
+            version: bytebuf.try_get_string()?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.namespace);
+        bytebuf.put_string(&self.id);
+        bytebuf.put_string(&self.version);
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum NumberFormat {
     /// Show nothing
     Blank,
@@ -185,7 +289,32 @@ pub enum NumberFormat {
     Fixed(TextComponent),
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+impl NumberFormat {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::Blank),
+            1 => Ok(Self::Styled(Style::from_nbt(&bytebuf.try_get_nbt()?))),
+            2 => Ok(Self::Fixed(bytebuf.try_get_text_component()?)),
+            _ => Err(ReadingError::Message("Invalid number format".to_string())),
+        }
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::Blank => bytebuf.put_var_int(&VarInt(0)),
+            Self::Styled(style) => {
+                bytebuf.put_var_int(&VarInt(1));
+                bytebuf.put_slice(&style.encode());
+            }
+            Self::Fixed(text) => {
+                bytebuf.put_var_int(&VarInt(2));
+                bytebuf.put_text_component(text);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PositionFlag {
     X,
     Y,
@@ -216,8 +345,26 @@ impl PositionFlag {
     pub fn get_bitfield(flags: &[PositionFlag]) -> i32 {
         flags.iter().fold(0, |acc, flag| acc | flag.get_mask())
     }
+
+    pub fn from_bitfield(bitfield: i32) -> Vec<PositionFlag> {
+        [
+            PositionFlag::X,
+            PositionFlag::Y,
+            PositionFlag::Z,
+            PositionFlag::YRot,
+            PositionFlag::XRot,
+            PositionFlag::DeltaX,
+            PositionFlag::DeltaY,
+            PositionFlag::DeltaZ,
+            PositionFlag::RotateDelta,
+        ]
+        .into_iter()
+        .filter(|flag| bitfield & flag.get_mask() != 0)
+        .collect()
+    }
 }
 
+#[derive(Debug, Clone)]
 pub enum Label {
     BuiltIn(LinkType),
     TextComponent(TextComponent),
@@ -235,15 +382,15 @@ impl Serialize for Label {
     }
 }
 
-#[derive(Serialize)]
-pub struct Link<'a> {
+#[derive(Serialize, Debug, Clone)]
+pub struct Link {
     pub is_built_in: bool,
     pub label: Label,
-    pub url: &'a String,
+    pub url: String,
 }
 
-impl<'a> Link<'a> {
-    pub fn new(label: Label, url: &'a String) -> Self {
+impl Link {
+    pub fn new(label: Label, url: String) -> Self {
         Self {
             is_built_in: match label {
                 Label::BuiltIn(_) => true,
@@ -255,6 +402,7 @@ impl<'a> Link<'a> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LinkType {
     BugReport,
     CommunityGuidelines,
@@ -268,6 +416,26 @@ pub enum LinkType {
     Announcements,
 }
 
+impl TryFrom<VarInt> for LinkType {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::BugReport),
+            1 => Ok(Self::CommunityGuidelines),
+            2 => Ok(Self::Support),
+            3 => Ok(Self::Status),
+            4 => Ok(Self::Feedback),
+            5 => Ok(Self::Community),
+            6 => Ok(Self::Website),
+            7 => Ok(Self::Forums),
+            8 => Ok(Self::News),
+            9 => Ok(Self::Announcements),
+            _ => Err(ReadingError::Message("Invalid link type".to_string())),
+        }
+    }
+}
+
 impl Serialize for LinkType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
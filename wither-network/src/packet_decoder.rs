@@ -10,6 +10,12 @@ use crate::{
 
 type Cipher = cfb8::Decryptor<aes::Aes128>;
 
+/// First byte of a pre-1.7 server list ping, no modern packet starts like this on a new
+/// connection.
+pub const LEGACY_PING: u8 = 0xFE;
+/// First byte of the kick packet a pre-1.7 server answers the legacy ping with.
+pub const LEGACY_KICK: u8 = 0xFF;
+
 // Decoder: Client -> Server
 // Supports ZLib decoding/decompression
 // Supports Aes128 Encryption
@@ -105,6 +111,41 @@ impl PacketDecoder {
         }))
     }
 
+    /// Whether the buffered bytes start with a legacy ping instead of a modern packet.
+    /// Only meaningful before the first packet of a connection was decoded.
+    pub fn is_legacy_ping(&self) -> bool {
+        self.buf.first() == Some(&LEGACY_PING)
+    }
+
+    /// Decodes the kick packet a legacy server answers a legacy ping with, returning its
+    /// message.
+    pub fn decode_legacy_kick(&mut self) -> Result<Option<String>, PacketDecodeError> {
+        let Some((&id, mut r)) = self.buf.split_first() else {
+            return Ok(None);
+        };
+        if id != LEGACY_KICK {
+            return Err(PacketDecodeError::DecodeID);
+        }
+        if r.len() < 2 {
+            return Ok(None);
+        }
+        // Length in UTF-16 code units
+        let len = r.get_u16() as usize;
+        if r.len() < len * 2 {
+            return Ok(None);
+        }
+
+        let units = r[..len * 2]
+            .chunks_exact(2)
+            .map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+        let message = char::decode_utf16(units)
+            .collect::<Result<String, _>>()
+            .map_err(|_| PacketDecodeError::MalformedLegacyString)?;
+        self.buf.advance(3 + len * 2);
+
+        Ok(Some(message))
+    }
+
     pub fn set_encryption(&mut self, key: Option<&[u8; 16]>) {
         if let Some(key) = key {
             assert!(self.cipher.is_none(), "encryption is already enabled");
@@ -178,6 +219,8 @@ pub enum PacketDecodeError {
     MalformedLength,
     #[error("failed to decompress packet: {0}")]
     FailedDecompression(String), // Updated to include error details
+    #[error("legacy packet string is not valid UTF-16")]
+    MalformedLegacyString,
 }
 
 impl From<DecompressionError> for PacketDecodeError {
@@ -485,4 +528,31 @@ mod tests {
             "Decoded payload does not match"
         );
     }
+
+    /// Test detecting a legacy ping and decoding the kick it is answered with
+    #[test]
+    fn test_decode_legacy_kick() {
+        let mut decoder = PacketDecoder::default();
+        decoder.queue_slice(&[0xFE, 0x01]);
+        assert!(decoder.is_legacy_ping());
+
+        let message = "\u{a7}1\x0047\x001.4.2\x00A Minecraft Server\x000\x0020";
+        let units: Vec<u16> = message.encode_utf16().collect();
+        let mut packet = vec![LEGACY_KICK];
+        packet.extend_from_slice(&(units.len() as u16).to_be_bytes());
+        for unit in units {
+            packet.extend_from_slice(&unit.to_be_bytes());
+        }
+
+        let mut decoder = PacketDecoder::default();
+        assert!(!decoder.is_legacy_ping());
+        // Not everything arrived yet
+        decoder.queue_slice(&packet[..10]);
+        assert_eq!(decoder.decode_legacy_kick().unwrap(), None);
+        decoder.queue_slice(&packet[10..]);
+        assert_eq!(
+            decoder.decode_legacy_kick().unwrap().as_deref(),
+            Some(message)
+        );
+    }
 }
@@ -0,0 +1,38 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::CONFIG_CUSTOM_PAYLOAD;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet,
+};
+
+pub(crate) const MAX_PAYLOAD_SIZE: usize = 1048576;
+
+/// Plugin channel message, e.g. `minecraft:brand`.
+#[wither_packet(CONFIG_CUSTOM_PAYLOAD)]
+pub struct CustomPayload {
+    pub channel: Identifier,
+    pub data: Bytes, // 1048576
+}
+
+impl CustomPayload {
+    pub fn new(channel: Identifier, data: Bytes) -> Self {
+        Self { channel, data }
+    }
+}
+
+impl Packet for CustomPayload {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            channel: bytebuf.try_get_identifer()?,
+            data: bytebuf.try_get_remaining(MAX_PAYLOAD_SIZE)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.channel);
+        bytebuf.put_slice(&self.data);
+    }
+}
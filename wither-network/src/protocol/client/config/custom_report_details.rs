@@ -0,0 +1,52 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_CUSTOM_REPORT_DETAILS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+const MAX_DETAILS: usize = 32;
+
+/// Extra key/value details the client includes in crash reports.
+#[wither_packet(CONFIG_CUSTOM_REPORT_DETAILS)]
+pub struct CustomReportDetails {
+    pub details: Vec<(String, String)>,
+}
+
+impl CustomReportDetails {
+    pub fn new(details: Vec<(String, String)>) -> Self {
+        Self { details }
+    }
+
+    pub(crate) fn read_details(
+        bytebuf: &mut impl Buf,
+    ) -> Result<Vec<(String, String)>, ReadingError> {
+        let details =
+            bytebuf.get_list(|v| Ok((v.try_get_string_len(128)?, v.try_get_string_len(4096)?)))?;
+        if details.len() > MAX_DETAILS {
+            return Err(ReadingError::TooLarge("custom report details".to_string()));
+        }
+        Ok(details)
+    }
+
+    pub(crate) fn write_details(details: &[(String, String)], bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(details, |p, (title, description)| {
+            p.put_string_len(title, 128);
+            p.put_string_len(description, 4096);
+        });
+    }
+}
+
+impl Packet for CustomReportDetails {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            details: Self::read_details(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        Self::write_details(&self.details, bytebuf);
+    }
+}
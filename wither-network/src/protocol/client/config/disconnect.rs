@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_DISCONNECT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[wither_packet(CONFIG_DISCONNECT)]
+pub struct Disconnect {
+    pub reason: TextComponent,
+}
+
+impl Disconnect {
+    pub fn new(reason: TextComponent) -> Self {
+        Self { reason }
+    }
+}
+
+impl Packet for Disconnect {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            reason: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.reason);
+    }
+}
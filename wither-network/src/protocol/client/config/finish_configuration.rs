@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::CONFIG_FINISH_CONFIGURATION;
+use wither_macros::wither_packet;
+
+/// Sent by the server once it is done configuring the client,
+/// the client acknowledges it and switches to Play.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(CONFIG_FINISH_CONFIGURATION)]
+pub struct FinishConfiguration {}
+
+impl FinishConfiguration {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::CONFIG_KEEP_ALIVE;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(CONFIG_KEEP_ALIVE)]
+pub struct KeepAlive {
+    pub keep_alive_id: i64,
+}
+
+impl KeepAlive {
+    pub fn new(keep_alive_id: i64) -> Self {
+        Self { keep_alive_id }
+    }
+}
@@ -0,0 +1,223 @@
+mod cookie_request;
+mod custom_payload;
+mod custom_report_details;
+mod disconnect;
+mod finish_configuration;
+mod keep_alive;
+mod ping;
+mod registry_data;
+mod reset_chat;
+mod resource_pack_pop;
+mod resource_pack_push;
+mod select_known_packs;
+mod server_links;
+mod store_cookie;
+mod transfer;
+mod update_enabled_features;
+mod update_tags;
+
+pub use cookie_request::*;
+pub use custom_payload::*;
+pub use custom_report_details::*;
+pub use disconnect::*;
+pub use finish_configuration::*;
+pub use keep_alive::*;
+pub use ping::*;
+pub use registry_data::*;
+pub use reset_chat::*;
+pub use resource_pack_pop::*;
+pub use resource_pack_push::*;
+pub use select_known_packs::*;
+pub use server_links::*;
+pub use store_cookie::*;
+pub use transfer::*;
+pub use update_enabled_features::*;
+pub use update_tags::*;
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use wither_nbt::{compound::NbtCompound, tag::NbtTag};
+    use wither_util::text::TextComponent;
+
+    use crate::{
+        codec::identifier::Identifier, protocol::round_trip, KnownPack, Label, Link, LinkType,
+        VarInt,
+    };
+
+    use super::*;
+
+    #[test]
+    fn cookies() {
+        let request = round_trip(&CookieRequest::new(Identifier::vanilla("session")));
+        assert_eq!(request.key, Identifier::vanilla("session"));
+
+        let cookie = round_trip(&StoreCookie::new(
+            Identifier::vanilla("session"),
+            Bytes::from_static(b"token"),
+        ));
+        assert_eq!(cookie.key, Identifier::vanilla("session"));
+        assert_eq!(&cookie.payload[..], b"token");
+    }
+
+    #[test]
+    fn custom_payload() {
+        let payload = round_trip(&CustomPayload::new(
+            Identifier::vanilla("brand"),
+            Bytes::from_static(b"\x07vanilla"),
+        ));
+        assert_eq!(payload.channel, Identifier::vanilla("brand"));
+        assert_eq!(&payload.data[..], b"\x07vanilla");
+    }
+
+    #[test]
+    fn custom_report_details() {
+        let details = vec![("world".to_string(), "lobby".to_string())];
+        assert_eq!(
+            round_trip(&CustomReportDetails::new(details.clone())).details,
+            details
+        );
+    }
+
+    #[test]
+    fn disconnect() {
+        let reason = TextComponent::text("Server closed");
+        assert_eq!(round_trip(&Disconnect::new(reason.clone())).reason, reason);
+    }
+
+    #[test]
+    fn empty_packets() {
+        round_trip(&FinishConfiguration::new());
+        round_trip(&ResetChat::new());
+    }
+
+    #[test]
+    fn keep_alive_and_ping() {
+        assert_eq!(round_trip(&KeepAlive::new(-42)).keep_alive_id, -42);
+        assert_eq!(round_trip(&Ping::new(7)).id, 7);
+    }
+
+    #[test]
+    fn registry_data() {
+        let mut overworld = NbtCompound::new();
+        overworld.put("min_y".to_string(), NbtTag::Int(-64));
+        overworld.put("height".to_string(), NbtTag::Int(384));
+        let registry = round_trip(&RegistryData::new(
+            Identifier::vanilla("dimension_type"),
+            vec![
+                RegistryEntry {
+                    entry_id: Identifier::vanilla("overworld"),
+                    data: Some(NbtTag::Compound(overworld.clone())),
+                },
+                RegistryEntry {
+                    entry_id: Identifier::vanilla("the_nether"),
+                    data: None,
+                },
+            ],
+        ));
+        assert_eq!(registry.registry_id, Identifier::vanilla("dimension_type"));
+        assert_eq!(registry.entries.len(), 2);
+        assert_eq!(registry.entries[0].data, Some(NbtTag::Compound(overworld)));
+        assert_eq!(
+            registry.entries[1].entry_id,
+            Identifier::vanilla("the_nether")
+        );
+        assert_eq!(registry.entries[1].data, None);
+    }
+
+    #[test]
+    fn resource_packs() {
+        let id = uuid::Uuid::from_u128(0x1234);
+        let push = round_trip(&ResourcePackPush::new(
+            id,
+            "https://example.com/pack.zip".to_string(),
+            "0".repeat(40),
+            true,
+            Some(TextComponent::text("Please accept")),
+        ));
+        assert_eq!(push.id, id);
+        assert_eq!(push.url, "https://example.com/pack.zip");
+        assert!(push.forced);
+        assert_eq!(push.prompt, Some(TextComponent::text("Please accept")));
+
+        assert_eq!(round_trip(&ResourcePackPop::new(Some(id))).id, Some(id));
+        assert_eq!(round_trip(&ResourcePackPop::new(None)).id, None);
+    }
+
+    #[test]
+    fn select_known_packs() {
+        let packs = round_trip(&SelectKnownPacks::new(vec![KnownPack::new(
+            "minecraft",
+            "core",
+            "1.21.4",
+        )]))
+        .known_packs;
+        assert_eq!(packs.len(), 1);
+        assert_eq!(
+            (
+                &packs[0].namespace[..],
+                &packs[0].id[..],
+                &packs[0].version[..]
+            ),
+            ("minecraft", "core", "1.21.4")
+        );
+    }
+
+    #[test]
+    fn server_links() {
+        let links = round_trip(&ServerLinks::new(vec![
+            Link::new(
+                Label::BuiltIn(LinkType::Support),
+                "https://example.com".to_string(),
+            ),
+            Link::new(
+                Label::TextComponent(TextComponent::text("Map")),
+                "https://example.com/map".to_string(),
+            ),
+        ]))
+        .links;
+        assert!(matches!(links[0].label, Label::BuiltIn(LinkType::Support)));
+        assert!(links[0].is_built_in);
+        assert!(
+            matches!(&links[1].label, Label::TextComponent(text) if *text == TextComponent::text("Map"))
+        );
+        assert_eq!(links[1].url, "https://example.com/map");
+    }
+
+    #[test]
+    fn transfer() {
+        let transfer = round_trip(&Transfer::new("example.com".to_string(), VarInt(25566)));
+        assert_eq!(transfer.host, "example.com");
+        assert_eq!(transfer.port, VarInt(25566));
+    }
+
+    #[test]
+    fn update_enabled_features() {
+        let features = vec![
+            Identifier::vanilla("vanilla"),
+            Identifier::vanilla("trade_rebalance"),
+        ];
+        assert_eq!(
+            round_trip(&UpdateEnabledFeatures::new(features.clone())).features,
+            features
+        );
+    }
+
+    #[test]
+    fn update_tags() {
+        let registries = round_trip(&UpdateTags::new(vec![RegistryTags {
+            registry: Identifier::vanilla("block"),
+            tags: vec![Tag {
+                name: Identifier::vanilla("mineable/pickaxe"),
+                entries: vec![VarInt(1), VarInt(2)],
+            }],
+        }]))
+        .registries;
+        assert_eq!(registries[0].registry, Identifier::vanilla("block"));
+        assert_eq!(
+            registries[0].tags[0].name,
+            Identifier::vanilla("mineable/pickaxe")
+        );
+        assert_eq!(registries[0].tags[0].entries, [VarInt(1), VarInt(2)]);
+    }
+}
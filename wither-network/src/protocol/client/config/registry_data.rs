@@ -0,0 +1,54 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_REGISTRY_DATA;
+use wither_macros::wither_packet;
+use wither_nbt::tag::NbtTag;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet,
+};
+
+#[derive(Debug, Clone)]
+pub struct RegistryEntry {
+    pub entry_id: Identifier,
+    /// Omitted when both sides share the entry through a known pack.
+    pub data: Option<NbtTag>,
+}
+
+#[wither_packet(CONFIG_REGISTRY_DATA)]
+pub struct RegistryData {
+    pub registry_id: Identifier,
+    pub entries: Vec<RegistryEntry>,
+}
+
+impl RegistryData {
+    pub fn new(registry_id: Identifier, entries: Vec<RegistryEntry>) -> Self {
+        Self {
+            registry_id,
+            entries,
+        }
+    }
+}
+
+impl Packet for RegistryData {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            registry_id: bytebuf.try_get_identifer()?,
+            entries: bytebuf.get_list(|v| {
+                Ok(RegistryEntry {
+                    entry_id: v.try_get_identifer()?,
+                    data: v.try_get_option(|v| v.try_get_nbt())?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.registry_id);
+        bytebuf.put_list(&self.entries, |p, entry| {
+            p.put_identifier(&entry.entry_id);
+            p.put_option(&entry.data, |p, data| p.put_nbt(data));
+        });
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::CONFIG_RESET_CHAT;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(CONFIG_RESET_CHAT)]
+pub struct ResetChat {}
+
+impl ResetChat {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
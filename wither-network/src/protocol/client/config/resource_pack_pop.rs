@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_RESOURCE_PACK_POP;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+/// Removes one resource pack, or all of them when `id` is `None`.
+#[wither_packet(CONFIG_RESOURCE_PACK_POP)]
+pub struct ResourcePackPop {
+    pub id: Option<uuid::Uuid>,
+}
+
+impl ResourcePackPop {
+    pub fn new(id: Option<uuid::Uuid>) -> Self {
+        Self { id }
+    }
+}
+
+impl Packet for ResourcePackPop {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_option(|v| v.try_get_uuid())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_option(&self.id, |p, id| p.put_uuid(id));
+    }
+}
@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_SELECT_KNOWN_PACKS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    KnownPack, Packet,
+};
+
+/// Data packs the server has enabled, the client answers with the ones it knows
+/// so the server can skip sending their registry contents.
+#[wither_packet(CONFIG_SELECT_KNOWN_PACKS)]
+pub struct SelectKnownPacks {
+    pub known_packs: Vec<KnownPack>,
+}
+
+impl SelectKnownPacks {
+    pub fn new(known_packs: Vec<KnownPack>) -> Self {
+        Self { known_packs }
+    }
+}
+
+impl Packet for SelectKnownPacks {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            known_packs: bytebuf.get_list(KnownPack::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.known_packs, |p, pack| pack.write(p));
+    }
+}
@@ -0,0 +1,52 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_SERVER_LINKS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Label, Link, LinkType, Packet, VarInt,
+};
+
+/// Links shown in the pause menu.
+#[wither_packet(CONFIG_SERVER_LINKS)]
+pub struct ServerLinks {
+    pub links: Vec<Link>,
+}
+
+impl ServerLinks {
+    pub fn new(links: Vec<Link>) -> Self {
+        Self { links }
+    }
+}
+
+impl Packet for ServerLinks {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            links: bytebuf.get_list(Link::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.links, |p, link| link.write(p));
+    }
+}
+
+impl Link {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let label = if bytebuf.try_get_bool()? {
+            Label::BuiltIn(LinkType::try_from(bytebuf.try_get_var_int()?)?)
+        } else {
+            Label::TextComponent(bytebuf.try_get_text_component()?)
+        };
+        Ok(Self::new(label, bytebuf.try_get_string()?))
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_bool(self.is_built_in);
+        match &self.label {
+            Label::BuiltIn(link_type) => bytebuf.put_var_int(&VarInt(*link_type as i32)),
+            Label::TextComponent(text) => bytebuf.put_text_component(text),
+        }
+        bytebuf.put_string(&self.url);
+    }
+}
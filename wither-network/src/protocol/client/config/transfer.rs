@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::CONFIG_TRANSFER;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Tells the client to reconnect to another server.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(CONFIG_TRANSFER)]
+pub struct Transfer {
+    pub host: String,
+    pub port: VarInt,
+}
+
+impl Transfer {
+    pub fn new(host: String, port: VarInt) -> Self {
+        Self { host, port }
+    }
+}
@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_UPDATE_ENABLED_FEATURES;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet,
+};
+
+#[wither_packet(CONFIG_UPDATE_ENABLED_FEATURES)]
+pub struct UpdateEnabledFeatures {
+    pub features: Vec<Identifier>,
+}
+
+impl UpdateEnabledFeatures {
+    pub fn new(features: Vec<Identifier>) -> Self {
+        Self { features }
+    }
+}
+
+impl Packet for UpdateEnabledFeatures {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            features: bytebuf.get_list(|v| v.try_get_identifer())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.features, |p, feature| p.put_identifier(feature));
+    }
+}
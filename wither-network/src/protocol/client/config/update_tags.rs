@@ -0,0 +1,68 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::CONFIG_UPDATE_TAGS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct Tag {
+    pub name: Identifier,
+    /// Protocol ids of the registry entries in this tag.
+    pub entries: Vec<VarInt>,
+}
+
+/// All tags of one registry, e.g. `minecraft:block`.
+#[derive(Debug, Clone)]
+pub struct RegistryTags {
+    pub registry: Identifier,
+    pub tags: Vec<Tag>,
+}
+
+impl RegistryTags {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            registry: bytebuf.try_get_identifer()?,
+            tags: bytebuf.get_list(|v| {
+                Ok(Tag {
+                    name: v.try_get_identifer()?,
+                    entries: v.get_list(|v| v.try_get_var_int())?,
+                })
+            })?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.registry);
+        bytebuf.put_list(&self.tags, |p, tag| {
+            p.put_identifier(&tag.name);
+            p.put_list(&tag.entries, |p, entry| p.put_var_int(entry));
+        });
+    }
+}
+
+#[wither_packet(CONFIG_UPDATE_TAGS)]
+pub struct UpdateTags {
+    pub registries: Vec<RegistryTags>,
+}
+
+impl UpdateTags {
+    pub fn new(registries: Vec<RegistryTags>) -> Self {
+        Self { registries }
+    }
+}
+
+impl Packet for UpdateTags {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            registries: bytebuf.get_list(RegistryTags::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.registries, |p, registry| registry.write(p));
+    }
+}
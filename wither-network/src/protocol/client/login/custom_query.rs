@@ -0,0 +1,45 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::LOGIN_CUSTOM_QUERY;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, VarInt,
+};
+
+const MAX_PAYLOAD_SIZE: usize = 1048576;
+
+/// Login plugin request, answered with a `CustomQueryAnswer` carrying the same message id.
+#[wither_packet(LOGIN_CUSTOM_QUERY)]
+pub struct CustomQuery {
+    pub message_id: VarInt,
+    pub channel: Identifier,
+    pub data: Bytes, // 1048576
+}
+
+impl CustomQuery {
+    pub fn new(message_id: VarInt, channel: Identifier, data: Bytes) -> Self {
+        Self {
+            message_id,
+            channel,
+            data,
+        }
+    }
+}
+
+impl Packet for CustomQuery {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            message_id: bytebuf.try_get_var_int()?,
+            channel: bytebuf.try_get_identifer()?,
+            data: bytebuf.try_get_remaining(MAX_PAYLOAD_SIZE)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.message_id);
+        bytebuf.put_identifier(&self.channel);
+        bytebuf.put_slice(&self.data);
+    }
+}
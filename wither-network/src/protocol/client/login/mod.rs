@@ -1,14 +1,14 @@
-// mod c_cookie_request;
+mod cookie_request;
+mod custom_query;
 mod hello;
 mod login_compression;
 mod login_finished;
 mod login_disconnect;
-// mod c_plugin_request;
 
-// pub use c_cookie_request::*;
+pub use cookie_request::*;
+pub use custom_query::*;
 pub use hello::*;
 pub use login_compression::*;
 pub use login_finished::*;
 pub use login_disconnect::*;
 // pub use c_login_success::*;
-// pub use c_plugin_request::*;
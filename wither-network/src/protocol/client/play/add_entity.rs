@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_ADD_ENTITY;
+use wither_macros::wither_packet;
+
+use crate::{types::Uuid, VarInt};
+
+/// Spawns a non-player entity, or a player that is already in the tab list.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ADD_ENTITY)]
+pub struct AddEntity {
+    pub entity_id: VarInt,
+    pub entity_uuid: Uuid,
+    pub entity_type: VarInt,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub pitch: u8,
+    pub yaw: u8,
+    pub head_yaw: u8,
+    pub data: VarInt,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
+impl AddEntity {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        entity_id: VarInt,
+        entity_uuid: Uuid,
+        entity_type: VarInt,
+        x: f64,
+        y: f64,
+        z: f64,
+        pitch: u8,
+        yaw: u8,
+        head_yaw: u8,
+        data: VarInt,
+        velocity_x: i16,
+        velocity_y: i16,
+        velocity_z: i16,
+    ) -> Self {
+        Self {
+            entity_id,
+            entity_uuid,
+            entity_type,
+            x,
+            y,
+            z,
+            pitch,
+            yaw,
+            head_yaw,
+            data,
+            velocity_x,
+            velocity_y,
+            velocity_z,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_ADD_EXPERIENCE_ORB;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ADD_EXPERIENCE_ORB)]
+pub struct AddExperienceOrb {
+    pub entity_id: VarInt,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub value: i16,
+}
+
+impl AddExperienceOrb {
+    pub fn new(entity_id: VarInt, x: f64, y: f64, z: f64, value: i16) -> Self {
+        Self {
+            entity_id,
+            x,
+            y,
+            z,
+            value,
+        }
+    }
+}
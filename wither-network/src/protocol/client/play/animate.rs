@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_ANIMATE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ANIMATE)]
+pub struct Animate {
+    pub entity_id: VarInt,
+    pub action: u8,
+}
+
+impl Animate {
+    pub fn new(entity_id: VarInt, action: u8) -> Self {
+        Self { entity_id, action }
+    }
+}
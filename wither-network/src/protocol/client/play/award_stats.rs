@@ -0,0 +1,49 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_AWARD_STATS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct Statistic {
+    pub category_id: VarInt,
+    pub statistic_id: VarInt,
+    pub value: VarInt,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_AWARD_STATS)]
+pub struct AwardStats {
+    pub statistics: Vec<Statistic>,
+}
+
+impl AwardStats {
+    pub fn new(statistics: Vec<Statistic>) -> Self {
+        Self { statistics }
+    }
+}
+
+impl Packet for AwardStats {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            statistics: bytebuf.get_list(|v| {
+                Ok(Statistic {
+                    category_id: v.try_get_var_int()?,
+                    statistic_id: v.try_get_var_int()?,
+                    value: v.try_get_var_int()?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.statistics, |p, statistic| {
+            p.put_var_int(&statistic.category_id);
+            p.put_var_int(&statistic.statistic_id);
+            p.put_var_int(&statistic.value);
+        });
+    }
+}
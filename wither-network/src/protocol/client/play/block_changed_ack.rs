@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_BLOCK_CHANGED_ACK;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Acknowledges block changes the client predicted up to `sequence`.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_BLOCK_CHANGED_ACK)]
+pub struct BlockChangedAck {
+    pub sequence: VarInt,
+}
+
+impl BlockChangedAck {
+    pub fn new(sequence: VarInt) -> Self {
+        Self { sequence }
+    }
+}
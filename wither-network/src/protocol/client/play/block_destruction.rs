@@ -0,0 +1,44 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_BLOCK_DESTRUCTION;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Shows the crack animation on a block, a `progress` outside of 0..=9 removes it.
+#[derive(Debug)]
+#[wither_packet(PLAY_BLOCK_DESTRUCTION)]
+pub struct BlockDestruction {
+    pub entity_id: VarInt,
+    pub location: WorldPosition,
+    pub progress: u8,
+}
+
+impl BlockDestruction {
+    pub fn new(entity_id: VarInt, location: WorldPosition, progress: u8) -> Self {
+        Self {
+            entity_id,
+            location,
+            progress,
+        }
+    }
+}
+
+impl Packet for BlockDestruction {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            location: bytebuf.try_get_position()?,
+            progress: bytebuf.try_get_u8()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_position(&self.location);
+        bytebuf.put_u8(self.progress);
+    }
+}
@@ -0,0 +1,44 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_BLOCK_ENTITY_DATA;
+use wither_macros::wither_packet;
+use wither_nbt::tag::NbtTag;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_BLOCK_ENTITY_DATA)]
+pub struct BlockEntityData {
+    pub location: WorldPosition,
+    pub block_entity_type: VarInt,
+    pub nbt: NbtTag,
+}
+
+impl BlockEntityData {
+    pub fn new(location: WorldPosition, block_entity_type: VarInt, nbt: NbtTag) -> Self {
+        Self {
+            location,
+            block_entity_type,
+            nbt,
+        }
+    }
+}
+
+impl Packet for BlockEntityData {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            block_entity_type: bytebuf.try_get_var_int()?,
+            nbt: bytebuf.try_get_nbt()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_var_int(&self.block_entity_type);
+        bytebuf.put_nbt(&self.nbt);
+    }
+}
@@ -0,0 +1,53 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_BLOCK_EVENT;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Block actions like chests opening or note blocks playing.
+#[derive(Debug)]
+#[wither_packet(PLAY_BLOCK_EVENT)]
+pub struct BlockEvent {
+    pub location: WorldPosition,
+    pub action_id: u8,
+    pub action_parameter: u8,
+    pub block_type: VarInt,
+}
+
+impl BlockEvent {
+    pub fn new(
+        location: WorldPosition,
+        action_id: u8,
+        action_parameter: u8,
+        block_type: VarInt,
+    ) -> Self {
+        Self {
+            location,
+            action_id,
+            action_parameter,
+            block_type,
+        }
+    }
+}
+
+impl Packet for BlockEvent {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            action_id: bytebuf.try_get_u8()?,
+            action_parameter: bytebuf.try_get_u8()?,
+            block_type: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_u8(self.action_id);
+        bytebuf.put_u8(self.action_parameter);
+        bytebuf.put_var_int(&self.block_type);
+    }
+}
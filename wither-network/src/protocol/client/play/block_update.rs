@@ -0,0 +1,39 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_BLOCK_UPDATE;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_BLOCK_UPDATE)]
+pub struct BlockUpdate {
+    pub location: WorldPosition,
+    pub block_state_id: VarInt,
+}
+
+impl BlockUpdate {
+    pub fn new(location: WorldPosition, block_state_id: VarInt) -> Self {
+        Self {
+            location,
+            block_state_id,
+        }
+    }
+}
+
+impl Packet for BlockUpdate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            block_state_id: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_var_int(&self.block_state_id);
+    }
+}
@@ -0,0 +1,109 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_BOSS_EVENT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub enum BossEventOperation {
+    Add {
+        name: TextComponent,
+        progress: f32,
+        color: VarInt,
+        overlay: VarInt,
+        /// 0x1: darken sky, 0x2: play boss music, 0x4: create fog
+        flags: u8,
+    },
+    Remove,
+    UpdateProgress(f32),
+    UpdateName(TextComponent),
+    UpdateStyle {
+        color: VarInt,
+        overlay: VarInt,
+    },
+    UpdateProperties(u8),
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_BOSS_EVENT)]
+pub struct BossEvent {
+    pub id: uuid::Uuid,
+    pub operation: BossEventOperation,
+}
+
+impl BossEvent {
+    pub fn new(id: uuid::Uuid, operation: BossEventOperation) -> Self {
+        Self { id, operation }
+    }
+}
+
+impl Packet for BossEvent {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let id = bytebuf.try_get_uuid()?;
+        let operation = match bytebuf.try_get_var_int()?.0 {
+            0 => BossEventOperation::Add {
+                name: bytebuf.try_get_text_component()?,
+                progress: bytebuf.try_get_f32()?,
+                color: bytebuf.try_get_var_int()?,
+                overlay: bytebuf.try_get_var_int()?,
+                flags: bytebuf.try_get_u8()?,
+            },
+            1 => BossEventOperation::Remove,
+            2 => BossEventOperation::UpdateProgress(bytebuf.try_get_f32()?),
+            3 => BossEventOperation::UpdateName(bytebuf.try_get_text_component()?),
+            4 => BossEventOperation::UpdateStyle {
+                color: bytebuf.try_get_var_int()?,
+                overlay: bytebuf.try_get_var_int()?,
+            },
+            5 => BossEventOperation::UpdateProperties(bytebuf.try_get_u8()?),
+            _ => {
+                return Err(ReadingError::Message(
+                    "Invalid boss event operation".to_string(),
+                ))
+            }
+        };
+        Ok(Self { id, operation })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.id);
+        match &self.operation {
+            BossEventOperation::Add {
+                name,
+                progress,
+                color,
+                overlay,
+                flags,
+            } => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_text_component(name);
+                bytebuf.put_f32(*progress);
+                bytebuf.put_var_int(color);
+                bytebuf.put_var_int(overlay);
+                bytebuf.put_u8(*flags);
+            }
+            BossEventOperation::Remove => bytebuf.put_var_int(&VarInt(1)),
+            BossEventOperation::UpdateProgress(progress) => {
+                bytebuf.put_var_int(&VarInt(2));
+                bytebuf.put_f32(*progress);
+            }
+            BossEventOperation::UpdateName(name) => {
+                bytebuf.put_var_int(&VarInt(3));
+                bytebuf.put_text_component(name);
+            }
+            BossEventOperation::UpdateStyle { color, overlay } => {
+                bytebuf.put_var_int(&VarInt(4));
+                bytebuf.put_var_int(color);
+                bytebuf.put_var_int(overlay);
+            }
+            BossEventOperation::UpdateProperties(flags) => {
+                bytebuf.put_var_int(&VarInt(5));
+                bytebuf.put_u8(*flags);
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_BUNDLE_DELIMITER;
+use wither_macros::wither_packet;
+
+/// Packets between two delimiters are handled by the client in the same tick.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(PLAY_BUNDLE_DELIMITER)]
+pub struct BundleDelimiter {}
+
+impl BundleDelimiter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
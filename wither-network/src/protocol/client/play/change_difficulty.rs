@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_CHANGE_DIFFICULTY;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CHANGE_DIFFICULTY)]
+pub struct ChangeDifficulty {
+    pub difficulty: u8,
+    pub locked: bool,
+}
+
+impl ChangeDifficulty {
+    pub fn new(difficulty: u8, locked: bool) -> Self {
+        Self { difficulty, locked }
+    }
+}
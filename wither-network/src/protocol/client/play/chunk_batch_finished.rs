@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_CHUNK_BATCH_FINISHED;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Ends a chunk batch, the client answers with the rate it wants chunks at.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CHUNK_BATCH_FINISHED)]
+pub struct ChunkBatchFinished {
+    pub batch_size: VarInt,
+}
+
+impl ChunkBatchFinished {
+    pub fn new(batch_size: VarInt) -> Self {
+        Self { batch_size }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_CHUNK_BATCH_START;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(PLAY_CHUNK_BATCH_START)]
+pub struct ChunkBatchStart {}
+
+impl ChunkBatchStart {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
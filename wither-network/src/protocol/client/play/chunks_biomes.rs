@@ -0,0 +1,50 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_CHUNKS_BIOMES;
+use wither_macros::wither_packet;
+use wither_util::math::vector2::Vector2;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+const MAX_BIOME_DATA_SIZE: usize = 2097152;
+
+#[derive(Debug, Clone)]
+pub struct ChunkBiomeData {
+    pub pos: Vector2<i32>,
+    /// Biome paletted containers of every section in the chunk.
+    pub buffer: Bytes,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_CHUNKS_BIOMES)]
+pub struct ChunksBiomes {
+    pub chunk_biome_data: Vec<ChunkBiomeData>,
+}
+
+impl ChunksBiomes {
+    pub fn new(chunk_biome_data: Vec<ChunkBiomeData>) -> Self {
+        Self { chunk_biome_data }
+    }
+}
+
+impl Packet for ChunksBiomes {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            chunk_biome_data: bytebuf.get_list(|v| {
+                Ok(ChunkBiomeData {
+                    pos: v.try_get_chunk_pos()?,
+                    buffer: v.try_get_byte_array(MAX_BIOME_DATA_SIZE)?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.chunk_biome_data, |p, data| {
+            p.put_chunk_pos(&data.pos);
+            p.put_byte_array(&data.buffer);
+        });
+    }
+}
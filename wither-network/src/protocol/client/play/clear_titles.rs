@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_CLEAR_TITLES;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CLEAR_TITLES)]
+pub struct ClearTitles {
+    pub reset_times: bool,
+}
+
+impl ClearTitles {
+    pub fn new(reset_times: bool) -> Self {
+        Self { reset_times }
+    }
+}
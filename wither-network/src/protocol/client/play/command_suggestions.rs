@@ -0,0 +1,69 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_COMMAND_SUGGESTIONS;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct CommandSuggestion {
+    pub suggestion: String,
+    pub tooltip: Option<TextComponent>,
+}
+
+/// Tab completions for the command with transaction `id`.
+#[derive(Debug)]
+#[wither_packet(PLAY_COMMAND_SUGGESTIONS)]
+pub struct CommandSuggestions {
+    pub id: VarInt,
+    pub start: VarInt,
+    pub length: VarInt,
+    pub suggestions: Vec<CommandSuggestion>,
+}
+
+impl CommandSuggestions {
+    pub fn new(
+        id: VarInt,
+        start: VarInt,
+        length: VarInt,
+        suggestions: Vec<CommandSuggestion>,
+    ) -> Self {
+        Self {
+            id,
+            start,
+            length,
+            suggestions,
+        }
+    }
+}
+
+impl Packet for CommandSuggestions {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_var_int()?,
+            start: bytebuf.try_get_var_int()?,
+            length: bytebuf.try_get_var_int()?,
+            suggestions: bytebuf.get_list(|v| {
+                Ok(CommandSuggestion {
+                    suggestion: v.try_get_string()?,
+                    tooltip: v.try_get_option(|v| v.try_get_text_component())?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.id);
+        bytebuf.put_var_int(&self.start);
+        bytebuf.put_var_int(&self.length);
+        bytebuf.put_list(&self.suggestions, |p, suggestion| {
+            p.put_string(&suggestion.suggestion);
+            p.put_option(&suggestion.tooltip, |p, tooltip| {
+                p.put_text_component(tooltip)
+            });
+        });
+    }
+}
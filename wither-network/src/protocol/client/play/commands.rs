@@ -0,0 +1,305 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_COMMANDS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, VarInt,
+};
+
+const FLAG_EXECUTABLE: u8 = 0x04;
+const FLAG_REDIRECT: u8 = 0x08;
+const FLAG_CUSTOM_SUGGESTIONS: u8 = 0x10;
+
+const PARSER_FLOAT: i32 = 1;
+const PARSER_DOUBLE: i32 = 2;
+const PARSER_INTEGER: i32 = 3;
+const PARSER_LONG: i32 = 4;
+const PARSER_STRING: i32 = 5;
+const PARSER_ENTITY: i32 = 6;
+const PARSER_SCORE_HOLDER: i32 = 30;
+const PARSER_TIME: i32 = 42;
+const PARSER_RESOURCE_OR_TAG: i32 = 43;
+const PARSER_RESOURCE_KEY: i32 = 46;
+
+#[derive(Debug, Clone)]
+pub enum CommandNodeType {
+    Root,
+    Literal {
+        name: String,
+    },
+    Argument {
+        name: String,
+        /// Id in the `minecraft:command_argument_type` registry.
+        parser_id: VarInt,
+        properties: ArgumentProperties,
+        suggestions_type: Option<Identifier>,
+    },
+}
+
+/// Parser specific settings of an argument node.
+#[derive(Debug, Clone)]
+pub enum ArgumentProperties {
+    None,
+    Float {
+        min: Option<f32>,
+        max: Option<f32>,
+    },
+    Double {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Integer {
+        min: Option<i32>,
+        max: Option<i32>,
+    },
+    Long {
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    /// 0: single word, 1: quotable phrase, 2: greedy phrase
+    String(VarInt),
+    /// Flags of the entity or score holder selector.
+    Flags(u8),
+    Time {
+        min: i32,
+    },
+    Registry(Identifier),
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandNode {
+    pub node_type: CommandNodeType,
+    pub executable: bool,
+    pub children: Vec<VarInt>,
+    pub redirect: Option<VarInt>,
+}
+
+/// The whole command tree, nodes reference each other by their index in `nodes`.
+#[derive(Debug)]
+#[wither_packet(PLAY_COMMANDS)]
+pub struct Commands {
+    pub nodes: Vec<CommandNode>,
+    pub root_index: VarInt,
+}
+
+impl Commands {
+    pub fn new(nodes: Vec<CommandNode>, root_index: VarInt) -> Self {
+        Self { nodes, root_index }
+    }
+}
+
+impl Packet for Commands {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            nodes: bytebuf.get_list(CommandNode::read)?,
+            root_index: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.nodes, |p, node| node.write(p));
+        bytebuf.put_var_int(&self.root_index);
+    }
+}
+
+impl CommandNode {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let flags = bytebuf.try_get_u8()?;
+        let children = bytebuf.get_list(|v| v.try_get_var_int())?;
+        let redirect = if flags & FLAG_REDIRECT != 0 {
+            Some(bytebuf.try_get_var_int()?)
+        } else {
+            None
+        };
+        let node_type = match flags & 0x03 {
+            0 => CommandNodeType::Root,
+            1 => CommandNodeType::Literal {
+                name: bytebuf.try_get_string()?,
+            },
+            2 => {
+                let name = bytebuf.try_get_string()?;
+                let parser_id = bytebuf.try_get_var_int()?;
+                let properties = ArgumentProperties::read(bytebuf, parser_id.0)?;
+                let suggestions_type = if flags & FLAG_CUSTOM_SUGGESTIONS != 0 {
+                    Some(bytebuf.try_get_identifer()?)
+                } else {
+                    None
+                };
+                CommandNodeType::Argument {
+                    name,
+                    parser_id,
+                    properties,
+                    suggestions_type,
+                }
+            }
+            _ => {
+                return Err(ReadingError::Message(
+                    "Invalid command node type".to_string(),
+                ))
+            }
+        };
+        Ok(Self {
+            node_type,
+            executable: flags & FLAG_EXECUTABLE != 0,
+            children,
+            redirect,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        let mut flags = match &self.node_type {
+            CommandNodeType::Root => 0,
+            CommandNodeType::Literal { .. } => 1,
+            CommandNodeType::Argument {
+                suggestions_type, ..
+            } => {
+                if suggestions_type.is_some() {
+                    2 | FLAG_CUSTOM_SUGGESTIONS
+                } else {
+                    2
+                }
+            }
+        };
+        if self.executable {
+            flags |= FLAG_EXECUTABLE;
+        }
+        if self.redirect.is_some() {
+            flags |= FLAG_REDIRECT;
+        }
+
+        bytebuf.put_u8(flags);
+        bytebuf.put_list(&self.children, |p, child| p.put_var_int(child));
+        if let Some(redirect) = &self.redirect {
+            bytebuf.put_var_int(redirect);
+        }
+        match &self.node_type {
+            CommandNodeType::Root => {}
+            CommandNodeType::Literal { name } => bytebuf.put_string(name),
+            CommandNodeType::Argument {
+                name,
+                parser_id,
+                properties,
+                suggestions_type,
+            } => {
+                bytebuf.put_string(name);
+                bytebuf.put_var_int(parser_id);
+                properties.write(bytebuf);
+                if let Some(suggestions_type) = suggestions_type {
+                    bytebuf.put_identifier(suggestions_type);
+                }
+            }
+        }
+    }
+}
+
+impl ArgumentProperties {
+    fn read(bytebuf: &mut impl Buf, parser_id: i32) -> Result<Self, ReadingError> {
+        Ok(match parser_id {
+            PARSER_FLOAT => {
+                let flags = bytebuf.try_get_u8()?;
+                Self::Float {
+                    min: (flags & 0x01 != 0)
+                        .then(|| bytebuf.try_get_f32())
+                        .transpose()?,
+                    max: (flags & 0x02 != 0)
+                        .then(|| bytebuf.try_get_f32())
+                        .transpose()?,
+                }
+            }
+            PARSER_DOUBLE => {
+                let flags = bytebuf.try_get_u8()?;
+                Self::Double {
+                    min: (flags & 0x01 != 0)
+                        .then(|| bytebuf.try_get_f64())
+                        .transpose()?,
+                    max: (flags & 0x02 != 0)
+                        .then(|| bytebuf.try_get_f64())
+                        .transpose()?,
+                }
+            }
+            PARSER_INTEGER => {
+                let flags = bytebuf.try_get_u8()?;
+                Self::Integer {
+                    min: (flags & 0x01 != 0)
+                        .then(|| bytebuf.try_get_i32())
+                        .transpose()?,
+                    max: (flags & 0x02 != 0)
+                        .then(|| bytebuf.try_get_i32())
+                        .transpose()?,
+                }
+            }
+            PARSER_LONG => {
+                let flags = bytebuf.try_get_u8()?;
+                Self::Long {
+                    min: (flags & 0x01 != 0)
+                        .then(|| bytebuf.try_get_i64())
+                        .transpose()?,
+                    max: (flags & 0x02 != 0)
+                        .then(|| bytebuf.try_get_i64())
+                        .transpose()?,
+                }
+            }
+            PARSER_STRING => Self::String(bytebuf.try_get_var_int()?),
+            PARSER_ENTITY | PARSER_SCORE_HOLDER => Self::Flags(bytebuf.try_get_u8()?),
+            PARSER_TIME => Self::Time {
+                min: bytebuf.try_get_i32()?,
+            },
+            PARSER_RESOURCE_OR_TAG..=PARSER_RESOURCE_KEY => {
+                Self::Registry(bytebuf.try_get_identifer()?)
+            }
+            _ => Self::None,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        fn flags<T>(min: &Option<T>, max: &Option<T>) -> u8 {
+            min.is_some() as u8 | (max.is_some() as u8) << 1
+        }
+
+        match self {
+            Self::None => {}
+            Self::Float { min, max } => {
+                bytebuf.put_u8(flags(min, max));
+                if let Some(min) = min {
+                    bytebuf.put_f32(*min);
+                }
+                if let Some(max) = max {
+                    bytebuf.put_f32(*max);
+                }
+            }
+            Self::Double { min, max } => {
+                bytebuf.put_u8(flags(min, max));
+                if let Some(min) = min {
+                    bytebuf.put_f64(*min);
+                }
+                if let Some(max) = max {
+                    bytebuf.put_f64(*max);
+                }
+            }
+            Self::Integer { min, max } => {
+                bytebuf.put_u8(flags(min, max));
+                if let Some(min) = min {
+                    bytebuf.put_i32(*min);
+                }
+                if let Some(max) = max {
+                    bytebuf.put_i32(*max);
+                }
+            }
+            Self::Long { min, max } => {
+                bytebuf.put_u8(flags(min, max));
+                if let Some(min) = min {
+                    bytebuf.put_i64(*min);
+                }
+                if let Some(max) = max {
+                    bytebuf.put_i64(*max);
+                }
+            }
+            Self::String(behavior) => bytebuf.put_var_int(behavior),
+            Self::Flags(flags) => bytebuf.put_u8(*flags),
+            Self::Time { min } => bytebuf.put_i32(*min),
+            Self::Registry(registry) => bytebuf.put_identifier(registry),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_CONTAINER_CLOSE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CONTAINER_CLOSE)]
+pub struct ContainerClose {
+    pub container_id: VarInt,
+}
+
+impl ContainerClose {
+    pub fn new(container_id: VarInt) -> Self {
+        Self { container_id }
+    }
+}
@@ -0,0 +1,52 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_CONTAINER_SET_CONTENT;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::slot::Slot,
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_CONTAINER_SET_CONTENT)]
+pub struct ContainerSetContent {
+    pub container_id: VarInt,
+    pub state_id: VarInt,
+    pub items: Vec<Slot>,
+    pub carried_item: Slot,
+}
+
+impl ContainerSetContent {
+    pub fn new(
+        container_id: VarInt,
+        state_id: VarInt,
+        items: Vec<Slot>,
+        carried_item: Slot,
+    ) -> Self {
+        Self {
+            container_id,
+            state_id,
+            items,
+            carried_item,
+        }
+    }
+}
+
+impl Packet for ContainerSetContent {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            container_id: bytebuf.try_get_var_int()?,
+            state_id: bytebuf.try_get_var_int()?,
+            items: bytebuf.get_list(Slot::read)?,
+            carried_item: Slot::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.container_id);
+        bytebuf.put_var_int(&self.state_id);
+        bytebuf.put_list(&self.items, |p, item| item.write(p));
+        self.carried_item.write(bytebuf);
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_CONTAINER_SET_DATA;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Updates a container property, like the progress arrow of a furnace.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CONTAINER_SET_DATA)]
+pub struct ContainerSetData {
+    pub container_id: VarInt,
+    pub id: i16,
+    pub value: i16,
+}
+
+impl ContainerSetData {
+    pub fn new(container_id: VarInt, id: i16, value: i16) -> Self {
+        Self {
+            container_id,
+            id,
+            value,
+        }
+    }
+}
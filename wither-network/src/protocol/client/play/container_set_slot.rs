@@ -0,0 +1,47 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_CONTAINER_SET_SLOT;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::slot::Slot,
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_CONTAINER_SET_SLOT)]
+pub struct ContainerSetSlot {
+    pub container_id: VarInt,
+    pub state_id: VarInt,
+    pub slot: i16,
+    pub item: Slot,
+}
+
+impl ContainerSetSlot {
+    pub fn new(container_id: VarInt, state_id: VarInt, slot: i16, item: Slot) -> Self {
+        Self {
+            container_id,
+            state_id,
+            slot,
+            item,
+        }
+    }
+}
+
+impl Packet for ContainerSetSlot {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            container_id: bytebuf.try_get_var_int()?,
+            state_id: bytebuf.try_get_var_int()?,
+            slot: bytebuf.try_get_i16()?,
+            item: Slot::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.container_id);
+        bytebuf.put_var_int(&self.state_id);
+        bytebuf.put_i16(self.slot);
+        self.item.write(bytebuf);
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_COOKIE_REQUEST;
+use wither_macros::wither_packet;
+
+use crate::codec::identifier::Identifier;
+
+/// Requests a cookie that was previously stored.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_COOKIE_REQUEST)]
+pub struct CookieRequest {
+    pub key: Identifier,
+}
+
+impl CookieRequest {
+    pub fn new(key: Identifier) -> Self {
+        Self { key }
+    }
+}
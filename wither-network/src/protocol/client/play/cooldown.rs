@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_COOLDOWN;
+use wither_macros::wither_packet;
+
+use crate::{codec::identifier::Identifier, VarInt};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_COOLDOWN)]
+pub struct Cooldown {
+    pub cooldown_group: Identifier,
+    pub duration: VarInt,
+}
+
+impl Cooldown {
+    pub fn new(cooldown_group: Identifier, duration: VarInt) -> Self {
+        Self {
+            cooldown_group,
+            duration,
+        }
+    }
+}
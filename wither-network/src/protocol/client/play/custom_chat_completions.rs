@@ -0,0 +1,58 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_CUSTOM_CHAT_COMPLETIONS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatCompletionAction {
+    Add,
+    Remove,
+    Set,
+}
+
+impl TryFrom<VarInt> for ChatCompletionAction {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Add),
+            1 => Ok(Self::Remove),
+            2 => Ok(Self::Set),
+            _ => Err(ReadingError::Message(
+                "Invalid chat completion action".to_string(),
+            )),
+        }
+    }
+}
+
+/// Extra entries suggested when tab completing chat messages.
+#[derive(Debug)]
+#[wither_packet(PLAY_CUSTOM_CHAT_COMPLETIONS)]
+pub struct CustomChatCompletions {
+    pub action: ChatCompletionAction,
+    pub entries: Vec<String>,
+}
+
+impl CustomChatCompletions {
+    pub fn new(action: ChatCompletionAction, entries: Vec<String>) -> Self {
+        Self { action, entries }
+    }
+}
+
+impl Packet for CustomChatCompletions {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            action: ChatCompletionAction::try_from(bytebuf.try_get_var_int()?)?,
+            entries: bytebuf.get_list(|v| v.try_get_string())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.action as i32));
+        bytebuf.put_list(&self.entries, |p, entry| p.put_string(entry));
+    }
+}
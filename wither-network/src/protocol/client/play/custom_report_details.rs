@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_CUSTOM_REPORT_DETAILS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::ReadingError, protocol::client::config::CustomReportDetails as ConfigDetails, Packet,
+};
+
+/// Extra key/value details the client includes in crash reports.
+#[derive(Debug)]
+#[wither_packet(PLAY_CUSTOM_REPORT_DETAILS)]
+pub struct CustomReportDetails {
+    pub details: Vec<(String, String)>,
+}
+
+impl CustomReportDetails {
+    pub fn new(details: Vec<(String, String)>) -> Self {
+        Self { details }
+    }
+}
+
+impl Packet for CustomReportDetails {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            details: ConfigDetails::read_details(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        ConfigDetails::write_details(&self.details, bytebuf);
+    }
+}
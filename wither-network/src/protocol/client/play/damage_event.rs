@@ -0,0 +1,70 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_DAMAGE_EVENT;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_DAMAGE_EVENT)]
+pub struct DamageEvent {
+    pub entity_id: VarInt,
+    /// Id in the `minecraft:damage_type` registry.
+    pub source_type_id: VarInt,
+    pub source_cause_id: Option<VarInt>,
+    pub source_direct_id: Option<VarInt>,
+    pub source_position: Option<Vector3<f64>>,
+}
+
+impl DamageEvent {
+    pub fn new(
+        entity_id: VarInt,
+        source_type_id: VarInt,
+        source_cause_id: Option<VarInt>,
+        source_direct_id: Option<VarInt>,
+        source_position: Option<Vector3<f64>>,
+    ) -> Self {
+        Self {
+            entity_id,
+            source_type_id,
+            source_cause_id,
+            source_direct_id,
+            source_position,
+        }
+    }
+
+    // Entity ids are sent as `id + 1`, `0` meaning there is none.
+    fn read_entity_id(bytebuf: &mut impl Buf) -> Result<Option<VarInt>, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(None),
+            id => Ok(Some(VarInt(id - 1))),
+        }
+    }
+
+    fn write_entity_id(bytebuf: &mut impl BufMut, id: &Option<VarInt>) {
+        bytebuf.put_var_int(&VarInt(id.as_ref().map_or(0, |id| id.0 + 1)));
+    }
+}
+
+impl Packet for DamageEvent {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            source_type_id: bytebuf.try_get_var_int()?,
+            source_cause_id: Self::read_entity_id(bytebuf)?,
+            source_direct_id: Self::read_entity_id(bytebuf)?,
+            source_position: bytebuf.try_get_option(|v| v.try_get_vec3())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_var_int(&self.source_type_id);
+        Self::write_entity_id(bytebuf, &self.source_cause_id);
+        Self::write_entity_id(bytebuf, &self.source_direct_id);
+        bytebuf.put_option(&self.source_position, |p, position| p.put_vec3(position));
+    }
+}
@@ -0,0 +1,39 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_DEBUG_SAMPLE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Tick time samples for the debug screen, only sent to subscribed operators.
+#[derive(Debug)]
+#[wither_packet(PLAY_DEBUG_SAMPLE)]
+pub struct DebugSample {
+    pub sample: Vec<i64>,
+    pub sample_type: VarInt,
+}
+
+impl DebugSample {
+    pub fn new(sample: Vec<i64>, sample_type: VarInt) -> Self {
+        Self {
+            sample,
+            sample_type,
+        }
+    }
+}
+
+impl Packet for DebugSample {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            sample: bytebuf.get_list(|v| v.try_get_i64())?,
+            sample_type: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.sample, |p, value| p.put_i64(*value));
+        bytebuf.put_var_int(&self.sample_type);
+    }
+}
@@ -0,0 +1,30 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_DELETE_CHAT;
+use wither_macros::wither_packet;
+
+use crate::{bytebuf::ReadingError, types::PackedSignature, Packet};
+
+/// Removes a signed message from the chat.
+#[derive(Debug)]
+#[wither_packet(PLAY_DELETE_CHAT)]
+pub struct DeleteChat {
+    pub message_signature: PackedSignature,
+}
+
+impl DeleteChat {
+    pub fn new(message_signature: PackedSignature) -> Self {
+        Self { message_signature }
+    }
+}
+
+impl Packet for DeleteChat {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            message_signature: PackedSignature::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.message_signature.write(bytebuf);
+    }
+}
@@ -0,0 +1,38 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_DISGUISED_CHAT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::ChatTypeBound,
+    Packet,
+};
+
+/// An unsigned chat message that is still decorated with a chat type.
+#[derive(Debug)]
+#[wither_packet(PLAY_DISGUISED_CHAT)]
+pub struct DisguisedChat {
+    pub message: TextComponent,
+    pub chat_type: ChatTypeBound,
+}
+
+impl DisguisedChat {
+    pub fn new(message: TextComponent, chat_type: ChatTypeBound) -> Self {
+        Self { message, chat_type }
+    }
+}
+
+impl Packet for DisguisedChat {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            message: bytebuf.try_get_text_component()?,
+            chat_type: ChatTypeBound::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.message);
+        self.chat_type.write(bytebuf);
+    }
+}
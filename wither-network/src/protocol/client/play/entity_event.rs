@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_ENTITY_EVENT;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ENTITY_EVENT)]
+pub struct EntityEvent {
+    pub entity_id: i32,
+    pub event: i8,
+}
+
+impl EntityEvent {
+    pub fn new(entity_id: i32, event: i8) -> Self {
+        Self { entity_id, event }
+    }
+}
@@ -0,0 +1,45 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_ENTITY_POSITION_SYNC;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+use super::PositionMoveRotation;
+
+/// Periodically corrects the client side position of an entity.
+#[derive(Debug)]
+#[wither_packet(PLAY_ENTITY_POSITION_SYNC)]
+pub struct EntityPositionSync {
+    pub entity_id: VarInt,
+    pub values: PositionMoveRotation,
+    pub on_ground: bool,
+}
+
+impl EntityPositionSync {
+    pub fn new(entity_id: VarInt, values: PositionMoveRotation, on_ground: bool) -> Self {
+        Self {
+            entity_id,
+            values,
+            on_ground,
+        }
+    }
+}
+
+impl Packet for EntityPositionSync {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            values: PositionMoveRotation::read(bytebuf)?,
+            on_ground: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        self.values.write(bytebuf);
+        bytebuf.put_bool(self.on_ground);
+    }
+}
@@ -0,0 +1,54 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_EXPLODE;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::ParticleOptions,
+    IDOrSoundEvent, Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_EXPLODE)]
+pub struct Explode {
+    pub center: Vector3<f64>,
+    /// Velocity added to the player, if they were hit.
+    pub player_knockback: Option<Vector3<f64>>,
+    pub explosion_particle: ParticleOptions,
+    pub explosion_sound: IDOrSoundEvent,
+}
+
+impl Explode {
+    pub fn new(
+        center: Vector3<f64>,
+        player_knockback: Option<Vector3<f64>>,
+        explosion_particle: ParticleOptions,
+        explosion_sound: IDOrSoundEvent,
+    ) -> Self {
+        Self {
+            center,
+            player_knockback,
+            explosion_particle,
+            explosion_sound,
+        }
+    }
+}
+
+impl Packet for Explode {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            center: bytebuf.try_get_vec3()?,
+            player_knockback: bytebuf.try_get_option(|v| v.try_get_vec3())?,
+            explosion_particle: ParticleOptions::read(bytebuf)?,
+            explosion_sound: IDOrSoundEvent::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_vec3(&self.center);
+        bytebuf.put_option(&self.player_knockback, |p, knockback| p.put_vec3(knockback));
+        self.explosion_particle.write(bytebuf);
+        self.explosion_sound.write(bytebuf);
+    }
+}
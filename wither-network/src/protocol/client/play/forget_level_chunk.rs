@@ -0,0 +1,34 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_FORGET_LEVEL_CHUNK;
+use wither_macros::wither_packet;
+use wither_util::math::vector2::Vector2;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+/// Unloads a chunk on the client.
+#[derive(Debug)]
+#[wither_packet(PLAY_FORGET_LEVEL_CHUNK)]
+pub struct ForgetLevelChunk {
+    pub pos: Vector2<i32>,
+}
+
+impl ForgetLevelChunk {
+    pub fn new(pos: Vector2<i32>) -> Self {
+        Self { pos }
+    }
+}
+
+impl Packet for ForgetLevelChunk {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            pos: bytebuf.try_get_chunk_pos()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_chunk_pos(&self.pos);
+    }
+}
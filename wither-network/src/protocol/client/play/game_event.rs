@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_GAME_EVENT;
+use wither_macros::wither_packet;
+
+/// Changes game state, e.g. the game mode or the rain level.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_GAME_EVENT)]
+pub struct GameEvent {
+    pub event: u8,
+    pub param: f32,
+}
+
+impl GameEvent {
+    pub fn new(event: u8, param: f32) -> Self {
+        Self { event, param }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_HORSE_SCREEN_OPEN;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_HORSE_SCREEN_OPEN)]
+pub struct HorseScreenOpen {
+    pub container_id: VarInt,
+    pub inventory_columns: VarInt,
+    pub entity_id: i32,
+}
+
+impl HorseScreenOpen {
+    pub fn new(container_id: VarInt, inventory_columns: VarInt, entity_id: i32) -> Self {
+        Self {
+            container_id,
+            inventory_columns,
+            entity_id,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_HURT_ANIMATION;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_HURT_ANIMATION)]
+pub struct HurtAnimation {
+    pub entity_id: VarInt,
+    pub yaw: f32,
+}
+
+impl HurtAnimation {
+    pub fn new(entity_id: VarInt, yaw: f32) -> Self {
+        Self { entity_id, yaw }
+    }
+}
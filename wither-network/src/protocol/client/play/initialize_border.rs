@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_INITIALIZE_BORDER;
+use wither_macros::wither_packet;
+
+use crate::{codec::var_long::VarLong, VarInt};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_INITIALIZE_BORDER)]
+pub struct InitializeBorder {
+    pub center_x: f64,
+    pub center_z: f64,
+    pub old_size: f64,
+    pub new_size: f64,
+    pub lerp_time: VarLong,
+    pub absolute_max_size: VarInt,
+    pub warning_blocks: VarInt,
+    pub warning_time: VarInt,
+}
+
+impl InitializeBorder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        center_x: f64,
+        center_z: f64,
+        old_size: f64,
+        new_size: f64,
+        lerp_time: VarLong,
+        absolute_max_size: VarInt,
+        warning_blocks: VarInt,
+        warning_time: VarInt,
+    ) -> Self {
+        Self {
+            center_x,
+            center_z,
+            old_size,
+            new_size,
+            lerp_time,
+            absolute_max_size,
+            warning_blocks,
+            warning_time,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_LEVEL_CHUNK_WITH_LIGHT;
+use wither_macros::wither_packet;
+use wither_nbt::tag::NbtTag;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+use super::LightData;
+
+const MAX_CHUNK_DATA_SIZE: usize = 2097152;
+
+#[derive(Debug, Clone)]
+pub struct ChunkBlockEntity {
+    /// Section relative x in the high and z in the low nibble.
+    pub packed_xz: u8,
+    pub y: i16,
+    pub block_entity_type: VarInt,
+    pub nbt: NbtTag,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_LEVEL_CHUNK_WITH_LIGHT)]
+pub struct LevelChunkWithLight {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub heightmaps: NbtTag,
+    /// The chunk sections, from the bottom of the world up.
+    pub data: Bytes,
+    pub block_entities: Vec<ChunkBlockEntity>,
+    pub light_data: LightData,
+}
+
+impl LevelChunkWithLight {
+    pub fn new(
+        chunk_x: i32,
+        chunk_z: i32,
+        heightmaps: NbtTag,
+        data: Bytes,
+        block_entities: Vec<ChunkBlockEntity>,
+        light_data: LightData,
+    ) -> Self {
+        Self {
+            chunk_x,
+            chunk_z,
+            heightmaps,
+            data,
+            block_entities,
+            light_data,
+        }
+    }
+}
+
+impl Packet for LevelChunkWithLight {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            chunk_x: bytebuf.try_get_i32()?,
+            chunk_z: bytebuf.try_get_i32()?,
+            heightmaps: bytebuf.try_get_nbt()?,
+            data: bytebuf.try_get_byte_array(MAX_CHUNK_DATA_SIZE)?,
+            block_entities: bytebuf.get_list(|v| {
+                Ok(ChunkBlockEntity {
+                    packed_xz: v.try_get_u8()?,
+                    y: v.try_get_i16()?,
+                    block_entity_type: v.try_get_var_int()?,
+                    nbt: v.try_get_nbt()?,
+                })
+            })?,
+            light_data: LightData::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_i32(self.chunk_x);
+        bytebuf.put_i32(self.chunk_z);
+        bytebuf.put_nbt(&self.heightmaps);
+        bytebuf.put_byte_array(&self.data);
+        bytebuf.put_list(&self.block_entities, |p, block_entity| {
+            p.put_u8(block_entity.packed_xz);
+            p.put_i16(block_entity.y);
+            p.put_var_int(&block_entity.block_entity_type);
+            p.put_nbt(&block_entity.nbt);
+        });
+        self.light_data.write(bytebuf);
+    }
+}
@@ -0,0 +1,49 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_LEVEL_EVENT;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+/// Plays a world event like a door sound or the block break particles.
+#[derive(Debug)]
+#[wither_packet(PLAY_LEVEL_EVENT)]
+pub struct LevelEvent {
+    pub event: i32,
+    pub location: WorldPosition,
+    pub data: i32,
+    /// Play the event for every player regardless of distance.
+    pub global: bool,
+}
+
+impl LevelEvent {
+    pub fn new(event: i32, location: WorldPosition, data: i32, global: bool) -> Self {
+        Self {
+            event,
+            location,
+            data,
+            global,
+        }
+    }
+}
+
+impl Packet for LevelEvent {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            event: bytebuf.try_get_i32()?,
+            location: bytebuf.try_get_position()?,
+            data: bytebuf.try_get_i32()?,
+            global: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_i32(self.event);
+        bytebuf.put_position(&self.location);
+        bytebuf.put_i32(self.data);
+        bytebuf.put_bool(self.global);
+    }
+}
@@ -0,0 +1,74 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_LEVEL_PARTICLES;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::ParticleOptions,
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_LEVEL_PARTICLES)]
+pub struct LevelParticles {
+    pub override_limiter: bool,
+    pub always_show: bool,
+    pub position: Vector3<f64>,
+    pub offset: Vector3<f32>,
+    pub max_speed: f32,
+    pub particle_count: i32,
+    pub particle: ParticleOptions,
+}
+
+impl LevelParticles {
+    pub fn new(
+        override_limiter: bool,
+        always_show: bool,
+        position: Vector3<f64>,
+        offset: Vector3<f32>,
+        max_speed: f32,
+        particle_count: i32,
+        particle: ParticleOptions,
+    ) -> Self {
+        Self {
+            override_limiter,
+            always_show,
+            position,
+            offset,
+            max_speed,
+            particle_count,
+            particle,
+        }
+    }
+}
+
+impl Packet for LevelParticles {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            override_limiter: bytebuf.try_get_bool()?,
+            always_show: bytebuf.try_get_bool()?,
+            position: bytebuf.try_get_vec3()?,
+            offset: Vector3::new(
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+            ),
+            max_speed: bytebuf.try_get_f32()?,
+            particle_count: bytebuf.try_get_i32()?,
+            particle: ParticleOptions::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_bool(self.override_limiter);
+        bytebuf.put_bool(self.always_show);
+        bytebuf.put_vec3(&self.position);
+        bytebuf.put_f32(self.offset.x);
+        bytebuf.put_f32(self.offset.y);
+        bytebuf.put_f32(self.offset.z);
+        bytebuf.put_f32(self.max_speed);
+        bytebuf.put_i32(self.particle_count);
+        self.particle.write(bytebuf);
+    }
+}
@@ -0,0 +1,81 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_LIGHT_UPDATE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::bit_set::BitSet,
+    Packet, VarInt,
+};
+
+const LIGHT_ARRAY_SIZE: usize = 2048;
+
+/// Light of one chunk column. Bit `i` of the masks is the section `i - 1` counted from
+/// below the world, so there is one extra section above and below.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LightData {
+    pub sky_y_mask: BitSet,
+    pub block_y_mask: BitSet,
+    /// Sections whose light is all zero.
+    pub empty_sky_y_mask: BitSet,
+    pub empty_block_y_mask: BitSet,
+    /// One nibble array per set bit in `sky_y_mask`.
+    pub sky_updates: Vec<Bytes>,
+    pub block_updates: Vec<Bytes>,
+}
+
+impl LightData {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            sky_y_mask: bytebuf.try_get_bit_set()?,
+            block_y_mask: bytebuf.try_get_bit_set()?,
+            empty_sky_y_mask: bytebuf.try_get_bit_set()?,
+            empty_block_y_mask: bytebuf.try_get_bit_set()?,
+            sky_updates: bytebuf.get_list(|v| v.try_get_byte_array(LIGHT_ARRAY_SIZE))?,
+            block_updates: bytebuf.get_list(|v| v.try_get_byte_array(LIGHT_ARRAY_SIZE))?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_bit_set(&self.sky_y_mask);
+        bytebuf.put_bit_set(&self.block_y_mask);
+        bytebuf.put_bit_set(&self.empty_sky_y_mask);
+        bytebuf.put_bit_set(&self.empty_block_y_mask);
+        bytebuf.put_list(&self.sky_updates, |p, light| p.put_byte_array(light));
+        bytebuf.put_list(&self.block_updates, |p, light| p.put_byte_array(light));
+    }
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_LIGHT_UPDATE)]
+pub struct LightUpdate {
+    pub chunk_x: VarInt,
+    pub chunk_z: VarInt,
+    pub light_data: LightData,
+}
+
+impl LightUpdate {
+    pub fn new(chunk_x: VarInt, chunk_z: VarInt, light_data: LightData) -> Self {
+        Self {
+            chunk_x,
+            chunk_z,
+            light_data,
+        }
+    }
+}
+
+impl Packet for LightUpdate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            chunk_x: bytebuf.try_get_var_int()?,
+            chunk_z: bytebuf.try_get_var_int()?,
+            light_data: LightData::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.chunk_x);
+        bytebuf.put_var_int(&self.chunk_z);
+        self.light_data.write(bytebuf);
+    }
+}
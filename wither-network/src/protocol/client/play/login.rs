@@ -0,0 +1,141 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_LOGIN;
+use wither_macros::wither_packet;
+use wither_util::{math::position::WorldPosition, GameMode};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, VarInt,
+};
+
+/// Dimension and game mode info shared by the login and respawn packets.
+#[derive(Debug, Clone)]
+pub struct CommonPlayerSpawnInfo {
+    /// Id in the `minecraft:dimension_type` registry.
+    pub dimension_type: VarInt,
+    pub dimension_name: Identifier,
+    /// First 8 bytes of the SHA-256 hash of the world seed.
+    pub hashed_seed: i64,
+    pub game_mode: GameMode,
+    pub previous_game_mode: GameMode,
+    pub debug: bool,
+    pub is_flat: bool,
+    pub death_location: Option<(Identifier, WorldPosition)>,
+    pub portal_cooldown: VarInt,
+    pub sea_level: VarInt,
+}
+
+impl CommonPlayerSpawnInfo {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            dimension_type: bytebuf.try_get_var_int()?,
+            dimension_name: bytebuf.try_get_identifer()?,
+            hashed_seed: bytebuf.try_get_i64()?,
+            game_mode: GameMode::from(bytebuf.try_get_i8()?),
+            previous_game_mode: GameMode::from(bytebuf.try_get_i8()?),
+            debug: bytebuf.try_get_bool()?,
+            is_flat: bytebuf.try_get_bool()?,
+            death_location: bytebuf
+                .try_get_option(|v| Ok((v.try_get_identifer()?, v.try_get_position()?)))?,
+            portal_cooldown: bytebuf.try_get_var_int()?,
+            sea_level: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.dimension_type);
+        bytebuf.put_identifier(&self.dimension_name);
+        bytebuf.put_i64(self.hashed_seed);
+        bytebuf.put_i8(self.game_mode as i8);
+        bytebuf.put_i8(self.previous_game_mode as i8);
+        bytebuf.put_bool(self.debug);
+        bytebuf.put_bool(self.is_flat);
+        bytebuf.put_option(&self.death_location, |p, (dimension, position)| {
+            p.put_identifier(dimension);
+            p.put_position(position);
+        });
+        bytebuf.put_var_int(&self.portal_cooldown);
+        bytebuf.put_var_int(&self.sea_level);
+    }
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_LOGIN)]
+pub struct Login {
+    pub entity_id: i32,
+    pub is_hardcore: bool,
+    pub dimension_names: Vec<Identifier>,
+    /// Unused by the client.
+    pub max_players: VarInt,
+    pub view_distance: VarInt,
+    pub simulation_distance: VarInt,
+    pub reduced_debug_info: bool,
+    pub enable_respawn_screen: bool,
+    pub do_limited_crafting: bool,
+    pub spawn_info: CommonPlayerSpawnInfo,
+    pub enforces_secure_chat: bool,
+}
+
+impl Login {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        entity_id: i32,
+        is_hardcore: bool,
+        dimension_names: Vec<Identifier>,
+        max_players: VarInt,
+        view_distance: VarInt,
+        simulation_distance: VarInt,
+        reduced_debug_info: bool,
+        enable_respawn_screen: bool,
+        do_limited_crafting: bool,
+        spawn_info: CommonPlayerSpawnInfo,
+        enforces_secure_chat: bool,
+    ) -> Self {
+        Self {
+            entity_id,
+            is_hardcore,
+            dimension_names,
+            max_players,
+            view_distance,
+            simulation_distance,
+            reduced_debug_info,
+            enable_respawn_screen,
+            do_limited_crafting,
+            spawn_info,
+            enforces_secure_chat,
+        }
+    }
+}
+
+impl Packet for Login {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_i32()?,
+            is_hardcore: bytebuf.try_get_bool()?,
+            dimension_names: bytebuf.get_list(|v| v.try_get_identifer())?,
+            max_players: bytebuf.try_get_var_int()?,
+            view_distance: bytebuf.try_get_var_int()?,
+            simulation_distance: bytebuf.try_get_var_int()?,
+            reduced_debug_info: bytebuf.try_get_bool()?,
+            enable_respawn_screen: bytebuf.try_get_bool()?,
+            do_limited_crafting: bytebuf.try_get_bool()?,
+            spawn_info: CommonPlayerSpawnInfo::read(bytebuf)?,
+            enforces_secure_chat: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_i32(self.entity_id);
+        bytebuf.put_bool(self.is_hardcore);
+        bytebuf.put_list(&self.dimension_names, |p, name| p.put_identifier(name));
+        bytebuf.put_var_int(&self.max_players);
+        bytebuf.put_var_int(&self.view_distance);
+        bytebuf.put_var_int(&self.simulation_distance);
+        bytebuf.put_bool(self.reduced_debug_info);
+        bytebuf.put_bool(self.enable_respawn_screen);
+        bytebuf.put_bool(self.do_limited_crafting);
+        self.spawn_info.write(bytebuf);
+        bytebuf.put_bool(self.enforces_secure_chat);
+    }
+}
@@ -0,0 +1,121 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_MAP_ITEM_DATA;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct MapDecoration {
+    /// Id in the `minecraft:map_decoration_type` registry.
+    pub decoration_type: VarInt,
+    pub x: i8,
+    pub y: i8,
+    pub rotation: i8,
+    pub name: Option<TextComponent>,
+}
+
+/// A rectangle of map colors that changed.
+#[derive(Debug, Clone)]
+pub struct MapPatch {
+    pub width: u8,
+    pub height: u8,
+    pub start_x: u8,
+    pub start_y: u8,
+    pub colors: Bytes,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_MAP_ITEM_DATA)]
+pub struct MapItemData {
+    pub map_id: VarInt,
+    pub scale: i8,
+    pub locked: bool,
+    pub decorations: Option<Vec<MapDecoration>>,
+    pub color_patch: Option<MapPatch>,
+}
+
+impl MapItemData {
+    pub fn new(
+        map_id: VarInt,
+        scale: i8,
+        locked: bool,
+        decorations: Option<Vec<MapDecoration>>,
+        color_patch: Option<MapPatch>,
+    ) -> Self {
+        Self {
+            map_id,
+            scale,
+            locked,
+            decorations,
+            color_patch,
+        }
+    }
+}
+
+impl Packet for MapItemData {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let map_id = bytebuf.try_get_var_int()?;
+        let scale = bytebuf.try_get_i8()?;
+        let locked = bytebuf.try_get_bool()?;
+        let decorations = bytebuf.try_get_option(|v| {
+            v.get_list(|v| {
+                Ok(MapDecoration {
+                    decoration_type: v.try_get_var_int()?,
+                    x: v.try_get_i8()?,
+                    y: v.try_get_i8()?,
+                    rotation: v.try_get_i8()?,
+                    name: v.try_get_option(|v| v.try_get_text_component())?,
+                })
+            })
+        })?;
+        // A width of 0 means there is no patch
+        let width = bytebuf.try_get_u8()?;
+        let color_patch = if width > 0 {
+            Some(MapPatch {
+                width,
+                height: bytebuf.try_get_u8()?,
+                start_x: bytebuf.try_get_u8()?,
+                start_y: bytebuf.try_get_u8()?,
+                colors: bytebuf.try_get_byte_array(128 * 128)?,
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            map_id,
+            scale,
+            locked,
+            decorations,
+            color_patch,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.map_id);
+        bytebuf.put_i8(self.scale);
+        bytebuf.put_bool(self.locked);
+        bytebuf.put_option(&self.decorations, |p, decorations| {
+            p.put_list(decorations, |p, decoration| {
+                p.put_var_int(&decoration.decoration_type);
+                p.put_i8(decoration.x);
+                p.put_i8(decoration.y);
+                p.put_i8(decoration.rotation);
+                p.put_option(&decoration.name, |p, name| p.put_text_component(name));
+            });
+        });
+        match &self.color_patch {
+            Some(patch) => {
+                bytebuf.put_u8(patch.width);
+                bytebuf.put_u8(patch.height);
+                bytebuf.put_u8(patch.start_x);
+                bytebuf.put_u8(patch.start_y);
+                bytebuf.put_byte_array(&patch.colors);
+            }
+            None => bytebuf.put_u8(0),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_MERCHANT_OFFERS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::{item_component::ItemComponent, slot::Slot},
+    Packet, VarInt,
+};
+
+/// Items a trade asks for, matching stacks must have all of `components`.
+#[derive(Debug, Clone)]
+pub struct ItemCost {
+    pub item_id: VarInt,
+    pub count: VarInt,
+    pub components: Vec<ItemComponent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MerchantOffer {
+    pub base_cost_a: ItemCost,
+    pub result: Slot,
+    pub cost_b: Option<ItemCost>,
+    pub out_of_stock: bool,
+    pub uses: i32,
+    pub max_uses: i32,
+    pub xp: i32,
+    pub special_price_diff: i32,
+    pub price_multiplier: f32,
+    pub demand: i32,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_MERCHANT_OFFERS)]
+pub struct MerchantOffers {
+    pub container_id: VarInt,
+    pub offers: Vec<MerchantOffer>,
+    pub villager_level: VarInt,
+    pub villager_xp: VarInt,
+    pub show_progress: bool,
+    pub can_restock: bool,
+}
+
+impl MerchantOffers {
+    pub fn new(
+        container_id: VarInt,
+        offers: Vec<MerchantOffer>,
+        villager_level: VarInt,
+        villager_xp: VarInt,
+        show_progress: bool,
+        can_restock: bool,
+    ) -> Self {
+        Self {
+            container_id,
+            offers,
+            villager_level,
+            villager_xp,
+            show_progress,
+            can_restock,
+        }
+    }
+}
+
+impl ItemCost {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            item_id: bytebuf.try_get_var_int()?,
+            count: bytebuf.try_get_var_int()?,
+            components: bytebuf.get_list(ItemComponent::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.item_id);
+        bytebuf.put_var_int(&self.count);
+        bytebuf.put_list(&self.components, |p, component| component.write(p));
+    }
+}
+
+impl MerchantOffer {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            base_cost_a: ItemCost::read(bytebuf)?,
+            result: Slot::read(bytebuf)?,
+            cost_b: bytebuf.try_get_option(ItemCost::read)?,
+            out_of_stock: bytebuf.try_get_bool()?,
+            uses: bytebuf.try_get_i32()?,
+            max_uses: bytebuf.try_get_i32()?,
+            xp: bytebuf.try_get_i32()?,
+            special_price_diff: bytebuf.try_get_i32()?,
+            price_multiplier: bytebuf.try_get_f32()?,
+            demand: bytebuf.try_get_i32()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.base_cost_a.write(bytebuf);
+        self.result.write(bytebuf);
+        bytebuf.put_option(&self.cost_b, |p, cost| cost.write(p));
+        bytebuf.put_bool(self.out_of_stock);
+        bytebuf.put_i32(self.uses);
+        bytebuf.put_i32(self.max_uses);
+        bytebuf.put_i32(self.xp);
+        bytebuf.put_i32(self.special_price_diff);
+        bytebuf.put_f32(self.price_multiplier);
+        bytebuf.put_i32(self.demand);
+    }
+}
+
+impl Packet for MerchantOffers {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            container_id: bytebuf.try_get_var_int()?,
+            offers: bytebuf.get_list(MerchantOffer::read)?,
+            villager_level: bytebuf.try_get_var_int()?,
+            villager_xp: bytebuf.try_get_var_int()?,
+            show_progress: bytebuf.try_get_bool()?,
+            can_restock: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.container_id);
+        bytebuf.put_list(&self.offers, |p, offer| offer.write(p));
+        bytebuf.put_var_int(&self.villager_level);
+        bytebuf.put_var_int(&self.villager_xp);
+        bytebuf.put_bool(self.show_progress);
+        bytebuf.put_bool(self.can_restock);
+    }
+}
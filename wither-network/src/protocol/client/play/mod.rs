@@ -0,0 +1,600 @@
+mod add_entity;
+mod add_experience_orb;
+mod animate;
+mod award_stats;
+mod block_changed_ack;
+mod block_destruction;
+mod block_entity_data;
+mod block_event;
+mod block_update;
+mod boss_event;
+mod bundle_delimiter;
+mod change_difficulty;
+mod chunk_batch_finished;
+mod chunk_batch_start;
+mod chunks_biomes;
+mod clear_titles;
+mod command_suggestions;
+mod commands;
+mod container_close;
+mod container_set_content;
+mod container_set_data;
+mod container_set_slot;
+mod cookie_request;
+mod cooldown;
+mod custom_chat_completions;
+mod custom_payload;
+mod custom_report_details;
+mod damage_event;
+mod debug_sample;
+mod delete_chat;
+mod disconnect;
+mod disguised_chat;
+mod entity_event;
+mod entity_position_sync;
+mod explode;
+mod forget_level_chunk;
+mod game_event;
+mod horse_screen_open;
+mod hurt_animation;
+mod initialize_border;
+mod keep_alive;
+mod level_chunk_with_light;
+mod level_event;
+mod level_particles;
+mod light_update;
+mod login;
+mod map_item_data;
+mod merchant_offers;
+mod move_entity_pos;
+mod move_entity_pos_rot;
+mod move_entity_rot;
+mod move_minecart_along_track;
+mod move_vehicle;
+mod open_book;
+mod open_screen;
+mod open_sign_editor;
+mod ping;
+mod place_ghost_recipe;
+mod player_abilities;
+mod player_chat;
+mod player_combat_end;
+mod player_combat_enter;
+mod player_combat_kill;
+mod player_info_remove;
+mod player_info_update;
+mod player_look_at;
+mod player_position;
+mod player_rotation;
+mod pong_response;
+mod projectile_power;
+mod recipe_book_add;
+mod recipe_book_remove;
+mod recipe_book_settings;
+mod remove_entities;
+mod remove_mob_effect;
+mod reset_score;
+mod resource_pack_pop;
+mod resource_pack_push;
+mod respawn;
+mod rotate_head;
+mod section_blocks_update;
+mod select_advancements_tab;
+mod server_data;
+mod server_links;
+mod set_action_bar_text;
+mod set_border_center;
+mod set_border_lerp_size;
+mod set_border_size;
+mod set_border_warning_delay;
+mod set_border_warning_distance;
+mod set_camera;
+mod set_chunk_cache_center;
+mod set_chunk_cache_radius;
+mod set_cursor_item;
+mod set_default_spawn_position;
+mod set_display_objective;
+mod set_entity_data;
+mod set_entity_link;
+mod set_entity_motion;
+mod set_equipment;
+mod set_experience;
+mod set_health;
+mod set_held_slot;
+mod set_objective;
+mod set_passengers;
+mod set_player_inventory;
+mod set_player_team;
+mod set_score;
+mod set_simulation_distance;
+mod set_subtitle_text;
+mod set_time;
+mod set_title_text;
+mod set_titles_animation;
+mod sound;
+mod sound_entity;
+mod start_configuration;
+mod stop_sound;
+mod store_cookie;
+mod system_chat;
+mod tab_list;
+mod tag_query;
+mod take_item_entity;
+mod teleport_entity;
+mod ticking_state;
+mod ticking_step;
+mod transfer;
+mod update_advancements;
+mod update_attributes;
+mod update_mob_effect;
+mod update_recipes;
+mod update_tags;
+
+pub use add_entity::*;
+pub use add_experience_orb::*;
+pub use animate::*;
+pub use award_stats::*;
+pub use block_changed_ack::*;
+pub use block_destruction::*;
+pub use block_entity_data::*;
+pub use block_event::*;
+pub use block_update::*;
+pub use boss_event::*;
+pub use bundle_delimiter::*;
+pub use change_difficulty::*;
+pub use chunk_batch_finished::*;
+pub use chunk_batch_start::*;
+pub use chunks_biomes::*;
+pub use clear_titles::*;
+pub use command_suggestions::*;
+pub use commands::*;
+pub use container_close::*;
+pub use container_set_content::*;
+pub use container_set_data::*;
+pub use container_set_slot::*;
+pub use cookie_request::*;
+pub use cooldown::*;
+pub use custom_chat_completions::*;
+pub use custom_payload::*;
+pub use custom_report_details::*;
+pub use damage_event::*;
+pub use debug_sample::*;
+pub use delete_chat::*;
+pub use disconnect::*;
+pub use disguised_chat::*;
+pub use entity_event::*;
+pub use entity_position_sync::*;
+pub use explode::*;
+pub use forget_level_chunk::*;
+pub use game_event::*;
+pub use horse_screen_open::*;
+pub use hurt_animation::*;
+pub use initialize_border::*;
+pub use keep_alive::*;
+pub use level_chunk_with_light::*;
+pub use level_event::*;
+pub use level_particles::*;
+pub use light_update::*;
+pub use login::*;
+pub use map_item_data::*;
+pub use merchant_offers::*;
+pub use move_entity_pos::*;
+pub use move_entity_pos_rot::*;
+pub use move_entity_rot::*;
+pub use move_minecart_along_track::*;
+pub use move_vehicle::*;
+pub use open_book::*;
+pub use open_screen::*;
+pub use open_sign_editor::*;
+pub use ping::*;
+pub use place_ghost_recipe::*;
+pub use player_abilities::*;
+pub use player_chat::*;
+pub use player_combat_end::*;
+pub use player_combat_enter::*;
+pub use player_combat_kill::*;
+pub use player_info_remove::*;
+pub use player_info_update::*;
+pub use player_look_at::*;
+pub use player_position::*;
+pub use player_rotation::*;
+pub use pong_response::*;
+pub use projectile_power::*;
+pub use recipe_book_add::*;
+pub use recipe_book_remove::*;
+pub use recipe_book_settings::*;
+pub use remove_entities::*;
+pub use remove_mob_effect::*;
+pub use reset_score::*;
+pub use resource_pack_pop::*;
+pub use resource_pack_push::*;
+pub use respawn::*;
+pub use rotate_head::*;
+pub use section_blocks_update::*;
+pub use select_advancements_tab::*;
+pub use server_data::*;
+pub use server_links::*;
+pub use set_action_bar_text::*;
+pub use set_border_center::*;
+pub use set_border_lerp_size::*;
+pub use set_border_size::*;
+pub use set_border_warning_delay::*;
+pub use set_border_warning_distance::*;
+pub use set_camera::*;
+pub use set_chunk_cache_center::*;
+pub use set_chunk_cache_radius::*;
+pub use set_cursor_item::*;
+pub use set_default_spawn_position::*;
+pub use set_display_objective::*;
+pub use set_entity_data::*;
+pub use set_entity_link::*;
+pub use set_entity_motion::*;
+pub use set_equipment::*;
+pub use set_experience::*;
+pub use set_health::*;
+pub use set_held_slot::*;
+pub use set_objective::*;
+pub use set_passengers::*;
+pub use set_player_inventory::*;
+pub use set_player_team::*;
+pub use set_score::*;
+pub use set_simulation_distance::*;
+pub use set_subtitle_text::*;
+pub use set_time::*;
+pub use set_title_text::*;
+pub use set_titles_animation::*;
+pub use sound::*;
+pub use sound_entity::*;
+pub use start_configuration::*;
+pub use stop_sound::*;
+pub use store_cookie::*;
+pub use system_chat::*;
+pub use tab_list::*;
+pub use tag_query::*;
+pub use take_item_entity::*;
+pub use teleport_entity::*;
+pub use ticking_state::*;
+pub use ticking_step::*;
+pub use transfer::*;
+pub use update_advancements::*;
+pub use update_attributes::*;
+pub use update_mob_effect::*;
+pub use update_recipes::*;
+pub use update_tags::*;
+
+use bytes::Buf;
+
+use crate::{bytebuf::ReadingError, Packet, PacketId, RawPacket};
+
+/// Every packet the server can send in the play state.
+#[derive(Debug)]
+pub enum ClientboundPlay {
+    BundleDelimiter(BundleDelimiter),
+    AddEntity(AddEntity),
+    AddExperienceOrb(AddExperienceOrb),
+    Animate(Animate),
+    AwardStats(AwardStats),
+    BlockChangedAck(BlockChangedAck),
+    BlockDestruction(BlockDestruction),
+    BlockEntityData(BlockEntityData),
+    BlockEvent(BlockEvent),
+    BlockUpdate(BlockUpdate),
+    BossEvent(BossEvent),
+    ChangeDifficulty(ChangeDifficulty),
+    ChunkBatchFinished(ChunkBatchFinished),
+    ChunkBatchStart(ChunkBatchStart),
+    ChunksBiomes(ChunksBiomes),
+    ClearTitles(ClearTitles),
+    CommandSuggestions(CommandSuggestions),
+    Commands(Commands),
+    ContainerClose(ContainerClose),
+    ContainerSetContent(ContainerSetContent),
+    ContainerSetData(ContainerSetData),
+    ContainerSetSlot(ContainerSetSlot),
+    CookieRequest(CookieRequest),
+    Cooldown(Cooldown),
+    CustomChatCompletions(CustomChatCompletions),
+    CustomPayload(CustomPayload),
+    DamageEvent(DamageEvent),
+    DebugSample(DebugSample),
+    DeleteChat(DeleteChat),
+    Disconnect(Disconnect),
+    DisguisedChat(DisguisedChat),
+    EntityEvent(EntityEvent),
+    EntityPositionSync(EntityPositionSync),
+    Explode(Explode),
+    ForgetLevelChunk(ForgetLevelChunk),
+    GameEvent(GameEvent),
+    HorseScreenOpen(HorseScreenOpen),
+    HurtAnimation(HurtAnimation),
+    InitializeBorder(InitializeBorder),
+    KeepAlive(KeepAlive),
+    LevelChunkWithLight(LevelChunkWithLight),
+    LevelEvent(LevelEvent),
+    LevelParticles(LevelParticles),
+    LightUpdate(LightUpdate),
+    Login(Login),
+    MapItemData(MapItemData),
+    MerchantOffers(MerchantOffers),
+    MoveEntityPos(MoveEntityPos),
+    MoveEntityPosRot(MoveEntityPosRot),
+    MoveMinecartAlongTrack(MoveMinecartAlongTrack),
+    MoveEntityRot(MoveEntityRot),
+    MoveVehicle(MoveVehicle),
+    OpenBook(OpenBook),
+    OpenScreen(OpenScreen),
+    OpenSignEditor(OpenSignEditor),
+    Ping(Ping),
+    PongResponse(PongResponse),
+    PlaceGhostRecipe(PlaceGhostRecipe),
+    PlayerAbilities(PlayerAbilities),
+    PlayerChat(PlayerChat),
+    PlayerCombatEnd(PlayerCombatEnd),
+    PlayerCombatEnter(PlayerCombatEnter),
+    PlayerCombatKill(PlayerCombatKill),
+    PlayerInfoRemove(PlayerInfoRemove),
+    PlayerInfoUpdate(PlayerInfoUpdate),
+    PlayerLookAt(PlayerLookAt),
+    PlayerPosition(PlayerPosition),
+    PlayerRotation(PlayerRotation),
+    RecipeBookAdd(RecipeBookAdd),
+    RecipeBookRemove(RecipeBookRemove),
+    RecipeBookSettings(RecipeBookSettings),
+    RemoveEntities(RemoveEntities),
+    RemoveMobEffect(RemoveMobEffect),
+    ResetScore(ResetScore),
+    ResourcePackPop(ResourcePackPop),
+    ResourcePackPush(ResourcePackPush),
+    Respawn(Respawn),
+    RotateHead(RotateHead),
+    SectionBlocksUpdate(SectionBlocksUpdate),
+    SelectAdvancementsTab(SelectAdvancementsTab),
+    ServerData(ServerData),
+    SetActionBarText(SetActionBarText),
+    SetBorderCenter(SetBorderCenter),
+    SetBorderLerpSize(SetBorderLerpSize),
+    SetBorderSize(SetBorderSize),
+    SetBorderWarningDelay(SetBorderWarningDelay),
+    SetBorderWarningDistance(SetBorderWarningDistance),
+    SetCamera(SetCamera),
+    SetChunkCacheCenter(SetChunkCacheCenter),
+    SetChunkCacheRadius(SetChunkCacheRadius),
+    SetCursorItem(SetCursorItem),
+    SetDefaultSpawnPosition(SetDefaultSpawnPosition),
+    SetDisplayObjective(SetDisplayObjective),
+    SetEntityData(SetEntityData),
+    SetEntityLink(SetEntityLink),
+    SetEntityMotion(SetEntityMotion),
+    SetEquipment(SetEquipment),
+    SetExperience(SetExperience),
+    SetHealth(SetHealth),
+    SetHeldSlot(SetHeldSlot),
+    SetObjective(SetObjective),
+    SetPassengers(SetPassengers),
+    SetPlayerInventory(SetPlayerInventory),
+    SetPlayerTeam(SetPlayerTeam),
+    SetScore(SetScore),
+    SetSimulationDistance(SetSimulationDistance),
+    SetSubtitleText(SetSubtitleText),
+    SetTime(SetTime),
+    SetTitleText(SetTitleText),
+    SetTitlesAnimation(SetTitlesAnimation),
+    SoundEntity(SoundEntity),
+    Sound(Sound),
+    StartConfiguration(StartConfiguration),
+    StopSound(StopSound),
+    StoreCookie(StoreCookie),
+    SystemChat(SystemChat),
+    TabList(TabList),
+    TagQuery(TagQuery),
+    TakeItemEntity(TakeItemEntity),
+    TeleportEntity(TeleportEntity),
+    TickingState(TickingState),
+    TickingStep(TickingStep),
+    Transfer(Transfer),
+    UpdateAdvancements(UpdateAdvancements),
+    UpdateAttributes(UpdateAttributes),
+    UpdateMobEffect(UpdateMobEffect),
+    UpdateRecipes(UpdateRecipes),
+    UpdateTags(UpdateTags),
+    ProjectilePower(ProjectilePower),
+    CustomReportDetails(CustomReportDetails),
+    ServerLinks(ServerLinks),
+}
+
+impl ClientboundPlay {
+    /// Decodes a raw packet, picking the type by its id.
+    pub fn decode(packet: &RawPacket) -> Result<Self, ReadingError> {
+        let bytebuf = &mut packet.bytebuf.clone();
+        Self::read(packet.id.0, bytebuf)
+    }
+
+    pub fn read(id: i32, bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(match id {
+            BundleDelimiter::PACKET_ID => Self::BundleDelimiter(BundleDelimiter::read(bytebuf)?),
+            AddEntity::PACKET_ID => Self::AddEntity(AddEntity::read(bytebuf)?),
+            AddExperienceOrb::PACKET_ID => Self::AddExperienceOrb(AddExperienceOrb::read(bytebuf)?),
+            Animate::PACKET_ID => Self::Animate(Animate::read(bytebuf)?),
+            AwardStats::PACKET_ID => Self::AwardStats(AwardStats::read(bytebuf)?),
+            BlockChangedAck::PACKET_ID => Self::BlockChangedAck(BlockChangedAck::read(bytebuf)?),
+            BlockDestruction::PACKET_ID => Self::BlockDestruction(BlockDestruction::read(bytebuf)?),
+            BlockEntityData::PACKET_ID => Self::BlockEntityData(BlockEntityData::read(bytebuf)?),
+            BlockEvent::PACKET_ID => Self::BlockEvent(BlockEvent::read(bytebuf)?),
+            BlockUpdate::PACKET_ID => Self::BlockUpdate(BlockUpdate::read(bytebuf)?),
+            BossEvent::PACKET_ID => Self::BossEvent(BossEvent::read(bytebuf)?),
+            ChangeDifficulty::PACKET_ID => Self::ChangeDifficulty(ChangeDifficulty::read(bytebuf)?),
+            ChunkBatchFinished::PACKET_ID => {
+                Self::ChunkBatchFinished(ChunkBatchFinished::read(bytebuf)?)
+            }
+            ChunkBatchStart::PACKET_ID => Self::ChunkBatchStart(ChunkBatchStart::read(bytebuf)?),
+            ChunksBiomes::PACKET_ID => Self::ChunksBiomes(ChunksBiomes::read(bytebuf)?),
+            ClearTitles::PACKET_ID => Self::ClearTitles(ClearTitles::read(bytebuf)?),
+            CommandSuggestions::PACKET_ID => {
+                Self::CommandSuggestions(CommandSuggestions::read(bytebuf)?)
+            }
+            Commands::PACKET_ID => Self::Commands(Commands::read(bytebuf)?),
+            ContainerClose::PACKET_ID => Self::ContainerClose(ContainerClose::read(bytebuf)?),
+            ContainerSetContent::PACKET_ID => {
+                Self::ContainerSetContent(ContainerSetContent::read(bytebuf)?)
+            }
+            ContainerSetData::PACKET_ID => Self::ContainerSetData(ContainerSetData::read(bytebuf)?),
+            ContainerSetSlot::PACKET_ID => Self::ContainerSetSlot(ContainerSetSlot::read(bytebuf)?),
+            CookieRequest::PACKET_ID => Self::CookieRequest(CookieRequest::read(bytebuf)?),
+            Cooldown::PACKET_ID => Self::Cooldown(Cooldown::read(bytebuf)?),
+            CustomChatCompletions::PACKET_ID => {
+                Self::CustomChatCompletions(CustomChatCompletions::read(bytebuf)?)
+            }
+            CustomPayload::PACKET_ID => Self::CustomPayload(CustomPayload::read(bytebuf)?),
+            DamageEvent::PACKET_ID => Self::DamageEvent(DamageEvent::read(bytebuf)?),
+            DebugSample::PACKET_ID => Self::DebugSample(DebugSample::read(bytebuf)?),
+            DeleteChat::PACKET_ID => Self::DeleteChat(DeleteChat::read(bytebuf)?),
+            Disconnect::PACKET_ID => Self::Disconnect(Disconnect::read(bytebuf)?),
+            DisguisedChat::PACKET_ID => Self::DisguisedChat(DisguisedChat::read(bytebuf)?),
+            EntityEvent::PACKET_ID => Self::EntityEvent(EntityEvent::read(bytebuf)?),
+            EntityPositionSync::PACKET_ID => {
+                Self::EntityPositionSync(EntityPositionSync::read(bytebuf)?)
+            }
+            Explode::PACKET_ID => Self::Explode(Explode::read(bytebuf)?),
+            ForgetLevelChunk::PACKET_ID => Self::ForgetLevelChunk(ForgetLevelChunk::read(bytebuf)?),
+            GameEvent::PACKET_ID => Self::GameEvent(GameEvent::read(bytebuf)?),
+            HorseScreenOpen::PACKET_ID => Self::HorseScreenOpen(HorseScreenOpen::read(bytebuf)?),
+            HurtAnimation::PACKET_ID => Self::HurtAnimation(HurtAnimation::read(bytebuf)?),
+            InitializeBorder::PACKET_ID => Self::InitializeBorder(InitializeBorder::read(bytebuf)?),
+            KeepAlive::PACKET_ID => Self::KeepAlive(KeepAlive::read(bytebuf)?),
+            LevelChunkWithLight::PACKET_ID => {
+                Self::LevelChunkWithLight(LevelChunkWithLight::read(bytebuf)?)
+            }
+            LevelEvent::PACKET_ID => Self::LevelEvent(LevelEvent::read(bytebuf)?),
+            LevelParticles::PACKET_ID => Self::LevelParticles(LevelParticles::read(bytebuf)?),
+            LightUpdate::PACKET_ID => Self::LightUpdate(LightUpdate::read(bytebuf)?),
+            Login::PACKET_ID => Self::Login(Login::read(bytebuf)?),
+            MapItemData::PACKET_ID => Self::MapItemData(MapItemData::read(bytebuf)?),
+            MerchantOffers::PACKET_ID => Self::MerchantOffers(MerchantOffers::read(bytebuf)?),
+            MoveEntityPos::PACKET_ID => Self::MoveEntityPos(MoveEntityPos::read(bytebuf)?),
+            MoveEntityPosRot::PACKET_ID => Self::MoveEntityPosRot(MoveEntityPosRot::read(bytebuf)?),
+            MoveMinecartAlongTrack::PACKET_ID => {
+                Self::MoveMinecartAlongTrack(MoveMinecartAlongTrack::read(bytebuf)?)
+            }
+            MoveEntityRot::PACKET_ID => Self::MoveEntityRot(MoveEntityRot::read(bytebuf)?),
+            MoveVehicle::PACKET_ID => Self::MoveVehicle(MoveVehicle::read(bytebuf)?),
+            OpenBook::PACKET_ID => Self::OpenBook(OpenBook::read(bytebuf)?),
+            OpenScreen::PACKET_ID => Self::OpenScreen(OpenScreen::read(bytebuf)?),
+            OpenSignEditor::PACKET_ID => Self::OpenSignEditor(OpenSignEditor::read(bytebuf)?),
+            Ping::PACKET_ID => Self::Ping(Ping::read(bytebuf)?),
+            PongResponse::PACKET_ID => Self::PongResponse(PongResponse::read(bytebuf)?),
+            PlaceGhostRecipe::PACKET_ID => Self::PlaceGhostRecipe(PlaceGhostRecipe::read(bytebuf)?),
+            PlayerAbilities::PACKET_ID => Self::PlayerAbilities(PlayerAbilities::read(bytebuf)?),
+            PlayerChat::PACKET_ID => Self::PlayerChat(PlayerChat::read(bytebuf)?),
+            PlayerCombatEnd::PACKET_ID => Self::PlayerCombatEnd(PlayerCombatEnd::read(bytebuf)?),
+            PlayerCombatEnter::PACKET_ID => {
+                Self::PlayerCombatEnter(PlayerCombatEnter::read(bytebuf)?)
+            }
+            PlayerCombatKill::PACKET_ID => Self::PlayerCombatKill(PlayerCombatKill::read(bytebuf)?),
+            PlayerInfoRemove::PACKET_ID => Self::PlayerInfoRemove(PlayerInfoRemove::read(bytebuf)?),
+            PlayerInfoUpdate::PACKET_ID => Self::PlayerInfoUpdate(PlayerInfoUpdate::read(bytebuf)?),
+            PlayerLookAt::PACKET_ID => Self::PlayerLookAt(PlayerLookAt::read(bytebuf)?),
+            PlayerPosition::PACKET_ID => Self::PlayerPosition(PlayerPosition::read(bytebuf)?),
+            PlayerRotation::PACKET_ID => Self::PlayerRotation(PlayerRotation::read(bytebuf)?),
+            RecipeBookAdd::PACKET_ID => Self::RecipeBookAdd(RecipeBookAdd::read(bytebuf)?),
+            RecipeBookRemove::PACKET_ID => Self::RecipeBookRemove(RecipeBookRemove::read(bytebuf)?),
+            RecipeBookSettings::PACKET_ID => {
+                Self::RecipeBookSettings(RecipeBookSettings::read(bytebuf)?)
+            }
+            RemoveEntities::PACKET_ID => Self::RemoveEntities(RemoveEntities::read(bytebuf)?),
+            RemoveMobEffect::PACKET_ID => Self::RemoveMobEffect(RemoveMobEffect::read(bytebuf)?),
+            ResetScore::PACKET_ID => Self::ResetScore(ResetScore::read(bytebuf)?),
+            ResourcePackPop::PACKET_ID => Self::ResourcePackPop(ResourcePackPop::read(bytebuf)?),
+            ResourcePackPush::PACKET_ID => Self::ResourcePackPush(ResourcePackPush::read(bytebuf)?),
+            Respawn::PACKET_ID => Self::Respawn(Respawn::read(bytebuf)?),
+            RotateHead::PACKET_ID => Self::RotateHead(RotateHead::read(bytebuf)?),
+            SectionBlocksUpdate::PACKET_ID => {
+                Self::SectionBlocksUpdate(SectionBlocksUpdate::read(bytebuf)?)
+            }
+            SelectAdvancementsTab::PACKET_ID => {
+                Self::SelectAdvancementsTab(SelectAdvancementsTab::read(bytebuf)?)
+            }
+            ServerData::PACKET_ID => Self::ServerData(ServerData::read(bytebuf)?),
+            SetActionBarText::PACKET_ID => Self::SetActionBarText(SetActionBarText::read(bytebuf)?),
+            SetBorderCenter::PACKET_ID => Self::SetBorderCenter(SetBorderCenter::read(bytebuf)?),
+            SetBorderLerpSize::PACKET_ID => {
+                Self::SetBorderLerpSize(SetBorderLerpSize::read(bytebuf)?)
+            }
+            SetBorderSize::PACKET_ID => Self::SetBorderSize(SetBorderSize::read(bytebuf)?),
+            SetBorderWarningDelay::PACKET_ID => {
+                Self::SetBorderWarningDelay(SetBorderWarningDelay::read(bytebuf)?)
+            }
+            SetBorderWarningDistance::PACKET_ID => {
+                Self::SetBorderWarningDistance(SetBorderWarningDistance::read(bytebuf)?)
+            }
+            SetCamera::PACKET_ID => Self::SetCamera(SetCamera::read(bytebuf)?),
+            SetChunkCacheCenter::PACKET_ID => {
+                Self::SetChunkCacheCenter(SetChunkCacheCenter::read(bytebuf)?)
+            }
+            SetChunkCacheRadius::PACKET_ID => {
+                Self::SetChunkCacheRadius(SetChunkCacheRadius::read(bytebuf)?)
+            }
+            SetCursorItem::PACKET_ID => Self::SetCursorItem(SetCursorItem::read(bytebuf)?),
+            SetDefaultSpawnPosition::PACKET_ID => {
+                Self::SetDefaultSpawnPosition(SetDefaultSpawnPosition::read(bytebuf)?)
+            }
+            SetDisplayObjective::PACKET_ID => {
+                Self::SetDisplayObjective(SetDisplayObjective::read(bytebuf)?)
+            }
+            SetEntityData::PACKET_ID => Self::SetEntityData(SetEntityData::read(bytebuf)?),
+            SetEntityLink::PACKET_ID => Self::SetEntityLink(SetEntityLink::read(bytebuf)?),
+            SetEntityMotion::PACKET_ID => Self::SetEntityMotion(SetEntityMotion::read(bytebuf)?),
+            SetEquipment::PACKET_ID => Self::SetEquipment(SetEquipment::read(bytebuf)?),
+            SetExperience::PACKET_ID => Self::SetExperience(SetExperience::read(bytebuf)?),
+            SetHealth::PACKET_ID => Self::SetHealth(SetHealth::read(bytebuf)?),
+            SetHeldSlot::PACKET_ID => Self::SetHeldSlot(SetHeldSlot::read(bytebuf)?),
+            SetObjective::PACKET_ID => Self::SetObjective(SetObjective::read(bytebuf)?),
+            SetPassengers::PACKET_ID => Self::SetPassengers(SetPassengers::read(bytebuf)?),
+            SetPlayerInventory::PACKET_ID => {
+                Self::SetPlayerInventory(SetPlayerInventory::read(bytebuf)?)
+            }
+            SetPlayerTeam::PACKET_ID => Self::SetPlayerTeam(SetPlayerTeam::read(bytebuf)?),
+            SetScore::PACKET_ID => Self::SetScore(SetScore::read(bytebuf)?),
+            SetSimulationDistance::PACKET_ID => {
+                Self::SetSimulationDistance(SetSimulationDistance::read(bytebuf)?)
+            }
+            SetSubtitleText::PACKET_ID => Self::SetSubtitleText(SetSubtitleText::read(bytebuf)?),
+            SetTime::PACKET_ID => Self::SetTime(SetTime::read(bytebuf)?),
+            SetTitleText::PACKET_ID => Self::SetTitleText(SetTitleText::read(bytebuf)?),
+            SetTitlesAnimation::PACKET_ID => {
+                Self::SetTitlesAnimation(SetTitlesAnimation::read(bytebuf)?)
+            }
+            SoundEntity::PACKET_ID => Self::SoundEntity(SoundEntity::read(bytebuf)?),
+            Sound::PACKET_ID => Self::Sound(Sound::read(bytebuf)?),
+            StartConfiguration::PACKET_ID => {
+                Self::StartConfiguration(StartConfiguration::read(bytebuf)?)
+            }
+            StopSound::PACKET_ID => Self::StopSound(StopSound::read(bytebuf)?),
+            StoreCookie::PACKET_ID => Self::StoreCookie(StoreCookie::read(bytebuf)?),
+            SystemChat::PACKET_ID => Self::SystemChat(SystemChat::read(bytebuf)?),
+            TabList::PACKET_ID => Self::TabList(TabList::read(bytebuf)?),
+            TagQuery::PACKET_ID => Self::TagQuery(TagQuery::read(bytebuf)?),
+            TakeItemEntity::PACKET_ID => Self::TakeItemEntity(TakeItemEntity::read(bytebuf)?),
+            TeleportEntity::PACKET_ID => Self::TeleportEntity(TeleportEntity::read(bytebuf)?),
+            TickingState::PACKET_ID => Self::TickingState(TickingState::read(bytebuf)?),
+            TickingStep::PACKET_ID => Self::TickingStep(TickingStep::read(bytebuf)?),
+            Transfer::PACKET_ID => Self::Transfer(Transfer::read(bytebuf)?),
+            UpdateAdvancements::PACKET_ID => {
+                Self::UpdateAdvancements(UpdateAdvancements::read(bytebuf)?)
+            }
+            UpdateAttributes::PACKET_ID => Self::UpdateAttributes(UpdateAttributes::read(bytebuf)?),
+            UpdateMobEffect::PACKET_ID => Self::UpdateMobEffect(UpdateMobEffect::read(bytebuf)?),
+            UpdateRecipes::PACKET_ID => Self::UpdateRecipes(UpdateRecipes::read(bytebuf)?),
+            UpdateTags::PACKET_ID => Self::UpdateTags(UpdateTags::read(bytebuf)?),
+            ProjectilePower::PACKET_ID => Self::ProjectilePower(ProjectilePower::read(bytebuf)?),
+            CustomReportDetails::PACKET_ID => {
+                Self::CustomReportDetails(CustomReportDetails::read(bytebuf)?)
+            }
+            ServerLinks::PACKET_ID => Self::ServerLinks(ServerLinks::read(bytebuf)?),
+            _ => {
+                return Err(ReadingError::Message(format!(
+                    "Unknown play packet id {id}"
+                )))
+            }
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_MOVE_ENTITY_POS;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Moves an entity by less than 8 blocks, deltas are in 1/4096 of a block.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_MOVE_ENTITY_POS)]
+pub struct MoveEntityPos {
+    pub entity_id: VarInt,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+    pub on_ground: bool,
+}
+
+impl MoveEntityPos {
+    pub fn new(
+        entity_id: VarInt,
+        delta_x: i16,
+        delta_y: i16,
+        delta_z: i16,
+        on_ground: bool,
+    ) -> Self {
+        Self {
+            entity_id,
+            delta_x,
+            delta_y,
+            delta_z,
+            on_ground,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_MOVE_ENTITY_POS_ROT;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_MOVE_ENTITY_POS_ROT)]
+pub struct MoveEntityPosRot {
+    pub entity_id: VarInt,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+    pub yaw: u8,
+    pub pitch: u8,
+    pub on_ground: bool,
+}
+
+impl MoveEntityPosRot {
+    pub fn new(
+        entity_id: VarInt,
+        delta_x: i16,
+        delta_y: i16,
+        delta_z: i16,
+        yaw: u8,
+        pitch: u8,
+        on_ground: bool,
+    ) -> Self {
+        Self {
+            entity_id,
+            delta_x,
+            delta_y,
+            delta_z,
+            yaw,
+            pitch,
+            on_ground,
+        }
+    }
+}
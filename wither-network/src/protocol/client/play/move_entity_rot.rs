@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_MOVE_ENTITY_ROT;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_MOVE_ENTITY_ROT)]
+pub struct MoveEntityRot {
+    pub entity_id: VarInt,
+    pub yaw: u8,
+    pub pitch: u8,
+    pub on_ground: bool,
+}
+
+impl MoveEntityRot {
+    pub fn new(entity_id: VarInt, yaw: u8, pitch: u8, on_ground: bool) -> Self {
+        Self {
+            entity_id,
+            yaw,
+            pitch,
+            on_ground,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_MOVE_MINECART_ALONG_TRACK;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct MinecartStep {
+    pub position: Vector3<f64>,
+    pub movement: Vector3<f64>,
+    pub yaw: u8,
+    pub pitch: u8,
+    pub weight: f32,
+}
+
+/// Interpolation steps of a minecart using the experimental minecart physics.
+#[derive(Debug)]
+#[wither_packet(PLAY_MOVE_MINECART_ALONG_TRACK)]
+pub struct MoveMinecartAlongTrack {
+    pub entity_id: VarInt,
+    pub lerp_steps: Vec<MinecartStep>,
+}
+
+impl MoveMinecartAlongTrack {
+    pub fn new(entity_id: VarInt, lerp_steps: Vec<MinecartStep>) -> Self {
+        Self {
+            entity_id,
+            lerp_steps,
+        }
+    }
+}
+
+impl Packet for MoveMinecartAlongTrack {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            lerp_steps: bytebuf.get_list(|v| {
+                Ok(MinecartStep {
+                    position: v.try_get_vec3()?,
+                    movement: v.try_get_vec3()?,
+                    yaw: v.try_get_u8()?,
+                    pitch: v.try_get_u8()?,
+                    weight: v.try_get_f32()?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_list(&self.lerp_steps, |p, step| {
+            p.put_vec3(&step.position);
+            p.put_vec3(&step.movement);
+            p.put_u8(step.yaw);
+            p.put_u8(step.pitch);
+            p.put_f32(step.weight);
+        });
+    }
+}
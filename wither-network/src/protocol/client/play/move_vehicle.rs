@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_MOVE_VEHICLE;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_MOVE_VEHICLE)]
+pub struct MoveVehicle {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl MoveVehicle {
+    pub fn new(x: f64, y: f64, z: f64, yaw: f32, pitch: f32) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_OPEN_BOOK;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_OPEN_BOOK)]
+pub struct OpenBook {
+    pub hand: VarInt,
+}
+
+impl OpenBook {
+    pub fn new(hand: VarInt) -> Self {
+        Self { hand }
+    }
+}
@@ -0,0 +1,44 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_OPEN_SCREEN;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_OPEN_SCREEN)]
+pub struct OpenScreen {
+    pub container_id: VarInt,
+    /// Id in the `minecraft:menu` registry.
+    pub menu_type: VarInt,
+    pub title: TextComponent,
+}
+
+impl OpenScreen {
+    pub fn new(container_id: VarInt, menu_type: VarInt, title: TextComponent) -> Self {
+        Self {
+            container_id,
+            menu_type,
+            title,
+        }
+    }
+}
+
+impl Packet for OpenScreen {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            container_id: bytebuf.try_get_var_int()?,
+            menu_type: bytebuf.try_get_var_int()?,
+            title: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.container_id);
+        bytebuf.put_var_int(&self.menu_type);
+        bytebuf.put_text_component(&self.title);
+    }
+}
@@ -0,0 +1,39 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_OPEN_SIGN_EDITOR;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_OPEN_SIGN_EDITOR)]
+pub struct OpenSignEditor {
+    pub location: WorldPosition,
+    pub is_front_text: bool,
+}
+
+impl OpenSignEditor {
+    pub fn new(location: WorldPosition, is_front_text: bool) -> Self {
+        Self {
+            location,
+            is_front_text,
+        }
+    }
+}
+
+impl Packet for OpenSignEditor {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            is_front_text: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_bool(self.is_front_text);
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PING;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PING)]
+pub struct Ping {
+    pub id: i32,
+}
+
+impl Ping {
+    pub fn new(id: i32) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,40 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_PLACE_GHOST_RECIPE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::RecipeDisplay,
+    Packet, VarInt,
+};
+
+/// Shows the recipe the player clicked as ghost items in the crafting grid.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLACE_GHOST_RECIPE)]
+pub struct PlaceGhostRecipe {
+    pub container_id: VarInt,
+    pub recipe: RecipeDisplay,
+}
+
+impl PlaceGhostRecipe {
+    pub fn new(container_id: VarInt, recipe: RecipeDisplay) -> Self {
+        Self {
+            container_id,
+            recipe,
+        }
+    }
+}
+
+impl Packet for PlaceGhostRecipe {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            container_id: bytebuf.try_get_var_int()?,
+            recipe: RecipeDisplay::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.container_id);
+        self.recipe.write(bytebuf);
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PLAYER_ABILITIES;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PLAYER_ABILITIES)]
+pub struct PlayerAbilities {
+    pub flags: i8,
+    pub flying_speed: f32,
+    pub walking_speed: f32,
+}
+
+impl PlayerAbilities {
+    pub fn new(flags: i8, flying_speed: f32, walking_speed: f32) -> Self {
+        Self {
+            flags,
+            flying_speed,
+            walking_speed,
+        }
+    }
+}
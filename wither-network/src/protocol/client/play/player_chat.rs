@@ -0,0 +1,105 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_PLAYER_CHAT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::{ChatTypeBound, FilterMask, PackedSignature, MESSAGE_SIGNATURE_SIZE},
+    Packet, VarInt,
+};
+
+const MAX_MESSAGE_LENGTH: usize = 256;
+const MAX_LAST_SEEN: usize = 20;
+
+/// A chat message sent by a player, possibly signed.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_CHAT)]
+pub struct PlayerChat {
+    pub sender: uuid::Uuid,
+    pub index: VarInt,
+    pub message_signature: Option<Bytes>,
+    pub message: String,
+    /// Milliseconds since the unix epoch.
+    pub timestamp: i64,
+    pub salt: i64,
+    pub previous_messages: Vec<PackedSignature>,
+    /// The message as the server wants it shown, if it differs from `message`.
+    pub unsigned_content: Option<TextComponent>,
+    pub filter_mask: FilterMask,
+    pub chat_type: ChatTypeBound,
+}
+
+impl PlayerChat {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sender: uuid::Uuid,
+        index: VarInt,
+        message_signature: Option<Bytes>,
+        message: String,
+        timestamp: i64,
+        salt: i64,
+        previous_messages: Vec<PackedSignature>,
+        unsigned_content: Option<TextComponent>,
+        filter_mask: FilterMask,
+        chat_type: ChatTypeBound,
+    ) -> Self {
+        Self {
+            sender,
+            index,
+            message_signature,
+            message,
+            timestamp,
+            salt,
+            previous_messages,
+            unsigned_content,
+            filter_mask,
+            chat_type,
+        }
+    }
+}
+
+impl Packet for PlayerChat {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let sender = bytebuf.try_get_uuid()?;
+        let index = bytebuf.try_get_var_int()?;
+        let message_signature =
+            bytebuf.try_get_option(|v| v.try_copy_to_bytes(MESSAGE_SIGNATURE_SIZE))?;
+        let message = bytebuf.try_get_string_len(MAX_MESSAGE_LENGTH)?;
+        let timestamp = bytebuf.try_get_i64()?;
+        let salt = bytebuf.try_get_i64()?;
+        let previous_messages = bytebuf.get_list(PackedSignature::read)?;
+        if previous_messages.len() > MAX_LAST_SEEN {
+            return Err(ReadingError::TooLarge("previous messages".to_string()));
+        }
+        Ok(Self {
+            sender,
+            index,
+            message_signature,
+            message,
+            timestamp,
+            salt,
+            previous_messages,
+            unsigned_content: bytebuf.try_get_option(|v| v.try_get_text_component())?,
+            filter_mask: FilterMask::read(bytebuf)?,
+            chat_type: ChatTypeBound::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.sender);
+        bytebuf.put_var_int(&self.index);
+        bytebuf.put_option(&self.message_signature, |p, signature| {
+            p.put_slice(signature)
+        });
+        bytebuf.put_string_len(&self.message, MAX_MESSAGE_LENGTH);
+        bytebuf.put_i64(self.timestamp);
+        bytebuf.put_i64(self.salt);
+        bytebuf.put_list(&self.previous_messages, |p, signature| signature.write(p));
+        bytebuf.put_option(&self.unsigned_content, |p, content| {
+            p.put_text_component(content)
+        });
+        self.filter_mask.write(bytebuf);
+        self.chat_type.write(bytebuf);
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PLAYER_COMBAT_END;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PLAYER_COMBAT_END)]
+pub struct PlayerCombatEnd {
+    pub duration: VarInt,
+}
+
+impl PlayerCombatEnd {
+    pub fn new(duration: VarInt) -> Self {
+        Self { duration }
+    }
+}
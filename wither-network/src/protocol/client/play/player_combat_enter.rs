@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PLAYER_COMBAT_ENTER;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(PLAY_PLAYER_COMBAT_ENTER)]
+pub struct PlayerCombatEnter {}
+
+impl PlayerCombatEnter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,37 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_PLAYER_COMBAT_KILL;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Opens the death screen.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_COMBAT_KILL)]
+pub struct PlayerCombatKill {
+    pub player_id: VarInt,
+    pub message: TextComponent,
+}
+
+impl PlayerCombatKill {
+    pub fn new(player_id: VarInt, message: TextComponent) -> Self {
+        Self { player_id, message }
+    }
+}
+
+impl Packet for PlayerCombatKill {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            player_id: bytebuf.try_get_var_int()?,
+            message: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.player_id);
+        bytebuf.put_text_component(&self.message);
+    }
+}
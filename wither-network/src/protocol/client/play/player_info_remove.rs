@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_PLAYER_INFO_REMOVE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_INFO_REMOVE)]
+pub struct PlayerInfoRemove {
+    pub profile_ids: Vec<uuid::Uuid>,
+}
+
+impl PlayerInfoRemove {
+    pub fn new(profile_ids: Vec<uuid::Uuid>) -> Self {
+        Self { profile_ids }
+    }
+}
+
+impl Packet for PlayerInfoRemove {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            profile_ids: bytebuf.get_list(|v| v.try_get_uuid())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.profile_ids, |p, id| p.put_uuid(id));
+    }
+}
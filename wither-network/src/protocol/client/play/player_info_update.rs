@@ -0,0 +1,145 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_PLAYER_INFO_UPDATE;
+use wither_macros::wither_packet;
+use wither_util::{text::TextComponent, GameMode};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::{PropertyMap, RemoteChatSession},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub enum PlayerAction {
+    AddPlayer {
+        name: String,
+        properties: PropertyMap,
+    },
+    InitializeChat(Option<RemoteChatSession>),
+    UpdateGameMode(GameMode),
+    UpdateListed(bool),
+    UpdateLatency(VarInt),
+    UpdateDisplayName(Option<TextComponent>),
+    UpdateListOrder(VarInt),
+    UpdateHat(bool),
+}
+
+impl PlayerAction {
+    pub const ADD_PLAYER: u8 = 0x01;
+    pub const INITIALIZE_CHAT: u8 = 0x02;
+    pub const UPDATE_GAME_MODE: u8 = 0x04;
+    pub const UPDATE_LISTED: u8 = 0x08;
+    pub const UPDATE_LATENCY: u8 = 0x10;
+    pub const UPDATE_DISPLAY_NAME: u8 = 0x20;
+    pub const UPDATE_LIST_ORDER: u8 = 0x40;
+    pub const UPDATE_HAT: u8 = 0x80;
+
+    /// The bit of this action in the packet's action set.
+    pub fn mask(&self) -> u8 {
+        match self {
+            Self::AddPlayer { .. } => Self::ADD_PLAYER,
+            Self::InitializeChat(_) => Self::INITIALIZE_CHAT,
+            Self::UpdateGameMode(_) => Self::UPDATE_GAME_MODE,
+            Self::UpdateListed(_) => Self::UPDATE_LISTED,
+            Self::UpdateLatency(_) => Self::UPDATE_LATENCY,
+            Self::UpdateDisplayName(_) => Self::UPDATE_DISPLAY_NAME,
+            Self::UpdateListOrder(_) => Self::UPDATE_LIST_ORDER,
+            Self::UpdateHat(_) => Self::UPDATE_HAT,
+        }
+    }
+
+    fn read(bytebuf: &mut impl Buf, mask: u8) -> Result<Self, ReadingError> {
+        Ok(match mask {
+            Self::ADD_PLAYER => Self::AddPlayer {
+                name: bytebuf.try_get_string_len(16)?,
+                properties: PropertyMap::read(bytebuf)?,
+            },
+            Self::INITIALIZE_CHAT => {
+                Self::InitializeChat(bytebuf.try_get_option(RemoteChatSession::read)?)
+            }
+            Self::UPDATE_GAME_MODE => {
+                Self::UpdateGameMode(GameMode::from(bytebuf.try_get_var_int()?.0 as i8))
+            }
+            Self::UPDATE_LISTED => Self::UpdateListed(bytebuf.try_get_bool()?),
+            Self::UPDATE_LATENCY => Self::UpdateLatency(bytebuf.try_get_var_int()?),
+            Self::UPDATE_DISPLAY_NAME => {
+                Self::UpdateDisplayName(bytebuf.try_get_option(|v| v.try_get_text_component())?)
+            }
+            Self::UPDATE_LIST_ORDER => Self::UpdateListOrder(bytebuf.try_get_var_int()?),
+            _ => Self::UpdateHat(bytebuf.try_get_bool()?),
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::AddPlayer { name, properties } => {
+                bytebuf.put_string_len(name, 16);
+                properties.write(bytebuf);
+            }
+            Self::InitializeChat(session) => {
+                bytebuf.put_option(session, |p, session| session.write(p))
+            }
+            Self::UpdateGameMode(game_mode) => bytebuf.put_var_int(&VarInt(*game_mode as i32)),
+            Self::UpdateListed(listed) => bytebuf.put_bool(*listed),
+            Self::UpdateLatency(latency) => bytebuf.put_var_int(latency),
+            Self::UpdateDisplayName(display_name) => {
+                bytebuf.put_option(display_name, |p, name| p.put_text_component(name))
+            }
+            Self::UpdateListOrder(order) => bytebuf.put_var_int(order),
+            Self::UpdateHat(show_hat) => bytebuf.put_bool(*show_hat),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayerInfoEntry {
+    pub uuid: uuid::Uuid,
+    /// Ordered by their bit in the action set.
+    pub actions: Vec<PlayerAction>,
+}
+
+/// Adds players to the tab list or updates their entries. Every entry carries
+/// exactly the actions set in `actions`.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_INFO_UPDATE)]
+pub struct PlayerInfoUpdate {
+    pub actions: u8,
+    pub players: Vec<PlayerInfoEntry>,
+}
+
+impl PlayerInfoUpdate {
+    pub fn new(actions: u8, players: Vec<PlayerInfoEntry>) -> Self {
+        Self { actions, players }
+    }
+}
+
+impl Packet for PlayerInfoUpdate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let actions = bytebuf.try_get_u8()?;
+        let players = bytebuf.get_list(|v| {
+            let uuid = v.try_get_uuid()?;
+            let mut player_actions = Vec::with_capacity(actions.count_ones() as usize);
+            for bit in 0..8 {
+                let mask = 1 << bit;
+                if actions & mask != 0 {
+                    player_actions.push(PlayerAction::read(v, mask)?);
+                }
+            }
+            Ok(PlayerInfoEntry {
+                uuid,
+                actions: player_actions,
+            })
+        })?;
+        Ok(Self { actions, players })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_u8(self.actions);
+        bytebuf.put_list(&self.players, |p, player| {
+            p.put_uuid(&player.uuid);
+            for action in &player.actions {
+                action.write(p);
+            }
+        });
+    }
+}
@@ -0,0 +1,75 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_PLAYER_LOOK_AT;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Which part of an entity to look from or at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityAnchor {
+    Feet,
+    Eyes,
+}
+
+impl TryFrom<VarInt> for EntityAnchor {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Feet),
+            1 => Ok(Self::Eyes),
+            _ => Err(ReadingError::Message("Invalid entity anchor".to_string())),
+        }
+    }
+}
+
+/// Rotates the player to face a point, or an entity when `entity` is set.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_LOOK_AT)]
+pub struct PlayerLookAt {
+    pub from_anchor: EntityAnchor,
+    pub target: Vector3<f64>,
+    pub entity: Option<(VarInt, EntityAnchor)>,
+}
+
+impl PlayerLookAt {
+    pub fn new(
+        from_anchor: EntityAnchor,
+        target: Vector3<f64>,
+        entity: Option<(VarInt, EntityAnchor)>,
+    ) -> Self {
+        Self {
+            from_anchor,
+            target,
+            entity,
+        }
+    }
+}
+
+impl Packet for PlayerLookAt {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            from_anchor: EntityAnchor::try_from(bytebuf.try_get_var_int()?)?,
+            target: bytebuf.try_get_vec3()?,
+            entity: bytebuf.try_get_option(|v| {
+                Ok((
+                    v.try_get_var_int()?,
+                    EntityAnchor::try_from(v.try_get_var_int()?)?,
+                ))
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.from_anchor as i32));
+        bytebuf.put_vec3(&self.target);
+        bytebuf.put_option(&self.entity, |p, (entity_id, anchor)| {
+            p.put_var_int(entity_id);
+            p.put_var_int(&VarInt(*anchor as i32));
+        });
+    }
+}
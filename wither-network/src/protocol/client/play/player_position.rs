@@ -0,0 +1,85 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_PLAYER_POSITION;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, PositionFlag, VarInt,
+};
+
+/// Position, velocity and rotation of an entity as sent in teleports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionMoveRotation {
+    pub position: Vector3<f64>,
+    pub delta_movement: Vector3<f64>,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl PositionMoveRotation {
+    pub fn new(position: Vector3<f64>, delta_movement: Vector3<f64>, yaw: f32, pitch: f32) -> Self {
+        Self {
+            position,
+            delta_movement,
+            yaw,
+            pitch,
+        }
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            position: bytebuf.try_get_vec3()?,
+            delta_movement: bytebuf.try_get_vec3()?,
+            yaw: bytebuf.try_get_f32()?,
+            pitch: bytebuf.try_get_f32()?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_vec3(&self.position);
+        bytebuf.put_vec3(&self.delta_movement);
+        bytebuf.put_f32(self.yaw);
+        bytebuf.put_f32(self.pitch);
+    }
+}
+
+/// Teleports the player, must be confirmed with the `teleport_id`.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_POSITION)]
+pub struct PlayerPosition {
+    pub teleport_id: VarInt,
+    pub change: PositionMoveRotation,
+    /// Fields in `change` that are relative to the current values.
+    pub relatives: Vec<PositionFlag>,
+}
+
+impl PlayerPosition {
+    pub fn new(
+        teleport_id: VarInt,
+        change: PositionMoveRotation,
+        relatives: Vec<PositionFlag>,
+    ) -> Self {
+        Self {
+            teleport_id,
+            change,
+            relatives,
+        }
+    }
+}
+
+impl Packet for PlayerPosition {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            teleport_id: bytebuf.try_get_var_int()?,
+            change: PositionMoveRotation::read(bytebuf)?,
+            relatives: PositionFlag::from_bitfield(bytebuf.try_get_i32()?),
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.teleport_id);
+        self.change.write(bytebuf);
+        bytebuf.put_i32(PositionFlag::get_bitfield(&self.relatives));
+    }
+}
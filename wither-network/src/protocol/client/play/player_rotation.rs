@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PLAYER_ROTATION;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PLAYER_ROTATION)]
+pub struct PlayerRotation {
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl PlayerRotation {
+    pub fn new(yaw: f32, pitch: f32) -> Self {
+        Self { yaw, pitch }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PONG_RESPONSE;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PONG_RESPONSE)]
+pub struct PongResponse {
+    pub time: i64,
+}
+
+impl PongResponse {
+    pub fn new(time: i64) -> Self {
+        Self { time }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_PROJECTILE_POWER;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PROJECTILE_POWER)]
+pub struct ProjectilePower {
+    pub entity_id: VarInt,
+    pub acceleration_power: f64,
+}
+
+impl ProjectilePower {
+    pub fn new(entity_id: VarInt, acceleration_power: f64) -> Self {
+        Self {
+            entity_id,
+            acceleration_power,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_RECIPE_BOOK_ADD;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::RecipeDisplayEntry,
+    Packet,
+};
+
+#[derive(Debug, Clone)]
+pub struct RecipeBookEntry {
+    pub contents: RecipeDisplayEntry,
+    /// 0x1: show notification, 0x2: highlight as new
+    pub flags: u8,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_RECIPE_BOOK_ADD)]
+pub struct RecipeBookAdd {
+    pub entries: Vec<RecipeBookEntry>,
+    /// Replace the whole recipe book instead of adding to it.
+    pub replace: bool,
+}
+
+impl RecipeBookAdd {
+    pub fn new(entries: Vec<RecipeBookEntry>, replace: bool) -> Self {
+        Self { entries, replace }
+    }
+}
+
+impl Packet for RecipeBookAdd {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entries: bytebuf.get_list(|v| {
+                Ok(RecipeBookEntry {
+                    contents: RecipeDisplayEntry::read(v)?,
+                    flags: v.try_get_u8()?,
+                })
+            })?,
+            replace: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.entries, |p, entry| {
+            entry.contents.write(p);
+            p.put_u8(entry.flags);
+        });
+        bytebuf.put_bool(self.replace);
+    }
+}
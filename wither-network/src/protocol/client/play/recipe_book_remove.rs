@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_RECIPE_BOOK_REMOVE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_RECIPE_BOOK_REMOVE)]
+pub struct RecipeBookRemove {
+    pub recipes: Vec<VarInt>,
+}
+
+impl RecipeBookRemove {
+    pub fn new(recipes: Vec<VarInt>) -> Self {
+        Self { recipes }
+    }
+}
+
+impl Packet for RecipeBookRemove {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            recipes: bytebuf.get_list(|v| v.try_get_var_int())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.recipes, |p, recipe| p.put_var_int(recipe));
+    }
+}
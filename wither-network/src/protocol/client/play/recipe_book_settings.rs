@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_RECIPE_BOOK_SETTINGS;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_RECIPE_BOOK_SETTINGS)]
+pub struct RecipeBookSettings {
+    pub crafting_open: bool,
+    pub crafting_filtering: bool,
+    pub furnace_open: bool,
+    pub furnace_filtering: bool,
+    pub blast_furnace_open: bool,
+    pub blast_furnace_filtering: bool,
+    pub smoker_open: bool,
+    pub smoker_filtering: bool,
+}
+
+impl RecipeBookSettings {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        crafting_open: bool,
+        crafting_filtering: bool,
+        furnace_open: bool,
+        furnace_filtering: bool,
+        blast_furnace_open: bool,
+        blast_furnace_filtering: bool,
+        smoker_open: bool,
+        smoker_filtering: bool,
+    ) -> Self {
+        Self {
+            crafting_open,
+            crafting_filtering,
+            furnace_open,
+            furnace_filtering,
+            blast_furnace_open,
+            blast_furnace_filtering,
+            smoker_open,
+            smoker_filtering,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_REMOVE_ENTITIES;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_REMOVE_ENTITIES)]
+pub struct RemoveEntities {
+    pub entity_ids: Vec<VarInt>,
+}
+
+impl RemoveEntities {
+    pub fn new(entity_ids: Vec<VarInt>) -> Self {
+        Self { entity_ids }
+    }
+}
+
+impl Packet for RemoveEntities {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_ids: bytebuf.get_list(|v| v.try_get_var_int())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.entity_ids, |p, id| p.put_var_int(id));
+    }
+}
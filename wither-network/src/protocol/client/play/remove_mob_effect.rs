@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_REMOVE_MOB_EFFECT;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_REMOVE_MOB_EFFECT)]
+pub struct RemoveMobEffect {
+    pub entity_id: VarInt,
+    pub effect: VarInt,
+}
+
+impl RemoveMobEffect {
+    pub fn new(entity_id: VarInt, effect: VarInt) -> Self {
+        Self { entity_id, effect }
+    }
+}
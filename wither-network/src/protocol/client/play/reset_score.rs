@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_RESET_SCORE;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_RESET_SCORE)]
+pub struct ResetScore {
+    pub owner: String,
+    pub objective: Option<String>,
+}
+
+impl ResetScore {
+    pub fn new(owner: String, objective: Option<String>) -> Self {
+        Self { owner, objective }
+    }
+}
@@ -0,0 +1,57 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_RESOURCE_PACK_PUSH;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_RESOURCE_PACK_PUSH)]
+pub struct ResourcePackPush {
+    pub id: uuid::Uuid,
+    pub url: String,
+    pub hash: String, // 40
+    pub forced: bool,
+    pub prompt: Option<TextComponent>,
+}
+
+impl ResourcePackPush {
+    pub fn new(
+        id: uuid::Uuid,
+        url: String,
+        hash: String,
+        forced: bool,
+        prompt: Option<TextComponent>,
+    ) -> Self {
+        Self {
+            id,
+            url,
+            hash,
+            forced,
+            prompt,
+        }
+    }
+}
+
+impl Packet for ResourcePackPush {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_uuid()?,
+            url: bytebuf.try_get_string()?,
+            hash: bytebuf.try_get_string_len(40)?,
+            forced: bytebuf.try_get_bool()?,
+            prompt: bytebuf.try_get_option(|v| v.try_get_text_component())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.id);
+        bytebuf.put_string(&self.url);
+        bytebuf.put_string_len(&self.hash, 40);
+        bytebuf.put_bool(self.forced);
+        bytebuf.put_option(&self.prompt, |p, prompt| p.put_text_component(prompt));
+    }
+}
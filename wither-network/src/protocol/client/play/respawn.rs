@@ -0,0 +1,42 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_RESPAWN;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ReadingError},
+    Packet,
+};
+
+use super::CommonPlayerSpawnInfo;
+
+/// Sent on death and when changing dimension.
+#[derive(Debug)]
+#[wither_packet(PLAY_RESPAWN)]
+pub struct Respawn {
+    pub spawn_info: CommonPlayerSpawnInfo,
+    /// 0x1: keep attributes, 0x2: keep entity data
+    pub data_kept: u8,
+}
+
+impl Respawn {
+    pub fn new(spawn_info: CommonPlayerSpawnInfo, data_kept: u8) -> Self {
+        Self {
+            spawn_info,
+            data_kept,
+        }
+    }
+}
+
+impl Packet for Respawn {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            spawn_info: CommonPlayerSpawnInfo::read(bytebuf)?,
+            data_kept: bytebuf.try_get_u8()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.spawn_info.write(bytebuf);
+        bytebuf.put_u8(self.data_kept);
+    }
+}
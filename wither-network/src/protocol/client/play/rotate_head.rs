@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_ROTATE_HEAD;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ROTATE_HEAD)]
+pub struct RotateHead {
+    pub entity_id: VarInt,
+    pub head_yaw: u8,
+}
+
+impl RotateHead {
+    pub fn new(entity_id: VarInt, head_yaw: u8) -> Self {
+        Self {
+            entity_id,
+            head_yaw,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SECTION_BLOCKS_UPDATE;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::var_long::VarLong,
+    Packet, VarInt,
+};
+
+/// Changes several blocks in one chunk section.
+#[derive(Debug)]
+#[wither_packet(PLAY_SECTION_BLOCKS_UPDATE)]
+pub struct SectionBlocksUpdate {
+    /// Position of the section in section coordinates.
+    pub section: Vector3<i32>,
+    /// Section relative position of each change together with the new block state id.
+    pub changes: Vec<(Vector3<u8>, VarInt)>,
+}
+
+impl SectionBlocksUpdate {
+    pub fn new(section: Vector3<i32>, changes: Vec<(Vector3<u8>, VarInt)>) -> Self {
+        Self { section, changes }
+    }
+}
+
+impl Packet for SectionBlocksUpdate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let packed = bytebuf.try_get_i64()?;
+        let section = Vector3::new(
+            (packed >> 42) as i32,
+            (packed << 44 >> 44) as i32,
+            (packed << 22 >> 42) as i32,
+        );
+        let changes = bytebuf.get_list(|v| {
+            let entry = v.try_get_var_long()?.0;
+            let position = Vector3::new(
+                ((entry >> 8) & 0xF) as u8,
+                (entry & 0xF) as u8,
+                ((entry >> 4) & 0xF) as u8,
+            );
+            Ok((position, VarInt((entry >> 12) as i32)))
+        })?;
+        Ok(Self { section, changes })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_i64(
+            ((self.section.x as i64 & 0x3FFFFF) << 42)
+                | ((self.section.z as i64 & 0x3FFFFF) << 20)
+                | (self.section.y as i64 & 0xFFFFF),
+        );
+        bytebuf.put_list(&self.changes, |p, (position, state)| {
+            let local = ((position.x as i64) << 8) | ((position.z as i64) << 4) | position.y as i64;
+            p.put_var_long(&VarLong(((state.0 as i64) << 12) | local));
+        });
+    }
+}
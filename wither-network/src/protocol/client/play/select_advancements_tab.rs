@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SELECT_ADVANCEMENTS_TAB;
+use wither_macros::wither_packet;
+
+use crate::codec::identifier::Identifier;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SELECT_ADVANCEMENTS_TAB)]
+pub struct SelectAdvancementsTab {
+    pub tab: Option<Identifier>,
+}
+
+impl SelectAdvancementsTab {
+    pub fn new(tab: Option<Identifier>) -> Self {
+        Self { tab }
+    }
+}
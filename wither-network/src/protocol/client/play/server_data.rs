@@ -0,0 +1,39 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_SERVER_DATA;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+const MAX_ICON_SIZE: usize = 32768;
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SERVER_DATA)]
+pub struct ServerData {
+    pub motd: TextComponent,
+    /// PNG bytes of the server icon.
+    pub icon: Option<Bytes>,
+}
+
+impl ServerData {
+    pub fn new(motd: TextComponent, icon: Option<Bytes>) -> Self {
+        Self { motd, icon }
+    }
+}
+
+impl Packet for ServerData {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            motd: bytebuf.try_get_text_component()?,
+            icon: bytebuf.try_get_option(|v| v.try_get_byte_array(MAX_ICON_SIZE))?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.motd);
+        bytebuf.put_option(&self.icon, |p, icon| p.put_byte_array(icon));
+    }
+}
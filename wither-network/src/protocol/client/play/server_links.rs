@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SERVER_LINKS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Link, Packet,
+};
+
+/// Links shown in the pause menu.
+#[derive(Debug)]
+#[wither_packet(PLAY_SERVER_LINKS)]
+pub struct ServerLinks {
+    pub links: Vec<Link>,
+}
+
+impl ServerLinks {
+    pub fn new(links: Vec<Link>) -> Self {
+        Self { links }
+    }
+}
+
+impl Packet for ServerLinks {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            links: bytebuf.get_list(Link::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.links, |p, link| link.write(p));
+    }
+}
@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_ACTION_BAR_TEXT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_ACTION_BAR_TEXT)]
+pub struct SetActionBarText {
+    pub text: TextComponent,
+}
+
+impl SetActionBarText {
+    pub fn new(text: TextComponent) -> Self {
+        Self { text }
+    }
+}
+
+impl Packet for SetActionBarText {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            text: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.text);
+    }
+}
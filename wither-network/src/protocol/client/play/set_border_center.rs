@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_BORDER_CENTER;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_BORDER_CENTER)]
+pub struct SetBorderCenter {
+    pub x: f64,
+    pub z: f64,
+}
+
+impl SetBorderCenter {
+    pub fn new(x: f64, z: f64) -> Self {
+        Self { x, z }
+    }
+}
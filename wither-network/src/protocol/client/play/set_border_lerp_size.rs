@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_BORDER_LERP_SIZE;
+use wither_macros::wither_packet;
+
+use crate::codec::var_long::VarLong;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_BORDER_LERP_SIZE)]
+pub struct SetBorderLerpSize {
+    pub old_size: f64,
+    pub new_size: f64,
+    pub lerp_time: VarLong,
+}
+
+impl SetBorderLerpSize {
+    pub fn new(old_size: f64, new_size: f64, lerp_time: VarLong) -> Self {
+        Self {
+            old_size,
+            new_size,
+            lerp_time,
+        }
+    }
+}
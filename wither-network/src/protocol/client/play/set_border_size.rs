@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_BORDER_SIZE;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_BORDER_SIZE)]
+pub struct SetBorderSize {
+    pub size: f64,
+}
+
+impl SetBorderSize {
+    pub fn new(size: f64) -> Self {
+        Self { size }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_BORDER_WARNING_DELAY;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_BORDER_WARNING_DELAY)]
+pub struct SetBorderWarningDelay {
+    pub warning_delay: VarInt,
+}
+
+impl SetBorderWarningDelay {
+    pub fn new(warning_delay: VarInt) -> Self {
+        Self { warning_delay }
+    }
+}
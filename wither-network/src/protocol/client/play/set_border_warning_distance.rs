@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_BORDER_WARNING_DISTANCE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_BORDER_WARNING_DISTANCE)]
+pub struct SetBorderWarningDistance {
+    pub warning_blocks: VarInt,
+}
+
+impl SetBorderWarningDistance {
+    pub fn new(warning_blocks: VarInt) -> Self {
+        Self { warning_blocks }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_CAMERA;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_CAMERA)]
+pub struct SetCamera {
+    pub camera_id: VarInt,
+}
+
+impl SetCamera {
+    pub fn new(camera_id: VarInt) -> Self {
+        Self { camera_id }
+    }
+}
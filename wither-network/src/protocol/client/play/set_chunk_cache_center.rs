@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_CHUNK_CACHE_CENTER;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// The chunk the player is in, chunks outside the view distance around it are unloaded.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_CHUNK_CACHE_CENTER)]
+pub struct SetChunkCacheCenter {
+    pub x: VarInt,
+    pub z: VarInt,
+}
+
+impl SetChunkCacheCenter {
+    pub fn new(x: VarInt, z: VarInt) -> Self {
+        Self { x, z }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_CHUNK_CACHE_RADIUS;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_CHUNK_CACHE_RADIUS)]
+pub struct SetChunkCacheRadius {
+    pub radius: VarInt,
+}
+
+impl SetChunkCacheRadius {
+    pub fn new(radius: VarInt) -> Self {
+        Self { radius }
+    }
+}
@@ -0,0 +1,30 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_CURSOR_ITEM;
+use wither_macros::wither_packet;
+
+use crate::{bytebuf::ReadingError, codec::slot::Slot, Packet};
+
+/// The item the player holds with their cursor in an open container.
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_CURSOR_ITEM)]
+pub struct SetCursorItem {
+    pub contents: Slot,
+}
+
+impl SetCursorItem {
+    pub fn new(contents: Slot) -> Self {
+        Self { contents }
+    }
+}
+
+impl Packet for SetCursorItem {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            contents: Slot::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.contents.write(bytebuf);
+    }
+}
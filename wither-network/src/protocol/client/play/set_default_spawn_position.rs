@@ -0,0 +1,37 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_DEFAULT_SPAWN_POSITION;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+/// Where the compass points to.
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_DEFAULT_SPAWN_POSITION)]
+pub struct SetDefaultSpawnPosition {
+    pub location: WorldPosition,
+    pub angle: f32,
+}
+
+impl SetDefaultSpawnPosition {
+    pub fn new(location: WorldPosition, angle: f32) -> Self {
+        Self { location, angle }
+    }
+}
+
+impl Packet for SetDefaultSpawnPosition {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            angle: bytebuf.try_get_f32()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_f32(self.angle);
+    }
+}
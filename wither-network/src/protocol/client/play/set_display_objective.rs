@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_DISPLAY_OBJECTIVE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_DISPLAY_OBJECTIVE)]
+pub struct SetDisplayObjective {
+    pub slot: VarInt,
+    pub objective_name: String,
+}
+
+impl SetDisplayObjective {
+    pub fn new(slot: VarInt, objective_name: String) -> Self {
+        Self {
+            slot,
+            objective_name,
+        }
+    }
+}
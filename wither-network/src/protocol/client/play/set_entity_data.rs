@@ -0,0 +1,45 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_SET_ENTITY_DATA;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::EntityData,
+    Packet, VarInt, MAX_PACKET_SIZE,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_ENTITY_DATA)]
+pub struct SetEntityData {
+    pub entity_id: VarInt,
+    /// The raw metadata entries, terminated by `0xFF`.
+    pub metadata: Bytes,
+}
+
+impl SetEntityData {
+    pub fn new(entity_id: VarInt, metadata: Bytes) -> Self {
+        Self {
+            entity_id,
+            metadata,
+        }
+    }
+
+    /// Decodes the metadata entries.
+    pub fn entries(&self) -> Result<Vec<EntityData>, ReadingError> {
+        EntityData::read_list(&mut self.metadata.clone())
+    }
+}
+
+impl Packet for SetEntityData {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            metadata: bytebuf.try_get_remaining(MAX_PACKET_SIZE)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_slice(&self.metadata);
+    }
+}
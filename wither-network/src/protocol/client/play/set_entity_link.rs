@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_ENTITY_LINK;
+use wither_macros::wither_packet;
+
+/// Attaches a leash from `source_id` to `dest_id`.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_ENTITY_LINK)]
+pub struct SetEntityLink {
+    pub source_id: i32,
+    pub dest_id: i32,
+}
+
+impl SetEntityLink {
+    pub fn new(source_id: i32, dest_id: i32) -> Self {
+        Self { source_id, dest_id }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_ENTITY_MOTION;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Velocity of an entity, in 1/8000 of a block per tick.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_ENTITY_MOTION)]
+pub struct SetEntityMotion {
+    pub entity_id: VarInt,
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
+impl SetEntityMotion {
+    pub fn new(entity_id: VarInt, velocity_x: i16, velocity_y: i16, velocity_z: i16) -> Self {
+        Self {
+            entity_id,
+            velocity_x,
+            velocity_y,
+            velocity_z,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_EQUIPMENT;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::slot::Slot,
+    Packet, VarInt,
+};
+
+/// Continuation bit of the slot byte, set on every entry but the last.
+const CONTINUE_MASK: u8 = 0x80;
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_EQUIPMENT)]
+pub struct SetEquipment {
+    pub entity_id: VarInt,
+    /// 0: main hand, 1: off hand, 2-5: boots to helmet, 6: body
+    pub equipment: Vec<(u8, Slot)>,
+}
+
+impl SetEquipment {
+    pub fn new(entity_id: VarInt, equipment: Vec<(u8, Slot)>) -> Self {
+        Self {
+            entity_id,
+            equipment,
+        }
+    }
+}
+
+impl Packet for SetEquipment {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let entity_id = bytebuf.try_get_var_int()?;
+        let mut equipment = Vec::new();
+        loop {
+            let slot = bytebuf.try_get_u8()?;
+            equipment.push((slot & !CONTINUE_MASK, Slot::read(bytebuf)?));
+            if slot & CONTINUE_MASK == 0 {
+                break;
+            }
+        }
+        Ok(Self {
+            entity_id,
+            equipment,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        for (i, (slot, item)) in self.equipment.iter().enumerate() {
+            if i + 1 < self.equipment.len() {
+                bytebuf.put_u8(slot | CONTINUE_MASK);
+            } else {
+                bytebuf.put_u8(*slot);
+            }
+            item.write(bytebuf);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_EXPERIENCE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_EXPERIENCE)]
+pub struct SetExperience {
+    pub progress: f32,
+    pub level: VarInt,
+    pub total_experience: VarInt,
+}
+
+impl SetExperience {
+    pub fn new(progress: f32, level: VarInt, total_experience: VarInt) -> Self {
+        Self {
+            progress,
+            level,
+            total_experience,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_HEALTH;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_HEALTH)]
+pub struct SetHealth {
+    pub health: f32,
+    pub food: VarInt,
+    pub saturation: f32,
+}
+
+impl SetHealth {
+    pub fn new(health: f32, food: VarInt, saturation: f32) -> Self {
+        Self {
+            health,
+            food,
+            saturation,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_HELD_SLOT;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_HELD_SLOT)]
+pub struct SetHeldSlot {
+    pub slot: VarInt,
+}
+
+impl SetHeldSlot {
+    pub fn new(slot: VarInt) -> Self {
+        Self { slot }
+    }
+}
@@ -0,0 +1,91 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_OBJECTIVE;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    NumberFormat, Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct ObjectiveParameters {
+    pub display_name: TextComponent,
+    /// 0: integer, 1: hearts
+    pub render_type: VarInt,
+    pub number_format: Option<NumberFormat>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ObjectiveMethod {
+    Add(ObjectiveParameters),
+    Remove,
+    Change(ObjectiveParameters),
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_OBJECTIVE)]
+pub struct SetObjective {
+    pub objective_name: String,
+    pub method: ObjectiveMethod,
+}
+
+impl SetObjective {
+    pub fn new(objective_name: String, method: ObjectiveMethod) -> Self {
+        Self {
+            objective_name,
+            method,
+        }
+    }
+}
+
+impl ObjectiveParameters {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            display_name: bytebuf.try_get_text_component()?,
+            render_type: bytebuf.try_get_var_int()?,
+            number_format: bytebuf.try_get_option(NumberFormat::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.display_name);
+        bytebuf.put_var_int(&self.render_type);
+        bytebuf.put_option(&self.number_format, |p, format| format.write(p));
+    }
+}
+
+impl Packet for SetObjective {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let objective_name = bytebuf.try_get_string()?;
+        let method = match bytebuf.try_get_u8()? {
+            0 => ObjectiveMethod::Add(ObjectiveParameters::read(bytebuf)?),
+            1 => ObjectiveMethod::Remove,
+            2 => ObjectiveMethod::Change(ObjectiveParameters::read(bytebuf)?),
+            _ => {
+                return Err(ReadingError::Message(
+                    "Invalid objective method".to_string(),
+                ))
+            }
+        };
+        Ok(Self {
+            objective_name,
+            method,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.objective_name);
+        match &self.method {
+            ObjectiveMethod::Add(parameters) => {
+                bytebuf.put_u8(0);
+                parameters.write(bytebuf);
+            }
+            ObjectiveMethod::Remove => bytebuf.put_u8(1),
+            ObjectiveMethod::Change(parameters) => {
+                bytebuf.put_u8(2);
+                parameters.write(bytebuf);
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_PASSENGERS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_PASSENGERS)]
+pub struct SetPassengers {
+    pub vehicle_id: VarInt,
+    pub passengers: Vec<VarInt>,
+}
+
+impl SetPassengers {
+    pub fn new(vehicle_id: VarInt, passengers: Vec<VarInt>) -> Self {
+        Self {
+            vehicle_id,
+            passengers,
+        }
+    }
+}
+
+impl Packet for SetPassengers {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            vehicle_id: bytebuf.try_get_var_int()?,
+            passengers: bytebuf.get_list(|v| v.try_get_var_int())?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.vehicle_id);
+        bytebuf.put_list(&self.passengers, |p, passenger| p.put_var_int(passenger));
+    }
+}
@@ -0,0 +1,37 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_PLAYER_INVENTORY;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::slot::Slot,
+    Packet, VarInt,
+};
+
+/// Sets a slot of the player inventory regardless of the open container.
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_PLAYER_INVENTORY)]
+pub struct SetPlayerInventory {
+    pub slot: VarInt,
+    pub contents: Slot,
+}
+
+impl SetPlayerInventory {
+    pub fn new(slot: VarInt, contents: Slot) -> Self {
+        Self { slot, contents }
+    }
+}
+
+impl Packet for SetPlayerInventory {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            slot: bytebuf.try_get_var_int()?,
+            contents: Slot::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.slot);
+        self.contents.write(bytebuf);
+    }
+}
@@ -0,0 +1,119 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_PLAYER_TEAM;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+const MAX_RULE_LENGTH: usize = 40;
+
+#[derive(Debug, Clone)]
+pub struct TeamParameters {
+    pub display_name: TextComponent,
+    /// 0x1: friendly fire, 0x2: see invisible teammates
+    pub options: u8,
+    /// `always`, `hideForOtherTeams`, `hideForOwnTeam` or `never`
+    pub name_tag_visibility: String,
+    /// `always`, `pushOtherTeams`, `pushOwnTeam` or `never`
+    pub collision_rule: String,
+    pub color: VarInt,
+    pub prefix: TextComponent,
+    pub suffix: TextComponent,
+}
+
+#[derive(Debug, Clone)]
+pub enum TeamMethod {
+    Add {
+        parameters: TeamParameters,
+        players: Vec<String>,
+    },
+    Remove,
+    Change(TeamParameters),
+    AddPlayers(Vec<String>),
+    RemovePlayers(Vec<String>),
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_PLAYER_TEAM)]
+pub struct SetPlayerTeam {
+    pub name: String,
+    pub method: TeamMethod,
+}
+
+impl SetPlayerTeam {
+    pub fn new(name: String, method: TeamMethod) -> Self {
+        Self { name, method }
+    }
+}
+
+impl TeamParameters {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            display_name: bytebuf.try_get_text_component()?,
+            options: bytebuf.try_get_u8()?,
+            name_tag_visibility: bytebuf.try_get_string_len(MAX_RULE_LENGTH)?,
+            collision_rule: bytebuf.try_get_string_len(MAX_RULE_LENGTH)?,
+            color: bytebuf.try_get_var_int()?,
+            prefix: bytebuf.try_get_text_component()?,
+            suffix: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.display_name);
+        bytebuf.put_u8(self.options);
+        bytebuf.put_string_len(&self.name_tag_visibility, MAX_RULE_LENGTH);
+        bytebuf.put_string_len(&self.collision_rule, MAX_RULE_LENGTH);
+        bytebuf.put_var_int(&self.color);
+        bytebuf.put_text_component(&self.prefix);
+        bytebuf.put_text_component(&self.suffix);
+    }
+}
+
+impl Packet for SetPlayerTeam {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let name = bytebuf.try_get_string()?;
+        let method = match bytebuf.try_get_u8()? {
+            0 => TeamMethod::Add {
+                parameters: TeamParameters::read(bytebuf)?,
+                players: bytebuf.get_list(|v| v.try_get_string())?,
+            },
+            1 => TeamMethod::Remove,
+            2 => TeamMethod::Change(TeamParameters::read(bytebuf)?),
+            3 => TeamMethod::AddPlayers(bytebuf.get_list(|v| v.try_get_string())?),
+            4 => TeamMethod::RemovePlayers(bytebuf.get_list(|v| v.try_get_string())?),
+            _ => return Err(ReadingError::Message("Invalid team method".to_string())),
+        };
+        Ok(Self { name, method })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.name);
+        match &self.method {
+            TeamMethod::Add {
+                parameters,
+                players,
+            } => {
+                bytebuf.put_u8(0);
+                parameters.write(bytebuf);
+                bytebuf.put_list(players, |p, player| p.put_string(player));
+            }
+            TeamMethod::Remove => bytebuf.put_u8(1),
+            TeamMethod::Change(parameters) => {
+                bytebuf.put_u8(2);
+                parameters.write(bytebuf);
+            }
+            TeamMethod::AddPlayers(players) => {
+                bytebuf.put_u8(3);
+                bytebuf.put_list(players, |p, player| p.put_string(player));
+            }
+            TeamMethod::RemovePlayers(players) => {
+                bytebuf.put_u8(4);
+                bytebuf.put_list(players, |p, player| p.put_string(player));
+            }
+        }
+    }
+}
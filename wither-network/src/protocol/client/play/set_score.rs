@@ -0,0 +1,57 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_SCORE;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    NumberFormat, Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_SCORE)]
+pub struct SetScore {
+    pub owner: String,
+    pub objective_name: String,
+    pub score: VarInt,
+    pub display: Option<TextComponent>,
+    pub number_format: Option<NumberFormat>,
+}
+
+impl SetScore {
+    pub fn new(
+        owner: String,
+        objective_name: String,
+        score: VarInt,
+        display: Option<TextComponent>,
+        number_format: Option<NumberFormat>,
+    ) -> Self {
+        Self {
+            owner,
+            objective_name,
+            score,
+            display,
+            number_format,
+        }
+    }
+}
+
+impl Packet for SetScore {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            owner: bytebuf.try_get_string()?,
+            objective_name: bytebuf.try_get_string()?,
+            score: bytebuf.try_get_var_int()?,
+            display: bytebuf.try_get_option(|v| v.try_get_text_component())?,
+            number_format: bytebuf.try_get_option(NumberFormat::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.owner);
+        bytebuf.put_string(&self.objective_name);
+        bytebuf.put_var_int(&self.score);
+        bytebuf.put_option(&self.display, |p, display| p.put_text_component(display));
+        bytebuf.put_option(&self.number_format, |p, format| format.write(p));
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_SIMULATION_DISTANCE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_SIMULATION_DISTANCE)]
+pub struct SetSimulationDistance {
+    pub simulation_distance: VarInt,
+}
+
+impl SetSimulationDistance {
+    pub fn new(simulation_distance: VarInt) -> Self {
+        Self {
+            simulation_distance,
+        }
+    }
+}
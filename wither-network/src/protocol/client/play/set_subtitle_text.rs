@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_SUBTITLE_TEXT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_SUBTITLE_TEXT)]
+pub struct SetSubtitleText {
+    pub subtitle: TextComponent,
+}
+
+impl SetSubtitleText {
+    pub fn new(subtitle: TextComponent) -> Self {
+        Self { subtitle }
+    }
+}
+
+impl Packet for SetSubtitleText {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            subtitle: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.subtitle);
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_TIME;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_TIME)]
+pub struct SetTime {
+    pub game_time: i64,
+    pub day_time: i64,
+    pub tick_day_time: bool,
+}
+
+impl SetTime {
+    pub fn new(game_time: i64, day_time: i64, tick_day_time: bool) -> Self {
+        Self {
+            game_time,
+            day_time,
+            tick_day_time,
+        }
+    }
+}
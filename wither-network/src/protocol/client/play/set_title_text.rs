@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SET_TITLE_TEXT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_TITLE_TEXT)]
+pub struct SetTitleText {
+    pub title: TextComponent,
+}
+
+impl SetTitleText {
+    pub fn new(title: TextComponent) -> Self {
+        Self { title }
+    }
+}
+
+impl Packet for SetTitleText {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            title: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.title);
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_SET_TITLES_ANIMATION;
+use wither_macros::wither_packet;
+
+/// Title timings, in ticks.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_TITLES_ANIMATION)]
+pub struct SetTitlesAnimation {
+    pub fade_in: i32,
+    pub stay: i32,
+    pub fade_out: i32,
+}
+
+impl SetTitlesAnimation {
+    pub fn new(fade_in: i32, stay: i32, fade_out: i32) -> Self {
+        Self {
+            fade_in,
+            stay,
+            fade_out,
+        }
+    }
+}
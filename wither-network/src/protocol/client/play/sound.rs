@@ -0,0 +1,69 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SOUND;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    IDOrSoundEvent, Packet, SoundCategory, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SOUND)]
+pub struct Sound {
+    pub sound: IDOrSoundEvent,
+    pub category: SoundCategory,
+    /// Position multiplied by 8.
+    pub position: Vector3<i32>,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+impl Sound {
+    pub fn new(
+        sound: IDOrSoundEvent,
+        category: SoundCategory,
+        position: Vector3<i32>,
+        volume: f32,
+        pitch: f32,
+        seed: i64,
+    ) -> Self {
+        Self {
+            sound,
+            category,
+            position,
+            volume,
+            pitch,
+            seed,
+        }
+    }
+}
+
+impl Packet for Sound {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            sound: IDOrSoundEvent::read(bytebuf)?,
+            category: SoundCategory::try_from(bytebuf.try_get_var_int()?)?,
+            position: Vector3::new(
+                bytebuf.try_get_i32()?,
+                bytebuf.try_get_i32()?,
+                bytebuf.try_get_i32()?,
+            ),
+            volume: bytebuf.try_get_f32()?,
+            pitch: bytebuf.try_get_f32()?,
+            seed: bytebuf.try_get_i64()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.sound.write(bytebuf);
+        bytebuf.put_var_int(&VarInt(self.category as i32));
+        bytebuf.put_i32(self.position.x);
+        bytebuf.put_i32(self.position.y);
+        bytebuf.put_i32(self.position.z);
+        bytebuf.put_f32(self.volume);
+        bytebuf.put_f32(self.pitch);
+        bytebuf.put_i64(self.seed);
+    }
+}
@@ -0,0 +1,62 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SOUND_ENTITY;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    IDOrSoundEvent, Packet, SoundCategory, VarInt,
+};
+
+/// Plays a sound that follows an entity.
+#[derive(Debug)]
+#[wither_packet(PLAY_SOUND_ENTITY)]
+pub struct SoundEntity {
+    pub sound: IDOrSoundEvent,
+    pub category: SoundCategory,
+    pub entity_id: VarInt,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+impl SoundEntity {
+    pub fn new(
+        sound: IDOrSoundEvent,
+        category: SoundCategory,
+        entity_id: VarInt,
+        volume: f32,
+        pitch: f32,
+        seed: i64,
+    ) -> Self {
+        Self {
+            sound,
+            category,
+            entity_id,
+            volume,
+            pitch,
+            seed,
+        }
+    }
+}
+
+impl Packet for SoundEntity {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            sound: IDOrSoundEvent::read(bytebuf)?,
+            category: SoundCategory::try_from(bytebuf.try_get_var_int()?)?,
+            entity_id: bytebuf.try_get_var_int()?,
+            volume: bytebuf.try_get_f32()?,
+            pitch: bytebuf.try_get_f32()?,
+            seed: bytebuf.try_get_i64()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.sound.write(bytebuf);
+        bytebuf.put_var_int(&VarInt(self.category as i32));
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_f32(self.volume);
+        bytebuf.put_f32(self.pitch);
+        bytebuf.put_i64(self.seed);
+    }
+}
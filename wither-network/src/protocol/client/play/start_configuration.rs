@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_START_CONFIGURATION;
+use wither_macros::wither_packet;
+
+/// Sends the client back to the configuration state.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(PLAY_START_CONFIGURATION)]
+pub struct StartConfiguration {}
+
+impl StartConfiguration {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
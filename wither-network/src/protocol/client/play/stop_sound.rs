@@ -0,0 +1,50 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_STOP_SOUND;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, SoundCategory, VarInt,
+};
+
+/// Stops matching sounds, or all of them when neither filter is set.
+#[derive(Debug)]
+#[wither_packet(PLAY_STOP_SOUND)]
+pub struct StopSound {
+    pub category: Option<SoundCategory>,
+    pub sound: Option<Identifier>,
+}
+
+impl StopSound {
+    pub fn new(category: Option<SoundCategory>, sound: Option<Identifier>) -> Self {
+        Self { category, sound }
+    }
+}
+
+impl Packet for StopSound {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let flags = bytebuf.try_get_u8()?;
+        let category = if flags & 0x01 != 0 {
+            Some(SoundCategory::try_from(bytebuf.try_get_var_int()?)?)
+        } else {
+            None
+        };
+        let sound = if flags & 0x02 != 0 {
+            Some(bytebuf.try_get_identifer()?)
+        } else {
+            None
+        };
+        Ok(Self { category, sound })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_u8(self.category.is_some() as u8 | (self.sound.is_some() as u8) << 1);
+        if let Some(category) = self.category {
+            bytebuf.put_var_int(&VarInt(category as i32));
+        }
+        if let Some(sound) = &self.sound {
+            bytebuf.put_identifier(sound);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::clientbound::PLAY_STORE_COOKIE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    protocol::client::config::MAX_COOKIE_LENGTH,
+    Packet,
+};
+
+/// Asks the client to store a cookie, it is handed back on cookie requests
+/// and survives transfers to other servers.
+#[derive(Debug)]
+#[wither_packet(PLAY_STORE_COOKIE)]
+pub struct StoreCookie {
+    pub key: Identifier,
+    pub payload: Bytes, // 5120
+}
+
+impl StoreCookie {
+    pub fn new(key: Identifier, payload: Bytes) -> Self {
+        Self { key, payload }
+    }
+}
+
+impl Packet for StoreCookie {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            key: bytebuf.try_get_identifer()?,
+            payload: bytebuf.try_get_byte_array(MAX_COOKIE_LENGTH)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.key);
+        bytebuf.put_byte_array(&self.payload);
+    }
+}
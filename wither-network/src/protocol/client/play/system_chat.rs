@@ -0,0 +1,37 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_SYSTEM_CHAT;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SYSTEM_CHAT)]
+pub struct SystemChat {
+    pub content: TextComponent,
+    /// Show the message above the hotbar instead of in the chat.
+    pub overlay: bool,
+}
+
+impl SystemChat {
+    pub fn new(content: TextComponent, overlay: bool) -> Self {
+        Self { content, overlay }
+    }
+}
+
+impl Packet for SystemChat {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            content: bytebuf.try_get_text_component()?,
+            overlay: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.content);
+        bytebuf.put_bool(self.overlay);
+    }
+}
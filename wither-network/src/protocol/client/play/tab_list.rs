@@ -0,0 +1,36 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_TAB_LIST;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_TAB_LIST)]
+pub struct TabList {
+    pub header: TextComponent,
+    pub footer: TextComponent,
+}
+
+impl TabList {
+    pub fn new(header: TextComponent, footer: TextComponent) -> Self {
+        Self { header, footer }
+    }
+}
+
+impl Packet for TabList {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            header: bytebuf.try_get_text_component()?,
+            footer: bytebuf.try_get_text_component()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.header);
+        bytebuf.put_text_component(&self.footer);
+    }
+}
@@ -0,0 +1,40 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_TAG_QUERY;
+use wither_macros::wither_packet;
+use wither_nbt::tag::NbtTag;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Answers a block or entity NBT query, `nbt` is `NbtTag::End` if there is none.
+#[derive(Debug)]
+#[wither_packet(PLAY_TAG_QUERY)]
+pub struct TagQuery {
+    pub transaction_id: VarInt,
+    pub nbt: NbtTag,
+}
+
+impl TagQuery {
+    pub fn new(transaction_id: VarInt, nbt: NbtTag) -> Self {
+        Self {
+            transaction_id,
+            nbt,
+        }
+    }
+}
+
+impl Packet for TagQuery {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            transaction_id: bytebuf.try_get_var_int()?,
+            nbt: bytebuf.try_get_nbt()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.transaction_id);
+        bytebuf.put_nbt(&self.nbt);
+    }
+}
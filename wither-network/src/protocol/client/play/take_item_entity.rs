@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_TAKE_ITEM_ENTITY;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_TAKE_ITEM_ENTITY)]
+pub struct TakeItemEntity {
+    pub item_id: VarInt,
+    pub player_id: VarInt,
+    pub amount: VarInt,
+}
+
+impl TakeItemEntity {
+    pub fn new(item_id: VarInt, player_id: VarInt, amount: VarInt) -> Self {
+        Self {
+            item_id,
+            player_id,
+            amount,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_TELEPORT_ENTITY;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, PositionFlag, VarInt,
+};
+
+use super::PositionMoveRotation;
+
+#[derive(Debug)]
+#[wither_packet(PLAY_TELEPORT_ENTITY)]
+pub struct TeleportEntity {
+    pub entity_id: VarInt,
+    pub change: PositionMoveRotation,
+    pub relatives: Vec<PositionFlag>,
+    pub on_ground: bool,
+}
+
+impl TeleportEntity {
+    pub fn new(
+        entity_id: VarInt,
+        change: PositionMoveRotation,
+        relatives: Vec<PositionFlag>,
+        on_ground: bool,
+    ) -> Self {
+        Self {
+            entity_id,
+            change,
+            relatives,
+            on_ground,
+        }
+    }
+}
+
+impl Packet for TeleportEntity {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            change: PositionMoveRotation::read(bytebuf)?,
+            relatives: PositionFlag::from_bitfield(bytebuf.try_get_i32()?),
+            on_ground: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        self.change.write(bytebuf);
+        bytebuf.put_i32(PositionFlag::get_bitfield(&self.relatives));
+        bytebuf.put_bool(self.on_ground);
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_TICKING_STATE;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_TICKING_STATE)]
+pub struct TickingState {
+    pub tick_rate: f32,
+    pub is_frozen: bool,
+}
+
+impl TickingState {
+    pub fn new(tick_rate: f32, is_frozen: bool) -> Self {
+        Self {
+            tick_rate,
+            is_frozen,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_TICKING_STEP;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_TICKING_STEP)]
+pub struct TickingStep {
+    pub tick_steps: VarInt,
+}
+
+impl TickingStep {
+    pub fn new(tick_steps: VarInt) -> Self {
+        Self { tick_steps }
+    }
+}
@@ -0,0 +1,162 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_UPDATE_ADVANCEMENTS;
+use wither_macros::wither_packet;
+use wither_util::text::TextComponent;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::{identifier::Identifier, slot::Slot},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct Advancement {
+    pub id: Identifier,
+    pub parent: Option<Identifier>,
+    pub display: Option<AdvancementDisplay>,
+    /// Every inner list needs at least one completed criterion.
+    pub requirements: Vec<Vec<String>>,
+    pub sends_telemetry_event: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdvancementDisplay {
+    pub title: TextComponent,
+    pub description: TextComponent,
+    pub icon: Slot,
+    /// 0: task, 1: challenge, 2: goal
+    pub frame_type: VarInt,
+    /// 0x2: show toast, 0x4: hidden
+    pub flags: i32,
+    pub background: Option<Identifier>,
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct AdvancementProgress {
+    pub id: Identifier,
+    /// Criteria with the time they were obtained at, if they were.
+    pub criteria: Vec<(String, Option<i64>)>,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_UPDATE_ADVANCEMENTS)]
+pub struct UpdateAdvancements {
+    pub reset: bool,
+    pub added: Vec<Advancement>,
+    pub removed: Vec<Identifier>,
+    pub progress: Vec<AdvancementProgress>,
+}
+
+impl UpdateAdvancements {
+    pub fn new(
+        reset: bool,
+        added: Vec<Advancement>,
+        removed: Vec<Identifier>,
+        progress: Vec<AdvancementProgress>,
+    ) -> Self {
+        Self {
+            reset,
+            added,
+            removed,
+            progress,
+        }
+    }
+}
+
+impl Advancement {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_identifer()?,
+            parent: bytebuf.try_get_option(|v| v.try_get_identifer())?,
+            display: bytebuf.try_get_option(AdvancementDisplay::read)?,
+            requirements: bytebuf.get_list(|v| v.get_list(|v| v.try_get_string()))?,
+            sends_telemetry_event: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.id);
+        bytebuf.put_option(&self.parent, |p, parent| p.put_identifier(parent));
+        bytebuf.put_option(&self.display, |p, display| display.write(p));
+        bytebuf.put_list(&self.requirements, |p, requirement| {
+            p.put_list(requirement, |p, criterion| p.put_string(criterion))
+        });
+        bytebuf.put_bool(self.sends_telemetry_event);
+    }
+}
+
+impl AdvancementDisplay {
+    const HAS_BACKGROUND: i32 = 0x1;
+
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let title = bytebuf.try_get_text_component()?;
+        let description = bytebuf.try_get_text_component()?;
+        let icon = Slot::read(bytebuf)?;
+        let frame_type = bytebuf.try_get_var_int()?;
+        let flags = bytebuf.try_get_i32()?;
+        let background = if flags & Self::HAS_BACKGROUND != 0 {
+            Some(bytebuf.try_get_identifer()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            title,
+            description,
+            icon,
+            frame_type,
+            flags: flags & !Self::HAS_BACKGROUND,
+            background,
+            x: bytebuf.try_get_f32()?,
+            y: bytebuf.try_get_f32()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_text_component(&self.title);
+        bytebuf.put_text_component(&self.description);
+        self.icon.write(bytebuf);
+        bytebuf.put_var_int(&self.frame_type);
+        match &self.background {
+            Some(background) => {
+                bytebuf.put_i32(self.flags | Self::HAS_BACKGROUND);
+                bytebuf.put_identifier(background);
+            }
+            None => bytebuf.put_i32(self.flags & !Self::HAS_BACKGROUND),
+        }
+        bytebuf.put_f32(self.x);
+        bytebuf.put_f32(self.y);
+    }
+}
+
+impl Packet for UpdateAdvancements {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            reset: bytebuf.try_get_bool()?,
+            added: bytebuf.get_list(Advancement::read)?,
+            removed: bytebuf.get_list(|v| v.try_get_identifer())?,
+            progress: bytebuf.get_list(|v| {
+                Ok(AdvancementProgress {
+                    id: v.try_get_identifer()?,
+                    criteria: v.get_list(|v| {
+                        Ok((v.try_get_string()?, v.try_get_option(|v| v.try_get_i64())?))
+                    })?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_bool(self.reset);
+        bytebuf.put_list(&self.added, |p, advancement| advancement.write(p));
+        bytebuf.put_list(&self.removed, |p, id| p.put_identifier(id));
+        bytebuf.put_list(&self.progress, |p, progress| {
+            p.put_identifier(&progress.id);
+            p.put_list(&progress.criteria, |p, (criterion, obtained)| {
+                p.put_string(criterion);
+                p.put_option(obtained, |p, obtained| p.put_i64(*obtained));
+            });
+        });
+    }
+}
@@ -0,0 +1,57 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_UPDATE_ATTRIBUTES;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::item_component::AttributeModifier,
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone)]
+pub struct AttributeSnapshot {
+    /// Id in the `minecraft:attribute` registry.
+    pub attribute: VarInt,
+    pub base: f64,
+    pub modifiers: Vec<AttributeModifier>,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_UPDATE_ATTRIBUTES)]
+pub struct UpdateAttributes {
+    pub entity_id: VarInt,
+    pub attributes: Vec<AttributeSnapshot>,
+}
+
+impl UpdateAttributes {
+    pub fn new(entity_id: VarInt, attributes: Vec<AttributeSnapshot>) -> Self {
+        Self {
+            entity_id,
+            attributes,
+        }
+    }
+}
+
+impl Packet for UpdateAttributes {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            attributes: bytebuf.get_list(|v| {
+                Ok(AttributeSnapshot {
+                    attribute: v.try_get_var_int()?,
+                    base: v.try_get_f64()?,
+                    modifiers: v.get_list(AttributeModifier::read)?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_list(&self.attributes, |p, attribute| {
+            p.put_var_int(&attribute.attribute);
+            p.put_f64(attribute.base);
+            p.put_list(&attribute.modifiers, |p, modifier| modifier.write(p));
+        });
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::clientbound::PLAY_UPDATE_MOB_EFFECT;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_UPDATE_MOB_EFFECT)]
+pub struct UpdateMobEffect {
+    pub entity_id: VarInt,
+    pub effect: VarInt,
+    pub amplifier: VarInt,
+    pub duration: VarInt,
+    pub flags: i8,
+}
+
+impl UpdateMobEffect {
+    pub fn new(
+        entity_id: VarInt,
+        effect: VarInt,
+        amplifier: VarInt,
+        duration: VarInt,
+        flags: i8,
+    ) -> Self {
+        Self {
+            entity_id,
+            effect,
+            amplifier,
+            duration,
+            flags,
+        }
+    }
+}
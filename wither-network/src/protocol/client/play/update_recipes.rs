@@ -0,0 +1,73 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_UPDATE_RECIPES;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    types::{HolderSet, SlotDisplay},
+    Packet, VarInt,
+};
+
+/// Items accepted by a recipe input, like the fuel slot of furnaces.
+#[derive(Debug, Clone)]
+pub struct RecipePropertySet {
+    pub id: Identifier,
+    pub items: Vec<VarInt>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StonecutterRecipe {
+    pub input: HolderSet,
+    pub option_display: SlotDisplay,
+}
+
+/// Recipe data the client needs to work without the full recipe book.
+#[derive(Debug)]
+#[wither_packet(PLAY_UPDATE_RECIPES)]
+pub struct UpdateRecipes {
+    pub property_sets: Vec<RecipePropertySet>,
+    pub stonecutter_recipes: Vec<StonecutterRecipe>,
+}
+
+impl UpdateRecipes {
+    pub fn new(
+        property_sets: Vec<RecipePropertySet>,
+        stonecutter_recipes: Vec<StonecutterRecipe>,
+    ) -> Self {
+        Self {
+            property_sets,
+            stonecutter_recipes,
+        }
+    }
+}
+
+impl Packet for UpdateRecipes {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            property_sets: bytebuf.get_list(|v| {
+                Ok(RecipePropertySet {
+                    id: v.try_get_identifer()?,
+                    items: v.get_list(|v| v.try_get_var_int())?,
+                })
+            })?,
+            stonecutter_recipes: bytebuf.get_list(|v| {
+                Ok(StonecutterRecipe {
+                    input: HolderSet::read(v)?,
+                    option_display: SlotDisplay::read(v)?,
+                })
+            })?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.property_sets, |p, set| {
+            p.put_identifier(&set.id);
+            p.put_list(&set.items, |p, item| p.put_var_int(item));
+        });
+        bytebuf.put_list(&self.stonecutter_recipes, |p, recipe| {
+            recipe.input.write(p);
+            recipe.option_display.write(p);
+        });
+    }
+}
@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::clientbound::PLAY_UPDATE_TAGS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    protocol::client::config::RegistryTags,
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_UPDATE_TAGS)]
+pub struct UpdateTags {
+    pub registries: Vec<RegistryTags>,
+}
+
+impl UpdateTags {
+    pub fn new(registries: Vec<RegistryTags>) -> Self {
+        Self { registries }
+    }
+}
+
+impl Packet for UpdateTags {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            registries: bytebuf.get_list(RegistryTags::read)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.registries, |p, registry| registry.write(p));
+    }
+}
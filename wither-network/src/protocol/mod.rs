@@ -3,4 +3,20 @@
 #[cfg(feature = "clientbound")]
 pub mod client;
 #[cfg(feature = "serverbound")]
-pub mod server;
\ No newline at end of file
+pub mod server;
+
+/// Writes `packet`, reads it back and checks that the copy writes the same bytes.
+#[cfg(test)]
+pub(crate) fn round_trip<P: crate::Packet>(packet: &P) -> P {
+    use bytes::{Buf, BytesMut};
+
+    let mut bytes = BytesMut::new();
+    packet.write(&mut bytes);
+    let mut buf = bytes.clone().freeze();
+    let read = P::read(&mut buf).unwrap();
+    assert!(!buf.has_remaining(), "{} bytes left over", buf.remaining());
+    let mut again = BytesMut::new();
+    read.write(&mut again);
+    assert_eq!(again, bytes);
+    read
+}
@@ -0,0 +1,162 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::CONFIG_CLIENT_INFORMATION;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatMode {
+    Enabled,
+    CommandsOnly,
+    Hidden,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MainHand {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleStatus {
+    All,
+    Decreased,
+    Minimal,
+}
+
+impl TryFrom<VarInt> for ChatMode {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Enabled),
+            1 => Ok(Self::CommandsOnly),
+            2 => Ok(Self::Hidden),
+            _ => Err(ReadingError::Message("Invalid chat mode".to_string())),
+        }
+    }
+}
+
+impl TryFrom<VarInt> for MainHand {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Left),
+            1 => Ok(Self::Right),
+            _ => Err(ReadingError::Message("Invalid main hand".to_string())),
+        }
+    }
+}
+
+impl TryFrom<VarInt> for ParticleStatus {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::All),
+            1 => Ok(Self::Decreased),
+            2 => Ok(Self::Minimal),
+            _ => Err(ReadingError::Message("Invalid particle status".to_string())),
+        }
+    }
+}
+
+/// Client settings, sent once when entering configuration and again whenever they change.
+#[derive(Debug, Clone)]
+#[wither_packet(CONFIG_CLIENT_INFORMATION)]
+pub struct ClientInformation {
+    pub locale: String, // 16
+    pub view_distance: i8,
+    pub chat_mode: ChatMode,
+    pub chat_colors: bool,
+    /// Bit mask: cape, jacket, left sleeve, right sleeve, left pants, right pants, hat.
+    pub skin_parts: u8,
+    pub main_hand: MainHand,
+    pub text_filtering: bool,
+    pub allow_server_listings: bool,
+    pub particle_status: ParticleStatus,
+}
+
+impl ClientInformation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        locale: String,
+        view_distance: i8,
+        chat_mode: ChatMode,
+        chat_colors: bool,
+        skin_parts: u8,
+        main_hand: MainHand,
+        text_filtering: bool,
+        allow_server_listings: bool,
+        particle_status: ParticleStatus,
+    ) -> Self {
+        Self {
+            locale,
+            view_distance,
+            chat_mode,
+            chat_colors,
+            skin_parts,
+            main_hand,
+            text_filtering,
+            allow_server_listings,
+            particle_status,
+        }
+    }
+
+    pub(crate) fn read_fields(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            locale: bytebuf.try_get_string_len(16)?,
+            view_distance: bytebuf.try_get_i8()?,
+            chat_mode: bytebuf.try_get_var_int()?.try_into()?,
+            chat_colors: bytebuf.try_get_bool()?,
+            skin_parts: bytebuf.try_get_u8()?,
+            main_hand: bytebuf.try_get_var_int()?.try_into()?,
+            text_filtering: bytebuf.try_get_bool()?,
+            allow_server_listings: bytebuf.try_get_bool()?,
+            particle_status: bytebuf.try_get_var_int()?.try_into()?,
+        })
+    }
+
+    pub(crate) fn write_fields(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string_len(&self.locale, 16);
+        bytebuf.put_i8(self.view_distance);
+        bytebuf.put_var_int(&VarInt(self.chat_mode as i32));
+        bytebuf.put_bool(self.chat_colors);
+        bytebuf.put_u8(self.skin_parts);
+        bytebuf.put_var_int(&VarInt(self.main_hand as i32));
+        bytebuf.put_bool(self.text_filtering);
+        bytebuf.put_bool(self.allow_server_listings);
+        bytebuf.put_var_int(&VarInt(self.particle_status as i32));
+    }
+}
+
+impl Default for ClientInformation {
+    /// The settings of a freshly installed vanilla client.
+    fn default() -> Self {
+        Self::new(
+            "en_us".to_string(),
+            10,
+            ChatMode::Enabled,
+            true,
+            0x7F,
+            MainHand::Right,
+            false,
+            true,
+            ParticleStatus::All,
+        )
+    }
+}
+
+impl Packet for ClientInformation {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Self::read_fields(bytebuf)
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.write_fields(bytebuf);
+    }
+}
@@ -0,0 +1,44 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::CONFIG_COOKIE_RESPONSE;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet,
+};
+
+pub(crate) const MAX_COOKIE_LENGTH: usize = 5120;
+
+/// Response to a Cookie Request (configuration) from the server.
+#[wither_packet(CONFIG_COOKIE_RESPONSE)]
+pub struct CookieResponse {
+    pub key: Identifier,
+    pub payload: Option<bytes::Bytes>, // 5120
+}
+
+impl CookieResponse {
+    pub fn new(key: Identifier, payload: Option<bytes::Bytes>) -> Self {
+        Self { key, payload }
+    }
+}
+
+impl Packet for CookieResponse {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            key: bytebuf.try_get_identifer()?,
+            payload: bytebuf.try_get_option(|v| v.try_get_byte_array(MAX_COOKIE_LENGTH))?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.key);
+        bytebuf.put_option(&self.payload, |p, payload| {
+            if payload.len() > MAX_COOKIE_LENGTH {
+                // Should be panic?, I mean its our fault
+                panic!("Cookie is too big");
+            }
+            p.put_byte_array(payload);
+        });
+    }
+}
@@ -0,0 +1,44 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::serverbound::CONFIG_CUSTOM_PAYLOAD;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet,
+};
+
+pub(crate) const MAX_PAYLOAD_SIZE: usize = 32767;
+
+#[wither_packet(CONFIG_CUSTOM_PAYLOAD)]
+pub struct CustomPayload {
+    pub channel: Identifier,
+    pub data: Bytes, // 32767
+}
+
+impl CustomPayload {
+    pub fn new(channel: Identifier, data: Bytes) -> Self {
+        Self { channel, data }
+    }
+
+    /// The `minecraft:brand` payload vanilla sends when entering configuration.
+    pub fn brand(brand: &str) -> Self {
+        let mut data = bytes::BytesMut::new();
+        data.put_string(brand);
+        Self::new(Identifier::vanilla("brand"), data.freeze())
+    }
+}
+
+impl Packet for CustomPayload {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            channel: bytebuf.try_get_identifer()?,
+            data: bytebuf.try_get_remaining(MAX_PAYLOAD_SIZE)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_identifier(&self.channel);
+        bytebuf.put_slice(&self.data);
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::CONFIG_FINISH_CONFIGURATION;
+use wither_macros::wither_packet;
+
+// Acknowledgement to the Finish Configuration packet sent to the server.
+#[derive(Serialize, Deserialize, Default)]
+#[wither_packet(CONFIG_FINISH_CONFIGURATION)]
+pub struct FinishConfiguration {}
+
+impl FinishConfiguration {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
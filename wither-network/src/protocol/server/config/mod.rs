@@ -0,0 +1,97 @@
+mod client_information;
+mod cookie_response;
+mod custom_payload;
+mod finish_configuration;
+mod keep_alive;
+mod pong;
+mod resource_pack;
+mod select_known_packs;
+
+pub use client_information::*;
+pub use cookie_response::*;
+pub use custom_payload::*;
+pub use finish_configuration::*;
+pub use keep_alive::*;
+pub use pong::*;
+pub use resource_pack::*;
+pub use select_known_packs::*;
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use crate::{codec::identifier::Identifier, protocol::round_trip, KnownPack};
+
+    use super::*;
+
+    #[test]
+    fn client_information() {
+        let information = round_trip(&ClientInformation::new(
+            "de_de".to_string(),
+            12,
+            ChatMode::CommandsOnly,
+            false,
+            0x55,
+            MainHand::Left,
+            true,
+            false,
+            ParticleStatus::Minimal,
+        ));
+        assert_eq!(information.locale, "de_de");
+        assert_eq!(information.view_distance, 12);
+        assert_eq!(information.chat_mode, ChatMode::CommandsOnly);
+        assert!(!information.chat_colors);
+        assert_eq!(information.skin_parts, 0x55);
+        assert_eq!(information.main_hand, MainHand::Left);
+        assert!(information.text_filtering);
+        assert!(!information.allow_server_listings);
+        assert_eq!(information.particle_status, ParticleStatus::Minimal);
+    }
+
+    #[test]
+    fn cookie_response() {
+        let response = round_trip(&CookieResponse::new(
+            Identifier::vanilla("session"),
+            Some(Bytes::from_static(b"token")),
+        ));
+        assert_eq!(response.key, Identifier::vanilla("session"));
+        assert_eq!(response.payload.as_deref(), Some(&b"token"[..]));
+
+        let missing = round_trip(&CookieResponse::new(Identifier::vanilla("session"), None));
+        assert_eq!(missing.payload, None);
+    }
+
+    #[test]
+    fn custom_payload() {
+        let brand = round_trip(&CustomPayload::brand("vanilla"));
+        assert_eq!(brand.channel, Identifier::vanilla("brand"));
+        assert_eq!(&brand.data[..], b"\x07vanilla");
+    }
+
+    #[test]
+    fn simple_packets() {
+        round_trip(&FinishConfiguration::new());
+        assert_eq!(round_trip(&KeepAlive::new(-42)).keep_alive_id, -42);
+        assert_eq!(round_trip(&Pong::new(7)).id, 7);
+    }
+
+    #[test]
+    fn resource_pack() {
+        let id = uuid::Uuid::from_u128(0x1234);
+        let response = round_trip(&ResourcePack::new(id, ResourcePackStatus::Downloaded));
+        assert_eq!(response.id, id);
+        assert_eq!(response.result, ResourcePackStatus::Downloaded);
+    }
+
+    #[test]
+    fn select_known_packs() {
+        let packs = round_trip(&SelectKnownPacks::new(vec![KnownPack::new(
+            "minecraft",
+            "core",
+            "1.21.4",
+        )]))
+        .known_packs;
+        assert_eq!(packs.len(), 1);
+        assert_eq!(packs[0].version, "1.21.4");
+    }
+}
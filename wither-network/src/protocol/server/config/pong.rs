@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::CONFIG_PONG;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize)]
+#[wither_packet(CONFIG_PONG)]
+pub struct Pong {
+    pub id: i32,
+}
+
+impl Pong {
+    pub fn new(id: i32) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,66 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::CONFIG_RESOURCE_PACK;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourcePackStatus {
+    SuccessfullyLoaded,
+    Declined,
+    FailedDownload,
+    Accepted,
+    Downloaded,
+    InvalidUrl,
+    FailedReload,
+    Discarded,
+}
+
+impl TryFrom<VarInt> for ResourcePackStatus {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::SuccessfullyLoaded),
+            1 => Ok(Self::Declined),
+            2 => Ok(Self::FailedDownload),
+            3 => Ok(Self::Accepted),
+            4 => Ok(Self::Downloaded),
+            5 => Ok(Self::InvalidUrl),
+            6 => Ok(Self::FailedReload),
+            7 => Ok(Self::Discarded),
+            _ => Err(ReadingError::Message(
+                "Invalid resource pack status".to_string(),
+            )),
+        }
+    }
+}
+
+#[wither_packet(CONFIG_RESOURCE_PACK)]
+pub struct ResourcePack {
+    pub id: uuid::Uuid,
+    pub result: ResourcePackStatus,
+}
+
+impl ResourcePack {
+    pub fn new(id: uuid::Uuid, result: ResourcePackStatus) -> Self {
+        Self { id, result }
+    }
+}
+
+impl Packet for ResourcePack {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_uuid()?,
+            result: bytebuf.try_get_var_int()?.try_into()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.id);
+        bytebuf.put_var_int(&VarInt(self.result as i32));
+    }
+}
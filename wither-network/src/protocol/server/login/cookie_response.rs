@@ -17,6 +17,12 @@ pub struct CookieResponse {
 
 const MAX_COOKIE_LENGTH: usize = 5120;
 
+impl CookieResponse {
+    pub fn new(key: Identifier, payload: Option<bytes::Bytes>) -> Self {
+        Self { key, payload }
+    }
+}
+
 impl Packet for CookieResponse {
     fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
         let key = bytebuf.try_get_identifer()?;
@@ -0,0 +1,37 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::serverbound::LOGIN_CUSTOM_QUERY_ANSWER;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+const MAX_PAYLOAD_SIZE: usize = 1048576;
+
+/// Answer to a login plugin request, without data if the channel is not understood.
+#[wither_packet(LOGIN_CUSTOM_QUERY_ANSWER)]
+pub struct CustomQueryAnswer {
+    pub message_id: VarInt,
+    pub data: Option<Bytes>, // 1048576
+}
+
+impl CustomQueryAnswer {
+    pub fn new(message_id: VarInt, data: Option<Bytes>) -> Self {
+        Self { message_id, data }
+    }
+}
+
+impl Packet for CustomQueryAnswer {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            message_id: bytebuf.try_get_var_int()?,
+            data: bytebuf.try_get_option(|v| v.try_get_remaining(MAX_PAYLOAD_SIZE))?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.message_id);
+        bytebuf.put_option(&self.data, |p, data| p.put_slice(data));
+    }
+}
@@ -2,11 +2,33 @@ mod hello;
 mod key;
 mod login_acknowledged;
 mod cookie_response;
-// mod s_plugin_response;
+mod custom_query_answer;
 
 pub use hello::*;
 pub use key::*;
 pub use login_acknowledged::*;
 pub use cookie_response::*;
+pub use custom_query_answer::*;
 // pub use s_login_response::*;
-// pub use s_plugin_response::*;
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use crate::{protocol::round_trip, VarInt};
+
+    use super::*;
+
+    #[test]
+    fn custom_query_answer() {
+        let answer = round_trip(&CustomQueryAnswer::new(VarInt(3), None));
+        assert_eq!(answer.message_id.0, 3);
+        assert!(answer.data.is_none());
+
+        let answer = round_trip(&CustomQueryAnswer::new(
+            VarInt(4),
+            Some(Bytes::from_static(b"velocity")),
+        ));
+        assert_eq!(&answer.data.unwrap()[..], b"velocity");
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_ACCEPT_TELEPORTATION;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Confirms a Player Position (play) teleport with its id.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ACCEPT_TELEPORTATION)]
+pub struct AcceptTeleportation {
+    pub teleport_id: VarInt,
+}
+
+impl AcceptTeleportation {
+    pub fn new(teleport_id: i32) -> Self {
+        Self {
+            teleport_id: VarInt(teleport_id),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_BLOCK_ENTITY_TAG_QUERY;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+/// Asks for the NBT of a block entity, answered with Tag Query.
+#[derive(Debug)]
+#[wither_packet(PLAY_BLOCK_ENTITY_TAG_QUERY)]
+pub struct BlockEntityTagQuery {
+    pub transaction_id: VarInt,
+    pub location: WorldPosition,
+}
+
+impl BlockEntityTagQuery {
+    pub fn new(transaction_id: i32, location: WorldPosition) -> Self {
+        Self {
+            transaction_id: VarInt(transaction_id),
+            location,
+        }
+    }
+}
+
+impl Packet for BlockEntityTagQuery {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            transaction_id: bytebuf.try_get_var_int()?,
+            location: bytebuf.try_get_position()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.transaction_id);
+        bytebuf.put_position(&self.location);
+    }
+}
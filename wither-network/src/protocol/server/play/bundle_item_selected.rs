@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_BUNDLE_ITEM_SELECTED;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_BUNDLE_ITEM_SELECTED)]
+pub struct BundleItemSelected {
+    pub slot_id: VarInt,
+    pub selected_item_index: VarInt,
+}
+
+impl BundleItemSelected {
+    pub fn new(slot_id: i32, selected_item_index: i32) -> Self {
+        Self {
+            slot_id: VarInt(slot_id),
+            selected_item_index: VarInt(selected_item_index),
+        }
+    }
+}
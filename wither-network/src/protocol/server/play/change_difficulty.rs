@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CHANGE_DIFFICULTY;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CHANGE_DIFFICULTY)]
+pub struct ChangeDifficulty {
+    pub difficulty: u8,
+}
+
+impl ChangeDifficulty {
+    pub fn new(difficulty: u8) -> Self {
+        Self { difficulty }
+    }
+}
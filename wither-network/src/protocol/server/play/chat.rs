@@ -0,0 +1,69 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_data::packet::serverbound::PLAY_CHAT;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::{LastSeenMessagesUpdate, MESSAGE_SIGNATURE_SIZE},
+    Packet,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_CHAT)]
+pub struct Chat {
+    pub message: String, // 256
+    /// Milliseconds since the unix epoch.
+    pub timestamp: i64,
+    pub salt: i64,
+    pub signature: Option<Bytes>,
+    pub last_seen: LastSeenMessagesUpdate,
+}
+
+impl Chat {
+    pub fn new(
+        message: String,
+        timestamp: i64,
+        salt: i64,
+        signature: Option<Bytes>,
+        last_seen: LastSeenMessagesUpdate,
+    ) -> Self {
+        Self {
+            message,
+            timestamp,
+            salt,
+            signature,
+            last_seen,
+        }
+    }
+
+    /// A message sent without a chat session, as offline mode clients do.
+    pub fn unsigned(message: String, timestamp: i64) -> Self {
+        Self::new(
+            message,
+            timestamp,
+            0,
+            None,
+            LastSeenMessagesUpdate::default(),
+        )
+    }
+}
+
+impl Packet for Chat {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            message: bytebuf.try_get_string_len(256)?,
+            timestamp: bytebuf.try_get_i64()?,
+            salt: bytebuf.try_get_i64()?,
+            signature: bytebuf.try_get_option(|v| v.try_copy_to_bytes(MESSAGE_SIGNATURE_SIZE))?,
+            last_seen: LastSeenMessagesUpdate::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string_len(&self.message, 256);
+        bytebuf.put_i64(self.timestamp);
+        bytebuf.put_i64(self.salt);
+        bytebuf.put_option(&self.signature, |p, signature| p.put_slice(signature));
+        self.last_seen.write(bytebuf);
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CHAT_ACK;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Acknowledges chat messages without sending one.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CHAT_ACK)]
+pub struct ChatAck {
+    pub offset: VarInt,
+}
+
+impl ChatAck {
+    pub fn new(offset: i32) -> Self {
+        Self {
+            offset: VarInt(offset),
+        }
+    }
+}
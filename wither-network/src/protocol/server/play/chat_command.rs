@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CHAT_COMMAND;
+use wither_macros::wither_packet;
+
+/// An unsigned command, without the leading `/`.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CHAT_COMMAND)]
+pub struct ChatCommand {
+    pub command: String,
+}
+
+impl ChatCommand {
+    pub fn new(command: String) -> Self {
+        Self { command }
+    }
+}
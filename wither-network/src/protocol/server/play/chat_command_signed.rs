@@ -0,0 +1,68 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_CHAT_COMMAND_SIGNED;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    types::{ArgumentSignature, LastSeenMessagesUpdate},
+    Packet,
+};
+
+const MAX_ARGUMENT_SIGNATURES: usize = 8;
+
+/// A command with signed message arguments, without the leading `/`.
+#[derive(Debug)]
+#[wither_packet(PLAY_CHAT_COMMAND_SIGNED)]
+pub struct ChatCommandSigned {
+    pub command: String,
+    /// Milliseconds since the unix epoch.
+    pub timestamp: i64,
+    pub salt: i64,
+    pub argument_signatures: Vec<ArgumentSignature>, // 8
+    pub last_seen: LastSeenMessagesUpdate,
+}
+
+impl ChatCommandSigned {
+    pub fn new(
+        command: String,
+        timestamp: i64,
+        salt: i64,
+        argument_signatures: Vec<ArgumentSignature>,
+        last_seen: LastSeenMessagesUpdate,
+    ) -> Self {
+        Self {
+            command,
+            timestamp,
+            salt,
+            argument_signatures,
+            last_seen,
+        }
+    }
+}
+
+impl Packet for ChatCommandSigned {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let command = bytebuf.try_get_string()?;
+        let timestamp = bytebuf.try_get_i64()?;
+        let salt = bytebuf.try_get_i64()?;
+        let argument_signatures = bytebuf.get_list(ArgumentSignature::read)?;
+        if argument_signatures.len() > MAX_ARGUMENT_SIGNATURES {
+            return Err(ReadingError::TooLarge("argument signatures".to_string()));
+        }
+        Ok(Self {
+            command,
+            timestamp,
+            salt,
+            argument_signatures,
+            last_seen: LastSeenMessagesUpdate::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.command);
+        bytebuf.put_i64(self.timestamp);
+        bytebuf.put_i64(self.salt);
+        bytebuf.put_list(&self.argument_signatures, |p, signature| signature.write(p));
+        self.last_seen.write(bytebuf);
+    }
+}
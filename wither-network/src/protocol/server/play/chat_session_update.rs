@@ -0,0 +1,30 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_CHAT_SESSION_UPDATE;
+use wither_macros::wither_packet;
+
+use crate::{bytebuf::ReadingError, types::RemoteChatSession, Packet};
+
+/// Gives the server the key the following chat messages are signed with.
+#[derive(Debug)]
+#[wither_packet(PLAY_CHAT_SESSION_UPDATE)]
+pub struct ChatSessionUpdate {
+    pub session: RemoteChatSession,
+}
+
+impl ChatSessionUpdate {
+    pub fn new(session: RemoteChatSession) -> Self {
+        Self { session }
+    }
+}
+
+impl Packet for ChatSessionUpdate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            session: RemoteChatSession::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.session.write(bytebuf);
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CHUNK_BATCH_RECEIVED;
+use wither_macros::wither_packet;
+
+/// Answers Chunk Batch Finished with the rate the client can handle.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CHUNK_BATCH_RECEIVED)]
+pub struct ChunkBatchReceived {
+    pub desired_chunks_per_tick: f32,
+}
+
+impl ChunkBatchReceived {
+    pub fn new(desired_chunks_per_tick: f32) -> Self {
+        Self {
+            desired_chunks_per_tick,
+        }
+    }
+}
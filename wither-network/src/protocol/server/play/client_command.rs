@@ -0,0 +1,52 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_CLIENT_COMMAND;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientCommandAction {
+    PerformRespawn,
+    RequestStats,
+}
+
+impl TryFrom<VarInt> for ClientCommandAction {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::PerformRespawn),
+            1 => Ok(Self::RequestStats),
+            _ => Err(ReadingError::Message(
+                "Invalid client command action".to_string(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_CLIENT_COMMAND)]
+pub struct ClientCommand {
+    pub action: ClientCommandAction,
+}
+
+impl ClientCommand {
+    pub fn new(action: ClientCommandAction) -> Self {
+        Self { action }
+    }
+}
+
+impl Packet for ClientCommand {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            action: bytebuf.try_get_var_int()?.try_into()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.action as i32));
+    }
+}
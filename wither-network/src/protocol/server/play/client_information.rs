@@ -0,0 +1,30 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_CLIENT_INFORMATION;
+use wither_macros::wither_packet;
+
+use crate::{bytebuf::ReadingError, protocol::server::config, Packet};
+
+/// Same fields as Client Information (configuration), sent when settings change in game.
+#[derive(Debug, Clone, Default)]
+#[wither_packet(PLAY_CLIENT_INFORMATION)]
+pub struct ClientInformation {
+    pub information: config::ClientInformation,
+}
+
+impl ClientInformation {
+    pub fn new(information: config::ClientInformation) -> Self {
+        Self { information }
+    }
+}
+
+impl Packet for ClientInformation {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            information: config::ClientInformation::read_fields(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        self.information.write_fields(bytebuf);
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CLIENT_TICK_END;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(PLAY_CLIENT_TICK_END)]
+pub struct ClientTickEnd {}
+
+impl ClientTickEnd {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_COMMAND_SUGGESTION;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Requests tab completions for a partially typed command.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_COMMAND_SUGGESTION)]
+pub struct CommandSuggestion {
+    pub transaction_id: VarInt,
+    pub text: String,
+}
+
+impl CommandSuggestion {
+    pub fn new(transaction_id: i32, text: String) -> Self {
+        Self {
+            transaction_id: VarInt(transaction_id),
+            text,
+        }
+    }
+}
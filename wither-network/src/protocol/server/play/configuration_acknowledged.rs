@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CONFIGURATION_ACKNOWLEDGED;
+use wither_macros::wither_packet;
+
+/// Acknowledges Start Configuration, the client switches to the configuration state.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[wither_packet(PLAY_CONFIGURATION_ACKNOWLEDGED)]
+pub struct ConfigurationAcknowledged {}
+
+impl ConfigurationAcknowledged {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
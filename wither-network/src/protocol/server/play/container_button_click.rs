@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CONTAINER_BUTTON_CLICK;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CONTAINER_BUTTON_CLICK)]
+pub struct ContainerButtonClick {
+    pub container_id: VarInt,
+    pub button_id: VarInt,
+}
+
+impl ContainerButtonClick {
+    pub fn new(container_id: i32, button_id: i32) -> Self {
+        Self {
+            container_id: VarInt(container_id),
+            button_id: VarInt(button_id),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_CONTAINER_CLICK;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::slot::Slot,
+    Packet, VarInt,
+};
+
+const MAX_CHANGED_SLOTS: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickType {
+    Pickup,
+    QuickMove,
+    Swap,
+    Clone,
+    Throw,
+    QuickCraft,
+    PickupAll,
+}
+
+impl TryFrom<VarInt> for ClickType {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Pickup),
+            1 => Ok(Self::QuickMove),
+            2 => Ok(Self::Swap),
+            3 => Ok(Self::Clone),
+            4 => Ok(Self::Throw),
+            5 => Ok(Self::QuickCraft),
+            6 => Ok(Self::PickupAll),
+            _ => Err(ReadingError::Message("Invalid click type".to_string())),
+        }
+    }
+}
+
+/// A click in an open container. The client predicts the outcome and sends the
+/// slots it thinks have changed.
+#[derive(Debug)]
+#[wither_packet(PLAY_CONTAINER_CLICK)]
+pub struct ContainerClick {
+    pub container_id: VarInt,
+    pub state_id: VarInt,
+    /// `-999` for clicks outside the window.
+    pub slot: i16,
+    pub button: i8,
+    pub click_type: ClickType,
+    pub changed_slots: Vec<(i16, Slot)>, // 128
+    pub carried_item: Slot,
+}
+
+impl ContainerClick {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        container_id: i32,
+        state_id: i32,
+        slot: i16,
+        button: i8,
+        click_type: ClickType,
+        changed_slots: Vec<(i16, Slot)>,
+        carried_item: Slot,
+    ) -> Self {
+        Self {
+            container_id: VarInt(container_id),
+            state_id: VarInt(state_id),
+            slot,
+            button,
+            click_type,
+            changed_slots,
+            carried_item,
+        }
+    }
+}
+
+impl Packet for ContainerClick {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let container_id = bytebuf.try_get_var_int()?;
+        let state_id = bytebuf.try_get_var_int()?;
+        let slot = bytebuf.try_get_i16()?;
+        let button = bytebuf.try_get_i8()?;
+        let click_type = bytebuf.try_get_var_int()?.try_into()?;
+        let changed_slots = bytebuf.get_list(|v| Ok((v.try_get_i16()?, Slot::read(v)?)))?;
+        if changed_slots.len() > MAX_CHANGED_SLOTS {
+            return Err(ReadingError::TooLarge("changed slots".to_string()));
+        }
+        Ok(Self {
+            container_id,
+            state_id,
+            slot,
+            button,
+            click_type,
+            changed_slots,
+            carried_item: Slot::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.container_id);
+        bytebuf.put_var_int(&self.state_id);
+        bytebuf.put_i16(self.slot);
+        bytebuf.put_i8(self.button);
+        bytebuf.put_var_int(&VarInt(self.click_type as i32));
+        bytebuf.put_list(&self.changed_slots, |p, (slot, item)| {
+            p.put_i16(*slot);
+            item.write(p);
+        });
+        self.carried_item.write(bytebuf);
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CONTAINER_CLOSE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CONTAINER_CLOSE)]
+pub struct ContainerClose {
+    pub container_id: VarInt,
+}
+
+impl ContainerClose {
+    pub fn new(container_id: i32) -> Self {
+        Self {
+            container_id: VarInt(container_id),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_CONTAINER_SLOT_STATE_CHANGED;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Toggles a crafter slot.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_CONTAINER_SLOT_STATE_CHANGED)]
+pub struct ContainerSlotStateChanged {
+    pub slot_id: VarInt,
+    pub container_id: VarInt,
+    pub new_state: bool,
+}
+
+impl ContainerSlotStateChanged {
+    pub fn new(slot_id: i32, container_id: i32, new_state: bool) -> Self {
+        Self {
+            slot_id: VarInt(slot_id),
+            container_id: VarInt(container_id),
+            new_state,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_DEBUG_SAMPLE_SUBSCRIPTION;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_DEBUG_SAMPLE_SUBSCRIPTION)]
+pub struct DebugSampleSubscription {
+    pub sample_type: VarInt,
+}
+
+impl DebugSampleSubscription {
+    pub fn new(sample_type: i32) -> Self {
+        Self {
+            sample_type: VarInt(sample_type),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_EDIT_BOOK;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+const MAX_PAGES: usize = 100;
+const MAX_PAGE_LENGTH: usize = 1024;
+const MAX_TITLE_LENGTH: usize = 32;
+
+/// Saves a book and quill, signing it when `title` is set.
+#[derive(Debug)]
+#[wither_packet(PLAY_EDIT_BOOK)]
+pub struct EditBook {
+    pub slot: VarInt,
+    pub pages: Vec<String>,    // 100
+    pub title: Option<String>, // 32
+}
+
+impl EditBook {
+    pub fn new(slot: i32, pages: Vec<String>, title: Option<String>) -> Self {
+        Self {
+            slot: VarInt(slot),
+            pages,
+            title,
+        }
+    }
+}
+
+impl Packet for EditBook {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let slot = bytebuf.try_get_var_int()?;
+        let pages = bytebuf.get_list(|v| v.try_get_string_len(MAX_PAGE_LENGTH))?;
+        if pages.len() > MAX_PAGES {
+            return Err(ReadingError::TooLarge("book pages".to_string()));
+        }
+        Ok(Self {
+            slot,
+            pages,
+            title: bytebuf.try_get_option(|v| v.try_get_string_len(MAX_TITLE_LENGTH))?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.slot);
+        bytebuf.put_list(&self.pages, |p, page| {
+            p.put_string_len(page, MAX_PAGE_LENGTH)
+        });
+        bytebuf.put_option(&self.title, |p, title| {
+            p.put_string_len(title, MAX_TITLE_LENGTH)
+        });
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_ENTITY_TAG_QUERY;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_ENTITY_TAG_QUERY)]
+pub struct EntityTagQuery {
+    pub transaction_id: VarInt,
+    pub entity_id: VarInt,
+}
+
+impl EntityTagQuery {
+    pub fn new(transaction_id: i32, entity_id: i32) -> Self {
+        Self {
+            transaction_id: VarInt(transaction_id),
+            entity_id: VarInt(entity_id),
+        }
+    }
+}
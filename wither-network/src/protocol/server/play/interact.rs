@@ -0,0 +1,90 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_INTERACT;
+use wither_macros::wither_packet;
+use wither_util::math::vector3::Vector3;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Hand, Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InteractAction {
+    Interact {
+        hand: Hand,
+    },
+    Attack,
+    /// Interacts at a point relative to the entity's position.
+    InteractAt {
+        location: Vector3<f32>,
+        hand: Hand,
+    },
+}
+
+/// Attacks or right clicks an entity.
+#[derive(Debug)]
+#[wither_packet(PLAY_INTERACT)]
+pub struct Interact {
+    pub entity_id: VarInt,
+    pub action: InteractAction,
+    pub sneaking: bool,
+}
+
+impl Interact {
+    pub fn new(entity_id: i32, action: InteractAction, sneaking: bool) -> Self {
+        Self {
+            entity_id: VarInt(entity_id),
+            action,
+            sneaking,
+        }
+    }
+
+    pub fn attack(entity_id: i32, sneaking: bool) -> Self {
+        Self::new(entity_id, InteractAction::Attack, sneaking)
+    }
+}
+
+impl Packet for Interact {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let entity_id = bytebuf.try_get_var_int()?;
+        let action = match bytebuf.try_get_var_int()?.0 {
+            0 => InteractAction::Interact {
+                hand: bytebuf.try_get_var_int()?.try_into()?,
+            },
+            1 => InteractAction::Attack,
+            2 => InteractAction::InteractAt {
+                location: Vector3::new(
+                    bytebuf.try_get_f32()?,
+                    bytebuf.try_get_f32()?,
+                    bytebuf.try_get_f32()?,
+                ),
+                hand: bytebuf.try_get_var_int()?.try_into()?,
+            },
+            _ => return Err(ReadingError::Message("Invalid interact action".to_string())),
+        };
+        Ok(Self {
+            entity_id,
+            action,
+            sneaking: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        match self.action {
+            InteractAction::Interact { hand } => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_var_int(&VarInt(hand as i32));
+            }
+            InteractAction::Attack => bytebuf.put_var_int(&VarInt(1)),
+            InteractAction::InteractAt { location, hand } => {
+                bytebuf.put_var_int(&VarInt(2));
+                bytebuf.put_f32(location.x);
+                bytebuf.put_f32(location.y);
+                bytebuf.put_f32(location.z);
+                bytebuf.put_var_int(&VarInt(hand as i32));
+            }
+        }
+        bytebuf.put_bool(self.sneaking);
+    }
+}
@@ -0,0 +1,43 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_JIGSAW_GENERATE;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_JIGSAW_GENERATE)]
+pub struct JigsawGenerate {
+    pub location: WorldPosition,
+    pub levels: VarInt,
+    pub keep_jigsaws: bool,
+}
+
+impl JigsawGenerate {
+    pub fn new(location: WorldPosition, levels: i32, keep_jigsaws: bool) -> Self {
+        Self {
+            location,
+            levels: VarInt(levels),
+            keep_jigsaws,
+        }
+    }
+}
+
+impl Packet for JigsawGenerate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            levels: bytebuf.try_get_var_int()?,
+            keep_jigsaws: bytebuf.try_get_bool()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_var_int(&self.levels);
+        bytebuf.put_bool(self.keep_jigsaws);
+    }
+}
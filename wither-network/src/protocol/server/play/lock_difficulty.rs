@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_LOCK_DIFFICULTY;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_LOCK_DIFFICULTY)]
+pub struct LockDifficulty {
+    pub locked: bool,
+}
+
+impl LockDifficulty {
+    pub fn new(locked: bool) -> Self {
+        Self { locked }
+    }
+}
@@ -0,0 +1,307 @@
+mod accept_teleportation;
+mod block_entity_tag_query;
+mod bundle_item_selected;
+mod change_difficulty;
+mod chat;
+mod chat_ack;
+mod chat_command;
+mod chat_command_signed;
+mod chat_session_update;
+mod chunk_batch_received;
+mod client_command;
+mod client_information;
+mod client_tick_end;
+mod command_suggestion;
+mod configuration_acknowledged;
+mod container_button_click;
+mod container_click;
+mod container_close;
+mod container_slot_state_changed;
+mod cookie_response;
+mod custom_payload;
+mod debug_sample_subscription;
+mod edit_book;
+mod entity_tag_query;
+mod interact;
+mod jigsaw_generate;
+mod keep_alive;
+mod lock_difficulty;
+mod move_player_pos;
+mod move_player_pos_rot;
+mod move_player_rot;
+mod move_player_status_only;
+mod move_vehicle;
+mod paddle_boat;
+mod pick_item;
+mod ping_request;
+mod place_recipe;
+mod player_abilities;
+mod player_action;
+mod player_command;
+mod player_input;
+mod pong;
+mod recipe_book_change_settings;
+mod recipe_book_seen_recipe;
+mod rename_item;
+mod resource_pack;
+mod seen_advancements;
+mod select_trade;
+mod set_beacon;
+mod set_carried_item;
+mod set_command_block;
+mod set_command_minecart;
+mod set_creative_mode_slot;
+mod set_jigsaw_block;
+mod set_structure_block;
+mod sign_update;
+mod swing;
+mod teleport_to_entity;
+mod use_item;
+mod use_item_on;
+
+pub use accept_teleportation::*;
+pub use block_entity_tag_query::*;
+pub use bundle_item_selected::*;
+pub use change_difficulty::*;
+pub use chat::*;
+pub use chat_ack::*;
+pub use chat_command::*;
+pub use chat_command_signed::*;
+pub use chat_session_update::*;
+pub use chunk_batch_received::*;
+pub use client_command::*;
+pub use client_information::*;
+pub use client_tick_end::*;
+pub use command_suggestion::*;
+pub use configuration_acknowledged::*;
+pub use container_button_click::*;
+pub use container_click::*;
+pub use container_close::*;
+pub use container_slot_state_changed::*;
+pub use cookie_response::*;
+pub use custom_payload::*;
+pub use debug_sample_subscription::*;
+pub use edit_book::*;
+pub use entity_tag_query::*;
+pub use interact::*;
+pub use jigsaw_generate::*;
+pub use keep_alive::*;
+pub use lock_difficulty::*;
+pub use move_player_pos::*;
+pub use move_player_pos_rot::*;
+pub use move_player_rot::*;
+pub use move_player_status_only::*;
+pub use move_vehicle::*;
+pub use paddle_boat::*;
+pub use pick_item::*;
+pub use ping_request::*;
+pub use place_recipe::*;
+pub use player_abilities::*;
+pub use player_action::*;
+pub use player_command::*;
+pub use player_input::*;
+pub use pong::*;
+pub use recipe_book_change_settings::*;
+pub use recipe_book_seen_recipe::*;
+pub use rename_item::*;
+pub use resource_pack::*;
+pub use seen_advancements::*;
+pub use select_trade::*;
+pub use set_beacon::*;
+pub use set_carried_item::*;
+pub use set_command_block::*;
+pub use set_command_minecart::*;
+pub use set_creative_mode_slot::*;
+pub use set_jigsaw_block::*;
+pub use set_structure_block::*;
+pub use sign_update::*;
+pub use swing::*;
+pub use teleport_to_entity::*;
+pub use use_item::*;
+pub use use_item_on::*;
+
+use bytes::Buf;
+
+use crate::{bytebuf::ReadingError, Packet, PacketId, RawPacket};
+
+/// Every packet the client can send in the play state, so outgoing traffic can be
+/// parsed back from its raw bytes.
+#[derive(Debug)]
+pub enum ServerboundPlay {
+    AcceptTeleportation(AcceptTeleportation),
+    BlockEntityTagQuery(BlockEntityTagQuery),
+    BundleItemSelected(BundleItemSelected),
+    ChangeDifficulty(ChangeDifficulty),
+    ChatAck(ChatAck),
+    ChatCommand(ChatCommand),
+    ChatCommandSigned(ChatCommandSigned),
+    Chat(Chat),
+    ChatSessionUpdate(ChatSessionUpdate),
+    ChunkBatchReceived(ChunkBatchReceived),
+    ClientCommand(ClientCommand),
+    ClientTickEnd(ClientTickEnd),
+    ClientInformation(ClientInformation),
+    CommandSuggestion(CommandSuggestion),
+    ConfigurationAcknowledged(ConfigurationAcknowledged),
+    ContainerButtonClick(ContainerButtonClick),
+    ContainerClick(ContainerClick),
+    ContainerClose(ContainerClose),
+    ContainerSlotStateChanged(ContainerSlotStateChanged),
+    CookieResponse(CookieResponse),
+    CustomPayload(CustomPayload),
+    DebugSampleSubscription(DebugSampleSubscription),
+    EditBook(EditBook),
+    EntityTagQuery(EntityTagQuery),
+    Interact(Interact),
+    JigsawGenerate(JigsawGenerate),
+    KeepAlive(KeepAlive),
+    LockDifficulty(LockDifficulty),
+    MovePlayerPos(MovePlayerPos),
+    MovePlayerPosRot(MovePlayerPosRot),
+    MovePlayerRot(MovePlayerRot),
+    MovePlayerStatusOnly(MovePlayerStatusOnly),
+    MoveVehicle(MoveVehicle),
+    PaddleBoat(PaddleBoat),
+    PickItem(PickItem),
+    PingRequest(PingRequest),
+    PlaceRecipe(PlaceRecipe),
+    PlayerAbilities(PlayerAbilities),
+    PlayerAction(PlayerAction),
+    PlayerCommand(PlayerCommand),
+    PlayerInput(PlayerInput),
+    Pong(Pong),
+    RecipeBookChangeSettings(RecipeBookChangeSettings),
+    RecipeBookSeenRecipe(RecipeBookSeenRecipe),
+    RenameItem(RenameItem),
+    ResourcePack(ResourcePack),
+    SeenAdvancements(SeenAdvancements),
+    SelectTrade(SelectTrade),
+    SetBeacon(SetBeacon),
+    SetCarriedItem(SetCarriedItem),
+    SetCommandBlock(SetCommandBlock),
+    SetCommandMinecart(SetCommandMinecart),
+    SetCreativeModeSlot(SetCreativeModeSlot),
+    SetJigsawBlock(Box<SetJigsawBlock>),
+    SetStructureBlock(SetStructureBlock),
+    SignUpdate(SignUpdate),
+    Swing(Swing),
+    TeleportToEntity(TeleportToEntity),
+    UseItemOn(UseItemOn),
+    UseItem(UseItem),
+}
+
+impl ServerboundPlay {
+    /// Decodes a raw packet, picking the type by its id.
+    pub fn decode(packet: &RawPacket) -> Result<Self, ReadingError> {
+        let bytebuf = &mut packet.bytebuf.clone();
+        Self::read(packet.id.0, bytebuf)
+    }
+
+    pub fn read(id: i32, bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(match id {
+            AcceptTeleportation::PACKET_ID => {
+                Self::AcceptTeleportation(AcceptTeleportation::read(bytebuf)?)
+            }
+            BlockEntityTagQuery::PACKET_ID => {
+                Self::BlockEntityTagQuery(BlockEntityTagQuery::read(bytebuf)?)
+            }
+            BundleItemSelected::PACKET_ID => {
+                Self::BundleItemSelected(BundleItemSelected::read(bytebuf)?)
+            }
+            ChangeDifficulty::PACKET_ID => Self::ChangeDifficulty(ChangeDifficulty::read(bytebuf)?),
+            ChatAck::PACKET_ID => Self::ChatAck(ChatAck::read(bytebuf)?),
+            ChatCommand::PACKET_ID => Self::ChatCommand(ChatCommand::read(bytebuf)?),
+            ChatCommandSigned::PACKET_ID => {
+                Self::ChatCommandSigned(ChatCommandSigned::read(bytebuf)?)
+            }
+            Chat::PACKET_ID => Self::Chat(Chat::read(bytebuf)?),
+            ChatSessionUpdate::PACKET_ID => {
+                Self::ChatSessionUpdate(ChatSessionUpdate::read(bytebuf)?)
+            }
+            ChunkBatchReceived::PACKET_ID => {
+                Self::ChunkBatchReceived(ChunkBatchReceived::read(bytebuf)?)
+            }
+            ClientCommand::PACKET_ID => Self::ClientCommand(ClientCommand::read(bytebuf)?),
+            ClientTickEnd::PACKET_ID => Self::ClientTickEnd(ClientTickEnd::read(bytebuf)?),
+            ClientInformation::PACKET_ID => {
+                Self::ClientInformation(ClientInformation::read(bytebuf)?)
+            }
+            CommandSuggestion::PACKET_ID => {
+                Self::CommandSuggestion(CommandSuggestion::read(bytebuf)?)
+            }
+            ConfigurationAcknowledged::PACKET_ID => {
+                Self::ConfigurationAcknowledged(ConfigurationAcknowledged::read(bytebuf)?)
+            }
+            ContainerButtonClick::PACKET_ID => {
+                Self::ContainerButtonClick(ContainerButtonClick::read(bytebuf)?)
+            }
+            ContainerClick::PACKET_ID => Self::ContainerClick(ContainerClick::read(bytebuf)?),
+            ContainerClose::PACKET_ID => Self::ContainerClose(ContainerClose::read(bytebuf)?),
+            ContainerSlotStateChanged::PACKET_ID => {
+                Self::ContainerSlotStateChanged(ContainerSlotStateChanged::read(bytebuf)?)
+            }
+            CookieResponse::PACKET_ID => Self::CookieResponse(CookieResponse::read(bytebuf)?),
+            CustomPayload::PACKET_ID => Self::CustomPayload(CustomPayload::read(bytebuf)?),
+            DebugSampleSubscription::PACKET_ID => {
+                Self::DebugSampleSubscription(DebugSampleSubscription::read(bytebuf)?)
+            }
+            EditBook::PACKET_ID => Self::EditBook(EditBook::read(bytebuf)?),
+            EntityTagQuery::PACKET_ID => Self::EntityTagQuery(EntityTagQuery::read(bytebuf)?),
+            Interact::PACKET_ID => Self::Interact(Interact::read(bytebuf)?),
+            JigsawGenerate::PACKET_ID => Self::JigsawGenerate(JigsawGenerate::read(bytebuf)?),
+            KeepAlive::PACKET_ID => Self::KeepAlive(KeepAlive::read(bytebuf)?),
+            LockDifficulty::PACKET_ID => Self::LockDifficulty(LockDifficulty::read(bytebuf)?),
+            MovePlayerPos::PACKET_ID => Self::MovePlayerPos(MovePlayerPos::read(bytebuf)?),
+            MovePlayerPosRot::PACKET_ID => Self::MovePlayerPosRot(MovePlayerPosRot::read(bytebuf)?),
+            MovePlayerRot::PACKET_ID => Self::MovePlayerRot(MovePlayerRot::read(bytebuf)?),
+            MovePlayerStatusOnly::PACKET_ID => {
+                Self::MovePlayerStatusOnly(MovePlayerStatusOnly::read(bytebuf)?)
+            }
+            MoveVehicle::PACKET_ID => Self::MoveVehicle(MoveVehicle::read(bytebuf)?),
+            PaddleBoat::PACKET_ID => Self::PaddleBoat(PaddleBoat::read(bytebuf)?),
+            PickItem::PACKET_ID => Self::PickItem(PickItem::read(bytebuf)?),
+            PingRequest::PACKET_ID => Self::PingRequest(PingRequest::read(bytebuf)?),
+            PlaceRecipe::PACKET_ID => Self::PlaceRecipe(PlaceRecipe::read(bytebuf)?),
+            PlayerAbilities::PACKET_ID => Self::PlayerAbilities(PlayerAbilities::read(bytebuf)?),
+            PlayerAction::PACKET_ID => Self::PlayerAction(PlayerAction::read(bytebuf)?),
+            PlayerCommand::PACKET_ID => Self::PlayerCommand(PlayerCommand::read(bytebuf)?),
+            PlayerInput::PACKET_ID => Self::PlayerInput(PlayerInput::read(bytebuf)?),
+            Pong::PACKET_ID => Self::Pong(Pong::read(bytebuf)?),
+            RecipeBookChangeSettings::PACKET_ID => {
+                Self::RecipeBookChangeSettings(RecipeBookChangeSettings::read(bytebuf)?)
+            }
+            RecipeBookSeenRecipe::PACKET_ID => {
+                Self::RecipeBookSeenRecipe(RecipeBookSeenRecipe::read(bytebuf)?)
+            }
+            RenameItem::PACKET_ID => Self::RenameItem(RenameItem::read(bytebuf)?),
+            ResourcePack::PACKET_ID => Self::ResourcePack(ResourcePack::read(bytebuf)?),
+            SeenAdvancements::PACKET_ID => Self::SeenAdvancements(SeenAdvancements::read(bytebuf)?),
+            SelectTrade::PACKET_ID => Self::SelectTrade(SelectTrade::read(bytebuf)?),
+            SetBeacon::PACKET_ID => Self::SetBeacon(SetBeacon::read(bytebuf)?),
+            SetCarriedItem::PACKET_ID => Self::SetCarriedItem(SetCarriedItem::read(bytebuf)?),
+            SetCommandBlock::PACKET_ID => Self::SetCommandBlock(SetCommandBlock::read(bytebuf)?),
+            SetCommandMinecart::PACKET_ID => {
+                Self::SetCommandMinecart(SetCommandMinecart::read(bytebuf)?)
+            }
+            SetCreativeModeSlot::PACKET_ID => {
+                Self::SetCreativeModeSlot(SetCreativeModeSlot::read(bytebuf)?)
+            }
+            SetJigsawBlock::PACKET_ID => {
+                Self::SetJigsawBlock(Box::new(SetJigsawBlock::read(bytebuf)?))
+            }
+            SetStructureBlock::PACKET_ID => {
+                Self::SetStructureBlock(SetStructureBlock::read(bytebuf)?)
+            }
+            SignUpdate::PACKET_ID => Self::SignUpdate(SignUpdate::read(bytebuf)?),
+            Swing::PACKET_ID => Self::Swing(Swing::read(bytebuf)?),
+            TeleportToEntity::PACKET_ID => Self::TeleportToEntity(TeleportToEntity::read(bytebuf)?),
+            UseItemOn::PACKET_ID => Self::UseItemOn(UseItemOn::read(bytebuf)?),
+            UseItem::PACKET_ID => Self::UseItem(UseItem::read(bytebuf)?),
+            _ => {
+                return Err(ReadingError::Message(format!(
+                    "Unknown play packet id {id}"
+                )))
+            }
+        })
+    }
+}
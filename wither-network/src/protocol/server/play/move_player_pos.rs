@@ -0,0 +1,68 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_MOVE_PLAYER_POS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ReadingError},
+    Packet,
+};
+
+pub(crate) const ON_GROUND: u8 = 0x01;
+pub(crate) const HORIZONTAL_COLLISION: u8 = 0x02;
+
+pub(crate) fn movement_flags(on_ground: bool, horizontal_collision: bool) -> u8 {
+    let mut flags = 0;
+    if on_ground {
+        flags |= ON_GROUND;
+    }
+    if horizontal_collision {
+        flags |= HORIZONTAL_COLLISION;
+    }
+    flags
+}
+
+/// Y is the position of the player's feet.
+#[derive(Debug)]
+#[wither_packet(PLAY_MOVE_PLAYER_POS)]
+pub struct MovePlayerPos {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub on_ground: bool,
+    pub horizontal_collision: bool,
+}
+
+impl MovePlayerPos {
+    pub fn new(x: f64, y: f64, z: f64, on_ground: bool, horizontal_collision: bool) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            on_ground,
+            horizontal_collision,
+        }
+    }
+}
+
+impl Packet for MovePlayerPos {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let x = bytebuf.try_get_f64()?;
+        let y = bytebuf.try_get_f64()?;
+        let z = bytebuf.try_get_f64()?;
+        let flags = bytebuf.try_get_u8()?;
+        Ok(Self {
+            x,
+            y,
+            z,
+            on_ground: flags & ON_GROUND != 0,
+            horizontal_collision: flags & HORIZONTAL_COLLISION != 0,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_f64(self.x);
+        bytebuf.put_f64(self.y);
+        bytebuf.put_f64(self.z);
+        bytebuf.put_u8(movement_flags(self.on_ground, self.horizontal_collision));
+    }
+}
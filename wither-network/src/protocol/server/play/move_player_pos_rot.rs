@@ -0,0 +1,74 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_MOVE_PLAYER_POS_ROT;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ReadingError},
+    Packet,
+};
+
+use super::move_player_pos::{movement_flags, HORIZONTAL_COLLISION, ON_GROUND};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_MOVE_PLAYER_POS_ROT)]
+pub struct MovePlayerPosRot {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub on_ground: bool,
+    pub horizontal_collision: bool,
+}
+
+impl MovePlayerPosRot {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: f32,
+        pitch: f32,
+        on_ground: bool,
+        horizontal_collision: bool,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+            on_ground,
+            horizontal_collision,
+        }
+    }
+}
+
+impl Packet for MovePlayerPosRot {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let x = bytebuf.try_get_f64()?;
+        let y = bytebuf.try_get_f64()?;
+        let z = bytebuf.try_get_f64()?;
+        let yaw = bytebuf.try_get_f32()?;
+        let pitch = bytebuf.try_get_f32()?;
+        let flags = bytebuf.try_get_u8()?;
+        Ok(Self {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+            on_ground: flags & ON_GROUND != 0,
+            horizontal_collision: flags & HORIZONTAL_COLLISION != 0,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_f64(self.x);
+        bytebuf.put_f64(self.y);
+        bytebuf.put_f64(self.z);
+        bytebuf.put_f32(self.yaw);
+        bytebuf.put_f32(self.pitch);
+        bytebuf.put_u8(movement_flags(self.on_ground, self.horizontal_collision));
+    }
+}
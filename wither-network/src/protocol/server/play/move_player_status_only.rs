@@ -0,0 +1,40 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_MOVE_PLAYER_STATUS_ONLY;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ReadingError},
+    Packet,
+};
+
+use super::move_player_pos::{movement_flags, HORIZONTAL_COLLISION, ON_GROUND};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_MOVE_PLAYER_STATUS_ONLY)]
+pub struct MovePlayerStatusOnly {
+    pub on_ground: bool,
+    pub horizontal_collision: bool,
+}
+
+impl MovePlayerStatusOnly {
+    pub fn new(on_ground: bool, horizontal_collision: bool) -> Self {
+        Self {
+            on_ground,
+            horizontal_collision,
+        }
+    }
+}
+
+impl Packet for MovePlayerStatusOnly {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let flags = bytebuf.try_get_u8()?;
+        Ok(Self {
+            on_ground: flags & ON_GROUND != 0,
+            horizontal_collision: flags & HORIZONTAL_COLLISION != 0,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_u8(movement_flags(self.on_ground, self.horizontal_collision));
+    }
+}
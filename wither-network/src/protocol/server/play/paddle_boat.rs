@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_PADDLE_BOAT;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PADDLE_BOAT)]
+pub struct PaddleBoat {
+    pub left_paddle_turning: bool,
+    pub right_paddle_turning: bool,
+}
+
+impl PaddleBoat {
+    pub fn new(left_paddle_turning: bool, right_paddle_turning: bool) -> Self {
+        Self {
+            left_paddle_turning,
+            right_paddle_turning,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_PICK_ITEM;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+/// Swaps the picked item from the inventory into the hotbar.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PICK_ITEM)]
+pub struct PickItem {
+    pub slot: VarInt,
+}
+
+impl PickItem {
+    pub fn new(slot: i32) -> Self {
+        Self { slot: VarInt(slot) }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_PING_REQUEST;
+use wither_macros::wither_packet;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PING_REQUEST)]
+pub struct PingRequest {
+    pub payload: i64,
+}
+
+impl PingRequest {
+    pub fn new(payload: i64) -> Self {
+        Self { payload }
+    }
+}
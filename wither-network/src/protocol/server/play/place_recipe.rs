@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_PLACE_RECIPE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PLACE_RECIPE)]
+pub struct PlaceRecipe {
+    pub container_id: VarInt,
+    pub recipe_id: VarInt,
+    pub use_max_items: bool,
+}
+
+impl PlaceRecipe {
+    pub fn new(container_id: i32, recipe_id: i32, use_max_items: bool) -> Self {
+        Self {
+            container_id: VarInt(container_id),
+            recipe_id: VarInt(recipe_id),
+            use_max_items,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_PLAYER_ABILITIES;
+use wither_macros::wither_packet;
+
+/// Sent when the player starts or stops flying.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PLAYER_ABILITIES)]
+pub struct PlayerAbilities {
+    pub flags: i8,
+}
+
+impl PlayerAbilities {
+    pub const FLYING: i8 = 0x02;
+
+    pub fn new(flying: bool) -> Self {
+        Self {
+            flags: if flying { Self::FLYING } else { 0 },
+        }
+    }
+}
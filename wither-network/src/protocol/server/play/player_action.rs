@@ -0,0 +1,88 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_PLAYER_ACTION;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+use wither_world::block::BlockFace;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerActionStatus {
+    StartDestroyBlock,
+    AbortDestroyBlock,
+    StopDestroyBlock,
+    DropAllItems,
+    DropItem,
+    /// Finishes eating, drawing a bow and similar.
+    ReleaseUseItem,
+    SwapItemWithOffhand,
+}
+
+impl TryFrom<VarInt> for PlayerActionStatus {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::StartDestroyBlock),
+            1 => Ok(Self::AbortDestroyBlock),
+            2 => Ok(Self::StopDestroyBlock),
+            3 => Ok(Self::DropAllItems),
+            4 => Ok(Self::DropItem),
+            5 => Ok(Self::ReleaseUseItem),
+            6 => Ok(Self::SwapItemWithOffhand),
+            _ => Err(ReadingError::Message(
+                "Invalid player action status".to_string(),
+            )),
+        }
+    }
+}
+
+/// Digging and a few item actions. `location` and `face` are only meaningful for the
+/// destroy block statuses.
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_ACTION)]
+pub struct PlayerAction {
+    pub status: PlayerActionStatus,
+    pub location: WorldPosition,
+    pub face: BlockFace,
+    /// Echoed back in Block Changed Ack.
+    pub sequence: VarInt,
+}
+
+impl PlayerAction {
+    pub fn new(
+        status: PlayerActionStatus,
+        location: WorldPosition,
+        face: BlockFace,
+        sequence: i32,
+    ) -> Self {
+        Self {
+            status,
+            location,
+            face,
+            sequence: VarInt(sequence),
+        }
+    }
+}
+
+impl Packet for PlayerAction {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            status: bytebuf.try_get_var_int()?.try_into()?,
+            location: bytebuf.try_get_position()?,
+            face: BlockFace::try_from(bytebuf.try_get_u8()? as i32)
+                .map_err(|_| ReadingError::Message("Invalid block face".to_string()))?,
+            sequence: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.status as i32));
+        bytebuf.put_position(&self.location);
+        bytebuf.put_u8(self.face as u8);
+        bytebuf.put_var_int(&self.sequence);
+    }
+}
@@ -0,0 +1,77 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_PLAYER_COMMAND;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerCommandAction {
+    PressShiftKey,
+    ReleaseShiftKey,
+    StopSleeping,
+    StartSprinting,
+    StopSprinting,
+    StartRidingJump,
+    StopRidingJump,
+    OpenInventory,
+    StartFallFlying,
+}
+
+impl TryFrom<VarInt> for PlayerCommandAction {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::PressShiftKey),
+            1 => Ok(Self::ReleaseShiftKey),
+            2 => Ok(Self::StopSleeping),
+            3 => Ok(Self::StartSprinting),
+            4 => Ok(Self::StopSprinting),
+            5 => Ok(Self::StartRidingJump),
+            6 => Ok(Self::StopRidingJump),
+            7 => Ok(Self::OpenInventory),
+            8 => Ok(Self::StartFallFlying),
+            _ => Err(ReadingError::Message(
+                "Invalid player command action".to_string(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_PLAYER_COMMAND)]
+pub struct PlayerCommand {
+    pub entity_id: VarInt,
+    pub action: PlayerCommandAction,
+    /// Jump boost (0-100) for `StartRidingJump`, otherwise 0.
+    pub data: VarInt,
+}
+
+impl PlayerCommand {
+    pub fn new(entity_id: i32, action: PlayerCommandAction, data: i32) -> Self {
+        Self {
+            entity_id: VarInt(entity_id),
+            action,
+            data: VarInt(data),
+        }
+    }
+}
+
+impl Packet for PlayerCommand {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            entity_id: bytebuf.try_get_var_int()?,
+            action: bytebuf.try_get_var_int()?.try_into()?,
+            data: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.entity_id);
+        bytebuf.put_var_int(&VarInt(self.action as i32));
+        bytebuf.put_var_int(&self.data);
+    }
+}
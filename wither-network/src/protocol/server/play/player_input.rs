@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_PLAYER_INPUT;
+use wither_macros::wither_packet;
+
+/// The movement keys currently held down.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_PLAYER_INPUT)]
+pub struct PlayerInput {
+    pub flags: u8,
+}
+
+impl PlayerInput {
+    pub const FORWARD: u8 = 0x01;
+    pub const BACKWARD: u8 = 0x02;
+    pub const LEFT: u8 = 0x04;
+    pub const RIGHT: u8 = 0x08;
+    pub const JUMP: u8 = 0x10;
+    pub const SHIFT: u8 = 0x20;
+    pub const SPRINT: u8 = 0x40;
+
+    pub fn new(flags: u8) -> Self {
+        Self { flags }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_RECIPE_BOOK_CHANGE_SETTINGS;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_RECIPE_BOOK_CHANGE_SETTINGS)]
+pub struct RecipeBookChangeSettings {
+    pub book_type: VarInt,
+    pub is_open: bool,
+    pub is_filtering: bool,
+}
+
+impl RecipeBookChangeSettings {
+    pub fn new(book_type: i32, is_open: bool, is_filtering: bool) -> Self {
+        Self {
+            book_type: VarInt(book_type),
+            is_open,
+            is_filtering,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_RECIPE_BOOK_SEEN_RECIPE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_RECIPE_BOOK_SEEN_RECIPE)]
+pub struct RecipeBookSeenRecipe {
+    pub recipe_id: VarInt,
+}
+
+impl RecipeBookSeenRecipe {
+    pub fn new(recipe_id: i32) -> Self {
+        Self {
+            recipe_id: VarInt(recipe_id),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_RENAME_ITEM;
+use wither_macros::wither_packet;
+
+/// Sets the name in an anvil.
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_RENAME_ITEM)]
+pub struct RenameItem {
+    pub item_name: String,
+}
+
+impl RenameItem {
+    pub fn new(item_name: String) -> Self {
+        Self { item_name }
+    }
+}
@@ -0,0 +1,36 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_RESOURCE_PACK;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    protocol::server::config::ResourcePackStatus,
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_RESOURCE_PACK)]
+pub struct ResourcePack {
+    pub id: uuid::Uuid,
+    pub result: ResourcePackStatus,
+}
+
+impl ResourcePack {
+    pub fn new(id: uuid::Uuid, result: ResourcePackStatus) -> Self {
+        Self { id, result }
+    }
+}
+
+impl Packet for ResourcePack {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_uuid()?,
+            result: bytebuf.try_get_var_int()?.try_into()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.id);
+        bytebuf.put_var_int(&VarInt(self.result as i32));
+    }
+}
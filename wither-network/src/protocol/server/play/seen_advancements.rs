@@ -0,0 +1,52 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SEEN_ADVANCEMENTS;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeenAdvancementsAction {
+    OpenedTab(Identifier),
+    ClosedScreen,
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SEEN_ADVANCEMENTS)]
+pub struct SeenAdvancements {
+    pub action: SeenAdvancementsAction,
+}
+
+impl SeenAdvancements {
+    pub fn new(action: SeenAdvancementsAction) -> Self {
+        Self { action }
+    }
+}
+
+impl Packet for SeenAdvancements {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let action = match bytebuf.try_get_var_int()?.0 {
+            0 => SeenAdvancementsAction::OpenedTab(bytebuf.try_get_identifer()?),
+            1 => SeenAdvancementsAction::ClosedScreen,
+            _ => {
+                return Err(ReadingError::Message(
+                    "Invalid seen advancements action".to_string(),
+                ))
+            }
+        };
+        Ok(Self { action })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        match &self.action {
+            SeenAdvancementsAction::OpenedTab(tab) => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_identifier(tab);
+            }
+            SeenAdvancementsAction::ClosedScreen => bytebuf.put_var_int(&VarInt(1)),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_SELECT_TRADE;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SELECT_TRADE)]
+pub struct SelectTrade {
+    pub selected_slot: VarInt,
+}
+
+impl SelectTrade {
+    pub fn new(selected_slot: i32) -> Self {
+        Self {
+            selected_slot: VarInt(selected_slot),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_SET_BEACON;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_BEACON)]
+pub struct SetBeacon {
+    pub primary_effect: Option<VarInt>,
+    pub secondary_effect: Option<VarInt>,
+}
+
+impl SetBeacon {
+    pub fn new(primary_effect: Option<i32>, secondary_effect: Option<i32>) -> Self {
+        Self {
+            primary_effect: primary_effect.map(VarInt),
+            secondary_effect: secondary_effect.map(VarInt),
+        }
+    }
+}
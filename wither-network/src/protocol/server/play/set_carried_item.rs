@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_SET_CARRIED_ITEM;
+use wither_macros::wither_packet;
+
+/// Selects a hotbar slot (0-8).
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_CARRIED_ITEM)]
+pub struct SetCarriedItem {
+    pub slot: i16,
+}
+
+impl SetCarriedItem {
+    pub fn new(slot: i16) -> Self {
+        Self { slot }
+    }
+}
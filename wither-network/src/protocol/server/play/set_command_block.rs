@@ -0,0 +1,78 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SET_COMMAND_BLOCK;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet, VarInt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandBlockMode {
+    Sequence,
+    Auto,
+    Redstone,
+}
+
+impl TryFrom<VarInt> for CommandBlockMode {
+    type Error = ReadingError;
+
+    fn try_from(value: VarInt) -> Result<Self, Self::Error> {
+        match value.0 {
+            0 => Ok(Self::Sequence),
+            1 => Ok(Self::Auto),
+            2 => Ok(Self::Redstone),
+            _ => Err(ReadingError::Message(
+                "Invalid command block mode".to_string(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_COMMAND_BLOCK)]
+pub struct SetCommandBlock {
+    pub location: WorldPosition,
+    pub command: String,
+    pub mode: CommandBlockMode,
+    pub flags: u8,
+}
+
+impl SetCommandBlock {
+    pub const TRACK_OUTPUT: u8 = 0x01;
+    pub const CONDITIONAL: u8 = 0x02;
+    pub const AUTOMATIC: u8 = 0x04;
+
+    pub fn new(
+        location: WorldPosition,
+        command: String,
+        mode: CommandBlockMode,
+        flags: u8,
+    ) -> Self {
+        Self {
+            location,
+            command,
+            mode,
+            flags,
+        }
+    }
+}
+
+impl Packet for SetCommandBlock {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            command: bytebuf.try_get_string()?,
+            mode: bytebuf.try_get_var_int()?.try_into()?,
+            flags: bytebuf.try_get_u8()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_string(&self.command);
+        bytebuf.put_var_int(&VarInt(self.mode as i32));
+        bytebuf.put_u8(self.flags);
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use wither_data::packet::serverbound::PLAY_SET_COMMAND_MINECART;
+use wither_macros::wither_packet;
+
+use crate::VarInt;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[wither_packet(PLAY_SET_COMMAND_MINECART)]
+pub struct SetCommandMinecart {
+    pub entity_id: VarInt,
+    pub command: String,
+    pub track_output: bool,
+}
+
+impl SetCommandMinecart {
+    pub fn new(entity_id: i32, command: String, track_output: bool) -> Self {
+        Self {
+            entity_id: VarInt(entity_id),
+            command,
+            track_output,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SET_CREATIVE_MODE_SLOT;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ReadingError},
+    codec::slot::Slot,
+    Packet,
+};
+
+/// Sets an inventory slot in creative mode, `-1` drops the item instead.
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_CREATIVE_MODE_SLOT)]
+pub struct SetCreativeModeSlot {
+    pub slot: i16,
+    pub item: Slot,
+}
+
+impl SetCreativeModeSlot {
+    pub fn new(slot: i16, item: Slot) -> Self {
+        Self { slot, item }
+    }
+}
+
+impl Packet for SetCreativeModeSlot {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            slot: bytebuf.try_get_i16()?,
+            item: Slot::read(bytebuf)?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_i16(self.slot);
+        self.item.write(bytebuf);
+    }
+}
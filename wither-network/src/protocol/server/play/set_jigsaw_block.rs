@@ -0,0 +1,75 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SET_JIGSAW_BLOCK;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_JIGSAW_BLOCK)]
+pub struct SetJigsawBlock {
+    pub location: WorldPosition,
+    pub name: Identifier,
+    pub target: Identifier,
+    pub pool: Identifier,
+    pub final_state: String,
+    /// `rollable` or `aligned`.
+    pub joint_type: String,
+    pub selection_priority: VarInt,
+    pub placement_priority: VarInt,
+}
+
+impl SetJigsawBlock {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        location: WorldPosition,
+        name: Identifier,
+        target: Identifier,
+        pool: Identifier,
+        final_state: String,
+        joint_type: String,
+        selection_priority: i32,
+        placement_priority: i32,
+    ) -> Self {
+        Self {
+            location,
+            name,
+            target,
+            pool,
+            final_state,
+            joint_type,
+            selection_priority: VarInt(selection_priority),
+            placement_priority: VarInt(placement_priority),
+        }
+    }
+}
+
+impl Packet for SetJigsawBlock {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            name: bytebuf.try_get_identifer()?,
+            target: bytebuf.try_get_identifer()?,
+            pool: bytebuf.try_get_identifer()?,
+            final_state: bytebuf.try_get_string()?,
+            joint_type: bytebuf.try_get_string()?,
+            selection_priority: bytebuf.try_get_var_int()?,
+            placement_priority: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_identifier(&self.name);
+        bytebuf.put_identifier(&self.target);
+        bytebuf.put_identifier(&self.pool);
+        bytebuf.put_string(&self.final_state);
+        bytebuf.put_string(&self.joint_type);
+        bytebuf.put_var_int(&self.selection_priority);
+        bytebuf.put_var_int(&self.placement_priority);
+    }
+}
@@ -0,0 +1,115 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SET_STRUCTURE_BLOCK;
+use wither_macros::wither_packet;
+use wither_util::math::{position::WorldPosition, vector3::Vector3};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::var_long::VarLong,
+    Packet, VarInt,
+};
+
+const MAX_METADATA_LENGTH: usize = 128;
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SET_STRUCTURE_BLOCK)]
+pub struct SetStructureBlock {
+    pub location: WorldPosition,
+    /// 0 update data, 1 save, 2 load, 3 detect size.
+    pub update_type: VarInt,
+    /// 0 save, 1 load, 2 corner, 3 data.
+    pub mode: VarInt,
+    pub name: String,
+    pub offset: Vector3<i8>,
+    pub size: Vector3<i8>,
+    pub mirror: VarInt,
+    pub rotation: VarInt,
+    pub metadata: String, // 128
+    pub integrity: f32,
+    pub seed: VarLong,
+    pub flags: u8,
+}
+
+impl SetStructureBlock {
+    pub const IGNORE_ENTITIES: u8 = 0x01;
+    pub const SHOW_AIR: u8 = 0x02;
+    pub const SHOW_BOUNDING_BOX: u8 = 0x04;
+    pub const STRICT: u8 = 0x08;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        location: WorldPosition,
+        update_type: i32,
+        mode: i32,
+        name: String,
+        offset: Vector3<i8>,
+        size: Vector3<i8>,
+        mirror: i32,
+        rotation: i32,
+        metadata: String,
+        integrity: f32,
+        seed: i64,
+        flags: u8,
+    ) -> Self {
+        Self {
+            location,
+            update_type: VarInt(update_type),
+            mode: VarInt(mode),
+            name,
+            offset,
+            size,
+            mirror: VarInt(mirror),
+            rotation: VarInt(rotation),
+            metadata,
+            integrity,
+            seed: VarLong(seed),
+            flags,
+        }
+    }
+}
+
+impl Packet for SetStructureBlock {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            update_type: bytebuf.try_get_var_int()?,
+            mode: bytebuf.try_get_var_int()?,
+            name: bytebuf.try_get_string()?,
+            offset: Vector3 {
+                x: bytebuf.try_get_i8()?,
+                y: bytebuf.try_get_i8()?,
+                z: bytebuf.try_get_i8()?,
+            },
+            size: Vector3 {
+                x: bytebuf.try_get_i8()?,
+                y: bytebuf.try_get_i8()?,
+                z: bytebuf.try_get_i8()?,
+            },
+            mirror: bytebuf.try_get_var_int()?,
+            rotation: bytebuf.try_get_var_int()?,
+            metadata: bytebuf.try_get_string_len(MAX_METADATA_LENGTH)?,
+            integrity: bytebuf.try_get_f32()?,
+            seed: bytebuf.try_get_var_long()?,
+            flags: bytebuf.try_get_u8()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_var_int(&self.update_type);
+        bytebuf.put_var_int(&self.mode);
+        bytebuf.put_string(&self.name);
+        bytebuf.put_i8(self.offset.x);
+        bytebuf.put_i8(self.offset.y);
+        bytebuf.put_i8(self.offset.z);
+        bytebuf.put_i8(self.size.x);
+        bytebuf.put_i8(self.size.y);
+        bytebuf.put_i8(self.size.z);
+        bytebuf.put_var_int(&self.mirror);
+        bytebuf.put_var_int(&self.rotation);
+        bytebuf.put_string_len(&self.metadata, MAX_METADATA_LENGTH);
+        bytebuf.put_f32(self.integrity);
+        bytebuf.put_var_long(&self.seed);
+        bytebuf.put_u8(self.flags);
+    }
+}
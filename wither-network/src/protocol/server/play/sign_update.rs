@@ -0,0 +1,53 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SIGN_UPDATE;
+use wither_macros::wither_packet;
+use wither_util::math::position::WorldPosition;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+const MAX_LINE_LENGTH: usize = 384;
+
+/// Sent when closing the sign editor opened by Open Sign Editor.
+#[derive(Debug)]
+#[wither_packet(PLAY_SIGN_UPDATE)]
+pub struct SignUpdate {
+    pub location: WorldPosition,
+    pub is_front_text: bool,
+    pub lines: [String; 4], // 384
+}
+
+impl SignUpdate {
+    pub fn new(location: WorldPosition, is_front_text: bool, lines: [String; 4]) -> Self {
+        Self {
+            location,
+            is_front_text,
+            lines,
+        }
+    }
+}
+
+impl Packet for SignUpdate {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            location: bytebuf.try_get_position()?,
+            is_front_text: bytebuf.try_get_bool()?,
+            lines: [
+                bytebuf.try_get_string_len(MAX_LINE_LENGTH)?,
+                bytebuf.try_get_string_len(MAX_LINE_LENGTH)?,
+                bytebuf.try_get_string_len(MAX_LINE_LENGTH)?,
+                bytebuf.try_get_string_len(MAX_LINE_LENGTH)?,
+            ],
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_position(&self.location);
+        bytebuf.put_bool(self.is_front_text);
+        for line in &self.lines {
+            bytebuf.put_string_len(line, MAX_LINE_LENGTH);
+        }
+    }
+}
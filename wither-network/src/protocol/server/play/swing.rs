@@ -0,0 +1,32 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_SWING;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Hand, Packet, VarInt,
+};
+
+#[derive(Debug)]
+#[wither_packet(PLAY_SWING)]
+pub struct Swing {
+    pub hand: Hand,
+}
+
+impl Swing {
+    pub fn new(hand: Hand) -> Self {
+        Self { hand }
+    }
+}
+
+impl Packet for Swing {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            hand: bytebuf.try_get_var_int()?.try_into()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.hand as i32));
+    }
+}
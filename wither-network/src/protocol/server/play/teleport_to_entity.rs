@@ -0,0 +1,33 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_TELEPORT_TO_ENTITY;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Packet,
+};
+
+/// Teleports a spectator to the given player.
+#[derive(Debug)]
+#[wither_packet(PLAY_TELEPORT_TO_ENTITY)]
+pub struct TeleportToEntity {
+    pub target: uuid::Uuid,
+}
+
+impl TeleportToEntity {
+    pub fn new(target: uuid::Uuid) -> Self {
+        Self { target }
+    }
+}
+
+impl Packet for TeleportToEntity {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            target: bytebuf.try_get_uuid()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.target);
+    }
+}
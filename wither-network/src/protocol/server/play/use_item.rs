@@ -0,0 +1,48 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_USE_ITEM;
+use wither_macros::wither_packet;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Hand, Packet, VarInt,
+};
+
+/// Uses the held item without targeting a block.
+#[derive(Debug)]
+#[wither_packet(PLAY_USE_ITEM)]
+pub struct UseItem {
+    pub hand: Hand,
+    /// Echoed back in Block Changed Ack.
+    pub sequence: VarInt,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl UseItem {
+    pub fn new(hand: Hand, sequence: i32, yaw: f32, pitch: f32) -> Self {
+        Self {
+            hand,
+            sequence: VarInt(sequence),
+            yaw,
+            pitch,
+        }
+    }
+}
+
+impl Packet for UseItem {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            hand: bytebuf.try_get_var_int()?.try_into()?,
+            sequence: bytebuf.try_get_var_int()?,
+            yaw: bytebuf.try_get_f32()?,
+            pitch: bytebuf.try_get_f32()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.hand as i32));
+        bytebuf.put_var_int(&self.sequence);
+        bytebuf.put_f32(self.yaw);
+        bytebuf.put_f32(self.pitch);
+    }
+}
@@ -0,0 +1,77 @@
+use bytes::{Buf, BufMut};
+use wither_data::packet::serverbound::PLAY_USE_ITEM_ON;
+use wither_macros::wither_packet;
+use wither_util::math::{position::WorldPosition, vector3::Vector3};
+use wither_world::block::BlockFace;
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    Hand, Packet, VarInt,
+};
+
+/// Right clicks a block, placing the held block or interacting with the target.
+#[derive(Debug)]
+#[wither_packet(PLAY_USE_ITEM_ON)]
+pub struct UseItemOn {
+    pub hand: Hand,
+    pub location: WorldPosition,
+    pub face: BlockFace,
+    /// Position of the crosshair on the block, each axis from 0 to 1.
+    pub cursor: Vector3<f32>,
+    pub inside_block: bool,
+    pub world_border_hit: bool,
+    /// Echoed back in Block Changed Ack.
+    pub sequence: VarInt,
+}
+
+impl UseItemOn {
+    pub fn new(
+        hand: Hand,
+        location: WorldPosition,
+        face: BlockFace,
+        cursor: Vector3<f32>,
+        inside_block: bool,
+        sequence: i32,
+    ) -> Self {
+        Self {
+            hand,
+            location,
+            face,
+            cursor,
+            inside_block,
+            world_border_hit: false,
+            sequence: VarInt(sequence),
+        }
+    }
+}
+
+impl Packet for UseItemOn {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            hand: bytebuf.try_get_var_int()?.try_into()?,
+            location: bytebuf.try_get_position()?,
+            face: BlockFace::try_from(bytebuf.try_get_var_int()?.0)
+                .map_err(|_| ReadingError::Message("Invalid block face".to_string()))?,
+            cursor: Vector3::new(
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+            ),
+            inside_block: bytebuf.try_get_bool()?,
+            world_border_hit: bytebuf.try_get_bool()?,
+            sequence: bytebuf.try_get_var_int()?,
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.hand as i32));
+        bytebuf.put_position(&self.location);
+        bytebuf.put_var_int(&VarInt(self.face as i32));
+        bytebuf.put_f32(self.cursor.x);
+        bytebuf.put_f32(self.cursor.y);
+        bytebuf.put_f32(self.cursor.z);
+        bytebuf.put_bool(self.inside_block);
+        bytebuf.put_bool(self.world_border_hit);
+        bytebuf.put_var_int(&self.sequence);
+    }
+}
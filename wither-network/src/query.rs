@@ -1,7 +1,28 @@
-use std::{ffi::CString, io::Cursor};
+use std::{
+    ffi::CString,
+    io::{BufRead, Cursor},
+};
 
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
+const MAGIC: u16 = 65277;
+
+#[derive(thiserror::Error, Debug)]
+pub enum QueryError {
+    #[error("invalid magic {0}")]
+    InvalidMagic(u16),
+    #[error("invalid packet type {0}")]
+    InvalidPacketType(u8),
+    #[error("session id mismatch, expected {expected} but got {actual}")]
+    SessionMismatch { expected: i32, actual: i32 },
+    #[error("malformed packet: {0}")]
+    Malformed(String),
+    #[error("query timed out")]
+    Timeout,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
 #[repr(u8)]
 pub enum PacketType {
     // There could be other types but they are not documented
@@ -30,19 +51,22 @@ pub struct RawQueryPacket {
 }
 
 impl RawQueryPacket {
-    pub async fn decode(bytes: Vec<u8>) -> Result<Self, ()> {
+    pub async fn decode(bytes: Vec<u8>) -> Result<Self, QueryError> {
         let mut reader = Cursor::new(bytes);
 
-        match reader.read_u16().await.map_err(|_| ())? {
+        match reader.read_u16().await? {
             // Magic should always equal 65277
             // Since it denotes the protocol being used
             // Should not attempt to decode packets with other magic values
-            65277 => Ok(Self {
-                packet_type: PacketType::try_from(reader.read_u8().await.map_err(|_| ())?)
-                    .map_err(|_| ())?,
-                reader,
-            }),
-            _ => Err(()),
+            MAGIC => {
+                let packet_type = reader.read_u8().await?;
+                Ok(Self {
+                    packet_type: PacketType::try_from(packet_type)
+                        .map_err(|_| QueryError::InvalidPacketType(packet_type))?,
+                    reader,
+                })
+            }
+            magic => Err(QueryError::InvalidMagic(magic)),
         }
     }
 }
@@ -53,11 +77,21 @@ pub struct SHandshake {
 }
 
 impl SHandshake {
-    pub async fn decode(packet: &mut RawQueryPacket) -> Result<Self, ()> {
+    pub async fn decode(packet: &mut RawQueryPacket) -> Result<Self, QueryError> {
         Ok(Self {
-            session_id: packet.reader.read_i32().await.map_err(|_| ())?,
+            session_id: packet.reader.read_i32().await?,
         })
     }
+
+    pub async fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        buf.write_u16(MAGIC).await.unwrap();
+        buf.write_u8(PacketType::Handshake as u8).await.unwrap();
+        buf.write_i32(self.session_id).await.unwrap();
+
+        buf
+    }
 }
 
 #[derive(PartialEq, Debug)]
@@ -70,10 +104,10 @@ pub struct SStatusRequest {
 }
 
 impl SStatusRequest {
-    pub async fn decode(packet: &mut RawQueryPacket) -> Result<Self, ()> {
+    pub async fn decode(packet: &mut RawQueryPacket) -> Result<Self, QueryError> {
         Ok(Self {
-            session_id: packet.reader.read_i32().await.map_err(|_| ())?,
-            challenge_token: packet.reader.read_i32().await.map_err(|_| ())?,
+            session_id: packet.reader.read_i32().await?,
+            challenge_token: packet.reader.read_i32().await?,
             is_full_request: {
                 let mut buf = [0; 4];
 
@@ -86,14 +120,62 @@ impl SStatusRequest {
                     Ok(4) => true,
                     _ => {
                         // Just ignore malformed packets or errors
-                        return Err(());
+                        return Err(QueryError::Malformed(
+                            "invalid status request padding".into(),
+                        ));
                     }
                 }
             },
         })
     }
+
+    pub async fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        buf.write_u16(MAGIC).await.unwrap();
+        buf.write_u8(PacketType::Status as u8).await.unwrap();
+        buf.write_i32(self.session_id).await.unwrap();
+        buf.write_i32(self.challenge_token).await.unwrap();
+        if self.is_full_request {
+            // Padding, only its presence matters
+            buf.write_i32(0).await.unwrap();
+        }
+
+        buf
+    }
+}
+
+/// Reads the packet type and session id every response starts with.
+async fn read_response_header(
+    reader: &mut Cursor<Vec<u8>>,
+    packet_type: PacketType,
+) -> Result<i32, QueryError> {
+    let actual = reader.read_u8().await?;
+    if actual != packet_type as u8 {
+        return Err(QueryError::InvalidPacketType(actual));
+    }
+    Ok(reader.read_i32().await?)
+}
+
+fn read_cstring(reader: &mut Cursor<Vec<u8>>) -> Result<CString, QueryError> {
+    let mut bytes = Vec::new();
+    reader.read_until(0, &mut bytes)?;
+    if bytes.pop() != Some(0) {
+        return Err(QueryError::Malformed("unterminated string".into()));
+    }
+    // `read_until` stopped at the first nul, so there is none left in `bytes`
+    Ok(CString::new(bytes).unwrap())
 }
 
+fn parse_cstring<T: std::str::FromStr>(value: &CString) -> Result<T, QueryError> {
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| QueryError::Malformed(format!("invalid number {value:?}")))
+}
+
+#[derive(PartialEq, Debug)]
 pub struct CHandshake {
     pub session_id: i32,
     // For simplicity use a number type
@@ -118,8 +200,18 @@ impl CHandshake {
 
         buf
     }
+
+    pub async fn decode(bytes: Vec<u8>) -> Result<Self, QueryError> {
+        let mut reader = Cursor::new(bytes);
+
+        Ok(Self {
+            session_id: read_response_header(&mut reader, PacketType::Handshake).await?,
+            challenge_token: parse_cstring(&read_cstring(&mut reader)?)?,
+        })
+    }
 }
 
+#[derive(PartialEq, Debug)]
 pub struct CBasicStatus {
     pub session_id: i32,
     // Use CString as protocol requires nul terminated strings
@@ -161,8 +253,33 @@ impl CBasicStatus {
 
         buf
     }
+
+    pub async fn decode(bytes: Vec<u8>) -> Result<Self, QueryError> {
+        let mut reader = Cursor::new(bytes);
+
+        let session_id = read_response_header(&mut reader, PacketType::Status).await?;
+        let motd = read_cstring(&mut reader)?;
+        // Game type, always SMP
+        read_cstring(&mut reader)?;
+        let map = read_cstring(&mut reader)?;
+        let num_players = parse_cstring(&read_cstring(&mut reader)?)?;
+        let max_players = parse_cstring(&read_cstring(&mut reader)?)?;
+        let host_port = reader.read_u16_le().await?;
+        let host_ip = read_cstring(&mut reader)?;
+
+        Ok(Self {
+            session_id,
+            motd,
+            map,
+            num_players,
+            max_players,
+            host_port,
+            host_ip,
+        })
+    }
 }
 
+#[derive(PartialEq, Debug)]
 pub struct CFullStatus {
     pub session_id: i32,
     pub hostname: CString,
@@ -237,19 +354,82 @@ impl CFullStatus {
 
         buf
     }
+
+    pub async fn decode(bytes: Vec<u8>) -> Result<Self, QueryError> {
+        let mut reader = Cursor::new(bytes);
+
+        let session_id = read_response_header(&mut reader, PacketType::Status).await?;
+        // Padding (11 bytes, meaningless)
+        reader.consume(11);
+
+        let mut hostname = None;
+        let mut version = None;
+        let mut plugins = None;
+        let mut map = None;
+        let mut num_players = None;
+        let mut max_players = None;
+        let mut host_port = None;
+        let mut host_ip = None;
+        // Key-value pairs, ended by an empty key
+        loop {
+            let key = read_cstring(&mut reader)?;
+            if key.is_empty() {
+                break;
+            }
+            let value = read_cstring(&mut reader)?;
+            match key.as_bytes() {
+                b"hostname" => hostname = Some(value),
+                b"version" => version = Some(value),
+                b"plugins" => plugins = Some(value),
+                b"map" => map = Some(value),
+                b"numplayers" => num_players = Some(parse_cstring(&value)?),
+                b"maxplayers" => max_players = Some(parse_cstring(&value)?),
+                b"hostport" => host_port = Some(parse_cstring(&value)?),
+                b"hostip" => host_ip = Some(value),
+                // Game type and game id are hardcoded
+                _ => {}
+            }
+        }
+
+        // Padding (10 bytes, meaningless)
+        reader.consume(10);
+        let mut players = Vec::new();
+        loop {
+            let player = read_cstring(&mut reader)?;
+            if player.is_empty() {
+                break;
+            }
+            players.push(player);
+        }
+
+        let missing = |key: &str| QueryError::Malformed(format!("missing key {key}"));
+        Ok(Self {
+            session_id,
+            hostname: hostname.ok_or_else(|| missing("hostname"))?,
+            version: version.ok_or_else(|| missing("version"))?,
+            plugins: plugins.unwrap_or_default(),
+            map: map.ok_or_else(|| missing("map"))?,
+            num_players: num_players.ok_or_else(|| missing("numplayers"))?,
+            max_players: max_players.ok_or_else(|| missing("maxplayers"))?,
+            host_port: host_port.ok_or_else(|| missing("hostport"))?,
+            host_ip: host_ip.ok_or_else(|| missing("hostip"))?,
+            players,
+        })
+    }
 }
 
 // All test bytes/packets are from protocol documentation
 #[tokio::test]
 async fn test_handshake_request() {
     let bytes = vec![0xFE, 0xFD, 0x09, 0x00, 0x00, 0x00, 0x01];
-    let mut raw_packet = RawQueryPacket::decode(bytes).await.unwrap();
+    let mut raw_packet = RawQueryPacket::decode(bytes.clone()).await.unwrap();
     let packet = SHandshake::decode(&mut raw_packet).await.unwrap();
 
     // What the decoded packet should look like
     let actual_packet = SHandshake { session_id: 1 };
 
     assert_eq!(packet, actual_packet);
+    assert_eq!(bytes, actual_packet.encode().await);
 }
 
 #[tokio::test]
@@ -263,7 +443,8 @@ async fn test_handshake_response() {
         challenge_token: 9513307,
     };
 
-    assert_eq!(bytes, packet.encode().await)
+    assert_eq!(bytes, packet.encode().await);
+    assert_eq!(CHandshake::decode(bytes).await.unwrap(), packet);
 }
 
 #[tokio::test]
@@ -271,7 +452,7 @@ async fn test_basic_stat_request() {
     let bytes = vec![
         0xFE, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x91, 0x29, 0x5B,
     ];
-    let mut raw_packet = RawQueryPacket::decode(bytes).await.unwrap();
+    let mut raw_packet = RawQueryPacket::decode(bytes.clone()).await.unwrap();
     let packet = SStatusRequest::decode(&mut raw_packet).await.unwrap();
 
     let actual_packet = SStatusRequest {
@@ -281,6 +462,7 @@ async fn test_basic_stat_request() {
     };
 
     assert_eq!(packet, actual_packet);
+    assert_eq!(bytes, actual_packet.encode().await);
 }
 
 #[tokio::test]
@@ -303,6 +485,7 @@ async fn test_basic_stat_response() {
     };
 
     assert_eq!(bytes, packet.encode().await);
+    assert_eq!(CBasicStatus::decode(bytes).await.unwrap(), packet);
 }
 
 #[tokio::test]
@@ -310,7 +493,7 @@ async fn test_full_stat_request() {
     let bytes = vec![
         0xFE, 0xFD, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x91, 0x29, 0x5B, 0x00, 0x00, 0x00, 0x00,
     ];
-    let mut raw_packet = RawQueryPacket::decode(bytes).await.unwrap();
+    let mut raw_packet = RawQueryPacket::decode(bytes.clone()).await.unwrap();
     let packet = SStatusRequest::decode(&mut raw_packet).await.unwrap();
 
     let actual_packet = SStatusRequest {
@@ -320,6 +503,7 @@ async fn test_full_stat_request() {
     };
 
     assert_eq!(packet, actual_packet);
+    assert_eq!(bytes, actual_packet.encode().await);
 }
 #[tokio::test]
 async fn test_full_stat_response() {
@@ -358,4 +542,5 @@ async fn test_full_stat_response() {
     };
 
     assert_eq!(bytes, packet.encode().await);
+    assert_eq!(CFullStatus::decode(bytes).await.unwrap(), packet);
 }
@@ -0,0 +1,223 @@
+use bytes::{Buf, BufMut, Bytes};
+use wither_util::text::{style::Style, TextComponent};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::bit_set::BitSet,
+    types::Holder,
+    FixedBitSet, VarInt,
+};
+
+pub const MESSAGE_SIGNATURE_SIZE: usize = 256;
+/// How many of the last seen messages are acknowledged with each chat message.
+pub const LAST_SEEN_MESSAGES: usize = 20;
+const MAX_KEY_SIZE: usize = 512;
+const MAX_KEY_SIGNATURE_SIZE: usize = 4096;
+
+/// The key a player signs their chat messages with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteChatSession {
+    pub session_id: uuid::Uuid,
+    /// Milliseconds since the unix epoch.
+    pub expires_at: i64,
+    pub public_key: Bytes,
+    pub key_signature: Bytes,
+}
+
+impl RemoteChatSession {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            session_id: bytebuf.try_get_uuid()?,
+            expires_at: bytebuf.try_get_i64()?,
+            public_key: bytebuf.try_get_byte_array(MAX_KEY_SIZE)?,
+            key_signature: bytebuf.try_get_byte_array(MAX_KEY_SIGNATURE_SIZE)?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_uuid(&self.session_id);
+        bytebuf.put_i64(self.expires_at);
+        bytebuf.put_byte_array(&self.public_key);
+        bytebuf.put_byte_array(&self.key_signature);
+    }
+}
+
+/// Tells the server which of the last 20 messages the client has seen since the
+/// previous update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastSeenMessagesUpdate {
+    pub offset: VarInt,
+    pub acknowledged: FixedBitSet,
+}
+
+impl LastSeenMessagesUpdate {
+    pub fn new(offset: i32, acknowledged: FixedBitSet) -> Self {
+        Self {
+            offset: VarInt(offset),
+            acknowledged,
+        }
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            offset: bytebuf.try_get_var_int()?,
+            acknowledged: bytebuf.try_get_fixed_bitset(LAST_SEEN_MESSAGES)?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.offset);
+        bytebuf.put_slice(&self.acknowledged);
+    }
+}
+
+impl Default for LastSeenMessagesUpdate {
+    /// Nothing new was seen.
+    fn default() -> Self {
+        Self::new(0, FixedBitSet::from_static(&[0; LAST_SEEN_MESSAGES.div_ceil(8)]))
+    }
+}
+
+/// A message signature, either sent in full or as an index into the client's cache of
+/// previously seen signatures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackedSignature {
+    Id(VarInt),
+    Full(Bytes),
+}
+
+impl PackedSignature {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::Full(bytebuf.try_copy_to_bytes(MESSAGE_SIGNATURE_SIZE)?)),
+            id => Ok(Self::Id(VarInt(id - 1))),
+        }
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::Id(id) => bytebuf.put_var_int(&VarInt(id.0 + 1)),
+            Self::Full(signature) => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_slice(signature);
+            }
+        }
+    }
+}
+
+/// The signature of a single signed argument of a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentSignature {
+    pub name: String, // 16
+    pub signature: Bytes,
+}
+
+impl ArgumentSignature {
+    pub fn new(name: String, signature: Bytes) -> Self {
+        Self { name, signature }
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            name: bytebuf.try_get_string_len(16)?,
+            signature: bytebuf.try_copy_to_bytes(MESSAGE_SIGNATURE_SIZE)?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string_len(&self.name, 16);
+        bytebuf.put_slice(&self.signature);
+    }
+}
+
+/// Which parts of a chat message were hidden by the server's chat filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterMask {
+    PassThrough,
+    FullyFiltered,
+    PartiallyFiltered(BitSet),
+}
+
+impl FilterMask {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::PassThrough),
+            1 => Ok(Self::FullyFiltered),
+            2 => Ok(Self::PartiallyFiltered(bytebuf.try_get_bit_set()?)),
+            _ => Err(ReadingError::Message("Invalid filter mask type".to_string())),
+        }
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::PassThrough => bytebuf.put_var_int(&VarInt(0)),
+            Self::FullyFiltered => bytebuf.put_var_int(&VarInt(1)),
+            Self::PartiallyFiltered(mask) => {
+                bytebuf.put_var_int(&VarInt(2));
+                bytebuf.put_bit_set(mask);
+            }
+        }
+    }
+}
+
+/// A chat type together with the names it is decorated with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatTypeBound {
+    pub chat_type: Holder<ChatType>,
+    pub name: TextComponent,
+    pub target_name: Option<TextComponent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatType {
+    pub chat: ChatTypeDecoration,
+    pub narration: ChatTypeDecoration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatTypeDecoration {
+    pub translation_key: String,
+    /// 0: sender, 1: target, 2: content
+    pub parameters: Vec<VarInt>,
+    pub style: Style,
+}
+
+impl ChatTypeBound {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            chat_type: Holder::read(bytebuf, |v| {
+                Ok(ChatType {
+                    chat: ChatTypeDecoration::read(v)?,
+                    narration: ChatTypeDecoration::read(v)?,
+                })
+            })?,
+            name: bytebuf.try_get_text_component()?,
+            target_name: bytebuf.try_get_option(|v| v.try_get_text_component())?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        self.chat_type.write(bytebuf, |p, chat_type| {
+            chat_type.chat.write(p);
+            chat_type.narration.write(p);
+        });
+        bytebuf.put_text_component(&self.name);
+        bytebuf.put_option(&self.target_name, |p, name| p.put_text_component(name));
+    }
+}
+
+impl ChatTypeDecoration {
+    fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            translation_key: bytebuf.try_get_string()?,
+            parameters: bytebuf.get_list(|v| v.try_get_var_int())?,
+            style: Style::from_nbt(&bytebuf.try_get_nbt()?),
+        })
+    }
+
+    fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string(&self.translation_key);
+        bytebuf.put_list(&self.parameters, |p, parameter| p.put_var_int(parameter));
+        bytebuf.put_slice(&self.style.encode());
+    }
+}
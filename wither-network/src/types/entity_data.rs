@@ -0,0 +1,361 @@
+use bytes::{Buf, BufMut};
+use wither_nbt::tag::NbtTag;
+use wither_util::{
+    math::{position::WorldPosition, vector3::Vector3},
+    text::TextComponent,
+};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::{identifier::Identifier, slot::Slot, var_long::VarLong},
+    VarInt,
+};
+
+use super::{Holder, ParticleOptions};
+
+/// Index that ends a list of entity data.
+const END_INDEX: u8 = 0xFF;
+
+/// One entry of `set_entity_data`. What an index means depends on the entity class.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityData {
+    pub index: u8,
+    pub value: EntityDataValue,
+}
+
+/// The variants are in the order of the serializer ids.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityDataValue {
+    Byte(i8),
+    VarInt(VarInt),
+    VarLong(VarLong),
+    Float(f32),
+    String(String),
+    Component(TextComponent),
+    OptionalComponent(Option<TextComponent>),
+    Slot(Box<Slot>),
+    Boolean(bool),
+    /// Pitch, yaw and roll in degrees.
+    Rotations(Vector3<f32>),
+    BlockPos(WorldPosition),
+    OptionalBlockPos(Option<WorldPosition>),
+    Direction(VarInt),
+    OptionalUuid(Option<uuid::Uuid>),
+    BlockState(VarInt),
+    /// `None` for air.
+    OptionalBlockState(Option<VarInt>),
+    CompoundTag(NbtTag),
+    Particle(Box<ParticleOptions>),
+    Particles(Vec<ParticleOptions>),
+    VillagerData(VillagerData),
+    OptionalUnsignedInt(Option<u32>),
+    Pose(VarInt),
+    CatVariant(VarInt),
+    WolfVariant(VarInt),
+    FrogVariant(VarInt),
+    OptionalGlobalPos(Option<(Identifier, WorldPosition)>),
+    PaintingVariant(Box<Holder<PaintingVariant>>),
+    SnifferState(VarInt),
+    ArmadilloState(VarInt),
+    Vector3(Vector3<f32>),
+    /// x, y, z and w.
+    Quaternion([f32; 4]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VillagerData {
+    /// The biome type, like desert or taiga.
+    pub kind: VarInt,
+    pub profession: VarInt,
+    pub level: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaintingVariant {
+    pub width: VarInt,
+    pub height: VarInt,
+    pub asset_id: Identifier,
+    pub title: Option<TextComponent>,
+    pub author: Option<TextComponent>,
+}
+
+impl EntityData {
+    pub fn new(index: u8, value: EntityDataValue) -> Self {
+        Self { index, value }
+    }
+
+    /// Reads entries up to the end marker.
+    pub fn read_list(bytebuf: &mut impl Buf) -> Result<Vec<Self>, ReadingError> {
+        let mut entries = Vec::new();
+        loop {
+            let index = bytebuf.try_get_u8()?;
+            if index == END_INDEX {
+                return Ok(entries);
+            }
+            entries.push(Self {
+                index,
+                value: EntityDataValue::read(bytebuf)?,
+            });
+        }
+    }
+
+    pub fn write_list(entries: &[Self], bytebuf: &mut impl BufMut) {
+        for entry in entries {
+            bytebuf.put_u8(entry.index);
+            entry.value.write(bytebuf);
+        }
+        bytebuf.put_u8(END_INDEX);
+    }
+}
+
+impl EntityDataValue {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let serializer = bytebuf.try_get_var_int()?.0;
+        Ok(match serializer {
+            0 => Self::Byte(bytebuf.try_get_i8()?),
+            1 => Self::VarInt(bytebuf.try_get_var_int()?),
+            2 => Self::VarLong(bytebuf.try_get_var_long()?),
+            3 => Self::Float(bytebuf.try_get_f32()?),
+            4 => Self::String(bytebuf.try_get_string()?),
+            5 => Self::Component(bytebuf.try_get_text_component()?),
+            6 => Self::OptionalComponent(bytebuf.try_get_option(|v| v.try_get_text_component())?),
+            7 => Self::Slot(Box::new(Slot::read(bytebuf)?)),
+            8 => Self::Boolean(bytebuf.try_get_bool()?),
+            9 => Self::Rotations(read_vector3(bytebuf)?),
+            10 => Self::BlockPos(bytebuf.try_get_position()?),
+            11 => Self::OptionalBlockPos(bytebuf.try_get_option(|v| v.try_get_position())?),
+            12 => Self::Direction(bytebuf.try_get_var_int()?),
+            13 => Self::OptionalUuid(bytebuf.try_get_option(|v| v.try_get_uuid())?),
+            14 => Self::BlockState(bytebuf.try_get_var_int()?),
+            15 => Self::OptionalBlockState(match bytebuf.try_get_var_int()? {
+                VarInt(0) => None,
+                state => Some(state),
+            }),
+            16 => Self::CompoundTag(bytebuf.try_get_nbt()?),
+            17 => Self::Particle(Box::new(ParticleOptions::read(bytebuf)?)),
+            18 => Self::Particles(bytebuf.get_list(|v| ParticleOptions::read(v))?),
+            19 => Self::VillagerData(VillagerData {
+                kind: bytebuf.try_get_var_int()?,
+                profession: bytebuf.try_get_var_int()?,
+                level: bytebuf.try_get_var_int()?,
+            }),
+            20 => Self::OptionalUnsignedInt(match bytebuf.try_get_var_int()?.0 {
+                0 => None,
+                value => Some(value as u32 - 1),
+            }),
+            21 => Self::Pose(bytebuf.try_get_var_int()?),
+            22 => Self::CatVariant(bytebuf.try_get_var_int()?),
+            23 => Self::WolfVariant(bytebuf.try_get_var_int()?),
+            24 => Self::FrogVariant(bytebuf.try_get_var_int()?),
+            25 => Self::OptionalGlobalPos(
+                bytebuf.try_get_option(|v| Ok((v.try_get_identifer()?, v.try_get_position()?)))?,
+            ),
+            26 => Self::PaintingVariant(Box::new(Holder::read(bytebuf, |v| {
+                Ok(PaintingVariant {
+                    width: v.try_get_var_int()?,
+                    height: v.try_get_var_int()?,
+                    asset_id: v.try_get_identifer()?,
+                    title: v.try_get_option(|v| v.try_get_text_component())?,
+                    author: v.try_get_option(|v| v.try_get_text_component())?,
+                })
+            })?)),
+            27 => Self::SnifferState(bytebuf.try_get_var_int()?),
+            28 => Self::ArmadilloState(bytebuf.try_get_var_int()?),
+            29 => Self::Vector3(read_vector3(bytebuf)?),
+            30 => Self::Quaternion([
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+                bytebuf.try_get_f32()?,
+            ]),
+            _ => {
+                return Err(ReadingError::Message(format!(
+                    "Unknown entity data serializer {serializer}"
+                )))
+            }
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.serializer_id()));
+        match self {
+            Self::Byte(value) => bytebuf.put_i8(*value),
+            Self::VarInt(value)
+            | Self::Direction(value)
+            | Self::BlockState(value)
+            | Self::Pose(value)
+            | Self::CatVariant(value)
+            | Self::WolfVariant(value)
+            | Self::FrogVariant(value)
+            | Self::SnifferState(value)
+            | Self::ArmadilloState(value) => bytebuf.put_var_int(value),
+            Self::VarLong(value) => bytebuf.put_var_long(value),
+            Self::Float(value) => bytebuf.put_f32(*value),
+            Self::String(value) => bytebuf.put_string(value),
+            Self::Component(text) => bytebuf.put_text_component(text),
+            Self::OptionalComponent(text) => {
+                bytebuf.put_option(text, |p, text| p.put_text_component(text))
+            }
+            Self::Slot(slot) => slot.write(bytebuf),
+            Self::Boolean(value) => bytebuf.put_bool(*value),
+            Self::Rotations(vector) | Self::Vector3(vector) => write_vector3(bytebuf, vector),
+            Self::BlockPos(position) => bytebuf.put_position(position),
+            Self::OptionalBlockPos(position) => {
+                bytebuf.put_option(position, |p, position| p.put_position(position))
+            }
+            Self::OptionalUuid(uuid) => bytebuf.put_option(uuid, |p, uuid| p.put_uuid(uuid)),
+            Self::OptionalBlockState(state) => {
+                bytebuf.put_var_int(state.as_ref().unwrap_or(&VarInt(0)))
+            }
+            Self::CompoundTag(tag) => bytebuf.put_nbt(tag),
+            Self::Particle(particle) => particle.write(bytebuf),
+            Self::Particles(particles) => bytebuf.put_list(particles, |p, v| v.write(p)),
+            Self::VillagerData(data) => {
+                bytebuf.put_var_int(&data.kind);
+                bytebuf.put_var_int(&data.profession);
+                bytebuf.put_var_int(&data.level);
+            }
+            Self::OptionalUnsignedInt(value) => {
+                bytebuf.put_var_int(&VarInt(value.map_or(0, |value| value as i32 + 1)))
+            }
+            Self::OptionalGlobalPos(position) => {
+                bytebuf.put_option(position, |p, (dimension, position)| {
+                    p.put_identifier(dimension);
+                    p.put_position(position);
+                })
+            }
+            Self::PaintingVariant(variant) => variant.write(bytebuf, |p, variant| {
+                p.put_var_int(&variant.width);
+                p.put_var_int(&variant.height);
+                p.put_identifier(&variant.asset_id);
+                p.put_option(&variant.title, |p, text| p.put_text_component(text));
+                p.put_option(&variant.author, |p, text| p.put_text_component(text));
+            }),
+            Self::Quaternion(quaternion) => {
+                for value in quaternion {
+                    bytebuf.put_f32(*value);
+                }
+            }
+        }
+    }
+
+    fn serializer_id(&self) -> i32 {
+        match self {
+            Self::Byte(_) => 0,
+            Self::VarInt(_) => 1,
+            Self::VarLong(_) => 2,
+            Self::Float(_) => 3,
+            Self::String(_) => 4,
+            Self::Component(_) => 5,
+            Self::OptionalComponent(_) => 6,
+            Self::Slot(_) => 7,
+            Self::Boolean(_) => 8,
+            Self::Rotations(_) => 9,
+            Self::BlockPos(_) => 10,
+            Self::OptionalBlockPos(_) => 11,
+            Self::Direction(_) => 12,
+            Self::OptionalUuid(_) => 13,
+            Self::BlockState(_) => 14,
+            Self::OptionalBlockState(_) => 15,
+            Self::CompoundTag(_) => 16,
+            Self::Particle(_) => 17,
+            Self::Particles(_) => 18,
+            Self::VillagerData(_) => 19,
+            Self::OptionalUnsignedInt(_) => 20,
+            Self::Pose(_) => 21,
+            Self::CatVariant(_) => 22,
+            Self::WolfVariant(_) => 23,
+            Self::FrogVariant(_) => 24,
+            Self::OptionalGlobalPos(_) => 25,
+            Self::PaintingVariant(_) => 26,
+            Self::SnifferState(_) => 27,
+            Self::ArmadilloState(_) => 28,
+            Self::Vector3(_) => 29,
+            Self::Quaternion(_) => 30,
+        }
+    }
+}
+
+fn read_vector3(bytebuf: &mut impl Buf) -> Result<Vector3<f32>, ReadingError> {
+    Ok(Vector3::new(
+        bytebuf.try_get_f32()?,
+        bytebuf.try_get_f32()?,
+        bytebuf.try_get_f32()?,
+    ))
+}
+
+fn write_vector3(bytebuf: &mut impl BufMut, vector: &Vector3<f32>) {
+    bytebuf.put_f32(vector.x);
+    bytebuf.put_f32(vector.y);
+    bytebuf.put_f32(vector.z);
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use wither_data::particle::Particle;
+    use wither_util::{
+        math::{position::WorldPosition, vector3::Vector3},
+        text::TextComponent,
+    };
+
+    use crate::{
+        codec::identifier::Identifier,
+        types::{Holder, ParticleData, ParticleOptions},
+        VarInt,
+    };
+
+    use super::{EntityData, EntityDataValue, PaintingVariant, VillagerData};
+
+    #[test]
+    fn round_trip() {
+        let entries = vec![
+            EntityData::new(0, EntityDataValue::Byte(0x01)),
+            EntityData::new(
+                2,
+                EntityDataValue::OptionalComponent(Some(TextComponent::text("Steve"))),
+            ),
+            EntityData::new(6, EntityDataValue::Pose(VarInt(5))),
+            EntityData::new(9, EntityDataValue::Float(12.5)),
+            EntityData::new(
+                10,
+                EntityDataValue::Particles(vec![ParticleOptions::new(
+                    Particle::EntityEffect,
+                    ParticleData::Color(-1),
+                )]),
+            ),
+            EntityData::new(14, EntityDataValue::OptionalBlockPos(None)),
+            EntityData::new(
+                18,
+                EntityDataValue::VillagerData(VillagerData {
+                    kind: VarInt(2),
+                    profession: VarInt(9),
+                    level: VarInt(1),
+                }),
+            ),
+            EntityData::new(
+                8,
+                EntityDataValue::PaintingVariant(Box::new(Holder::Direct(PaintingVariant {
+                    width: VarInt(2),
+                    height: VarInt(1),
+                    asset_id: Identifier::vanilla("kebab"),
+                    title: None,
+                    author: Some(TextComponent::text("Kristoffer Zetterstrand")),
+                }))),
+            ),
+            EntityData::new(
+                11,
+                EntityDataValue::OptionalGlobalPos(Some((
+                    Identifier::vanilla("overworld"),
+                    WorldPosition(Vector3::new(-3, 70, 12)),
+                ))),
+            ),
+            EntityData::new(12, EntityDataValue::Quaternion([0.0, 0.0, 0.0, 1.0])),
+        ];
+
+        let mut bytes = BytesMut::new();
+        EntityData::write_list(&entries, &mut bytes);
+        assert_eq!(bytes.last(), Some(&0xFF));
+        assert_eq!(EntityData::read_list(&mut bytes.freeze()).unwrap(), entries);
+    }
+}
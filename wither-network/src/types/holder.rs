@@ -0,0 +1,75 @@
+use bytes::{Buf, BufMut};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::identifier::Identifier,
+    VarInt,
+};
+
+/// A registry entry that is either referenced by its protocol id or sent inline.
+///
+/// On the wire this is a VarInt that is `0` for an inline value and `id + 1` otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Holder<T> {
+    Reference(VarInt),
+    Direct(T),
+}
+
+impl<T> Holder<T> {
+    pub(crate) fn read<B: Buf>(
+        bytebuf: &mut B,
+        read: impl FnOnce(&mut B) -> Result<T, ReadingError>,
+    ) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::Direct(read(bytebuf)?)),
+            id => Ok(Self::Reference(VarInt(id - 1))),
+        }
+    }
+
+    pub(crate) fn write<B: BufMut>(&self, bytebuf: &mut B, write: impl FnOnce(&mut B, &T)) {
+        match self {
+            Self::Reference(id) => bytebuf.put_var_int(&VarInt(id.0 + 1)),
+            Self::Direct(value) => {
+                bytebuf.put_var_int(&VarInt(0));
+                write(bytebuf, value);
+            }
+        }
+    }
+}
+
+/// A set of registry entries, either given by a tag or listed by protocol id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HolderSet {
+    Tag(Identifier),
+    Direct(Vec<VarInt>),
+}
+
+impl HolderSet {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::Tag(bytebuf.try_get_identifer()?)),
+            len => {
+                let mut ids = Vec::with_capacity((len - 1).max(0) as usize);
+                for _ in 1..len {
+                    ids.push(bytebuf.try_get_var_int()?);
+                }
+                Ok(Self::Direct(ids))
+            }
+        }
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::Tag(tag) => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_identifier(tag);
+            }
+            Self::Direct(ids) => {
+                bytebuf.put_var_int(&VarInt(ids.len() as i32 + 1));
+                for id in ids {
+                    bytebuf.put_var_int(id);
+                }
+            }
+        }
+    }
+}
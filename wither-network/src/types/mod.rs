@@ -6,4 +6,18 @@ mod binary;
 pub use binary::*;
 
 mod property;
-pub use property::*;
\ No newline at end of file
+pub use property::*;
+mod holder;
+pub use holder::*;
+
+mod particle;
+pub use particle::*;
+
+mod entity_data;
+pub use entity_data::*;
+
+mod chat;
+pub use chat::*;
+
+mod recipe;
+pub use recipe::*;
@@ -0,0 +1,172 @@
+use bytes::{Buf, BufMut};
+use wither_data::particle::Particle;
+use wither_util::math::{position::WorldPosition, vector3::Vector3};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::slot::Slot,
+    VarInt,
+};
+
+/// A particle type together with the options that particle type carries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticleOptions {
+    pub particle: Particle,
+    pub data: ParticleData,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParticleData {
+    None,
+    /// Used by `block`, `block_marker`, `falling_dust`, `dust_pillar` and `block_crumble`.
+    BlockState(VarInt),
+    Dust {
+        color: i32,
+        scale: f32,
+    },
+    DustColorTransition {
+        from_color: i32,
+        to_color: i32,
+        scale: f32,
+    },
+    /// ARGB color of `entity_effect`.
+    Color(i32),
+    SculkCharge {
+        roll: f32,
+    },
+    Item(Slot),
+    Vibration {
+        source: PositionSource,
+        arrival_in_ticks: VarInt,
+    },
+    Trail {
+        target: Vector3<f64>,
+        color: i32,
+        duration: VarInt,
+    },
+    Shriek {
+        delay: VarInt,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PositionSource {
+    Block(WorldPosition),
+    Entity { entity_id: VarInt, y_offset: f32 },
+}
+
+impl ParticleOptions {
+    pub fn new(particle: Particle, data: ParticleData) -> Self {
+        Self { particle, data }
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        let id = bytebuf.try_get_var_int()?.0;
+        let particle = u8::try_from(id)
+            .ok()
+            .and_then(Particle::from_id)
+            .ok_or_else(|| ReadingError::Message(format!("Unknown particle type {id}")))?;
+
+        let data = match particle {
+            Particle::Block
+            | Particle::BlockMarker
+            | Particle::FallingDust
+            | Particle::DustPillar
+            | Particle::BlockCrumble => ParticleData::BlockState(bytebuf.try_get_var_int()?),
+            Particle::Dust => ParticleData::Dust {
+                color: bytebuf.try_get_i32()?,
+                scale: bytebuf.try_get_f32()?,
+            },
+            Particle::DustColorTransition => ParticleData::DustColorTransition {
+                from_color: bytebuf.try_get_i32()?,
+                to_color: bytebuf.try_get_i32()?,
+                scale: bytebuf.try_get_f32()?,
+            },
+            Particle::EntityEffect => ParticleData::Color(bytebuf.try_get_i32()?),
+            Particle::SculkCharge => ParticleData::SculkCharge {
+                roll: bytebuf.try_get_f32()?,
+            },
+            Particle::Item => ParticleData::Item(Slot::read(bytebuf)?),
+            Particle::Vibration => ParticleData::Vibration {
+                source: match bytebuf.try_get_var_int()?.0 {
+                    0 => PositionSource::Block(bytebuf.try_get_position()?),
+                    1 => PositionSource::Entity {
+                        entity_id: bytebuf.try_get_var_int()?,
+                        y_offset: bytebuf.try_get_f32()?,
+                    },
+                    _ => {
+                        return Err(ReadingError::Message(
+                            "Invalid position source type".to_string(),
+                        ))
+                    }
+                },
+                arrival_in_ticks: bytebuf.try_get_var_int()?,
+            },
+            Particle::Trail => ParticleData::Trail {
+                target: bytebuf.try_get_vec3()?,
+                color: bytebuf.try_get_i32()?,
+                duration: bytebuf.try_get_var_int()?,
+            },
+            Particle::Shriek => ParticleData::Shriek {
+                delay: bytebuf.try_get_var_int()?,
+            },
+            _ => ParticleData::None,
+        };
+
+        Ok(Self { particle, data })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&VarInt(self.particle as i32));
+        match &self.data {
+            ParticleData::None => {}
+            ParticleData::BlockState(state) => bytebuf.put_var_int(state),
+            ParticleData::Dust { color, scale } => {
+                bytebuf.put_i32(*color);
+                bytebuf.put_f32(*scale);
+            }
+            ParticleData::DustColorTransition {
+                from_color,
+                to_color,
+                scale,
+            } => {
+                bytebuf.put_i32(*from_color);
+                bytebuf.put_i32(*to_color);
+                bytebuf.put_f32(*scale);
+            }
+            ParticleData::Color(color) => bytebuf.put_i32(*color),
+            ParticleData::SculkCharge { roll } => bytebuf.put_f32(*roll),
+            ParticleData::Item(item) => item.write(bytebuf),
+            ParticleData::Vibration {
+                source,
+                arrival_in_ticks,
+            } => {
+                match source {
+                    PositionSource::Block(position) => {
+                        bytebuf.put_var_int(&VarInt(0));
+                        bytebuf.put_position(position);
+                    }
+                    PositionSource::Entity {
+                        entity_id,
+                        y_offset,
+                    } => {
+                        bytebuf.put_var_int(&VarInt(1));
+                        bytebuf.put_var_int(entity_id);
+                        bytebuf.put_f32(*y_offset);
+                    }
+                }
+                bytebuf.put_var_int(arrival_in_ticks);
+            }
+            ParticleData::Trail {
+                target,
+                color,
+                duration,
+            } => {
+                bytebuf.put_vec3(target);
+                bytebuf.put_i32(*color);
+                bytebuf.put_var_int(duration);
+            }
+            ParticleData::Shriek { delay } => bytebuf.put_var_int(delay),
+        }
+    }
+}
@@ -4,10 +4,15 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::codec::var_int::VarInt;
+use bytes::{Buf, BufMut};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::var_int::VarInt,
+};
 
 // basically game profile
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Property {
     pub name: String,
     // base 64
@@ -16,15 +21,45 @@ pub struct Property {
     pub signature: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PropertyMap {
     inner: Vec<Property>,
 }
 
+impl Property {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            name: bytebuf.try_get_string_len(64)?,
+            value: bytebuf.try_get_string()?,
+            signature: bytebuf.try_get_option(|v| v.try_get_string_len(1024))?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_string_len(&self.name, 64);
+        bytebuf.put_string(&self.value);
+        bytebuf.put_option(&self.signature, |p, signature| {
+            p.put_string_len(signature, 1024)
+        });
+    }
+}
+
 impl PropertyMap {
     pub fn new(inner: Vec<Property>) -> Self {
         Self { inner }
     }
+
+    pub fn properties(&self) -> &[Property] {
+        &self.inner
+    }
+
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self::new(bytebuf.get_list(Property::read)?))
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_list(&self.inner, |p, property| property.write(p));
+    }
 }
 
 impl Serialize for PropertyMap {
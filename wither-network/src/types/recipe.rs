@@ -0,0 +1,276 @@
+use bytes::{Buf, BufMut};
+
+use crate::{
+    bytebuf::{ByteBuf, ByteBufMut, ReadingError},
+    codec::{identifier::Identifier, slot::Slot},
+    types::HolderSet,
+    VarInt,
+};
+
+/// Describes what the client should show in a recipe slot.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SlotDisplay {
+    Empty,
+    AnyFuel,
+    Item(VarInt),
+    ItemStack(Slot),
+    Tag(Identifier),
+    SmithingTrim {
+        base: Box<SlotDisplay>,
+        material: Box<SlotDisplay>,
+        pattern: Box<SlotDisplay>,
+    },
+    WithRemainder {
+        input: Box<SlotDisplay>,
+        remainder: Box<SlotDisplay>,
+    },
+    Composite(Vec<SlotDisplay>),
+}
+
+/// How a recipe is shown in the recipe book.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecipeDisplay {
+    CraftingShapeless {
+        ingredients: Vec<SlotDisplay>,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+    CraftingShaped {
+        width: VarInt,
+        height: VarInt,
+        ingredients: Vec<SlotDisplay>,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+    Furnace {
+        ingredient: SlotDisplay,
+        fuel: SlotDisplay,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+        duration: VarInt,
+        experience: f32,
+    },
+    Stonecutter {
+        input: SlotDisplay,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+    Smithing {
+        template: SlotDisplay,
+        base: SlotDisplay,
+        addition: SlotDisplay,
+        result: SlotDisplay,
+        crafting_station: SlotDisplay,
+    },
+}
+
+/// A recipe the player has unlocked, as shown in the recipe book.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeDisplayEntry {
+    pub id: VarInt,
+    pub display: RecipeDisplay,
+    pub group: Option<VarInt>,
+    pub category: VarInt,
+    /// Item sets, one per ingredient, used to tell whether the recipe can be crafted.
+    pub crafting_requirements: Option<Vec<HolderSet>>,
+}
+
+impl SlotDisplay {
+    pub(crate) fn read<B: Buf>(bytebuf: &mut B) -> Result<Self, ReadingError> {
+        let boxed = |v: &mut B| Ok(Box::new(Self::read(v)?));
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::Empty),
+            1 => Ok(Self::AnyFuel),
+            2 => Ok(Self::Item(bytebuf.try_get_var_int()?)),
+            3 => Ok(Self::ItemStack(Slot::read(bytebuf)?)),
+            4 => Ok(Self::Tag(bytebuf.try_get_identifer()?)),
+            5 => Ok(Self::SmithingTrim {
+                base: boxed(bytebuf)?,
+                material: boxed(bytebuf)?,
+                pattern: boxed(bytebuf)?,
+            }),
+            6 => Ok(Self::WithRemainder {
+                input: boxed(bytebuf)?,
+                remainder: boxed(bytebuf)?,
+            }),
+            7 => Ok(Self::Composite(bytebuf.get_list(Self::read)?)),
+            id => Err(ReadingError::Message(format!(
+                "Unknown slot display type {id}"
+            ))),
+        }
+    }
+
+    pub(crate) fn write<B: BufMut>(&self, bytebuf: &mut B) {
+        match self {
+            Self::Empty => bytebuf.put_var_int(&VarInt(0)),
+            Self::AnyFuel => bytebuf.put_var_int(&VarInt(1)),
+            Self::Item(item) => {
+                bytebuf.put_var_int(&VarInt(2));
+                bytebuf.put_var_int(item);
+            }
+            Self::ItemStack(stack) => {
+                bytebuf.put_var_int(&VarInt(3));
+                stack.write(bytebuf);
+            }
+            Self::Tag(tag) => {
+                bytebuf.put_var_int(&VarInt(4));
+                bytebuf.put_identifier(tag);
+            }
+            Self::SmithingTrim {
+                base,
+                material,
+                pattern,
+            } => {
+                bytebuf.put_var_int(&VarInt(5));
+                base.write(bytebuf);
+                material.write(bytebuf);
+                pattern.write(bytebuf);
+            }
+            Self::WithRemainder { input, remainder } => {
+                bytebuf.put_var_int(&VarInt(6));
+                input.write(bytebuf);
+                remainder.write(bytebuf);
+            }
+            Self::Composite(contents) => {
+                bytebuf.put_var_int(&VarInt(7));
+                bytebuf.put_list(contents, |p: &mut B, display| display.write(p));
+            }
+        }
+    }
+}
+
+impl RecipeDisplay {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        match bytebuf.try_get_var_int()?.0 {
+            0 => Ok(Self::CraftingShapeless {
+                ingredients: bytebuf.get_list(SlotDisplay::read)?,
+                result: SlotDisplay::read(bytebuf)?,
+                crafting_station: SlotDisplay::read(bytebuf)?,
+            }),
+            1 => Ok(Self::CraftingShaped {
+                width: bytebuf.try_get_var_int()?,
+                height: bytebuf.try_get_var_int()?,
+                ingredients: bytebuf.get_list(SlotDisplay::read)?,
+                result: SlotDisplay::read(bytebuf)?,
+                crafting_station: SlotDisplay::read(bytebuf)?,
+            }),
+            2 => Ok(Self::Furnace {
+                ingredient: SlotDisplay::read(bytebuf)?,
+                fuel: SlotDisplay::read(bytebuf)?,
+                result: SlotDisplay::read(bytebuf)?,
+                crafting_station: SlotDisplay::read(bytebuf)?,
+                duration: bytebuf.try_get_var_int()?,
+                experience: bytebuf.try_get_f32()?,
+            }),
+            3 => Ok(Self::Stonecutter {
+                input: SlotDisplay::read(bytebuf)?,
+                result: SlotDisplay::read(bytebuf)?,
+                crafting_station: SlotDisplay::read(bytebuf)?,
+            }),
+            4 => Ok(Self::Smithing {
+                template: SlotDisplay::read(bytebuf)?,
+                base: SlotDisplay::read(bytebuf)?,
+                addition: SlotDisplay::read(bytebuf)?,
+                result: SlotDisplay::read(bytebuf)?,
+                crafting_station: SlotDisplay::read(bytebuf)?,
+            }),
+            id => Err(ReadingError::Message(format!(
+                "Unknown recipe display type {id}"
+            ))),
+        }
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        match self {
+            Self::CraftingShapeless {
+                ingredients,
+                result,
+                crafting_station,
+            } => {
+                bytebuf.put_var_int(&VarInt(0));
+                bytebuf.put_list(ingredients, |p, display| display.write(p));
+                result.write(bytebuf);
+                crafting_station.write(bytebuf);
+            }
+            Self::CraftingShaped {
+                width,
+                height,
+                ingredients,
+                result,
+                crafting_station,
+            } => {
+                bytebuf.put_var_int(&VarInt(1));
+                bytebuf.put_var_int(width);
+                bytebuf.put_var_int(height);
+                bytebuf.put_list(ingredients, |p, display| display.write(p));
+                result.write(bytebuf);
+                crafting_station.write(bytebuf);
+            }
+            Self::Furnace {
+                ingredient,
+                fuel,
+                result,
+                crafting_station,
+                duration,
+                experience,
+            } => {
+                bytebuf.put_var_int(&VarInt(2));
+                ingredient.write(bytebuf);
+                fuel.write(bytebuf);
+                result.write(bytebuf);
+                crafting_station.write(bytebuf);
+                bytebuf.put_var_int(duration);
+                bytebuf.put_f32(*experience);
+            }
+            Self::Stonecutter {
+                input,
+                result,
+                crafting_station,
+            } => {
+                bytebuf.put_var_int(&VarInt(3));
+                input.write(bytebuf);
+                result.write(bytebuf);
+                crafting_station.write(bytebuf);
+            }
+            Self::Smithing {
+                template,
+                base,
+                addition,
+                result,
+                crafting_station,
+            } => {
+                bytebuf.put_var_int(&VarInt(4));
+                template.write(bytebuf);
+                base.write(bytebuf);
+                addition.write(bytebuf);
+                result.write(bytebuf);
+                crafting_station.write(bytebuf);
+            }
+        }
+    }
+}
+
+impl RecipeDisplayEntry {
+    pub(crate) fn read(bytebuf: &mut impl Buf) -> Result<Self, ReadingError> {
+        Ok(Self {
+            id: bytebuf.try_get_var_int()?,
+            display: RecipeDisplay::read(bytebuf)?,
+            group: match bytebuf.try_get_var_int()?.0 {
+                0 => None,
+                group => Some(VarInt(group - 1)),
+            },
+            category: bytebuf.try_get_var_int()?,
+            crafting_requirements: bytebuf.try_get_option(|v| v.get_list(HolderSet::read))?,
+        })
+    }
+
+    pub(crate) fn write(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_var_int(&self.id);
+        self.display.write(bytebuf);
+        bytebuf.put_var_int(&VarInt(self.group.as_ref().map_or(0, |group| group.0 + 1)));
+        bytebuf.put_var_int(&self.category);
+        bytebuf.put_option(&self.crafting_requirements, |p, requirements| {
+            p.put_list(requirements, |p, items| items.write(p))
+        });
+    }
+}
@@ -1,6 +1,29 @@
 use super::{position::WorldPosition, vector3::Vector3};
 
-#[derive(Clone, Copy)]
+/// Movements shorter than this are treated as no movement when colliding.
+const COLLISION_EPSILON: f64 = 1.0e-7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    pub const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+
+    /// The component of `vector` along this axis.
+    pub fn get(self, vector: Vector3<f64>) -> f64 {
+        match self {
+            Axis::X => vector.x,
+            Axis::Y => vector.y,
+            Axis::Z => vector.z,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BoundingBox {
     pub min_x: f64,
     pub min_y: f64,
@@ -59,6 +82,83 @@ impl BoundingBox {
             && self.max_z > other.min_z
     }
 
+    pub fn min(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.min_x,
+            Axis::Y => self.min_y,
+            Axis::Z => self.min_z,
+        }
+    }
+
+    pub fn max(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.max_x,
+            Axis::Y => self.max_y,
+            Axis::Z => self.max_z,
+        }
+    }
+
+    pub fn offset(&self, offset: Vector3<f64>) -> Self {
+        Self {
+            min_x: self.min_x + offset.x,
+            min_y: self.min_y + offset.y,
+            min_z: self.min_z + offset.z,
+            max_x: self.max_x + offset.x,
+            max_y: self.max_y + offset.y,
+            max_z: self.max_z + offset.z,
+        }
+    }
+
+    /// Grows the box in the direction of `offset`, covering everything it passes when
+    /// moved by it.
+    pub fn expand_towards(&self, offset: Vector3<f64>) -> Self {
+        Self {
+            min_x: self.min_x + offset.x.min(0.0),
+            min_y: self.min_y + offset.y.min(0.0),
+            min_z: self.min_z + offset.z.min(0.0),
+            max_x: self.max_x + offset.x.max(0.0),
+            max_y: self.max_y + offset.y.max(0.0),
+            max_z: self.max_z + offset.z.max(0.0),
+        }
+    }
+
+    /// Grows the box on both sides of each axis, shrinks it for negative values.
+    pub fn inflate(&self, x: f64, y: f64, z: f64) -> Self {
+        Self {
+            min_x: self.min_x - x,
+            min_y: self.min_y - y,
+            min_z: self.min_z - z,
+            max_x: self.max_x + x,
+            max_y: self.max_y + y,
+            max_z: self.max_z + z,
+        }
+    }
+
+    /// Shortens `offset`, a movement of `moving` along `axis`, so that `moving` stops at
+    /// this box instead of entering it.
+    pub fn collide(&self, axis: Axis, moving: &BoundingBox, offset: f64) -> f64 {
+        if offset.abs() < COLLISION_EPSILON {
+            return 0.0;
+        }
+        let overlaps = Axis::ALL
+            .into_iter()
+            .filter(|&other| other != axis)
+            .all(|other| {
+                moving.max(other) - COLLISION_EPSILON > self.min(other)
+                    && moving.min(other) + COLLISION_EPSILON < self.max(other)
+            });
+        if !overlaps {
+            return offset;
+        }
+        if offset > 0.0 && moving.max(axis) - COLLISION_EPSILON <= self.min(axis) {
+            offset.min(self.min(axis) - moving.max(axis))
+        } else if offset < 0.0 && moving.min(axis) + COLLISION_EPSILON >= self.max(axis) {
+            offset.max(self.max(axis) - moving.min(axis))
+        } else {
+            offset
+        }
+    }
+
     pub fn squared_magnitude(&self, pos: Vector3<f64>) -> f64 {
         let d = f64::max(f64::max(self.min_x - pos.x, pos.x - self.max_x), 0.0);
         let e = f64::max(f64::max(self.min_y - pos.y, pos.y - self.max_y), 0.0);
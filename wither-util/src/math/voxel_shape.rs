@@ -1,9 +1,348 @@
+use super::{
+    boundingbox::{Axis, BoundingBox},
+    vector3::Vector3,
+};
+
+const EPSILON: f64 = 1.0e-7;
+
+/// Where a ray enters a shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapeHit {
+    pub position: Vector3<f64>,
+    /// The axis the face that was hit is perpendicular to.
+    pub axis: Axis,
+    /// Whether the face that was hit is on the positive side of the shape.
+    pub positive: bool,
+}
+
+/// A shape made of boxes, like the collision shape of a block. The boxes may overlap.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct VoxelShape {
-    // TODO
+    boxes: Vec<BoundingBox>,
 }
 
 impl VoxelShape {
-    pub fn is_empty() -> bool {
-        unimplemented!()
+    /// Boxes without volume are left out.
+    pub fn new(boxes: impl IntoIterator<Item = BoundingBox>) -> Self {
+        Self {
+            boxes: boxes.into_iter().filter(has_volume).collect(),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// A whole block, from 0 to 1 on each axis.
+    pub fn block() -> Self {
+        Self::new([BoundingBox::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+        )])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    pub fn boxes(&self) -> &[BoundingBox] {
+        &self.boxes
+    }
+
+    /// The smallest box containing the whole shape.
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        self.boxes.iter().copied().reduce(|bounds, b| BoundingBox {
+            min_x: bounds.min_x.min(b.min_x),
+            min_y: bounds.min_y.min(b.min_y),
+            min_z: bounds.min_z.min(b.min_z),
+            max_x: bounds.max_x.max(b.max_x),
+            max_y: bounds.max_y.max(b.max_y),
+            max_z: bounds.max_z.max(b.max_z),
+        })
+    }
+
+    pub fn offset(&self, offset: Vector3<f64>) -> Self {
+        Self {
+            boxes: self.boxes.iter().map(|b| b.offset(offset)).collect(),
+        }
+    }
+
+    pub fn union(&self, other: &VoxelShape) -> Self {
+        Self {
+            boxes: self.boxes.iter().chain(&other.boxes).copied().collect(),
+        }
+    }
+
+    pub fn intersection(&self, other: &VoxelShape) -> Self {
+        Self::new(self.boxes.iter().flat_map(|a| {
+            other.boxes.iter().map(|b| BoundingBox {
+                min_x: a.min_x.max(b.min_x),
+                min_y: a.min_y.max(b.min_y),
+                min_z: a.min_z.max(b.min_z),
+                max_x: a.max_x.min(b.max_x),
+                max_y: a.max_y.min(b.max_y),
+                max_z: a.max_z.min(b.max_z),
+            })
+        }))
+    }
+
+    /// Whether the shape and `other` share some volume, touching is not enough.
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.boxes.iter().any(|b| b.intersects(other))
+    }
+
+    /// Whether `point` is inside the shape.
+    pub fn contains(&self, point: Vector3<f64>) -> bool {
+        self.boxes.iter().any(|b| {
+            Axis::ALL
+                .into_iter()
+                .all(|axis| b.min(axis) <= axis.get(point) && axis.get(point) < b.max(axis))
+        })
+    }
+
+    /// The edges of the boxes along `axis`, sorted.
+    pub fn coords(&self, axis: Axis) -> Vec<f64> {
+        let mut coords: Vec<f64> = self
+            .boxes
+            .iter()
+            .flat_map(|b| [b.min(axis), b.max(axis)])
+            .collect();
+        coords.sort_by(f64::total_cmp);
+        coords.dedup();
+        coords
+    }
+
+    /// Shortens `offset`, a movement of `moving` along `axis`, so that `moving` stops at
+    /// the shape instead of entering it.
+    pub fn collide(&self, axis: Axis, moving: &BoundingBox, offset: f64) -> f64 {
+        let mut offset = offset;
+        for b in &self.boxes {
+            if offset.abs() < EPSILON {
+                return 0.0;
+            }
+            offset = b.collide(axis, moving, offset);
+        }
+        offset
+    }
+
+    /// The first point of the shape on the line from `from` to `to`. A line starting
+    /// inside the shape hits it right away, on the face it points away from.
+    pub fn raycast(&self, from: Vector3<f64>, to: Vector3<f64>) -> Option<ShapeHit> {
+        let direction = to.sub(&from);
+        if self.is_empty() || direction.length_squared() < EPSILON {
+            return None;
+        }
+        if self.contains(from.add(&(direction * 0.001))) {
+            let axis = Axis::ALL
+                .into_iter()
+                .max_by(|a, b| a.get(direction).abs().total_cmp(&b.get(direction).abs()))
+                .unwrap();
+            return Some(ShapeHit {
+                position: from.add(&(direction * 0.001)),
+                axis,
+                positive: axis.get(direction) < 0.0,
+            });
+        }
+        self.boxes
+            .iter()
+            .filter_map(|b| clip(b, from, direction))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(t, axis, positive)| ShapeHit {
+                position: from.add(&(direction * t)),
+                axis,
+                positive,
+            })
+    }
+
+    /// Whether the shape covers the whole face of the block on the given side.
+    pub fn is_face_full(&self, axis: Axis, positive: bool) -> bool {
+        covers_square(&self.face(axis, positive))
+    }
+
+    /// Whether the face of this shape on the given side and the opposite face of the
+    /// neighbouring shape together close the whole face between the two blocks.
+    pub fn face_occludes(&self, neighbour: &VoxelShape, axis: Axis, positive: bool) -> bool {
+        let mut face = self.face(axis, positive);
+        face.extend(neighbour.face(axis, !positive));
+        covers_square(&face)
+    }
+
+    /// The parts of the shape lying on a face of the block, as rectangles on the two
+    /// other axes.
+    fn face(&self, axis: Axis, positive: bool) -> Vec<[f64; 4]> {
+        let [u, v] = match axis {
+            Axis::X => [Axis::Y, Axis::Z],
+            Axis::Y => [Axis::X, Axis::Z],
+            Axis::Z => [Axis::X, Axis::Y],
+        };
+        self.boxes
+            .iter()
+            .filter(|b| {
+                if positive {
+                    b.max(axis) >= 1.0 - EPSILON
+                } else {
+                    b.min(axis) <= EPSILON
+                }
+            })
+            .map(|b| {
+                [
+                    b.min(u).max(0.0),
+                    b.min(v).max(0.0),
+                    b.max(u).min(1.0),
+                    b.max(v).min(1.0),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl From<BoundingBox> for VoxelShape {
+    fn from(b: BoundingBox) -> Self {
+        Self::new([b])
+    }
+}
+
+fn has_volume(b: &BoundingBox) -> bool {
+    Axis::ALL
+        .into_iter()
+        .all(|axis| b.max(axis) - b.min(axis) > EPSILON)
+}
+
+/// Where the line from `from` along `direction` enters `b`, as the fraction of
+/// `direction` and the face it enters through.
+fn clip(b: &BoundingBox, from: Vector3<f64>, direction: Vector3<f64>) -> Option<(f64, Axis, bool)> {
+    let mut enter = (0.0, None);
+    let mut exit = 1.0f64;
+    for axis in Axis::ALL {
+        let (start, delta) = (axis.get(from), axis.get(direction));
+        if delta.abs() < EPSILON {
+            if start < b.min(axis) || start > b.max(axis) {
+                return None;
+            }
+            continue;
+        }
+        let (near, far, positive) = if delta > 0.0 {
+            (b.min(axis), b.max(axis), false)
+        } else {
+            (b.max(axis), b.min(axis), true)
+        };
+        let t_near = (near - start) / delta;
+        let t_far = (far - start) / delta;
+        if t_near >= enter.0 {
+            enter = (t_near, Some((axis, positive)));
+        }
+        exit = exit.min(t_far);
+    }
+    let (t, face) = enter;
+    let (axis, positive) = face?;
+    (t <= exit && t <= 1.0).then_some((t, axis, positive))
+}
+
+/// Whether the rectangles together cover the square from 0 to 1.
+fn covers_square(rectangles: &[[f64; 4]]) -> bool {
+    let edges = |low: usize, high: usize| {
+        let mut edges: Vec<f64> = rectangles
+            .iter()
+            .flat_map(|r| [r[low], r[high]])
+            .chain([0.0, 1.0])
+            .filter(|edge| (0.0..=1.0).contains(edge))
+            .collect();
+        edges.sort_by(f64::total_cmp);
+        edges.dedup();
+        edges
+    };
+    let (us, vs) = (edges(0, 2), edges(1, 3));
+    us.windows(2).all(|u| {
+        vs.windows(2).all(|v| {
+            let (mid_u, mid_v) = ((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0);
+            u[1] - u[0] < EPSILON
+                || v[1] - v[0] < EPSILON
+                || rectangles
+                    .iter()
+                    .any(|r| r[0] <= mid_u && mid_u <= r[2] && r[1] <= mid_v && mid_v <= r[3])
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cuboid(min: [f64; 3], max: [f64; 3]) -> BoundingBox {
+        BoundingBox::new(
+            Vector3::new(min[0], min[1], min[2]),
+            Vector3::new(max[0], max[1], max[2]),
+        )
+    }
+
+    #[test]
+    fn combines_shapes() {
+        let bottom = VoxelShape::from(cuboid([0.0, 0.0, 0.0], [1.0, 0.5, 1.0]));
+        let post = VoxelShape::from(cuboid([0.25, 0.0, 0.25], [0.75, 1.0, 0.75]));
+        let stairs = bottom.union(&post);
+        assert_eq!(stairs.boxes().len(), 2);
+        assert_eq!(stairs.bounds(), Some(cuboid([0.0; 3], [1.0; 3])));
+        assert_eq!(stairs.coords(Axis::Y), vec![0.0, 0.5, 1.0]);
+
+        let overlap = bottom.intersection(&post);
+        assert_eq!(
+            overlap.boxes(),
+            &[cuboid([0.25, 0.0, 0.25], [0.75, 0.5, 0.75])]
+        );
+        assert!(bottom
+            .intersection(&VoxelShape::from(cuboid([0.0, 0.5, 0.0], [1.0, 1.0, 1.0])))
+            .is_empty());
+    }
+
+    #[test]
+    fn collides_along_axes() {
+        let slab = VoxelShape::from(cuboid([0.0, 0.0, 0.0], [1.0, 0.5, 1.0]));
+        let above = cuboid([0.2, 1.0, 0.2], [0.8, 2.8, 0.8]);
+        assert_eq!(slab.collide(Axis::Y, &above, -1.0), -0.5);
+        assert_eq!(slab.collide(Axis::Y, &above, 1.0), 1.0);
+        let beside = cuboid([1.0, 0.0, 0.2], [1.6, 1.8, 0.8]);
+        assert_eq!(slab.collide(Axis::X, &beside, -0.3), 0.0);
+        assert_eq!(
+            slab.collide(Axis::X, &beside.offset((0.0, 0.5, 0.0).into()), -0.3),
+            -0.3
+        );
+    }
+
+    #[test]
+    fn raycasts() {
+        let slab = VoxelShape::from(cuboid([0.0, 0.0, 0.0], [1.0, 0.5, 1.0]));
+        let hit = slab
+            .raycast((0.5, 2.0, 0.5).into(), (0.5, -1.0, 0.5).into())
+            .unwrap();
+        assert_eq!(hit.position, Vector3::new(0.5, 0.5, 0.5));
+        assert_eq!((hit.axis, hit.positive), (Axis::Y, true));
+
+        let hit = slab
+            .raycast((-1.0, 0.25, 0.5).into(), (2.0, 0.25, 0.5).into())
+            .unwrap();
+        assert_eq!((hit.axis, hit.positive), (Axis::X, false));
+        assert!((hit.position.x).abs() < 1e-9);
+
+        assert!(slab
+            .raycast((-1.0, 0.75, 0.5).into(), (2.0, 0.75, 0.5).into())
+            .is_none());
+        assert!(slab
+            .raycast((0.5, 2.0, 0.5).into(), (0.5, 1.0, 0.5).into())
+            .is_none());
+    }
+
+    #[test]
+    fn checks_faces() {
+        let slab = VoxelShape::from(cuboid([0.0, 0.0, 0.0], [1.0, 0.5, 1.0]));
+        let top = VoxelShape::from(cuboid([0.0, 0.5, 0.0], [1.0, 1.0, 1.0]));
+        assert!(slab.is_face_full(Axis::Y, false));
+        assert!(!slab.is_face_full(Axis::Y, true));
+        assert!(!slab.is_face_full(Axis::X, true));
+        assert!(VoxelShape::block().is_face_full(Axis::Z, true));
+
+        // Two halves of a wall close the face between them.
+        assert!(slab.face_occludes(&top, Axis::X, true));
+        assert!(!slab.face_occludes(&slab, Axis::X, true));
+        assert!(!slab.face_occludes(&VoxelShape::empty(), Axis::Y, true));
     }
 }
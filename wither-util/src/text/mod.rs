@@ -8,6 +8,7 @@ use colored::Colorize;
 use hover::HoverEvent;
 use serde::{Deserialize, Serialize};
 use style::Style;
+use wither_nbt::tag::NbtTag;
 
 pub mod click;
 pub mod color;
@@ -211,6 +212,107 @@ impl TextComponent {
     }
 }
 
+impl TextComponent {
+    /// Decodes a Text component sent as network NBT, as used by the protocol since 1.20.3.
+    /// A bare string tag is a plain text component.
+    pub fn from_nbt(tag: &NbtTag) -> Self {
+        Self::from_value(nbt_to_json(tag, false))
+    }
+
+    /// Decodes a Text component from JSON, like the description of a status response.
+    /// Plain strings are text and lists are their first entry with the rest as
+    /// children. Components with a style that can't be represented keep their text.
+    pub fn from_value(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::String(text) => Self::text(text),
+            serde_json::Value::Array(list) if !list.is_empty() => {
+                let mut list = list.into_iter().map(Self::from_value);
+                let first = list.next().unwrap();
+                list.fold(first, Self::add_child)
+            }
+            value => serde_json::from_value(value.clone()).unwrap_or_else(|_| Self::plain(&value)),
+        }
+    }
+
+    /// Fallback for components whose style we can't represent (e.g. item hover events),
+    /// keeps the text content and children but drops the style.
+    fn plain(value: &serde_json::Value) -> Self {
+        let field = |name: &str| {
+            value
+                .get(name)
+                .and_then(|v| v.as_str())
+                .map(|s| Cow::Owned(s.to_string()))
+        };
+        let children = |name: &str| {
+            value
+                .get(name)
+                .and_then(|v| v.as_array())
+                .map(|list| list.iter().cloned().map(Self::from_value).collect())
+                .unwrap_or_default()
+        };
+
+        let content = if let Some(translate) = field("translate") {
+            TextContent::Translate {
+                translate,
+                with: children("with"),
+            }
+        } else {
+            TextContent::Text {
+                text: field("text").unwrap_or_default(),
+            }
+        };
+
+        Self {
+            content,
+            style: Style::default(),
+            extra: children("extra"),
+        }
+    }
+}
+
+/// Converts NBT into the JSON shape of a Text component.
+/// Bytes are booleans here and heterogeneous lists wrap their entries in `{"": value}`.
+pub(super) fn nbt_to_json(tag: &NbtTag, in_component_list: bool) -> serde_json::Value {
+    use serde_json::Value;
+
+    match tag {
+        NbtTag::End => Value::Null,
+        NbtTag::Byte(byte) => Value::Bool(*byte != 0),
+        NbtTag::Short(short) => Value::from(*short),
+        NbtTag::Int(int) => Value::from(*int),
+        NbtTag::Long(long) => Value::from(*long),
+        NbtTag::Float(float) => Value::from(*float),
+        NbtTag::Double(double) => Value::from(*double),
+        NbtTag::ByteArray(bytes) => Value::from(bytes.to_vec()),
+        NbtTag::String(string) if in_component_list => serde_json::json!({ "text": string }),
+        NbtTag::String(string) => Value::String(string.clone()),
+        NbtTag::List(list) => Value::Array(
+            list.iter()
+                .map(|tag| nbt_to_json(tag, in_component_list))
+                .collect(),
+        ),
+        NbtTag::Compound(compound) => {
+            if let [(key, inner)] = compound.child_tags.as_slice() {
+                if key.is_empty() {
+                    return nbt_to_json(inner, in_component_list);
+                }
+            }
+            Value::Object(
+                compound
+                    .child_tags
+                    .iter()
+                    .map(|(key, tag)| {
+                        let is_list = key == "extra" || key == "with";
+                        (key.clone(), nbt_to_json(tag, is_list))
+                    })
+                    .collect(),
+            )
+        }
+        NbtTag::IntArray(ints) => Value::from(ints.clone()),
+        NbtTag::LongArray(longs) => Value::from(longs.clone()),
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum TextContent {
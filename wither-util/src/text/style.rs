@@ -5,6 +5,7 @@ use super::{
 };
 use crate::text::color::ARGBColor;
 use serde::{Deserialize, Serialize};
+use wither_nbt::tag::NbtTag;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
@@ -47,6 +48,17 @@ pub struct Style {
 }
 
 impl Style {
+    /// Decodes a Style sent as network NBT, e.g. in a styled score number format.
+    /// Unknown or malformed fields fall back to the default style.
+    pub fn from_nbt(tag: &NbtTag) -> Self {
+        serde_json::from_value(super::nbt_to_json(tag, false)).unwrap_or_default()
+    }
+
+    /// Encodes the Style as network NBT.
+    pub fn encode(&self) -> bytes::BytesMut {
+        wither_nbt::serializer::to_bytes_unnamed(self).unwrap()
+    }
+
     pub fn color(mut self, color: Color) -> Self {
         self.color = Some(color);
         self
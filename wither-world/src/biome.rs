@@ -1,6 +1,38 @@
+use std::{collections::HashMap, sync::LazyLock};
+
 use enum_dispatch::enum_dispatch;
 use serde::{Deserialize, Serialize};
 
+/// Biome names by registry id. Vanilla sends the biome registry sorted by name, so this
+/// matches the ids a vanilla server uses.
+pub static BIOMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let registries: HashMap<String, serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_str(include_str!("../../assets/synced_registries.json"))
+            .expect("Could not parse synced_registries.json registry.");
+    let mut biomes: Vec<String> = registries["worldgen/biome"].keys().cloned().collect();
+    biomes.sort();
+    biomes
+});
+
+pub static BIOME_ID_BY_NAME: LazyLock<HashMap<String, u16>> = LazyLock::new(|| {
+    let mut map = HashMap::new();
+    for (id, name) in BIOMES.iter().enumerate() {
+        map.insert(name.clone(), id as u16);
+    }
+    map
+});
+
+pub fn get_biome_id(registry_id: &str) -> Option<u16> {
+    let name = registry_id
+        .strip_prefix("minecraft:")
+        .unwrap_or(registry_id);
+    BIOME_ID_BY_NAME.get(name).copied()
+}
+
+pub fn get_biome_name<'a>(id: u16) -> Option<&'a str> {
+    BIOMES.get(id as usize).map(String::as_str)
+}
+
 // TODO make this work with the protocol
 // Send by the registry
 #[derive(Serialize, Deserialize, Clone, Copy)]
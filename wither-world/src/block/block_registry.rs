@@ -90,11 +90,49 @@ pub fn get_block_by_item<'a>(item_id: u16) -> Option<&'a Block> {
     let block_id = BLOCK_ID_BY_ITEM_ID.get(&item_id)?;
     BLOCKS_BY_ID.get(block_id)
 }
+/// Finds the state of a block with the given property values, properties that are
+/// not given keep the value of the default state.
+pub fn get_state_id<'a>(
+    registry_id: &str,
+    properties: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Option<u16> {
+    let block = get_block(registry_id)?;
+    let mut values = block.property_values(block.default_state_id)?;
+    for (name, value) in properties {
+        let index = block.properties.iter().position(|p| p.name == name)?;
+        values[index] = block.properties[index]
+            .values
+            .iter()
+            .position(|v| v == value)?;
+    }
+
+    // States are ordered like nested loops over the properties, the last one innermost.
+    let mut state_index = 0;
+    for (property, value) in block.properties.iter().zip(values) {
+        state_index = state_index * property.values.len() + value;
+    }
+    block.states.get(state_index).map(|state| state.id)
+}
+
+/// The block of a state and the values of its properties.
+pub fn get_state_properties<'a>(state_id: u16) -> Option<(&'a Block, Vec<(&'a str, &'a str)>)> {
+    let block = get_block_by_state_id(state_id)?;
+    let values = block.property_values(state_id)?;
+    let properties = block
+        .properties
+        .iter()
+        .zip(values)
+        .map(|(property, value)| (property.name.as_str(), property.values[value].as_str()))
+        .collect();
+    Some((block, properties))
+}
+
 #[expect(dead_code)]
 #[derive(Deserialize, Clone, Debug)]
 pub struct TopLevel {
     block_entity_types: Vec<String>,
-    shapes: Vec<Shape>,
+    /// Boxes referenced by `State::collision_shapes` and `State::outline_shapes`.
+    pub shapes: Vec<Shape>,
     pub blocks: Vec<Block>,
 }
 #[derive(Deserialize, Clone, Debug)]
@@ -102,6 +140,8 @@ pub struct Block {
     pub id: u16,
     pub item_id: u16,
     pub hardness: f32,
+    /// Whether the block drops nothing unless broken with a tool that is correct for it.
+    pub requires_correct_tool: bool,
     pub wall_variant_id: Option<u16>,
     pub translation_key: String,
     pub name: String,
@@ -109,12 +149,27 @@ pub struct Block {
     pub default_state_id: u16,
     pub states: Vec<State>,
 }
-#[expect(dead_code)]
 #[derive(Deserialize, Clone, Debug)]
 pub struct Property {
-    name: String,
-    values: Vec<String>,
+    pub name: String,
+    pub values: Vec<String>,
 }
+impl Block {
+    /// Index of the value of each property in a state of this block.
+    fn property_values(&self, state_id: u16) -> Option<Vec<usize>> {
+        let mut state_index = state_id.checked_sub(self.states.first()?.id)? as usize;
+        if state_index >= self.states.len() {
+            return None;
+        }
+        let mut values = vec![0; self.properties.len()];
+        for (value, property) in values.iter_mut().zip(&self.properties).rev() {
+            *value = state_index % property.values.len();
+            state_index /= property.values.len();
+        }
+        Some(values)
+    }
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct State {
     pub id: u16,
@@ -124,11 +179,13 @@ pub struct State {
     pub opacity: Option<u32>,
     pub replaceable: bool,
     pub collision_shapes: Vec<u16>,
+    /// Only present when the outline differs from the collision shape.
+    pub outline_shapes: Option<Vec<u16>>,
     pub block_entity_type: Option<u32>,
 }
-#[expect(dead_code)]
+/// A box inside a block, from 0 to 1 on each axis.
 #[derive(Deserialize, Clone, Debug)]
-struct Shape {
-    min: [f32; 3],
-    max: [f32; 3],
+pub struct Shape {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
 }
@@ -1,4 +1,12 @@
-use super::block_registry::{get_block, get_state_by_state_id};
+use wither_util::math::{boundingbox::BoundingBox, vector3::Vector3, voxel_shape::VoxelShape};
+
+use super::{
+    block_registry::{
+        get_block, get_block_by_id, get_block_by_state_id, get_state_by_state_id,
+        get_state_properties, Block, BLOCKS,
+    },
+    BlockFace,
+};
 
 #[derive(Clone, Copy, Debug, Eq)]
 pub struct BlockState {
@@ -27,6 +35,15 @@ impl BlockState {
         })
     }
 
+    /// Looks up the block of a state id, like the ones in chunk data.
+    pub fn from_state_id(state_id: u16) -> Option<Self> {
+        let block = get_block_by_state_id(state_id)?;
+        Some(Self {
+            state_id,
+            block_id: block.id,
+        })
+    }
+
     pub fn get_id(&self) -> u16 {
         self.state_id
     }
@@ -40,11 +57,84 @@ impl BlockState {
     pub fn of_block(&self, block_id: u16) -> bool {
         self.block_id == block_id
     }
+
+    /// Whether placing a block here replaces this one, like for grass or water.
+    pub fn is_replaceable(&self) -> bool {
+        get_state_by_state_id(self.state_id).is_some_and(|state| state.replaceable)
+    }
+
+    pub fn block(&self) -> &'static Block {
+        get_block_by_id(self.block_id).unwrap()
+    }
+
+    /// The registry name without the `minecraft:` namespace.
+    pub fn name(&self) -> &'static str {
+        &self.block().name
+    }
+
+    pub fn property(&self, name: &str) -> Option<&'static str> {
+        let (_, properties) = get_state_properties(self.state_id)?;
+        properties
+            .into_iter()
+            .find_map(|(property, value)| (property == name).then_some(value))
+    }
+
+    /// The collision shape of this state, relative to the corner of the block.
+    pub fn collision_shape(&self) -> VoxelShape {
+        get_state_by_state_id(self.state_id)
+            .map_or_else(VoxelShape::empty, |state| shape(&state.collision_shapes))
+    }
+
+    /// The shape the crosshair targets, which also covers blocks without collision like
+    /// flowers and torches.
+    pub fn outline_shape(&self) -> VoxelShape {
+        get_state_by_state_id(self.state_id).map_or_else(VoxelShape::empty, |state| {
+            shape(
+                state
+                    .outline_shapes
+                    .as_ref()
+                    .unwrap_or(&state.collision_shapes),
+            )
+        })
+    }
+
+    /// Whether the collision shape covers the whole face, like what torches need to be
+    /// placed on.
+    pub fn is_face_full(&self, face: BlockFace) -> bool {
+        self.collision_shape()
+            .is_face_full(face.axis(), face.is_positive())
+    }
+
+    /// Whether this block and the one next to it on `face` close the face between them.
+    pub fn face_occludes(&self, neighbour: BlockState, face: BlockFace) -> bool {
+        self.collision_shape().face_occludes(
+            &neighbour.collision_shape(),
+            face.axis(),
+            face.is_positive(),
+        )
+    }
+}
+
+/// Builds a shape out of boxes in the block registry.
+fn shape(indices: &[u16]) -> VoxelShape {
+    VoxelShape::new(indices.iter().map(|&index| {
+        let shape = &BLOCKS.shapes[index as usize];
+        let [min_x, min_y, min_z] = shape.min.map(f64::from);
+        let [max_x, max_y, max_z] = shape.max.map(f64::from);
+        BoundingBox::new(
+            Vector3::new(min_x, min_y, min_z),
+            Vector3::new(max_x, max_y, max_z),
+        )
+    }))
 }
 
 #[cfg(test)]
 mod tests {
     use super::BlockState;
+    use crate::block::{
+        block_registry::{get_state_id, get_state_properties},
+        BlockFace,
+    };
 
     #[test]
     fn not_existing() {
@@ -57,4 +147,47 @@ mod tests {
         let result = BlockState::new("dirt");
         assert!(result.is_some());
     }
+
+    #[test]
+    fn state_properties() {
+        let state_id = get_state_id(
+            "minecraft:oak_stairs",
+            [("facing", "east"), ("waterlogged", "true")],
+        )
+        .unwrap();
+        let (block, properties) = get_state_properties(state_id).unwrap();
+        assert_eq!(block.name, "oak_stairs");
+        assert_eq!(
+            properties,
+            [
+                ("facing", "east"),
+                ("half", "bottom"),
+                ("shape", "straight"),
+                ("waterlogged", "true")
+            ]
+        );
+    }
+
+    #[test]
+    fn collision_shapes() {
+        let slab = BlockState::new("stone_slab").unwrap();
+        assert_eq!(slab.collision_shape().bounds().unwrap().max_y, 0.5);
+        assert!(slab.is_face_full(BlockFace::Bottom));
+        assert!(!slab.is_face_full(BlockFace::Top));
+        assert!(BlockState::new("torch")
+            .unwrap()
+            .collision_shape()
+            .is_empty());
+        let torch = BlockState::new("torch").unwrap().outline_shape();
+        assert_eq!(torch.bounds().unwrap().max_y, 0.625);
+        let fence = BlockState::new("oak_fence").unwrap();
+        assert_eq!(fence.collision_shape().bounds().unwrap().max_y, 1.5);
+        assert_eq!(fence.outline_shape().bounds().unwrap().max_y, 1.0);
+        assert!(BlockState::new("water").unwrap().outline_shape().is_empty());
+
+        let top = BlockState::from_state_id(get_state_id("stone_slab", [("type", "top")]).unwrap())
+            .unwrap();
+        assert!(slab.face_occludes(top, BlockFace::East));
+        assert!(!slab.face_occludes(slab, BlockFace::East));
+    }
 }
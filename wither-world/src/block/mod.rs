@@ -1,10 +1,11 @@
 pub mod block_registry;
 pub mod block_state;
 
-use wither_util::math::vector3::Vector3;
+use wither_util::math::{boundingbox::Axis, vector3::Vector3};
 
 pub use block_state::BlockState;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BlockFace {
     Bottom = 0,
     Top,
@@ -14,6 +15,7 @@ pub enum BlockFace {
     East,
 }
 
+#[derive(Debug)]
 pub struct InvalidBlockFace;
 
 impl TryFrom<i32> for BlockFace {
@@ -33,6 +35,44 @@ impl TryFrom<i32> for BlockFace {
 }
 
 impl BlockFace {
+    pub const ALL: [BlockFace; 6] = [
+        BlockFace::Bottom,
+        BlockFace::Top,
+        BlockFace::North,
+        BlockFace::South,
+        BlockFace::West,
+        BlockFace::East,
+    ];
+
+    /// The face of a block on the given side along `axis`.
+    pub fn from_axis(axis: Axis, positive: bool) -> Self {
+        match (axis, positive) {
+            (Axis::X, false) => BlockFace::West,
+            (Axis::X, true) => BlockFace::East,
+            (Axis::Y, false) => BlockFace::Bottom,
+            (Axis::Y, true) => BlockFace::Top,
+            (Axis::Z, false) => BlockFace::North,
+            (Axis::Z, true) => BlockFace::South,
+        }
+    }
+
+    pub fn axis(&self) -> Axis {
+        match self {
+            BlockFace::West | BlockFace::East => Axis::X,
+            BlockFace::Bottom | BlockFace::Top => Axis::Y,
+            BlockFace::North | BlockFace::South => Axis::Z,
+        }
+    }
+
+    /// Whether the face points towards increasing coordinates.
+    pub fn is_positive(&self) -> bool {
+        matches!(self, BlockFace::Top | BlockFace::South | BlockFace::East)
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::from_axis(self.axis(), !self.is_positive())
+    }
+
     pub fn to_offset(&self) -> Vector3<i32> {
         match self {
             BlockFace::Bottom => (0, -1, 0),
@@ -0,0 +1,49 @@
+use super::block_index;
+
+/// Size of a light array, one nibble for each block of a section.
+pub const LIGHT_ARRAY_SIZE: usize = 2048;
+
+/// Light levels of a 16x16x16 section, packed two per byte with the lower nibble first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LightArray(Box<[u8]>);
+
+impl LightArray {
+    /// A section without any light.
+    pub fn new() -> Self {
+        Self(vec![0; LIGHT_ARRAY_SIZE].into_boxed_slice())
+    }
+
+    /// A section where every block has `level`.
+    pub fn filled(level: u8) -> Self {
+        let level = level & 0xF;
+        Self(vec![level << 4 | level; LIGHT_ARRAY_SIZE].into_boxed_slice())
+    }
+
+    /// Wraps an array as sent by the server, `None` if it has the wrong size.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        (bytes.len() == LIGHT_ARRAY_SIZE).then(|| Self(bytes.into()))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Light level at section relative coordinates.
+    pub fn get(&self, x: usize, y: usize, z: usize) -> u8 {
+        let index = block_index(x, y, z);
+        (self.0[index >> 1] >> ((index & 1) * 4)) & 0xF
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, z: usize, level: u8) {
+        let index = block_index(x, y, z);
+        let shift = (index & 1) * 4;
+        let byte = &mut self.0[index >> 1];
+        *byte = (*byte & !(0xF << shift)) | ((level & 0xF) << shift);
+    }
+}
+
+impl Default for LightArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,302 @@
+use bytes::{Buf, BufMut};
+use thiserror::Error;
+use wither_nbt::compound::NbtCompound;
+use wither_util::math::vector2::Vector2;
+
+use crate::{
+    block::block_registry::get_state_by_state_id, coordinates::ChunkRelativeBlockCoordinates,
+};
+
+mod light;
+mod nbt;
+mod palette;
+
+pub use light::*;
+pub use nbt::*;
+pub use palette::*;
+
+pub const SECTION_SIZE: usize = 16;
+
+#[derive(Error, Debug)]
+pub enum ChunkParsingError {
+    #[error("Chunk data ended early")]
+    Incomplete,
+    #[error("VarInt is too large")]
+    VarIntTooLarge,
+    #[error("Invalid bits per entry {0}")]
+    InvalidBitsPerEntry(u8),
+    #[error("Invalid palette length {0}")]
+    InvalidPaletteLength(i32),
+    #[error("Palette entry {0} is out of bounds")]
+    InvalidPaletteEntry(u16),
+    #[error("Invalid registry id {0}")]
+    InvalidRegistryId(i32),
+    #[error("Expected {expected} longs of data, got {actual}")]
+    InvalidDataLength { expected: usize, actual: i32 },
+}
+
+/// 16 blocks high slice of a chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkSection {
+    /// Number of blocks that are not air, the client uses it to skip empty sections.
+    pub block_count: i16,
+    pub block_states: PalettedContainer,
+    pub biomes: PalettedContainer,
+    /// `None` until the server sent the light of this section.
+    pub sky_light: Option<LightArray>,
+    pub block_light: Option<LightArray>,
+}
+
+impl ChunkSection {
+    /// A section of only air, with biome id 0.
+    pub fn empty() -> Self {
+        Self {
+            block_count: 0,
+            block_states: PalettedContainer::single(ContainerKind::BlockStates, 0),
+            biomes: PalettedContainer::single(ContainerKind::Biomes, 0),
+            sky_light: None,
+            block_light: None,
+        }
+    }
+
+    /// Block state id at section relative coordinates.
+    pub fn get_block_state(&self, x: usize, y: usize, z: usize) -> u16 {
+        self.block_states.get(block_index(x, y, z))
+    }
+
+    /// Sets the block state id at section relative coordinates, returns the previous one.
+    pub fn set_block_state(&mut self, x: usize, y: usize, z: usize, state_id: u16) -> u16 {
+        let previous = self.block_states.set(block_index(x, y, z), state_id);
+        match (is_air(previous), is_air(state_id)) {
+            (true, false) => self.block_count += 1,
+            (false, true) => self.block_count -= 1,
+            _ => {}
+        }
+        previous
+    }
+
+    /// Biome id of the 4x4x4 cell containing the section relative coordinates.
+    pub fn get_biome(&self, x: usize, y: usize, z: usize) -> u16 {
+        self.biomes.get(biome_index(x, y, z))
+    }
+
+    pub fn set_biome(&mut self, x: usize, y: usize, z: usize, biome_id: u16) -> u16 {
+        self.biomes.set(biome_index(x, y, z), biome_id)
+    }
+
+    /// Sky and block light at section relative coordinates, unknown light counts as 0.
+    pub fn get_light(&self, x: usize, y: usize, z: usize) -> (u8, u8) {
+        let level =
+            |light: &Option<LightArray>| light.as_ref().map_or(0, |light| light.get(x, y, z));
+        (level(&self.sky_light), level(&self.block_light))
+    }
+
+    pub fn decode(bytebuf: &mut impl Buf) -> Result<Self, ChunkParsingError> {
+        if bytebuf.remaining() < 2 {
+            return Err(ChunkParsingError::Incomplete);
+        }
+        Ok(Self {
+            block_count: bytebuf.get_i16(),
+            block_states: PalettedContainer::decode(ContainerKind::BlockStates, bytebuf)?,
+            biomes: PalettedContainer::decode(ContainerKind::Biomes, bytebuf)?,
+            sky_light: None,
+            block_light: None,
+        })
+    }
+
+    pub fn encode(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_i16(self.block_count);
+        self.block_states.encode(bytebuf);
+        self.biomes.encode(bytebuf);
+    }
+}
+
+/// The blocks and biomes of a chunk column, as sent in `level_chunk_with_light`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkData {
+    pub position: Vector2<i32>,
+    /// Y of the bottom of the lowest section, the `min_y` of the dimension.
+    pub min_y: i32,
+    /// From the bottom of the world up.
+    pub sections: Vec<ChunkSection>,
+    /// Region file tags other than the sections, like heightmaps, entities and block
+    /// entities, written back as they were read.
+    pub other_nbt: NbtCompound,
+}
+
+impl ChunkData {
+    /// A chunk of only air.
+    pub fn empty(position: Vector2<i32>, min_y: i32, section_count: usize) -> Self {
+        Self {
+            position,
+            min_y,
+            sections: vec![ChunkSection::empty(); section_count],
+            other_nbt: NbtCompound::new(),
+        }
+    }
+
+    /// Reads sections until `bytebuf` is empty, the section count depends on the
+    /// height of the dimension.
+    pub fn decode(
+        position: Vector2<i32>,
+        min_y: i32,
+        bytebuf: &mut impl Buf,
+    ) -> Result<Self, ChunkParsingError> {
+        let mut sections = Vec::new();
+        while bytebuf.has_remaining() {
+            sections.push(ChunkSection::decode(bytebuf)?);
+        }
+        Ok(Self {
+            position,
+            min_y,
+            sections,
+            other_nbt: NbtCompound::new(),
+        })
+    }
+
+    pub fn encode(&self, bytebuf: &mut impl BufMut) {
+        for section in &self.sections {
+            section.encode(bytebuf);
+        }
+    }
+
+    /// Block state id at `position`, air if it is outside of the chunk's sections.
+    pub fn get_block_state(&self, position: ChunkRelativeBlockCoordinates) -> u16 {
+        self.section(position).map_or(0, |(section, y)| {
+            section.get_block_state(*position.x as usize, y, *position.z as usize)
+        })
+    }
+
+    /// Sets the block state id at `position`, returns the previous one.
+    pub fn set_block_state(
+        &mut self,
+        position: ChunkRelativeBlockCoordinates,
+        state_id: u16,
+    ) -> u16 {
+        let Some((section, y)) = self.section_mut(position) else {
+            return 0;
+        };
+        section.set_block_state(*position.x as usize, y, *position.z as usize, state_id)
+    }
+
+    /// Sky and block light at `position`.
+    pub fn get_light(&self, position: ChunkRelativeBlockCoordinates) -> (u8, u8) {
+        self.section(position).map_or((0, 0), |(section, y)| {
+            section.get_light(*position.x as usize, y, *position.z as usize)
+        })
+    }
+
+    pub fn get_biome(&self, position: ChunkRelativeBlockCoordinates) -> u16 {
+        self.section(position).map_or(0, |(section, y)| {
+            section.get_biome(*position.x as usize, y, *position.z as usize)
+        })
+    }
+
+    pub fn set_biome(&mut self, position: ChunkRelativeBlockCoordinates, biome_id: u16) -> u16 {
+        let Some((section, y)) = self.section_mut(position) else {
+            return 0;
+        };
+        section.set_biome(*position.x as usize, y, *position.z as usize, biome_id)
+    }
+
+    /// The section `position` is in and the y inside of it.
+    fn section(&self, position: ChunkRelativeBlockCoordinates) -> Option<(&ChunkSection, usize)> {
+        let (index, y) = self.section_position(position)?;
+        Some((self.sections.get(index)?, y))
+    }
+
+    fn section_mut(
+        &mut self,
+        position: ChunkRelativeBlockCoordinates,
+    ) -> Option<(&mut ChunkSection, usize)> {
+        let (index, y) = self.section_position(position)?;
+        Some((self.sections.get_mut(index)?, y))
+    }
+
+    /// Index of the section and the y inside of it, `None` below the chunk.
+    fn section_position(&self, position: ChunkRelativeBlockCoordinates) -> Option<(usize, usize)> {
+        let y = usize::try_from(*position.y as i32 - self.min_y).ok()?;
+        Some((y / SECTION_SIZE, y % SECTION_SIZE))
+    }
+}
+
+fn block_index(x: usize, y: usize, z: usize) -> usize {
+    (y * SECTION_SIZE + z) * SECTION_SIZE + x
+}
+
+fn biome_index(x: usize, y: usize, z: usize) -> usize {
+    ((y / 4) * 4 + z / 4) * 4 + x / 4
+}
+
+fn is_air(state_id: u16) -> bool {
+    get_state_by_state_id(state_id).is_some_and(|state| state.air)
+}
+
+fn get_var_int(bytebuf: &mut impl Buf) -> Result<i32, ChunkParsingError> {
+    let mut value = 0;
+    for i in 0..5 {
+        if !bytebuf.has_remaining() {
+            return Err(ChunkParsingError::Incomplete);
+        }
+        let byte = bytebuf.get_u8();
+        value |= (i32::from(byte) & 0x7F) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(ChunkParsingError::VarIntTooLarge)
+}
+
+fn put_var_int(bytebuf: &mut impl BufMut, value: i32) {
+    let mut value = value as u32;
+    loop {
+        let byte = value as u8 & 0x7F;
+        value >>= 7;
+        if value == 0 {
+            bytebuf.put_u8(byte);
+            break;
+        }
+        bytebuf.put_u8(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wither_util::math::vector2::Vector2;
+
+    use super::{ChunkData, LightArray};
+    use crate::{block::BlockState, coordinates::ChunkRelativeBlockCoordinates};
+
+    #[test]
+    fn set_block_round_trip() {
+        let mut chunk = ChunkData::empty(Vector2::new(3, -2), -64, 24);
+        let stone = BlockState::new("stone").unwrap().state_id;
+        let position = ChunkRelativeBlockCoordinates {
+            x: 4u8.into(),
+            y: (-60i16).into(),
+            z: 15u8.into(),
+        };
+        assert_eq!(chunk.set_block_state(position, stone), 0);
+        assert_eq!(chunk.get_block_state(position), stone);
+        assert_eq!(chunk.sections[0].block_count, 1);
+
+        let mut bytes = Vec::new();
+        chunk.encode(&mut bytes);
+        let decoded = ChunkData::decode(chunk.position, chunk.min_y, &mut &bytes[..]).unwrap();
+        assert_eq!(decoded, chunk);
+
+        chunk.set_block_state(position, 0);
+        assert_eq!(chunk.sections[0].block_count, 0);
+    }
+
+    #[test]
+    fn light_nibbles() {
+        let mut light = LightArray::filled(15);
+        light.set(1, 2, 3, 7);
+        light.set(0, 2, 3, 4);
+        assert_eq!(light.get(1, 2, 3), 7);
+        assert_eq!(light.get(0, 2, 3), 4);
+        assert_eq!(light.get(2, 2, 3), 15);
+        assert_eq!(light.as_bytes()[(2 * 256 + 3 * 16) / 2], 0x74);
+    }
+}
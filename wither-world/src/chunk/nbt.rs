@@ -0,0 +1,352 @@
+use thiserror::Error;
+use wither_nbt::{compound::NbtCompound, tag::NbtTag};
+use wither_util::math::vector2::Vector2;
+
+use crate::{
+    biome::{get_biome_id, get_biome_name},
+    block::block_registry::{get_state_id, get_state_properties},
+    WORLD_LOWEST_Y,
+};
+
+use super::{
+    is_air, BitStorage, ChunkData, ChunkSection, ContainerKind, LightArray, PalettedContainer,
+    SECTION_SIZE,
+};
+
+/// Data version of chunks written by 1.21.4.
+pub const DATA_VERSION: i32 = 4189;
+
+#[derive(Error, Debug)]
+pub enum ChunkNbtError {
+    #[error("Missing or invalid tag {0}")]
+    MissingTag(&'static str),
+    #[error("Unknown block state {0}")]
+    UnknownBlockState(String),
+    #[error("Unknown biome {0}")]
+    UnknownBiome(String),
+    #[error("Palette data does not match the palette of {0} entries")]
+    InvalidPaletteData(usize),
+}
+
+/// Tags `to_nbt` writes itself, the others are kept in `ChunkData::other_nbt`.
+const CHUNK_TAGS: [&str; 4] = ["xPos", "yPos", "zPos", "sections"];
+
+impl ChunkData {
+    /// Reads the blocks, biomes and light of a chunk as stored in region files. Other
+    /// tags, like heightmaps and entities, are kept as they are.
+    pub fn from_nbt(nbt: &NbtCompound) -> Result<Self, ChunkNbtError> {
+        let position = Vector2::new(
+            nbt.get_int("xPos")
+                .ok_or(ChunkNbtError::MissingTag("xPos"))?,
+            nbt.get_int("zPos")
+                .ok_or(ChunkNbtError::MissingTag("zPos"))?,
+        );
+
+        let sections = nbt
+            .get_list("sections")
+            .ok_or(ChunkNbtError::MissingTag("sections"))?
+            .iter()
+            .map(|section| {
+                let section = section
+                    .extract_compound()
+                    .ok_or(ChunkNbtError::MissingTag("sections"))?;
+                let y = section
+                    .get_byte("Y")
+                    .ok_or(ChunkNbtError::MissingTag("Y"))? as i32;
+                Ok((y, section))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // The sections above and below the world only hold light, the ones in it always
+        // have block states.
+        let in_world = || {
+            sections
+                .iter()
+                .filter(|(_, section)| section.get_compound("block_states").is_some())
+                .map(|(y, _)| *y)
+        };
+        let bottom = nbt
+            .get_int("yPos")
+            .or_else(|| in_world().min())
+            .unwrap_or(WORLD_LOWEST_Y as i32 / SECTION_SIZE as i32);
+        let top = in_world().filter(|y| *y >= bottom).max();
+        let section_count = top.map_or(0, |top| (top - bottom + 1) as usize);
+
+        let mut chunk = Self::empty(position, bottom * SECTION_SIZE as i32, section_count);
+        for (y, section) in sections {
+            let Some(target) = usize::try_from(y - bottom)
+                .ok()
+                .and_then(|index| chunk.sections.get_mut(index))
+            else {
+                continue;
+            };
+            *target = section_from_nbt(section)?;
+        }
+        chunk.other_nbt.child_tags = nbt
+            .child_tags
+            .iter()
+            .filter(|(name, _)| !CHUNK_TAGS.contains(&name.as_str()))
+            .cloned()
+            .collect();
+        Ok(chunk)
+    }
+
+    /// Writes the chunk the way region files store it, with the tags it was read with.
+    /// Chunks that were not read from a region file have no heightmaps, entities or
+    /// block entities.
+    pub fn to_nbt(&self) -> NbtCompound {
+        let bottom = self.min_y.div_euclid(SECTION_SIZE as i32);
+        let sections = self
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(index, section)| {
+                let y = index as i32 + bottom;
+                NbtTag::Compound(section_to_nbt(section, y as i8))
+            })
+            .collect();
+
+        let mut nbt = NbtCompound::new();
+        // Chunks keep the version they were saved with, the game upgrades older ones.
+        let data_version = self.other_nbt.get_int("DataVersion");
+        nbt.put(
+            "DataVersion".to_string(),
+            NbtTag::Int(data_version.unwrap_or(DATA_VERSION)),
+        );
+        nbt.put("xPos".to_string(), NbtTag::Int(self.position.x));
+        nbt.put("yPos".to_string(), NbtTag::Int(bottom));
+        nbt.put("zPos".to_string(), NbtTag::Int(self.position.z));
+        nbt.put("sections".to_string(), NbtTag::List(sections));
+        for (name, tag) in &self.other_nbt.child_tags {
+            nbt.put(name.clone(), tag.clone());
+        }
+        // `put` keeps the tags the chunk was read with.
+        nbt.put("Status".to_string(), "minecraft:full");
+        nbt.put("LastUpdate".to_string(), NbtTag::Long(0));
+        let has_light = self
+            .sections
+            .iter()
+            .any(|section| section.block_light.is_some() || section.sky_light.is_some());
+        if has_light {
+            nbt.put("isLightOn".to_string(), true);
+        }
+        nbt
+    }
+}
+
+fn section_from_nbt(nbt: &NbtCompound) -> Result<ChunkSection, ChunkNbtError> {
+    let mut section = ChunkSection::empty();
+
+    if let Some(block_states) = nbt.get_compound("block_states") {
+        let palette = block_states
+            .get_list("palette")
+            .ok_or(ChunkNbtError::MissingTag("palette"))?
+            .iter()
+            .map(block_state_from_nbt)
+            .collect::<Result<Vec<_>, _>>()?;
+        let bits = disk_bits(palette.len()).max(4);
+        section.block_states =
+            container_from_nbt(ContainerKind::BlockStates, &palette, bits, block_states)?;
+        section.block_count = section
+            .block_states
+            .iter()
+            .filter(|state| !is_air(*state))
+            .count() as i16;
+    }
+
+    if let Some(biomes) = nbt.get_compound("biomes") {
+        let palette = biomes
+            .get_list("palette")
+            .ok_or(ChunkNbtError::MissingTag("palette"))?
+            .iter()
+            .map(|biome| {
+                let name = biome
+                    .extract_string()
+                    .ok_or(ChunkNbtError::MissingTag("palette"))?;
+                get_biome_id(name).ok_or_else(|| ChunkNbtError::UnknownBiome(name.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let bits = disk_bits(palette.len());
+        section.biomes = container_from_nbt(ContainerKind::Biomes, &palette, bits, biomes)?;
+    }
+
+    section.block_light = nbt
+        .get("BlockLight")
+        .and_then(NbtTag::extract_byte_array)
+        .and_then(|light| LightArray::from_bytes(&light));
+    section.sky_light = nbt
+        .get("SkyLight")
+        .and_then(NbtTag::extract_byte_array)
+        .and_then(|light| LightArray::from_bytes(&light));
+
+    Ok(section)
+}
+
+fn section_to_nbt(section: &ChunkSection, y: i8) -> NbtCompound {
+    let block_states = container_to_nbt(&section.block_states, 4, |state| {
+        let mut nbt = NbtCompound::new();
+        let Some((block, properties)) = get_state_properties(state) else {
+            nbt.put("Name".to_string(), "minecraft:air");
+            return NbtTag::Compound(nbt);
+        };
+        nbt.put(
+            "Name".to_string(),
+            NbtTag::String(format!("minecraft:{}", block.name)),
+        );
+        if !properties.is_empty() {
+            let mut values = NbtCompound::new();
+            for (name, value) in properties {
+                values.put(name.to_string(), value);
+            }
+            nbt.put("Properties".to_string(), NbtTag::Compound(values));
+        }
+        NbtTag::Compound(nbt)
+    });
+    let biomes = container_to_nbt(&section.biomes, 0, |biome| {
+        NbtTag::String(format!(
+            "minecraft:{}",
+            get_biome_name(biome).unwrap_or("plains")
+        ))
+    });
+
+    let mut nbt = NbtCompound::new();
+    nbt.put("Y".to_string(), NbtTag::Byte(y));
+    nbt.put("block_states".to_string(), NbtTag::Compound(block_states));
+    nbt.put("biomes".to_string(), NbtTag::Compound(biomes));
+    if let Some(light) = &section.block_light {
+        nbt.put("BlockLight".to_string(), light.as_bytes());
+    }
+    if let Some(light) = &section.sky_light {
+        nbt.put("SkyLight".to_string(), light.as_bytes());
+    }
+    nbt
+}
+
+fn block_state_from_nbt(tag: &NbtTag) -> Result<u16, ChunkNbtError> {
+    let nbt = tag
+        .extract_compound()
+        .ok_or(ChunkNbtError::MissingTag("palette"))?;
+    let name = nbt
+        .get_string("Name")
+        .ok_or(ChunkNbtError::MissingTag("Name"))?;
+    let mut properties = Vec::new();
+    if let Some(values) = nbt.get_compound("Properties") {
+        for (name, value) in &values.child_tags {
+            let value = value
+                .extract_string()
+                .ok_or(ChunkNbtError::MissingTag("Properties"))?;
+            properties.push((name.as_str(), value.as_str()));
+        }
+    }
+    get_state_id(name, properties).ok_or_else(|| ChunkNbtError::UnknownBlockState(name.clone()))
+}
+
+/// Region files always use a palette local to the section, with the fewest bits that
+/// fit it.
+fn disk_bits(palette_len: usize) -> u8 {
+    (usize::BITS - palette_len.saturating_sub(1).leading_zeros()) as u8
+}
+
+fn container_from_nbt(
+    kind: ContainerKind,
+    palette: &[u16],
+    bits: u8,
+    nbt: &NbtCompound,
+) -> Result<PalettedContainer, ChunkNbtError> {
+    match palette {
+        [] => Err(ChunkNbtError::MissingTag("palette")),
+        [single] => Ok(PalettedContainer::single(kind, *single)),
+        _ => {
+            let data = nbt
+                .get_long_array("data")
+                .ok_or(ChunkNbtError::MissingTag("data"))?;
+            let storage = BitStorage::from_data(
+                bits,
+                kind.size(),
+                data.iter().map(|long| *long as u64).collect(),
+            )
+            .ok_or(ChunkNbtError::InvalidPaletteData(palette.len()))?;
+            let values = (0..kind.size())
+                .map(|index| palette.get(storage.get(index) as usize).copied())
+                .collect::<Option<Vec<_>>>()
+                .ok_or(ChunkNbtError::InvalidPaletteData(palette.len()))?;
+            Ok(PalettedContainer::from_values(kind, &values))
+        }
+    }
+}
+
+fn container_to_nbt(
+    container: &PalettedContainer,
+    min_bits: u8,
+    mut entry: impl FnMut(u16) -> NbtTag,
+) -> NbtCompound {
+    let values: Vec<u16> = container.iter().collect();
+    let mut palette = Vec::new();
+    for value in &values {
+        if !palette.contains(value) {
+            palette.push(*value);
+        }
+    }
+
+    let mut nbt = NbtCompound::new();
+    if palette.len() > 1 {
+        let bits = disk_bits(palette.len()).max(min_bits);
+        let mut storage = BitStorage::new(bits, values.len());
+        for (index, value) in values.iter().enumerate() {
+            let entry = palette.iter().position(|v| v == value).unwrap();
+            storage.set(index, entry as u16);
+        }
+        let data = storage.data().iter().map(|long| *long as i64).collect();
+        nbt.put("data".to_string(), NbtTag::LongArray(data));
+    }
+    let palette = palette.into_iter().map(&mut entry).collect();
+    nbt.put("palette".to_string(), NbtTag::List(palette));
+    nbt
+}
+
+#[cfg(test)]
+mod tests {
+    use wither_nbt::{compound::NbtCompound, tag::NbtTag};
+    use wither_util::math::vector2::Vector2;
+
+    use crate::{block::BlockState, chunk::ChunkData, coordinates::ChunkRelativeBlockCoordinates};
+
+    #[test]
+    fn round_trips_other_tags() {
+        // A chunk of the nether, from y 0 to 256.
+        let mut chunk = ChunkData::empty(Vector2::new(7, -2), 0, 16);
+        let position = ChunkRelativeBlockCoordinates {
+            x: 3u8.into(),
+            y: 5i16.into(),
+            z: 4u8.into(),
+        };
+        let netherrack = BlockState::new("netherrack").unwrap().state_id;
+        chunk.set_block_state(position, netherrack);
+
+        let mut nbt = chunk.to_nbt();
+        assert_eq!(nbt.get_int("yPos"), Some(0));
+        assert!(nbt.get("isLightOn").is_none());
+        // Saved by an older version.
+        *nbt.get_mut("DataVersion").unwrap() = NbtTag::Int(3953);
+        let mut heightmaps = NbtCompound::new();
+        heightmaps.put("WORLD_SURFACE".to_string(), NbtTag::LongArray(vec![1; 37]));
+        nbt.child_tags.retain(|(name, _)| name != "Status");
+        nbt.put("Status".to_string(), "minecraft:spawn");
+        nbt.put("Heightmaps".to_string(), NbtTag::Compound(heightmaps));
+        nbt.put("block_entities".to_string(), NbtTag::List(vec![]));
+        nbt.put("entities".to_string(), NbtTag::List(vec![]));
+
+        let read = ChunkData::from_nbt(&nbt).unwrap();
+        assert_eq!(read.min_y, 0);
+        assert_eq!(read.sections.len(), 16);
+        assert_eq!(read.get_block_state(position), netherrack);
+        assert_eq!(read.to_nbt(), nbt);
+
+        // Sections that only hold light don't change the height of the chunk.
+        let mut light = NbtCompound::new();
+        light.put("Y".to_string(), NbtTag::Byte(-1));
+        if let Some(NbtTag::List(sections)) = nbt.get_mut("sections") {
+            sections.insert(0, NbtTag::Compound(light));
+        }
+        assert_eq!(ChunkData::from_nbt(&nbt).unwrap(), read);
+    }
+}
@@ -0,0 +1,381 @@
+use bytes::{Buf, BufMut};
+
+use crate::DIRECT_PALETTE_BITS;
+
+use super::{get_var_int, put_var_int, ChunkParsingError};
+
+/// Bits of a direct biome palette, enough for every biome of the vanilla registry.
+pub const BIOME_DIRECT_PALETTE_BITS: u8 = 7;
+
+/// What a container holds, this decides its size and which palettes it uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerKind {
+    /// 16x16x16 block states.
+    BlockStates,
+    /// 4x4x4 biomes, one for every 4x4x4 blocks.
+    Biomes,
+}
+
+impl ContainerKind {
+    pub fn size(self) -> usize {
+        match self {
+            Self::BlockStates => 4096,
+            Self::Biomes => 64,
+        }
+    }
+
+    fn min_indirect_bits(self) -> u8 {
+        match self {
+            Self::BlockStates => 4,
+            Self::Biomes => 1,
+        }
+    }
+
+    fn max_indirect_bits(self) -> u8 {
+        match self {
+            Self::BlockStates => 8,
+            Self::Biomes => 3,
+        }
+    }
+
+    fn direct_bits(self) -> u8 {
+        match self {
+            Self::BlockStates => DIRECT_PALETTE_BITS as u8,
+            Self::Biomes => BIOME_DIRECT_PALETTE_BITS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Palette {
+    /// Every entry has the same value, no data is stored.
+    SingleValue(u16),
+    /// Entries are indices into the palette.
+    Indirect(Vec<u16>),
+    /// Entries are the registry ids themselves.
+    Direct,
+}
+
+/// Fixed size array of entries packed into longs, entries never span two longs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitStorage {
+    bits: u8,
+    data: Box<[u64]>,
+}
+
+impl BitStorage {
+    pub fn new(bits: u8, size: usize) -> Self {
+        let data = if bits == 0 {
+            Box::default()
+        } else {
+            vec![0; Self::long_count(bits, size)].into_boxed_slice()
+        };
+        Self { bits, data }
+    }
+
+    /// Wraps packed longs, `None` if there are not as many as `size` entries need.
+    pub fn from_data(bits: u8, size: usize, data: Vec<u64>) -> Option<Self> {
+        let expected = if bits == 0 {
+            0
+        } else {
+            Self::long_count(bits, size)
+        };
+        (bits <= 16 && data.len() == expected).then(|| Self {
+            bits,
+            data: data.into_boxed_slice(),
+        })
+    }
+
+    fn long_count(bits: u8, size: usize) -> usize {
+        let values_per_long = 64 / bits as usize;
+        size.div_ceil(values_per_long)
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    pub fn data(&self) -> &[u64] {
+        &self.data
+    }
+
+    pub fn get(&self, index: usize) -> u16 {
+        if self.bits == 0 {
+            return 0;
+        }
+        let (long, shift) = self.position(index);
+        ((self.data[long] >> shift) & self.mask()) as u16
+    }
+
+    pub fn set(&mut self, index: usize, value: u16) {
+        let (long, shift) = self.position(index);
+        let mask = self.mask();
+        self.data[long] = (self.data[long] & !(mask << shift)) | ((value as u64 & mask) << shift);
+    }
+
+    fn position(&self, index: usize) -> (usize, usize) {
+        let values_per_long = 64 / self.bits as usize;
+        let long = index / values_per_long;
+        (long, (index - long * values_per_long) * self.bits as usize)
+    }
+
+    fn mask(&self) -> u64 {
+        (1 << self.bits) - 1
+    }
+}
+
+/// A palette compressed array of block states or biomes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PalettedContainer {
+    kind: ContainerKind,
+    palette: Palette,
+    storage: BitStorage,
+}
+
+impl PalettedContainer {
+    /// A container where every entry is `value`.
+    pub fn single(kind: ContainerKind, value: u16) -> Self {
+        Self {
+            kind,
+            palette: Palette::SingleValue(value),
+            storage: BitStorage::new(0, kind.size()),
+        }
+    }
+
+    /// Packs `values` with the smallest palette they fit in.
+    pub fn from_values(kind: ContainerKind, values: &[u16]) -> Self {
+        assert_eq!(values.len(), kind.size());
+        let mut palette: Vec<u16> = Vec::new();
+        for value in values {
+            if !palette.contains(value) {
+                palette.push(*value);
+            }
+        }
+        if palette.len() == 1 {
+            return Self::single(kind, palette[0]);
+        }
+
+        let bits = (usize::BITS - (palette.len() - 1).leading_zeros()) as u8;
+        let (palette, bits) = if bits <= kind.max_indirect_bits() {
+            (
+                Palette::Indirect(palette),
+                bits.max(kind.min_indirect_bits()),
+            )
+        } else {
+            (Palette::Direct, kind.direct_bits())
+        };
+        Self {
+            kind,
+            storage: pack(&palette, bits, values),
+            palette,
+        }
+    }
+
+    pub fn kind(&self) -> ContainerKind {
+        self.kind
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    pub fn storage(&self) -> &BitStorage {
+        &self.storage
+    }
+
+    pub fn get(&self, index: usize) -> u16 {
+        let entry = self.storage.get(index);
+        match &self.palette {
+            Palette::SingleValue(value) => *value,
+            Palette::Indirect(palette) => palette[entry as usize],
+            Palette::Direct => entry,
+        }
+    }
+
+    /// All entries in index order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        (0..self.kind.size()).map(|index| self.get(index))
+    }
+
+    /// Sets the entry at `index`, growing the palette when `value` is not in it yet.
+    /// Returns the previous value.
+    pub fn set(&mut self, index: usize, value: u16) -> u16 {
+        let previous = self.get(index);
+        if previous == value {
+            return previous;
+        }
+
+        let entry = match &mut self.palette {
+            Palette::SingleValue(_) => None,
+            Palette::Indirect(palette) => match palette.iter().position(|v| *v == value) {
+                Some(entry) => Some(entry as u16),
+                None if palette.len() < 1 << self.storage.bits => {
+                    palette.push(value);
+                    Some(palette.len() as u16 - 1)
+                }
+                None => None,
+            },
+            Palette::Direct => Some(value),
+        };
+        match entry {
+            Some(entry) => self.storage.set(index, entry),
+            None => {
+                self.grow(value);
+                self.set(index, value);
+            }
+        }
+        previous
+    }
+
+    /// Moves to the next bigger palette so that `value` fits in it.
+    fn grow(&mut self, value: u16) {
+        let values: Vec<u16> = self.iter().collect();
+        let (palette, bits) = match &self.palette {
+            Palette::SingleValue(single) => (
+                Palette::Indirect(vec![*single, value]),
+                self.kind.min_indirect_bits(),
+            ),
+            Palette::Indirect(palette) if self.storage.bits < self.kind.max_indirect_bits() => {
+                let mut palette = palette.clone();
+                palette.push(value);
+                (Palette::Indirect(palette), self.storage.bits + 1)
+            }
+            _ => (Palette::Direct, self.kind.direct_bits()),
+        };
+
+        self.storage = pack(&palette, bits, &values);
+        self.palette = palette;
+    }
+
+    pub fn decode(kind: ContainerKind, bytebuf: &mut impl Buf) -> Result<Self, ChunkParsingError> {
+        if !bytebuf.has_remaining() {
+            return Err(ChunkParsingError::Incomplete);
+        }
+        let wire_bits = bytebuf.get_u8();
+
+        let (palette, bits) = match wire_bits {
+            0 => (Palette::SingleValue(get_registry_id(bytebuf)?), 0),
+            bits if bits <= kind.max_indirect_bits() => {
+                let len = get_var_int(bytebuf)?;
+                if len <= 0 || len > 1 << bits.max(kind.min_indirect_bits()) {
+                    return Err(ChunkParsingError::InvalidPaletteLength(len));
+                }
+                let palette = (0..len)
+                    .map(|_| get_registry_id(bytebuf))
+                    .collect::<Result<_, _>>()?;
+                (
+                    Palette::Indirect(palette),
+                    bits.max(kind.min_indirect_bits()),
+                )
+            }
+            bits if bits <= 16 => (Palette::Direct, bits),
+            bits => return Err(ChunkParsingError::InvalidBitsPerEntry(bits)),
+        };
+
+        let long_count = get_var_int(bytebuf)?;
+        let expected = if bits == 0 {
+            0
+        } else {
+            BitStorage::long_count(bits, kind.size())
+        };
+        if long_count as usize != expected {
+            return Err(ChunkParsingError::InvalidDataLength {
+                expected,
+                actual: long_count,
+            });
+        }
+        if bytebuf.remaining() < expected * 8 {
+            return Err(ChunkParsingError::Incomplete);
+        }
+        let data = (0..expected).map(|_| bytebuf.get_u64()).collect();
+
+        let container = Self {
+            kind,
+            palette,
+            storage: BitStorage { bits, data },
+        };
+        if let Palette::Indirect(palette) = &container.palette {
+            let len = palette.len();
+            if let Some(entry) = (0..kind.size())
+                .map(|i| container.storage.get(i))
+                .find(|entry| *entry as usize >= len)
+            {
+                return Err(ChunkParsingError::InvalidPaletteEntry(entry));
+            }
+        }
+        Ok(container)
+    }
+
+    pub fn encode(&self, bytebuf: &mut impl BufMut) {
+        bytebuf.put_u8(self.storage.bits);
+        match &self.palette {
+            Palette::SingleValue(value) => put_var_int(bytebuf, *value as i32),
+            Palette::Indirect(palette) => {
+                put_var_int(bytebuf, palette.len() as i32);
+                for value in palette {
+                    put_var_int(bytebuf, *value as i32);
+                }
+            }
+            Palette::Direct => {}
+        }
+        put_var_int(bytebuf, self.storage.data.len() as i32);
+        for long in self.storage.data.iter() {
+            bytebuf.put_u64(*long);
+        }
+    }
+}
+
+/// Stores `values` as entries of `palette`, every value has to be in the palette.
+fn pack(palette: &Palette, bits: u8, values: &[u16]) -> BitStorage {
+    let mut storage = BitStorage::new(bits, values.len());
+    for (index, value) in values.iter().enumerate() {
+        let entry = match palette {
+            Palette::Indirect(palette) => palette.iter().position(|v| v == value).unwrap() as u16,
+            _ => *value,
+        };
+        storage.set(index, entry);
+    }
+    storage
+}
+
+fn get_registry_id(bytebuf: &mut impl Buf) -> Result<u16, ChunkParsingError> {
+    let id = get_var_int(bytebuf)?;
+    u16::try_from(id).map_err(|_| ChunkParsingError::InvalidRegistryId(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContainerKind, Palette, PalettedContainer};
+
+    #[test]
+    fn grows_through_all_palettes() {
+        let mut container = PalettedContainer::single(ContainerKind::BlockStates, 0);
+        assert_eq!(container.set(17, 1), 0);
+        assert!(matches!(container.palette(), Palette::Indirect(_)));
+        assert_eq!(container.storage().bits(), 4);
+
+        for (index, value) in (2..300).enumerate() {
+            container.set(index * 13, value);
+        }
+        assert_eq!(container.palette(), &Palette::Direct);
+        assert_eq!(container.storage().bits(), 15);
+        for (index, value) in (2..300).enumerate() {
+            assert_eq!(container.get(index * 13), value);
+        }
+        assert_eq!(container.get(4095), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut container = PalettedContainer::single(ContainerKind::Biomes, 3);
+        container.set(5, 7);
+        container.set(63, 9);
+
+        let mut bytes = Vec::new();
+        container.encode(&mut bytes);
+        let decoded = PalettedContainer::decode(ContainerKind::Biomes, &mut &bytes[..]).unwrap();
+        assert_eq!(decoded, container);
+        assert_eq!(decoded.get(5), 7);
+        assert_eq!(decoded.get(6), 3);
+    }
+}
@@ -0,0 +1,73 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use serde::Deserialize;
+
+use crate::{WORLD_HEIGHT, WORLD_LOWEST_Y};
+
+/// Heights of the vanilla dimension types, for servers that leave out their data.
+static DIMENSION_HEIGHTS: LazyLock<HashMap<String, DimensionHeight>> = LazyLock::new(|| {
+    let mut registries: HashMap<String, serde_json::Value> =
+        serde_json::from_str(include_str!("../../assets/synced_registries.json"))
+            .expect("Could not parse synced_registries.json registry.");
+    serde_json::from_value(registries.remove("dimension_type").unwrap())
+        .expect("Could not parse the dimension types of synced_registries.json.")
+});
+
+/// The blocks of a dimension from bottom to top, from its dimension type.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionHeight {
+    /// Y of the lowest block, a multiple of 16.
+    pub min_y: i32,
+    pub height: i32,
+}
+
+impl Default for DimensionHeight {
+    fn default() -> Self {
+        Self::OVERWORLD
+    }
+}
+
+impl DimensionHeight {
+    pub const OVERWORLD: Self = Self {
+        min_y: WORLD_LOWEST_Y as i32,
+        height: WORLD_HEIGHT as i32,
+    };
+
+    /// Y above the highest block.
+    pub fn max_y(&self) -> i32 {
+        self.min_y + self.height
+    }
+
+    pub fn contains(&self, y: i32) -> bool {
+        (self.min_y..self.max_y()).contains(&y)
+    }
+
+    pub fn section_count(&self) -> usize {
+        self.height as usize / 16
+    }
+}
+
+pub fn get_dimension_height(registry_id: &str) -> Option<DimensionHeight> {
+    let name = registry_id
+        .strip_prefix("minecraft:")
+        .unwrap_or(registry_id);
+    DIMENSION_HEIGHTS.get(name).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vanilla_heights() {
+        assert_eq!(
+            get_dimension_height("minecraft:overworld"),
+            Some(DimensionHeight::OVERWORLD)
+        );
+        let nether = get_dimension_height("the_nether").unwrap();
+        assert_eq!((nether.min_y, nether.max_y()), (0, 256));
+        assert!(!nether.contains(-1));
+        assert_eq!(nether.section_count(), 16);
+        assert!(get_dimension_height("minecraft:moon").is_none());
+    }
+}
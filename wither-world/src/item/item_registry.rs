@@ -54,6 +54,35 @@ pub struct ItemComponents {
     pub max_damage: Option<u16>,
     #[serde(rename = "minecraft:attribute_modifiers")]
     pub attribute_modifiers: Option<AttributeModifiers>,
+    #[serde(rename = "minecraft:tool")]
+    pub tool: Option<Tool>,
+}
+
+/// How fast an item breaks blocks, and which blocks it drops.
+#[derive(Deserialize, Clone, Debug)]
+pub struct Tool {
+    pub rules: Vec<ToolRule>,
+    #[serde(default = "default_mining_speed")]
+    pub default_mining_speed: f32,
+}
+
+fn default_mining_speed() -> f32 {
+    1.0
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ToolRule {
+    pub blocks: ToolBlocks,
+    pub speed: Option<f32>,
+    pub correct_for_drops: Option<bool>,
+}
+
+/// A block tag starting with `#`, or block names.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ToolBlocks {
+    One(String),
+    Many(Vec<String>),
 }
 
 #[derive(Deserialize, Clone, Debug)]
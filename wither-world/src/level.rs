@@ -0,0 +1,404 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+use uuid::Uuid;
+use wither_nbt::{compound::NbtCompound, tag::NbtTag, Nbt};
+use wither_util::{
+    math::{vector2::Vector2, vector3::Vector3},
+    GameMode,
+};
+
+use crate::{
+    chunk::ChunkData,
+    region::{RegionError, RegionFile},
+};
+
+#[derive(Error, Debug)]
+pub enum WorldError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid NBT: {0}")]
+    Nbt(#[from] wither_nbt::Error),
+    #[error("Missing or invalid tag {0}")]
+    MissingTag(&'static str),
+    #[error(transparent)]
+    Region(#[from] RegionError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Overworld,
+    Nether,
+    End,
+}
+
+impl Dimension {
+    /// Folder of the dimension inside the world folder.
+    pub fn folder(self) -> &'static str {
+        match self {
+            Self::Overworld => "",
+            Self::Nether => "DIM-1",
+            Self::End => "DIM1",
+        }
+    }
+}
+
+/// The contents of `level.dat`.
+#[derive(Debug, Clone)]
+pub struct LevelData {
+    pub level_name: String,
+    pub data_version: i32,
+    /// The game version that last saved the world, like `1.21.4`.
+    pub version_name: Option<String>,
+    pub seed: i64,
+    pub spawn: Vector3<i32>,
+    pub spawn_angle: f32,
+    pub game_mode: GameMode,
+    pub hardcore: bool,
+    pub allow_commands: bool,
+    /// Ticks since the world was created.
+    pub time: i64,
+    pub day_time: i64,
+    /// Milliseconds since the Unix epoch.
+    pub last_played: i64,
+    /// Game rules are stored as strings, even the numeric and boolean ones.
+    pub game_rules: HashMap<String, String>,
+    /// The whole `Data` compound, for everything not listed above.
+    pub nbt: NbtCompound,
+}
+
+impl LevelData {
+    pub fn from_nbt(nbt: &NbtCompound) -> Result<Self, WorldError> {
+        let data = nbt
+            .get_compound("Data")
+            .ok_or(WorldError::MissingTag("Data"))?;
+
+        // Moved into the world generation settings in 1.16.
+        let seed = data
+            .get_compound("WorldGenSettings")
+            .and_then(|settings| settings.get_long("seed"))
+            .or_else(|| data.get_long("RandomSeed"))
+            .ok_or(WorldError::MissingTag("seed"))?;
+        let mut game_rules = HashMap::new();
+        if let Some(rules) = data.get_compound("GameRules") {
+            for (name, value) in &rules.child_tags {
+                if let Some(value) = value.extract_string() {
+                    game_rules.insert(name.clone(), value.clone());
+                }
+            }
+        }
+
+        Ok(Self {
+            level_name: data.get_string("LevelName").cloned().unwrap_or_default(),
+            data_version: data.get_int("DataVersion").unwrap_or(0),
+            version_name: data
+                .get_compound("Version")
+                .and_then(|version| version.get_string("Name"))
+                .cloned(),
+            seed,
+            spawn: Vector3::new(
+                data.get_int("SpawnX").unwrap_or(0),
+                data.get_int("SpawnY").unwrap_or(0),
+                data.get_int("SpawnZ").unwrap_or(0),
+            ),
+            spawn_angle: data.get_float("SpawnAngle").unwrap_or(0.0),
+            game_mode: game_mode(data, "GameType"),
+            hardcore: data.get_bool("hardcore").unwrap_or(false),
+            allow_commands: data.get_bool("allowCommands").unwrap_or(false),
+            time: data.get_long("Time").unwrap_or(0),
+            day_time: data.get_long("DayTime").unwrap_or(0),
+            last_played: data.get_long("LastPlayed").unwrap_or(0),
+            game_rules,
+            nbt: data.clone(),
+        })
+    }
+}
+
+/// An item stack in a saved inventory.
+#[derive(Debug, Clone)]
+pub struct SavedItem {
+    pub slot: i8,
+    pub id: String,
+    pub count: i32,
+    /// Data components since 1.20.5, `tag` before.
+    pub components: Option<NbtCompound>,
+}
+
+impl SavedItem {
+    fn from_nbt(nbt: &NbtCompound) -> Option<Self> {
+        Some(Self {
+            slot: nbt.get_byte("Slot")?,
+            id: nbt.get_string("id")?.clone(),
+            // Stored as a byte called `Count` before 1.20.5.
+            count: nbt
+                .get_int("count")
+                .or_else(|| nbt.get_byte("Count").map(i32::from))
+                .unwrap_or(1),
+            components: nbt
+                .get_compound("components")
+                .or_else(|| nbt.get_compound("tag"))
+                .cloned(),
+        })
+    }
+}
+
+/// The contents of `playerdata/<uuid>.dat`.
+#[derive(Debug, Clone)]
+pub struct PlayerData {
+    pub uuid: Uuid,
+    pub position: Vector3<f64>,
+    /// Yaw and pitch.
+    pub rotation: Vector2<f32>,
+    pub dimension: String,
+    pub game_mode: GameMode,
+    pub health: f32,
+    pub food_level: i32,
+    pub xp_level: i32,
+    pub inventory: Vec<SavedItem>,
+    pub ender_items: Vec<SavedItem>,
+    pub nbt: NbtCompound,
+}
+
+impl PlayerData {
+    pub fn from_nbt(uuid: Uuid, nbt: &NbtCompound) -> Result<Self, WorldError> {
+        let doubles: Vec<f64> = nbt
+            .get_list("Pos")
+            .ok_or(WorldError::MissingTag("Pos"))?
+            .iter()
+            .filter_map(NbtTag::extract_double)
+            .collect();
+        let [x, y, z] = doubles[..] else {
+            return Err(WorldError::MissingTag("Pos"));
+        };
+        let floats: Vec<f32> = nbt
+            .get_list("Rotation")
+            .map(|rotation| rotation.iter().filter_map(NbtTag::extract_float).collect())
+            .unwrap_or_default();
+        let rotation = match floats[..] {
+            [yaw, pitch] => Vector2::new(yaw, pitch),
+            _ => Vector2::new(0.0, 0.0),
+        };
+
+        Ok(Self {
+            uuid,
+            position: Vector3::new(x, y, z),
+            rotation,
+            dimension: nbt
+                .get_string("Dimension")
+                .cloned()
+                .unwrap_or_else(|| "minecraft:overworld".to_string()),
+            game_mode: game_mode(nbt, "playerGameType"),
+            health: nbt.get_float("Health").unwrap_or(0.0),
+            food_level: nbt.get_int("foodLevel").unwrap_or(0),
+            xp_level: nbt.get_int("XpLevel").unwrap_or(0),
+            inventory: items(nbt, "Inventory"),
+            ender_items: items(nbt, "EnderItems"),
+            nbt: nbt.clone(),
+        })
+    }
+}
+
+/// Survival when missing or unknown, like vanilla.
+fn game_mode(nbt: &NbtCompound, name: &str) -> GameMode {
+    nbt.get_int(name)
+        .and_then(|id| i8::try_from(id).ok())
+        .map(GameMode::from)
+        .filter(|game_mode| *game_mode != GameMode::Undefined)
+        .unwrap_or(GameMode::Survival)
+}
+
+fn items(nbt: &NbtCompound, name: &str) -> Vec<SavedItem> {
+    nbt.get_list(name)
+        .into_iter()
+        .flatten()
+        .filter_map(NbtTag::extract_compound)
+        .filter_map(SavedItem::from_nbt)
+        .collect()
+}
+
+/// A vanilla world folder, the one holding `level.dat`.
+pub struct WorldFolder {
+    path: PathBuf,
+    pub level: LevelData,
+}
+
+impl WorldFolder {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, WorldError> {
+        let path = path.as_ref().to_path_buf();
+        let level = LevelData::from_nbt(&Nbt::read_file(path.join("level.dat"))?.root_tag)?;
+        Ok(Self { path, level })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn region_folder(&self, dimension: Dimension) -> PathBuf {
+        self.path.join(dimension.folder()).join("region")
+    }
+
+    /// Paths of all region files of a dimension.
+    pub fn regions(&self, dimension: Dimension) -> Result<Vec<PathBuf>, WorldError> {
+        let folder = self.region_folder(dimension);
+        if !folder.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut regions = Vec::new();
+        for entry in std::fs::read_dir(folder)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "mca") {
+                regions.push(path);
+            }
+        }
+        regions.sort();
+        Ok(regions)
+    }
+
+    /// Opens the region file holding `chunk`, `None` if nothing was saved there.
+    pub fn open_region(
+        &self,
+        dimension: Dimension,
+        chunk: Vector2<i32>,
+    ) -> Result<Option<RegionFile>, WorldError> {
+        let path = self
+            .region_folder(dimension)
+            .join(RegionFile::file_name(chunk));
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(RegionFile::open(path)?))
+    }
+
+    pub fn read_chunk(
+        &self,
+        dimension: Dimension,
+        chunk: Vector2<i32>,
+    ) -> Result<Option<ChunkData>, WorldError> {
+        match self.open_region(dimension, chunk)? {
+            Some(region) => Ok(region.read_chunk(chunk)?),
+            None => Ok(None),
+        }
+    }
+
+    /// UUIDs of all players that have joined the world.
+    pub fn players(&self) -> Result<Vec<Uuid>, WorldError> {
+        let folder = self.path.join("playerdata");
+        if !folder.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut players = Vec::new();
+        for entry in std::fs::read_dir(folder)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "dat") {
+                if let Some(uuid) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| Uuid::parse_str(stem).ok())
+                {
+                    players.push(uuid);
+                }
+            }
+        }
+        players.sort();
+        Ok(players)
+    }
+
+    /// Reads `playerdata/<uuid>.dat`, `None` if the player never joined.
+    pub fn read_player(&self, uuid: Uuid) -> Result<Option<PlayerData>, WorldError> {
+        let path = self
+            .path
+            .join("playerdata")
+            .join(format!("{}.dat", uuid.hyphenated()));
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(PlayerData::from_nbt(
+            uuid,
+            &Nbt::read_file(path)?.root_tag,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use wither_nbt::{compound::NbtCompound, tag::NbtTag, Compression, Nbt};
+    use wither_util::GameMode;
+
+    use super::{Dimension, WorldFolder};
+
+    #[test]
+    fn open_world_folder() {
+        let dir = std::env::temp_dir().join(format!("wither-level-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("playerdata")).unwrap();
+        std::fs::create_dir_all(dir.join("DIM-1/region")).unwrap();
+        std::fs::write(dir.join("DIM-1/region/r.0.-1.mca"), []).unwrap();
+
+        let mut settings = NbtCompound::new();
+        settings.put("seed".to_string(), NbtTag::Long(-42));
+        let mut rules = NbtCompound::new();
+        rules.put("keepInventory".to_string(), "true");
+        let mut data = NbtCompound::new();
+        data.put("LevelName".to_string(), "Test");
+        data.put("DataVersion".to_string(), NbtTag::Int(4189));
+        data.put("WorldGenSettings".to_string(), NbtTag::Compound(settings));
+        data.put("SpawnY".to_string(), NbtTag::Int(70));
+        data.put("GameType".to_string(), NbtTag::Int(1));
+        data.put("GameRules".to_string(), NbtTag::Compound(rules));
+        let mut root = NbtCompound::new();
+        root.put("Data".to_string(), NbtTag::Compound(data));
+        Nbt::from(root)
+            .write_file(dir.join("level.dat"), Compression::Gzip)
+            .unwrap();
+
+        let uuid = Uuid::from_u128(0x1234);
+        let mut item = NbtCompound::new();
+        item.put("Slot".to_string(), NbtTag::Byte(3));
+        item.put("id".to_string(), "minecraft:stone");
+        item.put("count".to_string(), NbtTag::Int(12));
+        let mut player = NbtCompound::new();
+        player.put(
+            "Pos".to_string(),
+            NbtTag::List(vec![
+                NbtTag::Double(0.5),
+                NbtTag::Double(64.0),
+                NbtTag::Double(-3.5),
+            ]),
+        );
+        // Would be adventure if cut down to a byte.
+        player.put("playerGameType".to_string(), NbtTag::Int(258));
+        player.put(
+            "Inventory".to_string(),
+            NbtTag::List(vec![NbtTag::Compound(item)]),
+        );
+        Nbt::from(player)
+            .write_file(
+                dir.join(format!("playerdata/{uuid}.dat")),
+                Compression::Gzip,
+            )
+            .unwrap();
+
+        let world = WorldFolder::open(&dir).unwrap();
+        assert_eq!(world.level.level_name, "Test");
+        assert_eq!(world.level.seed, -42);
+        assert_eq!(world.level.spawn.y, 70);
+        assert_eq!(world.level.game_mode, GameMode::Creative);
+        assert_eq!(world.level.game_rules["keepInventory"], "true");
+
+        assert!(world.regions(Dimension::Overworld).unwrap().is_empty());
+        assert_eq!(world.regions(Dimension::Nether).unwrap().len(), 1);
+
+        assert_eq!(world.players().unwrap(), vec![uuid]);
+        let player = world.read_player(uuid).unwrap().unwrap();
+        assert_eq!(player.position.z, -3.5);
+        assert_eq!(player.game_mode, GameMode::Survival);
+        assert_eq!(player.inventory[0].count, 12);
+        assert!(world.read_player(Uuid::nil()).unwrap().is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
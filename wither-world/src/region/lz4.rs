@@ -0,0 +1,160 @@
+//! The block stream of lz4-java (`LZ4BlockOutputStream`), which vanilla uses for LZ4
+//! compressed chunks. This is not the LZ4 frame format.
+
+use super::RegionError;
+
+const MAGIC: &[u8; 8] = b"LZ4Block";
+const HEADER_SIZE: usize = MAGIC.len() + 1 + 4 + 4 + 4;
+const METHOD_RAW: u8 = 0x10;
+const METHOD_LZ4: u8 = 0x20;
+/// Blocks are at most `1 << (10 + level)` bytes, vanilla uses the 64 KiB default.
+const COMPRESSION_LEVEL: u8 = 6;
+const BLOCK_SIZE: usize = 1 << (10 + COMPRESSION_LEVEL);
+const CHECKSUM_SEED: u32 = 0x9747b28c;
+
+pub(crate) fn decompress(mut input: &[u8]) -> Result<Vec<u8>, RegionError> {
+    let mut output = Vec::new();
+    while !input.is_empty() {
+        if input.len() < HEADER_SIZE || &input[..MAGIC.len()] != MAGIC {
+            return Err(RegionError::InvalidLz4);
+        }
+        let token = input[MAGIC.len()];
+        let int = |offset: usize| {
+            let start = MAGIC.len() + 1 + offset;
+            u32::from_le_bytes(input[start..start + 4].try_into().unwrap())
+        };
+        let (compressed_len, len, checksum) = (int(0) as usize, int(4) as usize, int(8));
+        input = &input[HEADER_SIZE..];
+
+        if len == 0 {
+            // Written when the stream is closed.
+            break;
+        }
+        if len > 1 << (10 + (token & 0x0F)) || input.len() < compressed_len {
+            return Err(RegionError::InvalidLz4);
+        }
+        let block = match token & 0xF0 {
+            METHOD_RAW if compressed_len == len => input[..len].to_vec(),
+            METHOD_LZ4 => lz4::block::decompress(&input[..compressed_len], Some(len as i32))
+                .map_err(|_| RegionError::InvalidLz4)?,
+            _ => return Err(RegionError::InvalidLz4),
+        };
+        if block.len() != len || xxhash32(&block, CHECKSUM_SEED) & 0x0FFFFFFF != checksum {
+            return Err(RegionError::InvalidLz4);
+        }
+        output.extend_from_slice(&block);
+        input = &input[compressed_len..];
+    }
+    Ok(output)
+}
+
+pub(crate) fn compress(input: &[u8]) -> Result<Vec<u8>, RegionError> {
+    let mut output = Vec::new();
+    for block in input.chunks(BLOCK_SIZE) {
+        let compressed = lz4::block::compress(block, None, false)?;
+        let checksum = xxhash32(block, CHECKSUM_SEED) & 0x0FFFFFFF;
+        // Like lz4-java, incompressible blocks are stored as they are.
+        let (method, data) = if compressed.len() < block.len() {
+            (METHOD_LZ4, &compressed[..])
+        } else {
+            (METHOD_RAW, block)
+        };
+        write_header(&mut output, method, data.len(), block.len(), checksum);
+        output.extend_from_slice(data);
+    }
+    write_header(&mut output, METHOD_RAW, 0, 0, 0);
+    Ok(output)
+}
+
+fn write_header(
+    output: &mut Vec<u8>,
+    method: u8,
+    compressed_len: usize,
+    len: usize,
+    checksum: u32,
+) {
+    output.extend_from_slice(MAGIC);
+    output.push(method | COMPRESSION_LEVEL);
+    output.extend_from_slice(&(compressed_len as u32).to_le_bytes());
+    output.extend_from_slice(&(len as u32).to_le_bytes());
+    output.extend_from_slice(&checksum.to_le_bytes());
+}
+
+const PRIME_1: u32 = 2654435761;
+const PRIME_2: u32 = 2246822519;
+const PRIME_3: u32 = 3266489917;
+const PRIME_4: u32 = 668265263;
+const PRIME_5: u32 = 374761393;
+
+fn xxhash32(data: &[u8], seed: u32) -> u32 {
+    let word = |bytes: &[u8]| u32::from_le_bytes(bytes[..4].try_into().unwrap());
+    let round = |acc: u32, input: u32| {
+        acc.wrapping_add(input.wrapping_mul(PRIME_2))
+            .rotate_left(13)
+            .wrapping_mul(PRIME_1)
+    };
+
+    let mut stripes = data.chunks_exact(16);
+    let mut hash = if data.len() >= 16 {
+        let mut acc = [
+            seed.wrapping_add(PRIME_1).wrapping_add(PRIME_2),
+            seed.wrapping_add(PRIME_2),
+            seed,
+            seed.wrapping_sub(PRIME_1),
+        ];
+        for stripe in &mut stripes {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = round(*acc, word(&stripe[i * 4..]));
+            }
+        }
+        acc[0]
+            .rotate_left(1)
+            .wrapping_add(acc[1].rotate_left(7))
+            .wrapping_add(acc[2].rotate_left(12))
+            .wrapping_add(acc[3].rotate_left(18))
+    } else {
+        seed.wrapping_add(PRIME_5)
+    };
+    hash = hash.wrapping_add(data.len() as u32);
+
+    let mut words = stripes.remainder().chunks_exact(4);
+    for bytes in &mut words {
+        hash = hash
+            .wrapping_add(word(bytes).wrapping_mul(PRIME_3))
+            .rotate_left(17)
+            .wrapping_mul(PRIME_4);
+    }
+    for byte in words.remainder() {
+        hash = hash
+            .wrapping_add((*byte as u32).wrapping_mul(PRIME_5))
+            .rotate_left(11)
+            .wrapping_mul(PRIME_1);
+    }
+
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(PRIME_2);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(PRIME_3);
+    hash ^ (hash >> 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compress, decompress, xxhash32};
+
+    #[test]
+    fn xxhash32_reference() {
+        assert_eq!(xxhash32(b"", 0), 0x02CC5D05);
+        assert_eq!(xxhash32(b"abc", 0), 0x32D153FF);
+        assert_eq!(
+            xxhash32(b"Nobody inspects the spammish repetition", 0),
+            0xE2293B2F
+        );
+    }
+
+    #[test]
+    fn block_stream_round_trip() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        assert_eq!(decompress(&compress(&data).unwrap()).unwrap(), data);
+    }
+}
@@ -0,0 +1,467 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use flate2::{
+    read::{GzDecoder, ZlibDecoder},
+    write::{GzEncoder, ZlibEncoder},
+    Compression,
+};
+use thiserror::Error;
+use wither_nbt::{compound::NbtCompound, Nbt, COMPOUND_ID};
+use wither_util::math::vector2::Vector2;
+
+use crate::chunk::{ChunkData, ChunkNbtError};
+
+mod lz4;
+
+pub const SECTOR_SIZE: usize = 4096;
+/// Chunks per side of a region.
+pub const REGION_SIZE: i32 = 32;
+const CHUNK_COUNT: usize = (REGION_SIZE * REGION_SIZE) as usize;
+/// Locations and timestamps, one sector each.
+const HEADER_SECTORS: u32 = 2;
+/// Set in the compression byte when the chunk is stored in a `.mcc` file next to the region.
+const EXTERNAL_FLAG: u8 = 0x80;
+/// Chunks are at most this many sectors, bigger ones go to a `.mcc` file.
+const MAX_CHUNK_SECTORS: usize = 255;
+
+#[derive(Error, Debug)]
+pub enum RegionError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid region file name {0}")]
+    InvalidFileName(String),
+    #[error("Unknown compression scheme {0}")]
+    UnknownCompression(u8),
+    #[error("Chunk at sector {0} is malformed")]
+    MalformedChunk(u32),
+    #[error("Invalid LZ4 block stream")]
+    InvalidLz4,
+    #[error("Chunk is not valid NBT: {0}")]
+    Nbt(#[from] wither_nbt::Error),
+    #[error("Chunk NBT does not match the chunk format: {0}")]
+    ChunkNbt(#[from] ChunkNbtError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CompressionScheme {
+    Gzip = 1,
+    Zlib = 2,
+    None = 3,
+    Lz4 = 4,
+}
+
+impl TryFrom<u8> for CompressionScheme {
+    type Error = RegionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Gzip),
+            2 => Ok(Self::Zlib),
+            3 => Ok(Self::None),
+            4 => Ok(Self::Lz4),
+            _ => Err(RegionError::UnknownCompression(value)),
+        }
+    }
+}
+
+impl CompressionScheme {
+    pub fn compress(self, data: &[u8]) -> Result<Vec<u8>, RegionError> {
+        Ok(match self {
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()?
+            }
+            Self::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()?
+            }
+            Self::None => data.to_vec(),
+            Self::Lz4 => lz4::compress(data)?,
+        })
+    }
+
+    pub fn decompress(self, data: &[u8]) -> Result<Vec<u8>, RegionError> {
+        let mut output = Vec::new();
+        match self {
+            Self::Gzip => {
+                GzDecoder::new(data).read_to_end(&mut output)?;
+            }
+            Self::Zlib => {
+                ZlibDecoder::new(data).read_to_end(&mut output)?;
+            }
+            Self::None => output.extend_from_slice(data),
+            Self::Lz4 => output = lz4::decompress(data)?,
+        }
+        Ok(output)
+    }
+}
+
+/// Where a chunk is stored in a region file, in sectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkLocation {
+    pub offset: u32,
+    pub sectors: u8,
+}
+
+impl ChunkLocation {
+    fn from_raw(raw: u32) -> Option<Self> {
+        (raw != 0).then_some(Self {
+            offset: raw >> 8,
+            sectors: raw as u8,
+        })
+    }
+
+    fn to_raw(self) -> u32 {
+        (self.offset << 8) | self.sectors as u32
+    }
+}
+
+/// An Anvil region file (`r.<x>.<z>.mca`), holding 32x32 chunks.
+pub struct RegionFile {
+    path: PathBuf,
+    position: Vector2<i32>,
+    /// Locked while reading a chunk so the seek and the reads after it stay together.
+    file: Mutex<File>,
+    locations: Box<[u32]>,
+    timestamps: Box<[u32]>,
+}
+
+impl RegionFile {
+    /// Name of the region file holding `chunk`.
+    pub fn file_name(chunk: Vector2<i32>) -> String {
+        format!(
+            "r.{}.{}.mca",
+            chunk.x.div_euclid(REGION_SIZE),
+            chunk.z.div_euclid(REGION_SIZE)
+        )
+    }
+
+    /// Opens an existing region file for reading.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RegionError> {
+        let file = File::open(path.as_ref())?;
+        Self::from_file(path.as_ref(), file)
+    }
+
+    /// Opens a region file for reading and writing, creating it if needed.
+    pub fn open_writable(path: impl AsRef<Path>) -> Result<Self, RegionError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path.as_ref())?;
+        if file.metadata()?.len() < (HEADER_SECTORS as usize * SECTOR_SIZE) as u64 {
+            (&file).write_all(&[0; HEADER_SECTORS as usize * SECTOR_SIZE])?;
+        }
+        Self::from_file(path.as_ref(), file)
+    }
+
+    fn from_file(path: &Path, mut file: File) -> Result<Self, RegionError> {
+        let position = parse_file_name(path)
+            .ok_or_else(|| RegionError::InvalidFileName(path.display().to_string()))?;
+
+        let mut header = vec![0; HEADER_SECTORS as usize * SECTOR_SIZE];
+        file.seek(SeekFrom::Start(0))?;
+        file.read_exact(&mut header)?;
+        let mut ints = header
+            .chunks_exact(4)
+            .map(|int| u32::from_be_bytes(int.try_into().unwrap()));
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            position,
+            file: Mutex::new(file),
+            locations: ints.by_ref().take(CHUNK_COUNT).collect(),
+            timestamps: ints.collect(),
+        })
+    }
+
+    /// Position of the region, in regions.
+    pub fn position(&self) -> Vector2<i32> {
+        self.position
+    }
+
+    /// All chunks stored in this region, in chunk coordinates.
+    pub fn chunks(&self) -> impl Iterator<Item = Vector2<i32>> + '_ {
+        (0..CHUNK_COUNT)
+            .filter(|index| self.locations[*index] != 0)
+            .map(|index| self.chunk_position(index))
+    }
+
+    /// `chunk` is in chunk coordinates, only its position inside the region is used.
+    pub fn location(&self, chunk: Vector2<i32>) -> Option<ChunkLocation> {
+        ChunkLocation::from_raw(self.locations[chunk_index(chunk)])
+    }
+
+    pub fn has_chunk(&self, chunk: Vector2<i32>) -> bool {
+        self.location(chunk).is_some()
+    }
+
+    /// When the chunk was last written, in seconds since the Unix epoch.
+    pub fn timestamp(&self, chunk: Vector2<i32>) -> Option<u32> {
+        self.location(chunk)
+            .map(|_| self.timestamps[chunk_index(chunk)])
+    }
+
+    /// Reads the NBT of a chunk, `None` if it was never saved.
+    pub fn read_chunk_nbt(&self, chunk: Vector2<i32>) -> Result<Option<NbtCompound>, RegionError> {
+        let Some(location) = self.location(chunk) else {
+            return Ok(None);
+        };
+        let malformed = || RegionError::MalformedChunk(location.offset);
+
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(location.offset as u64 * SECTOR_SIZE as u64))?;
+        let mut header = [0; 5];
+        file.read_exact(&mut header)?;
+        let length = u32::from_be_bytes(header[..4].try_into().unwrap()) as usize;
+        if length == 0 || length + 4 > location.sectors as usize * SECTOR_SIZE {
+            return Err(malformed());
+        }
+
+        let data = if header[4] & EXTERNAL_FLAG != 0 {
+            std::fs::read(self.external_path(chunk))?
+        } else {
+            let mut data = vec![0; length - 1];
+            file.read_exact(&mut data)?;
+            data
+        };
+        drop(file);
+        let data = CompressionScheme::try_from(header[4] & !EXTERNAL_FLAG)?.decompress(&data)?;
+        if data.first() != Some(&COMPOUND_ID) {
+            return Err(malformed());
+        }
+        Ok(Some(Nbt::read(&mut &data[..])?.root_tag))
+    }
+
+    pub fn read_chunk(&self, chunk: Vector2<i32>) -> Result<Option<ChunkData>, RegionError> {
+        match self.read_chunk_nbt(chunk)? {
+            Some(nbt) => Ok(Some(ChunkData::from_nbt(&nbt)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores the NBT of a chunk, chunks over 1 MiB compressed go to a `.mcc` file.
+    pub fn write_chunk_nbt(
+        &mut self,
+        chunk: Vector2<i32>,
+        nbt: &NbtCompound,
+        compression: CompressionScheme,
+    ) -> Result<(), RegionError> {
+        let mut raw = vec![COMPOUND_ID, 0, 0];
+        raw.extend_from_slice(&nbt.serialize_content());
+        let data = compression.compress(&raw)?;
+
+        let external_path = self.external_path(chunk);
+        let mut payload = Vec::new();
+        if (data.len() + 5).div_ceil(SECTOR_SIZE) > MAX_CHUNK_SECTORS {
+            std::fs::write(&external_path, &data)?;
+            payload.extend_from_slice(&1u32.to_be_bytes());
+            payload.push(compression as u8 | EXTERNAL_FLAG);
+        } else {
+            payload.extend_from_slice(&(data.len() as u32 + 1).to_be_bytes());
+            payload.push(compression as u8);
+            payload.extend_from_slice(&data);
+            remove_if_exists(&external_path)?;
+        }
+        let sectors = payload.len().div_ceil(SECTOR_SIZE);
+        payload.resize(sectors * SECTOR_SIZE, 0);
+
+        let index = chunk_index(chunk);
+        let offset = self.allocate(index, sectors as u32);
+        let file = self.file.get_mut().unwrap();
+        file.seek(SeekFrom::Start(offset as u64 * SECTOR_SIZE as u64))?;
+        file.write_all(&payload)?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs() as u32);
+        let location = ChunkLocation {
+            offset,
+            sectors: sectors as u8,
+        };
+        self.write_header(index, location.to_raw(), timestamp)
+    }
+
+    pub fn write_chunk(
+        &mut self,
+        chunk: &ChunkData,
+        compression: CompressionScheme,
+    ) -> Result<(), RegionError> {
+        self.write_chunk_nbt(chunk.position, &chunk.to_nbt(), compression)
+    }
+
+    /// Forgets a chunk, the game generates it again the next time it is loaded.
+    pub fn remove_chunk(&mut self, chunk: Vector2<i32>) -> Result<(), RegionError> {
+        remove_if_exists(&self.external_path(chunk))?;
+        self.write_header(chunk_index(chunk), 0, 0)
+    }
+
+    /// Finds `sectors` free sectors for the chunk at `index`, the sectors it uses now
+    /// count as free.
+    fn allocate(&self, index: usize, sectors: u32) -> u32 {
+        let mut used: Vec<(u32, u32)> = self
+            .locations
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .filter_map(|(_, raw)| ChunkLocation::from_raw(*raw))
+            .map(|location| (location.offset, location.offset + location.sectors as u32))
+            .collect();
+        used.sort_unstable();
+
+        let mut start = HEADER_SECTORS;
+        for (used_start, used_end) in used {
+            if used_start >= start + sectors {
+                break;
+            }
+            start = start.max(used_end);
+        }
+        start
+    }
+
+    fn write_header(
+        &mut self,
+        index: usize,
+        location: u32,
+        timestamp: u32,
+    ) -> Result<(), RegionError> {
+        self.locations[index] = location;
+        self.timestamps[index] = timestamp;
+        let file = self.file.get_mut().unwrap();
+        file.seek(SeekFrom::Start(index as u64 * 4))?;
+        file.write_all(&location.to_be_bytes())?;
+        file.seek(SeekFrom::Start((SECTOR_SIZE + index * 4) as u64))?;
+        file.write_all(&timestamp.to_be_bytes())?;
+        Ok(())
+    }
+
+    fn chunk_position(&self, index: usize) -> Vector2<i32> {
+        Vector2::new(
+            self.position.x * REGION_SIZE + index as i32 % REGION_SIZE,
+            self.position.z * REGION_SIZE + index as i32 / REGION_SIZE,
+        )
+    }
+
+    fn external_path(&self, chunk: Vector2<i32>) -> PathBuf {
+        let chunk = self.chunk_position(chunk_index(chunk));
+        self.path
+            .with_file_name(format!("c.{}.{}.mcc", chunk.x, chunk.z))
+    }
+}
+
+fn chunk_index(chunk: Vector2<i32>) -> usize {
+    (chunk.x.rem_euclid(REGION_SIZE) + chunk.z.rem_euclid(REGION_SIZE) * REGION_SIZE) as usize
+}
+
+/// Parses `r.<x>.<z>.mca`.
+fn parse_file_name(path: &Path) -> Option<Vector2<i32>> {
+    let name = path.file_name()?.to_str()?;
+    let mut parts = name.strip_prefix("r.")?.strip_suffix(".mca")?.split('.');
+    let position = Vector2::new(parts.next()?.parse().ok()?, parts.next()?.parse().ok()?);
+    parts.next().is_none().then_some(position)
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wither_nbt::tag::NbtTag;
+    use wither_util::math::vector2::Vector2;
+
+    use super::{CompressionScheme, RegionFile};
+    use crate::{block::BlockState, chunk::ChunkData, coordinates::ChunkRelativeBlockCoordinates};
+
+    #[test]
+    fn write_and_read_chunks() {
+        let dir = std::env::temp_dir().join(format!("wither-region-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("r.-1.0.mca");
+
+        let mut chunk = ChunkData::empty(Vector2::new(-3, 5), -64, 24);
+        let position = ChunkRelativeBlockCoordinates {
+            x: 1u8.into(),
+            y: 70i16.into(),
+            z: 2u8.into(),
+        };
+        chunk.set_block_state(position, BlockState::new("oak_log").unwrap().state_id);
+        chunk.set_biome(position, 4);
+
+        let mut region = RegionFile::open_writable(&path).unwrap();
+        for (x, compression) in [
+            CompressionScheme::Gzip,
+            CompressionScheme::Zlib,
+            CompressionScheme::None,
+            CompressionScheme::Lz4,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            chunk.position = Vector2::new(-32 + x as i32, 5);
+            region.write_chunk(&chunk, compression).unwrap();
+        }
+
+        // Too big for the region, ends up in a .mcc file.
+        let mut big = chunk.to_nbt();
+        let noise: Vec<i64> = (0..200_000i64)
+            .map(|i| i.wrapping_mul(0x5DEECE66D) ^ (i << 29))
+            .collect();
+        big.put("noise".to_string(), NbtTag::LongArray(noise));
+        region
+            .write_chunk_nbt(Vector2::new(-1, 31), &big, CompressionScheme::Zlib)
+            .unwrap();
+        assert!(dir.join("c.-1.31.mcc").exists());
+
+        let region = RegionFile::open(&path).unwrap();
+        assert_eq!(region.position(), Vector2::new(-1, 0));
+        assert_eq!(region.chunks().count(), 5);
+        for x in 0..4 {
+            chunk.position = Vector2::new(-32 + x, 5);
+            assert!(region.timestamp(chunk.position).is_some());
+            let read = region.read_chunk(chunk.position).unwrap().unwrap();
+            assert_eq!(read.to_nbt(), chunk.to_nbt());
+        }
+        assert_eq!(
+            region
+                .read_chunk_nbt(Vector2::new(-1, 31))
+                .unwrap()
+                .unwrap(),
+            big
+        );
+        assert!(region.read_chunk(Vector2::new(-2, 0)).unwrap().is_none());
+
+        // Reads from several threads share the file without mixing up their seeks.
+        let positions: Vec<_> = (0..4).map(|x| Vector2::new(-32 + x, 5)).collect();
+        let expected: Vec<_> = positions
+            .iter()
+            .map(|&position| region.read_chunk_nbt(position).unwrap())
+            .collect();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..16 {
+                        for (&position, expected) in positions.iter().zip(&expected) {
+                            assert_eq!(&region.read_chunk_nbt(position).unwrap(), expected);
+                        }
+                    }
+                });
+            }
+        });
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}